@@ -1,77 +1,114 @@
+use std::{env, fs};
+
+/// Frameworks (or dylibs) to link, paired with the target operating systems that ship them.
+const APPLE_LIBRARIES: &[(&str, &str, &[&str])] = &[
+    ("framework", "UIKit", &["ios", "tvos", "watchos"]),
+    ("framework", "AppKit", &["macos"]),
+    (
+        "framework",
+        "UserNotifications",
+        &["ios", "macos", "tvos", "watchos"],
+    ),
+    (
+        "framework",
+        "Foundation",
+        &["ios", "macos", "tvos", "watchos"],
+    ),
+    ("dylib", "Compression", &["ios", "macos", "tvos", "watchos"]),
+    (
+        "framework",
+        "NaturalLanguage",
+        &["ios", "macos", "tvos", "watchos"],
+    ),
+    ("framework", "BackgroundTasks", &["ios", "tvos"]),
+    (
+        "framework",
+        "CoreGraphics",
+        &["ios", "macos", "tvos", "watchos"],
+    ),
+    (
+        "framework",
+        "CoreFoundation",
+        &["ios", "macos", "tvos", "watchos"],
+    ),
+    ("framework", "Contacts", &["ios", "macos", "watchos"]),
+    ("framework", "CoreML", &["ios", "macos", "tvos", "watchos"]),
+    (
+        "framework",
+        "CoreLocation",
+        &["ios", "macos", "tvos", "watchos"],
+    ),
+];
+
 fn main() {
-    #[cfg(any(target_os = "ios", target_os = "tvos", target_os = "watchos"))]
-    println!("cargo:rustc-link-lib=framework=UIKit");
-
-    #[cfg(target_os = "macos")]
-    println!("cargo:rustc-link-lib=framework=AppKit");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=UserNotifications");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=Foundation");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=dylib=Compression");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=NaturalLanguage");
-
-    #[cfg(any(target_os = "ios", target_os = "tvos"))]
-    println!("cargo:rustc-link-lib=framework=BackgroundTasks");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=CoreGraphics");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=CoreFoundation");
-
-    #[cfg(any(target_os = "ios", target_os = "macos", target_os = "watchos"))]
-    println!("cargo:rustc-link-lib=framework=Contacts");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=CoreML");
-
-    #[cfg(any(
-        target_os = "ios",
-        target_os = "macos",
-        target_os = "tvos",
-        target_os = "watchos"
-    ))]
-    println!("cargo:rustc-link-lib=framework=CoreLocation");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // `cfg!` in a build script describes the host, so the target has to be read
+    // from the environment Cargo provides.
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap_or_default();
+
+    if target_vendor != "apple" {
+        if env::var_os("CARGO_FEATURE_TEST_RUNTIME").is_some() {
+            link_test_runtime();
+        } else if env::var_os("CARGO_FEATURE_GNUSTEP").is_some() {
+            link_gnustep();
+            compile_exception_shim(&["-fobjc-runtime=gnustep-2.0"]);
+        }
+
+        return;
+    }
+
+    compile_exception_shim(&[]);
+
+    for (kind, name, target_oses) in APPLE_LIBRARIES {
+        if target_oses.contains(&target_os.as_str()) {
+            println!("cargo:rustc-link-lib={kind}={name}");
+        }
+    }
+}
+
+/// Links libobjc2 and gnustep-base in place of the Apple frameworks.
+fn link_gnustep() {
+    println!("cargo:rerun-if-env-changed=GNUSTEP_LIB_DIR");
+
+    // `gnustep-config --variable=GNUSTEP_SYSTEM_LIBRARIES` prints this directory
+    // on installations that are not in the default linker search path.
+    if let Some(lib_dir) = env::var_os("GNUSTEP_LIB_DIR") {
+        println!(
+            "cargo:rustc-link-search=native={}",
+            lib_dir.to_string_lossy()
+        );
+    }
+
+    println!("cargo:rustc-link-lib=dylib=objc");
+    println!("cargo:rustc-link-lib=dylib=gnustep-base");
+}
+
+/// Satisfies the libraries `objc` and `block` link against with empty archives, as
+/// `test_runtime` defines the functions that are called.
+fn link_test_runtime() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    for library in ["objc", "BlocksRuntime"] {
+        fs::write(format!("{out_dir}/lib{library}.a"), "!<arch>\n").unwrap();
+    }
+
+    println!("cargo:rustc-link-search=native={out_dir}");
+}
+
+/// Compiles the `@try`/`@catch` wrapper behind `objective_c_runtime::catch_exception`.
+fn compile_exception_shim(flags: &[&str]) {
+    const SHIM: &str = "src/objective_c_runtime/exception.m";
+
+    println!("cargo:rerun-if-changed={SHIM}");
+
+    let mut build = cc::Build::new();
+    build.file(SHIM).flag("-fobjc-exceptions");
+
+    for flag in flags {
+        build.flag_if_supported(flag);
+    }
+
+    build.compile("rust_macios_exception");
 }
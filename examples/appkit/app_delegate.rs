@@ -1,28 +1,38 @@
-use rust_macios::appkit::{
-    ns_application_main, INSApplication, NSApplication, NSApplicationActivationPolicy,
-    PNSApplicationDelegate,
+#[cfg(target_os = "macos")]
+use std::cell::RefCell;
+
+#[cfg(target_os = "macos")]
+use rust_macios::{
+    appkit::{ns_application_main, PNSApplicationDelegate},
+    objective_c_runtime::protocol_impl,
 };
 
+#[cfg(target_os = "macos")]
 #[derive(Default, Clone)]
 struct AppDelegate {
-    name: String,
+    name: RefCell<String>,
 }
 
+#[cfg(target_os = "macos")]
 unsafe impl Sync for AppDelegate {}
 
+#[cfg(target_os = "macos")]
 unsafe impl Send for AppDelegate {}
 
+#[cfg(target_os = "macos")]
+#[protocol_impl]
 impl PNSApplicationDelegate for AppDelegate {
-    fn did_finish_launching(&mut self) {
-        self.name = "Hello".to_string();
-        println!("Done Launching. \n{}", self.name);
+    fn did_finish_launching(&self) {
+        *self.name.borrow_mut() = "Hello".to_string();
+        println!("Done Launching. \n{}", self.name.borrow());
     }
 }
 
-#[ns_application_main]
-fn main() {
-    let mut app = NSApplication::shared_application();
+#[cfg(target_os = "macos")]
+#[ns_application_main(delegate = AppDelegate, activation_policy = Regular, menu = default)]
+fn main() {}
 
-    app.p_set_delegate(AppDelegate::default());
-    app.set_activation_policy(NSApplicationActivationPolicy::Regular);
+#[cfg(not(target_os = "macos"))]
+fn main() {
+    eprintln!("This example requires macOS.");
 }
@@ -1,24 +1,29 @@
+#[cfg(target_os = "macos")]
+use std::cell::RefCell;
+
+#[cfg(target_os = "macos")]
 use rust_macios::{
     appkit::{
         ns_application_main, INSApplication, INSLayoutAnchor, INSResponder, INSView,
-        INSViewController, INSWindow, NSApplication, NSApplicationActivationPolicy,
-        NSLayoutConstraint, NSTextField, NSView, NSWindow, PNSApplicationDelegate,
+        INSViewController, INSWindow, MainThreadMarker, NSApplication, NSLayoutConstraint,
+        NSTextField, NSView, NSWindow, PNSApplicationDelegate,
     },
     foundation::{NSPoint, NSRect, NSSize, NSString},
     nsarray,
     objective_c_runtime::{
-        class_init, id, msg_send, nil, objc_sel, register_class, runtime::Object, sel, sel_impl,
-        traits::PNSObject, Id, ShareId,
+        autoreleasepool, msg_send, nil, objc_sel, protocol_impl, register_class, runtime::Object,
+        sel, sel_impl, traits::PNSObject, Id, ShareId,
     },
 };
 
+#[cfg(target_os = "macos")]
 pub struct ViewController {
     pub ptr: ShareId<Object>,
 }
 
+#[cfg(target_os = "macos")]
 #[register_class(NSViewController)]
 impl ViewController {
-    #[class_init]
     fn init() -> Self {
         Self {
             ptr: unsafe { Id::from_ptr(msg_send![Self::m_class(), new]) },
@@ -27,17 +32,24 @@ impl ViewController {
 
     #[objc_sel("viewDidLoad")]
     pub fn view_did_load(&self) {
+        let mtm = MainThreadMarker::new().expect("views load on the main thread");
+
         // 1: Create a view
-        self.p_set_view(NSView::init_with_frame(NSRect {
-            origin: NSPoint { x: 0.0, y: 0.0 },
-            size: NSSize {
-                width: 500.0,
-                height: 300.0,
+        self.p_set_view(NSView::init_with_frame(
+            NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize {
+                    width: 500.0,
+                    height: 300.0,
+                },
             },
-        }));
+            mtm,
+        ));
 
         // 2: Create a label
-        let label = NSTextField::label_with_string(NSString::from("Hello World!"));
+        let label = autoreleasepool(|pool| {
+            NSTextField::label_with_string(NSString::from("Hello World!"), mtm, pool).retain()
+        });
 
         label.p_set_translates_autoresizing_mask_to_constraints(false);
         let view = self.p_view();
@@ -56,46 +68,56 @@ impl ViewController {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl INSResponder for ViewController {}
 
+#[cfg(target_os = "macos")]
 impl INSViewController for ViewController {}
 
+#[cfg(target_os = "macos")]
 #[derive(Default)]
 struct AppDelegate {
-    window: Option<NSWindow>,
+    window: RefCell<Option<NSWindow>>,
 }
 
+#[cfg(target_os = "macos")]
 impl AppDelegate {}
 
+#[cfg(target_os = "macos")]
+#[protocol_impl]
 impl PNSApplicationDelegate for AppDelegate {
-    fn did_finish_launching(&mut self) {
+    fn did_finish_launching(&self) {
+        let mtm = MainThreadMarker::new().expect("apps finish launching on the main thread");
+
         let view_controller = ViewController::init();
         view_controller.view_did_load();
 
-        let window = NSWindow::tm_window_with_content_view_controller(view_controller);
+        let window = autoreleasepool(|pool| {
+            NSWindow::tm_window_with_content_view_controller(view_controller, mtm, pool).retain()
+        });
 
-        self.window = Some(window);
+        window.set_minimum_content_size(NSSize {
+            width: 200.0,
+            height: 200.0,
+        });
+        window.set_title("Hello World!");
+        window.make_key_and_order_front(nil);
 
-        if let Some(window) = &mut self.window {
-            window.set_minimum_content_size(NSSize {
-                width: 200.0,
-                height: 200.0,
-            });
-            window.set_title("Hello World!");
-            window.make_key_and_order_front(nil);
-        }
+        *self.window.borrow_mut() = Some(window);
     }
 
-    fn should_terminate_after_last_window_closed(&mut self) -> bool {
+    fn should_terminate_after_last_window_closed(&self) -> bool {
         true
     }
 }
 
-#[ns_application_main]
-fn main() {
-    let mut app = NSApplication::shared_application();
+#[cfg(target_os = "macos")]
+#[ns_application_main(delegate = AppDelegate, activation_policy = Regular, menu = default)]
+fn main(mtm: MainThreadMarker) {
+    NSApplication::shared_application(mtm).m_activate_ignoring_other_apps(true);
+}
 
-    app.p_set_delegate(AppDelegate::default());
-    app.set_activation_policy(NSApplicationActivationPolicy::Regular);
-    app.m_activate_ignoring_other_apps(true);
+#[cfg(not(target_os = "macos"))]
+fn main() {
+    eprintln!("This example requires macOS.");
 }
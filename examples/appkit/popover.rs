@@ -1,27 +1,29 @@
-use std::sync::{Arc, Mutex};
+#[cfg(target_os = "macos")]
+use std::{cell::RefCell, rc::Rc};
 
+#[cfg(target_os = "macos")]
 use rust_macios::{
     appkit::{
         ns_application_main, INSApplication, INSButton, INSLayoutAnchor, INSResponder, INSView,
-        INSViewController, NSApplication, NSApplicationActivationPolicy, NSLayoutConstraint,
-        NSPopover, NSPopoverBehavior, NSStatusBar, NSStatusItem, NSTextField, NSView,
-        PNSApplicationDelegate,
+        INSViewController, MainThreadMarker, NSApplication, NSLayoutConstraint, NSPopover,
+        NSPopoverBehavior, NSStatusBar, NSStatusItem, NSTextField, NSView, PNSApplicationDelegate,
     },
     foundation::{NSPoint, NSRect, NSRectEdge, NSSize, NSString},
     nsarray,
     objective_c_runtime::{
-        class_init, id, msg_send, nil, objc_sel, register_class, runtime::Object, sel, sel_impl,
-        traits::PNSObject, Id, ShareId,
+        autoreleasepool, id, msg_send, nil, objc_sel, protocol_impl, register_class,
+        runtime::Object, sel, sel_impl, traits::PNSObject, Id, ShareId,
     },
 };
 
+#[cfg(target_os = "macos")]
 pub struct ViewController {
     pub ptr: ShareId<Object>,
 }
 
+#[cfg(target_os = "macos")]
 #[register_class(NSViewController)]
 impl ViewController {
-    #[class_init]
     fn init() -> Self {
         Self {
             ptr: unsafe { Id::from_ptr(msg_send![Self::m_class(), new]) },
@@ -30,18 +32,29 @@ impl ViewController {
 
     #[objc_sel("viewDidLoad")]
     pub fn view_did_load(&self) {
+        let mtm = MainThreadMarker::new().expect("views load on the main thread");
+
         // 1: Create a view
-        self.p_set_view(NSView::init_with_frame(NSRect {
-            origin: NSPoint { x: 0.0, y: 0.0 },
-            size: NSSize {
-                width: 300.0,
-                height: 300.0,
+        self.p_set_view(NSView::init_with_frame(
+            NSRect {
+                origin: NSPoint { x: 0.0, y: 0.0 },
+                size: NSSize {
+                    width: 300.0,
+                    height: 300.0,
+                },
             },
-        }));
+            mtm,
+        ));
 
         // 2: Create a label
-        let label =
-            NSTextField::label_with_string(NSString::from("NSViewController without Storyboard"));
+        let label = autoreleasepool(|pool| {
+            NSTextField::label_with_string(
+                NSString::from("NSViewController without Storyboard"),
+                mtm,
+                pool,
+            )
+            .retain()
+        });
 
         label.p_set_translates_autoresizing_mask_to_constraints(false);
         let view = self.p_view();
@@ -60,19 +73,30 @@ impl ViewController {
     }
 }
 
+#[cfg(target_os = "macos")]
 impl INSResponder for ViewController {}
 
+#[cfg(target_os = "macos")]
 impl INSViewController for ViewController {}
 
-#[derive(Default, Clone)]
+#[cfg(target_os = "macos")]
+#[derive(Clone)]
 struct AppDelegate {
-    status_bar_item: NSStatusItem,
+    status_bar_item: RefCell<NSStatusItem>,
     popover: NSPopover,
 }
 
+#[cfg(target_os = "macos")]
 impl AppDelegate {
+    pub fn new(mtm: MainThreadMarker) -> Self {
+        Self {
+            status_bar_item: RefCell::new(NSStatusItem::new(mtm)),
+            popover: NSPopover::new(mtm),
+        }
+    }
+
     pub fn toggle_popover(&self, sender: id) {
-        if let Some(button) = self.status_bar_item.button() {
+        if let Some(button) = self.status_bar_item.borrow().button() {
             if self.popover.shown() {
                 self.popover.perform_close(sender)
             } else {
@@ -86,12 +110,12 @@ impl AppDelegate {
     }
 }
 
-unsafe impl Sync for AppDelegate {}
-
-unsafe impl Send for AppDelegate {}
-
+#[cfg(target_os = "macos")]
+#[protocol_impl]
 impl PNSApplicationDelegate for AppDelegate {
-    fn did_finish_launching(&mut self) {
+    fn did_finish_launching(&self) {
+        let mtm = MainThreadMarker::new().expect("apps finish launching on the main thread");
+
         // Create a popover
         self.popover.set_behavior(NSPopoverBehavior::Transient);
 
@@ -100,30 +124,30 @@ impl PNSApplicationDelegate for AppDelegate {
         view_controller.view_did_load();
         self.popover.set_content_view_controller(view_controller);
 
-        self.status_bar_item = NSStatusBar::system_status_bar()
+        *self.status_bar_item.borrow_mut() = NSStatusBar::system_status_bar(mtm)
             .status_item_with_length(NSStatusItem::NSVARIABLE_STATUS_ITEM_LENGTH);
 
-        if let Some(mut button) = self.status_bar_item.button() {
-            button.p_set_title("🦀".into());
-
-            let mutex = Arc::new(Mutex::new(self.clone()));
+        let button = self.status_bar_item.borrow().button();
 
-            let _mutex_cloned = mutex.clone();
+        if let Some(mut button) = button {
+            button.p_set_title("🦀".into());
 
-            let tmp_callback = Box::new(move || {
-                let mutex = mutex.lock().unwrap();
-                mutex.toggle_popover(nil);
-            });
+            let delegate = Rc::new(self.clone());
 
-            button.set_action(tmp_callback);
+            button.set_action(move || delegate.toggle_popover(nil));
         }
     }
 }
 
-#[ns_application_main]
-fn main() {
-    let mut app = NSApplication::shared_application();
+#[cfg(target_os = "macos")]
+#[ns_application_main(activation_policy = Regular)]
+fn main(mtm: MainThreadMarker) {
+    NSApplication::shared_application(mtm)
+        .p_set_delegate(AppDelegate::new(mtm))
+        .expect("the app delegate class is declared");
+}
 
-    app.p_set_delegate(AppDelegate::default());
-    app.set_activation_policy(NSApplicationActivationPolicy::Regular);
+#[cfg(not(target_os = "macos"))]
+fn main() {
+    eprintln!("This example requires macOS.");
 }
@@ -1,10 +1,13 @@
+#[cfg(target_vendor = "apple")]
 use std::env::temp_dir;
 
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     compression::{compression_algorithm, compression_decode_buffer, compression_encode_buffer},
     kernel::size_t,
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     // Create the Source Data
     let source_string = r#"
@@ -78,3 +81,8 @@ fn main() {
         String::from_utf8_lossy(&decoded_destination_buffer)
     );
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
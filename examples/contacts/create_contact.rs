@@ -1,3 +1,4 @@
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     contacts::{
         CNContactStore, CNLabelHome, CNLabelWork, CNLabeledValue, CNMutableContact,
@@ -8,6 +9,7 @@ use rust_macios::{
     objective_c_runtime::traits::PNSObject,
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() -> Result<(), NSError> {
     // Create a new mutable contact (read/write)
     let mut contact = CNMutableContact::m_new();
@@ -17,11 +19,11 @@ fn main() -> Result<(), NSError> {
     contact.set_family_name("Appleseed".into());
 
     // Add email addresses
-    let home_email = CNLabeledValue::<NSString>::labeled_value_with_label_value(
+    let home_email = CNLabeledValue::<NSString>::init_with_label_value(
         unsafe { &CNLabelHome },
         "john.appleseed@mac.com".into(),
     );
-    let work_email = CNLabeledValue::<NSString>::labeled_value_with_label_value(
+    let work_email = CNLabeledValue::<NSString>::init_with_label_value(
         unsafe { &CNLabelWork },
         "john.appleseed@apple.com".into(),
     );
@@ -36,7 +38,7 @@ fn main() -> Result<(), NSError> {
     work_address.set_state("CA".into());
     work_address.set_postal_code("95014".into());
 
-    contact.set_postal_addresses(nsarray![CNLabeledValue::labeled_value_with_label_value(
+    contact.set_postal_addresses(nsarray![CNLabeledValue::init_with_label_value(
         unsafe { &CNLabelWork.clone() },
         work_address,
     )]);
@@ -53,3 +55,8 @@ fn main() -> Result<(), NSError> {
 
     Ok(())
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
@@ -1,3 +1,4 @@
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     contacts::{
         CNContactFetchRequest, CNContactFormatter, CNContactFormatterStyle,
@@ -8,6 +9,7 @@ use rust_macios::{
     objective_c_runtime::traits::PNSObject,
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     // Create a new `CNContactStore` to fetch the contacts from the user's
     // contact database.
@@ -40,8 +42,7 @@ fn main() {
 
                 // Creates the fetch request we would be using to partial
                 // fetch data from the database
-                let request =
-                    CNContactFetchRequest::m_alloc().init_with_keys_to_fetch(keys_to_fetch);
+                let request = CNContactFetchRequest::init_with_keys_to_fetch(keys_to_fetch);
 
                 // We use this method becasue it's easiar to get an enumerator with it
                 let fetch_result = captured_store
@@ -59,3 +60,8 @@ fn main() {
         },
     );
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
@@ -1,7 +1,14 @@
+#[cfg(target_vendor = "apple")]
 use rust_macios::foundation::NSProcessInfo;
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     let thermal_state = NSProcessInfo::process_info().thermal_state();
 
     println!("{thermal_state:?}");
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
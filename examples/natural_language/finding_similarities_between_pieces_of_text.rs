@@ -1,8 +1,10 @@
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     foundation::NSString,
     natural_language::{English, NLDistanceType, NLEmbedding},
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     unsafe {
         // Find similar words
@@ -49,3 +51,8 @@ fn main() {
         }
     }
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
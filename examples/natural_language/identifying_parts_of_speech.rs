@@ -1,5 +1,7 @@
+#[cfg(target_vendor = "apple")]
 use std::ops::Range;
 
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     foundation::NSRange,
     natural_language::{
@@ -9,10 +11,11 @@ use rust_macios::{
     objective_c_runtime::{nil, traits::PNSObject},
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     unsafe {
         let text = "The ripe taste of cheese improves with age.";
-        let mut tagger = NLTagger::m_new().init_with_tag_schemes(nsarray![LexicalClass.clone()]);
+        let mut tagger = NLTagger::init_with_tag_schemes(nsarray![LexicalClass.clone()]);
         tagger.set_string(Some(text.into()));
 
         let options = [
@@ -34,3 +37,8 @@ fn main() {
         )
     }
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
@@ -1,5 +1,7 @@
+#[cfg(target_vendor = "apple")]
 use std::ops::Range;
 
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     foundation::NSRange,
     natural_language::{
@@ -11,11 +13,12 @@ use rust_macios::{
     objective_c_runtime::{nil, traits::PNSObject},
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     unsafe {
         let text = "The American Red Cross was established in Washington, D.C., by Clara Barton.";
 
-        let mut tagger = NLTagger::m_new().init_with_tag_schemes(nsarray![NameType.clone()]);
+        let mut tagger = NLTagger::init_with_tag_schemes(nsarray![NameType.clone()]);
         tagger.set_string(Some(text.into()));
 
         let options = [
@@ -60,3 +63,8 @@ fn main() {
             )
     }
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
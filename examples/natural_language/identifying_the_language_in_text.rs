@@ -1,3 +1,4 @@
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     natural_language::{
         English, French, Italian, NLLanguageRecognizer, Portuguese, Spanish, Swedish,
@@ -6,6 +7,7 @@ use rust_macios::{
     objective_c_runtime::traits::PNSObject,
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     // Create a language recognizer.
     let mut recognizer = NLLanguageRecognizer::m_new();
@@ -52,3 +54,8 @@ fn main() {
     // Process additional strings for language identification.
     recognizer.process_string(&"Este es un idioma diferente.".into());
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
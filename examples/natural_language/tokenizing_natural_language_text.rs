@@ -1,16 +1,19 @@
+#[cfg(target_vendor = "apple")]
 use std::ops::Range;
 
+#[cfg(target_vendor = "apple")]
 use rust_macios::{
     foundation::NSRange,
     natural_language::{NLTokenUnit, NLTokenizer},
     objective_c_runtime::traits::PNSObject,
 };
 
+#[cfg(target_vendor = "apple")]
 fn main() {
     let text = "All human beings are born free and equal in dignity and rights.\
                     They are endowed with reason and conscience and should act towards one another in a spirit of brotherhood.";
 
-    let mut tokenizer = NLTokenizer::m_new().init_with_unit(NLTokenUnit::Word);
+    let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
     tokenizer.set_string(text.into());
 
     tokenizer.enumerate_tokens_in_range_using_block(
@@ -21,3 +24,8 @@ fn main() {
         },
     )
 }
+
+#[cfg(not(target_vendor = "apple"))]
+fn main() {
+    eprintln!("This example requires an Apple target.");
+}
@@ -0,0 +1,168 @@
+//! The API description that bindings are generated from.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// The classes and enums of one module of the crate, such as `foundation`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Api {
+    /// The module of the crate that the bindings are generated into.
+    pub module: String,
+    /// The modules that declare types the generator can't place from their prefix, by type name.
+    #[serde(default)]
+    pub imports: BTreeMap<String, String>,
+    /// Types that are passed by value rather than as objects, besides the built-in ones.
+    #[serde(default)]
+    pub value_types: Vec<String>,
+    /// The enums, in the order they are generated in.
+    #[serde(default, rename = "enum")]
+    pub enums: Vec<Enum>,
+    /// The classes, in the order they are generated in.
+    #[serde(default, rename = "class")]
+    pub classes: Vec<Class>,
+}
+
+/// An integer enum, such as `NSURLBookmarkResolutionOptions`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Enum {
+    /// The name of the enum.
+    pub name: String,
+    /// The documentation of the enum.
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// The integer type that backs the enum.
+    #[serde(default = "default_repr")]
+    pub repr: String,
+    /// The platforms the enum is available on, or all of them if empty.
+    #[serde(default)]
+    pub platforms: Vec<String>,
+    /// The cases of the enum.
+    #[serde(rename = "variant")]
+    pub variants: Vec<Variant>,
+}
+
+/// A case of an [`Enum`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Variant {
+    /// The name of the case.
+    pub name: String,
+    /// The value of the case, as a Rust expression.
+    pub value: toml::Value,
+    /// The documentation of the case.
+    #[serde(default)]
+    pub doc: Option<String>,
+}
+
+/// An Objective-C class.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Class {
+    /// The name of the class.
+    pub name: String,
+    /// The superclass of the class.
+    #[serde(default = "default_superclass")]
+    pub superclass: String,
+    /// The documentation of the class.
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// The platforms the class is available on, or all of them if empty.
+    #[serde(default)]
+    pub platforms: Vec<String>,
+    /// The properties of the class.
+    #[serde(default, rename = "property")]
+    pub properties: Vec<Property>,
+    /// The methods of the class.
+    #[serde(default, rename = "method")]
+    pub methods: Vec<Method>,
+}
+
+/// A declared property of a [`Class`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Property {
+    /// The name of the property.
+    pub name: String,
+    /// The type of the property.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The documentation of the property.
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// The name of the getter, if it isn't the name of the property.
+    #[serde(default)]
+    pub getter: Option<String>,
+    /// Whether the property has no setter.
+    #[serde(default)]
+    pub readonly: bool,
+    /// Whether the property can be `nil`.
+    #[serde(default)]
+    pub nullable: bool,
+    /// Whether this is a class property.
+    #[serde(default)]
+    pub class: bool,
+    /// The topic the property is grouped under.
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// The platforms the property is available on, or all of the class's if empty.
+    #[serde(default)]
+    pub platforms: Vec<String>,
+}
+
+/// A method of a [`Class`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Method {
+    /// The selector of the method.
+    pub selector: String,
+    /// The name of the Rust method, if it isn't derived from the selector.
+    #[serde(default)]
+    pub rust_name: Option<String>,
+    /// The documentation of the method.
+    #[serde(default)]
+    pub doc: Option<String>,
+    /// The return type of the method.
+    #[serde(default = "default_returns")]
+    pub returns: String,
+    /// Whether the method can return `nil`.
+    #[serde(default)]
+    pub nullable: bool,
+    /// The arguments of the method, one for each part of the selector.
+    #[serde(default, rename = "arg")]
+    pub args: Vec<Argument>,
+    /// Whether this is a class method.
+    #[serde(default)]
+    pub class: bool,
+    /// The topic the method is grouped under.
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// The platforms the method is available on, or all of the class's if empty.
+    #[serde(default)]
+    pub platforms: Vec<String>,
+}
+
+/// An argument of a [`Method`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Argument {
+    /// The name of the argument.
+    pub name: String,
+    /// The type of the argument.
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+fn default_repr() -> String {
+    "i64".into()
+}
+
+fn default_superclass() -> String {
+    "NSObject".into()
+}
+
+fn default_returns() -> String {
+    "void".into()
+}
@@ -0,0 +1,868 @@
+//! Renders the bindings for an API description.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    api::{Api, Class, Enum, Method, Property},
+    naming::{identifier, method_name, returns_retained, setter_name, setter_selector, snake_case},
+    types::{is_declared, module_of, Type},
+    Error,
+};
+
+/// The width that rustfmt wraps lines at.
+const WIDTH: usize = 100;
+
+/// The width of the arguments of a call that rustfmt lays out on one line.
+const CALL_WIDTH: usize = 60;
+
+const PLATFORMS: &[&str] = &["macos", "ios", "tvos", "watchos"];
+
+/// How a method hands over the object it returns.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Ownership {
+    /// A +0 reference owned by the receiver, which the wrapper retains with `from_id`.
+    Retain,
+    /// A +1 reference, which the wrapper takes over with `from_retained_id`.
+    TakeOver,
+    /// A +0 reference in the autorelease pool, which the wrapper returns as an `Autoreleased`.
+    Autoreleased,
+}
+
+/// A method of the generated `impl` block.
+struct Member<'a> {
+    topic: Option<&'a str>,
+    doc: Option<String>,
+    platforms: &'a [String],
+    attribute: &'static str,
+    name: String,
+    params: Vec<String>,
+    arguments: Vec<Type>,
+    returns: Option<String>,
+    instancetype: bool,
+    receiver: &'static str,
+    selector: Vec<(String, Option<String>)>,
+    ty: Type,
+    nullable: bool,
+    ownership: Ownership,
+    /// Whether the method allocates the object its `init` message initializes.
+    allocates: bool,
+}
+
+/// What the generated code uses, and so must import.
+#[derive(Default)]
+struct Uses {
+    crate_paths: BTreeSet<Vec<String>>,
+    super_names: BTreeSet<String>,
+    msg_send: bool,
+    sel: bool,
+}
+
+impl Uses {
+    fn crate_path(&mut self, path: &[&str]) {
+        self.crate_paths
+            .insert(path.iter().map(|s| s.to_string()).collect());
+    }
+
+    fn ty(&mut self, ty: &Type, api: &Api) {
+        match ty {
+            Type::Id => self.crate_path(&["objective_c_runtime", "id"]),
+            Type::Sel => self.sel = true,
+            _ => {
+                if let Some(name) = ty.imported_name() {
+                    self.name(name, api);
+                }
+            }
+        }
+    }
+
+    fn name(&mut self, name: &str, api: &Api) {
+        match module_of(name, api) {
+            Some(module) if module == api.module => {
+                self.super_names.insert(name.into());
+            }
+            Some(module) => {
+                let mut path: Vec<String> = module.split("::").map(String::from).collect();
+                path.push(name.into());
+                self.crate_paths.insert(path);
+            }
+            None => {}
+        }
+    }
+
+    /// Drops what the other uses import already.
+    fn without(mut self, other: &Uses) -> Self {
+        self.crate_paths
+            .retain(|path| !other.crate_paths.contains(path));
+        self.super_names
+            .retain(|name| !other.super_names.contains(name));
+        self.msg_send &= !other.msg_send;
+        self.sel &= !other.sel;
+        self
+    }
+}
+
+/// The uses of the generated code, by the platforms they are gated on.
+#[derive(Default)]
+struct Imports(BTreeMap<Vec<String>, Uses>);
+
+impl Imports {
+    /// The uses of code that is compiled for the platforms, or for all of them if empty.
+    fn on(&mut self, platforms: &[String]) -> &mut Uses {
+        let mut platforms = platforms.to_vec();
+        platforms.sort();
+        self.0.entry(platforms).or_default()
+    }
+}
+
+/// Generates the bindings for the API description.
+pub fn generate(api: &Api) -> Result<String, Error> {
+    let mut imports = Imports::default();
+    let mut items = Vec::new();
+
+    for e in &api.enums {
+        items.push(render_enum(e)?);
+    }
+
+    for class in &api.classes {
+        items.push(render_class(class, api, &mut imports)?);
+    }
+
+    let mut out = String::from(
+        "// Generated by rust-macios-generator. Edit the API description instead of this file.\n\n",
+    );
+
+    let mut imports = imports.0;
+    let always = imports.remove(&Vec::new()).unwrap_or_default();
+    out.push_str(&render_imports(&always, &[])?);
+    for (platforms, uses) in imports {
+        out.push_str(&render_imports(&uses.without(&always), &platforms)?);
+    }
+
+    out.push_str(&items.join("\n"));
+
+    Ok(out)
+}
+
+fn render_imports(uses: &Uses, platforms: &[String]) -> Result<String, Error> {
+    let mut gate = String::new();
+    cfg(&mut gate, "", platforms)?;
+
+    let mut out = String::new();
+
+    if uses.msg_send {
+        out.push_str(&format!(
+            "{}use crate::objective_c_runtime::msg_send;\n",
+            gate
+        ));
+        if uses.sel {
+            out.push_str(&format!(
+                "{}use objc::{{runtime::Sel, sel, sel_impl}};\n",
+                gate
+            ));
+        } else {
+            out.push_str(&format!("{}use objc::{{sel, sel_impl}};\n", gate));
+        }
+        out.push('\n');
+    } else if uses.sel {
+        out.push_str(&format!("{}use objc::runtime::Sel;\n\n", gate));
+    }
+
+    if !uses.crate_paths.is_empty() {
+        let paths: Vec<Vec<String>> = uses.crate_paths.iter().cloned().collect();
+        out.push_str(&gate);
+        out.push_str(&render_use("crate", &paths));
+        out.push('\n');
+    }
+
+    if !uses.super_names.is_empty() {
+        let paths: Vec<Vec<String>> = uses.super_names.iter().map(|n| vec![n.clone()]).collect();
+        out.push_str(&gate);
+        out.push_str(&render_use("super", &paths));
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+fn render_enum(e: &Enum) -> Result<String, Error> {
+    let mut out = String::new();
+
+    doc(&mut out, "", e.doc.as_deref());
+    cfg(&mut out, "", &e.platforms)?;
+    out.push_str("#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]\n");
+    out.push_str(&format!("#[repr({})]\n", e.repr));
+    out.push_str(&format!("pub enum {} {{\n", e.name));
+
+    for variant in &e.variants {
+        let value = match &variant.value {
+            toml::Value::Integer(value) => value.to_string(),
+            toml::Value::String(value) => value.clone(),
+            value => {
+                return Err(Error::Invalid(format!(
+                    "`{}::{}` has the value `{}`, which is not an integer or an expression",
+                    e.name, variant.name, value
+                )))
+            }
+        };
+
+        doc(&mut out, "    ", variant.doc.as_deref());
+        out.push_str(&format!("    {} = {},\n", variant.name, value));
+    }
+
+    out.push_str("}\n");
+
+    Ok(out)
+}
+
+fn render_class(class: &Class, api: &Api, imports: &mut Imports) -> Result<String, Error> {
+    let mut members = Vec::new();
+
+    for property in &class.properties {
+        property_members(class, property, api, &mut members)?;
+    }
+
+    for method in &class.methods {
+        members.push(method_member(class, method, api)?);
+    }
+
+    let mut names = BTreeSet::new();
+    for member in &members {
+        if !names.insert(&member.name) {
+            return Err(Error::Invalid(format!(
+                "`{}` has more than one member named `{}`",
+                class.name, member.name
+            )));
+        }
+    }
+
+    let subclassed = api.classes.iter().any(|c| c.superclass == class.name);
+
+    let uses = imports.on(&class.platforms);
+    uses.crate_path(&["object"]);
+    uses.crate_path(&["objective_c_runtime", "macros", "interface_impl"]);
+    uses.crate_path(&["objective_c_runtime", "traits", "PNSObject"]);
+
+    if class.superclass != "NSObject" {
+        uses.name(&class.superclass, api);
+        if !is_declared(&class.superclass, api) {
+            uses.name(&format!("I{}", class.superclass), api);
+        }
+    }
+
+    if subclassed {
+        uses.crate_path(&["objective_c_runtime", "traits", "FromId"]);
+        uses.crate_path(&["objective_c_runtime", "traits", "Subclass"]);
+    }
+
+    for member in &members {
+        let platforms = if member.platforms.is_empty() {
+            &class.platforms
+        } else {
+            member.platforms
+        };
+        let uses = imports.on(platforms);
+
+        uses.msg_send = true;
+        uses.ty(&member.ty, api);
+        for arg in &member.arguments {
+            uses.ty(arg, api);
+        }
+        if member.instancetype
+            || matches!(member.ty, Type::Object(_)) && member.ownership != Ownership::Autoreleased
+        {
+            uses.crate_path(&["objective_c_runtime", "traits", "FromId"]);
+        }
+        if member.ownership == Ownership::Autoreleased {
+            uses.crate_path(&["objective_c_runtime", "AutoreleasePool"]);
+            uses.crate_path(&["objective_c_runtime", "Autoreleased"]);
+        }
+        if member.allocates {
+            uses.crate_path(&["objective_c_runtime", "id"]);
+        }
+        if member.ty == Type::Bool && member.returns.is_some() {
+            uses.crate_path(&["utils", "to_bool"]);
+        }
+    }
+
+    let mut out = String::new();
+
+    cfg(&mut out, "", &class.platforms)?;
+    out.push_str("object! {\n");
+    doc(&mut out, "    ", class.doc.as_deref());
+    if class.superclass == "NSObject" {
+        out.push_str(&format!("    unsafe pub struct {};\n", class.name));
+    } else {
+        out.push_str(&format!(
+            "    unsafe pub struct {}: {};\n",
+            class.name, class.superclass
+        ));
+    }
+    out.push_str("}\n\n");
+
+    if subclassed {
+        // Let the subclasses use the interface of the class, as AppKit's class hierarchy does.
+        cfg(&mut out, "", &class.platforms)?;
+        out.push_str(&format!(
+            "impl<T> I{name} for T\nwhere\n    T: Subclass,\n    T::Super: I{name},\n{{\n}}\n\n",
+            name = class.name
+        ));
+        cfg(&mut out, "", &class.platforms)?;
+        out.push_str(&format!(
+            "impl<T> From<T> for {name}\nwhere\n    T: Subclass,\n    T::Super: I{name},\n{{\n    \
+             fn from(object: T) -> Self {{\n        \
+             unsafe {{ {name}::from_id(object.m_self()) }}\n    }}\n}}\n\n",
+            name = class.name
+        ));
+    }
+
+    cfg(&mut out, "", &class.platforms)?;
+    out.push_str(&format!("#[interface_impl({})]\n", class.superclass));
+
+    if members.is_empty() {
+        out.push_str(&format!("impl {} {{}}\n", class.name));
+        return Ok(out);
+    }
+
+    out.push_str(&format!("impl {} {{\n", class.name));
+
+    let mut topics: Vec<Option<&str>> = Vec::new();
+    for member in &members {
+        if !topics.contains(&member.topic) {
+            topics.push(member.topic);
+        }
+    }
+
+    let mut first = true;
+    for topic in topics {
+        if let Some(topic) = topic {
+            if !first {
+                out.push('\n');
+            }
+            out.push_str(&format!("    /* {} */\n", topic));
+            first = false;
+        }
+
+        for member in members.iter().filter(|m| m.topic == topic) {
+            if !first {
+                out.push('\n');
+            }
+            render_member(&mut out, member)?;
+            first = false;
+        }
+    }
+
+    out.push_str("}\n");
+
+    Ok(out)
+}
+
+fn property_members<'a>(
+    class: &Class,
+    property: &'a Property,
+    api: &Api,
+    members: &mut Vec<Member<'a>>,
+) -> Result<(), Error> {
+    let ty = Type::parse(&property.ty, api)?;
+    let context = format!("the property `{}` of `{}`", property.name, class.name);
+
+    match ty {
+        Type::Void => return Err(Error::Invalid(format!("{} has no type", context))),
+        _ if property.nullable && !ty.is_object() => {
+            return Err(Error::Invalid(format!(
+                "{} is nullable, but `{}` is not an object type",
+                context, property.ty
+            )))
+        }
+        _ => {}
+    }
+
+    let receiver = if property.class {
+        "Self::m_class()"
+    } else {
+        "self.m_self()"
+    };
+    let getter = property.getter.as_deref().unwrap_or(&property.name);
+
+    members.push(Member {
+        topic: property.topic.as_deref(),
+        doc: property.doc.clone(),
+        platforms: &property.platforms,
+        attribute: "property",
+        name: identifier(&snake_case(&property.name)),
+        params: if property.class {
+            Vec::new()
+        } else {
+            vec!["&self".into()]
+        },
+        arguments: Vec::new(),
+        returns: ty.returns(property.nullable),
+        instancetype: false,
+        receiver,
+        selector: vec![(getter.into(), None)],
+        ty: ty.clone(),
+        nullable: property.nullable,
+        ownership: Ownership::Retain,
+        allocates: false,
+    });
+
+    if !property.readonly {
+        let arg = identifier(&snake_case(&property.name));
+
+        members.push(Member {
+            topic: property.topic.as_deref(),
+            doc: Some(format!("Sets the value of `{}`.", arg)),
+            platforms: &property.platforms,
+            attribute: "property",
+            name: setter_name(&property.name),
+            params: if property.class {
+                vec![format!("{}: {}", arg, ty.argument())]
+            } else {
+                vec!["&mut self".into(), format!("{}: {}", arg, ty.argument())]
+            },
+            arguments: vec![ty.clone()],
+            returns: None,
+            instancetype: false,
+            receiver,
+            selector: vec![(
+                setter_selector(&property.name).trim_end_matches(':').into(),
+                Some(ty.pass(&arg)),
+            )],
+            ty: Type::Void,
+            nullable: false,
+            ownership: Ownership::Retain,
+            allocates: false,
+        });
+    }
+
+    Ok(())
+}
+
+fn method_member<'a>(class: &Class, method: &'a Method, api: &Api) -> Result<Member<'a>, Error> {
+    let context = format!("the method `{}` of `{}`", method.selector, class.name);
+    let arity = method.selector.matches(':').count();
+    let names: Vec<&str> = method.selector.split_terminator(':').collect();
+
+    if method.selector.is_empty()
+        || arity > 0 && !method.selector.ends_with(':')
+        || names.iter().any(|name| name.is_empty())
+    {
+        return Err(Error::Invalid(format!(
+            "{} is not a valid selector",
+            context
+        )));
+    }
+
+    if arity != method.args.len() {
+        return Err(Error::Invalid(format!(
+            "{} takes {} arguments, but {} are described",
+            context,
+            arity,
+            method.args.len()
+        )));
+    }
+
+    let instancetype = method.returns == "instancetype";
+    let ty = if instancetype {
+        Type::Object("Self".into())
+    } else {
+        Type::parse(&method.returns, api)?
+    };
+
+    if method.nullable && !ty.is_object() {
+        return Err(Error::Invalid(format!(
+            "{} is nullable, but `{}` is not an object type",
+            context, method.returns
+        )));
+    }
+
+    // `init` consumes its receiver, so the wrapper allocates the object instead of taking one.
+    let init = !method.class && returns_retained(&method.selector);
+    let ownership = if !matches!(ty, Type::Object(_)) {
+        Ownership::Retain
+    } else if returns_retained(&method.selector) {
+        Ownership::TakeOver
+    } else if method.class {
+        Ownership::Autoreleased
+    } else {
+        Ownership::Retain
+    };
+
+    let mut params = Vec::new();
+    let mut arguments = Vec::new();
+    if !method.class && !init {
+        params.push("&self".into());
+    }
+
+    let mut selector = Vec::new();
+    if method.args.is_empty() {
+        selector.push((method.selector.clone(), None));
+    }
+
+    for (part, arg) in names.iter().zip(&method.args) {
+        let arg_ty = match Type::parse(&arg.ty, api)? {
+            Type::Void => {
+                return Err(Error::Invalid(format!(
+                    "the argument `{}` of {} has no type",
+                    arg.name, context
+                )))
+            }
+            arg_ty => arg_ty,
+        };
+        let name = identifier(&snake_case(&arg.name));
+
+        params.push(format!("{}: {}", name, arg_ty.argument()));
+        selector.push((part.to_string(), Some(arg_ty.pass(&name))));
+        arguments.push(arg_ty);
+    }
+
+    if ownership == Ownership::Autoreleased {
+        params.push("pool: &'pool AutoreleasePool".into());
+    }
+
+    let returns = match (&ty, ownership) {
+        (Type::Object(name), Ownership::Autoreleased) if method.nullable => {
+            Some(format!("Option<Autoreleased<'pool, {}>>", name))
+        }
+        (Type::Object(name), Ownership::Autoreleased) => {
+            Some(format!("Autoreleased<'pool, {}>", name))
+        }
+        _ if instancetype && method.nullable => Some("Option<Self>".into()),
+        _ if instancetype => Some("Self".into()),
+        _ => ty.returns(method.nullable),
+    };
+
+    Ok(Member {
+        topic: method.topic.as_deref(),
+        doc: method.doc.clone(),
+        platforms: &method.platforms,
+        attribute: "method",
+        name: method
+            .rust_name
+            .clone()
+            .unwrap_or_else(|| method_name(&method.selector)),
+        params,
+        arguments,
+        returns,
+        instancetype,
+        receiver: if method.class {
+            "Self::m_class()"
+        } else if init {
+            "obj"
+        } else {
+            "self.m_self()"
+        },
+        selector,
+        ty,
+        nullable: method.nullable,
+        ownership,
+        allocates: init,
+    })
+}
+
+fn render_member(out: &mut String, member: &Member) -> Result<(), Error> {
+    doc(out, "    ", member.doc.as_deref());
+    cfg(out, "    ", member.platforms)?;
+    out.push_str(&format!("    #[{}]\n", member.attribute));
+
+    let returns = member
+        .returns
+        .as_ref()
+        .map(|r| format!(" -> {}", r))
+        .unwrap_or_default();
+    let where_clause = member.instancetype;
+    let generics = if member.ownership == Ownership::Autoreleased {
+        "<'pool>"
+    } else {
+        ""
+    };
+    let head = format!(
+        "    pub fn {}{}({}){}",
+        member.name,
+        generics,
+        member.params.join(", "),
+        returns
+    );
+
+    if head.len() + if where_clause { 0 } else { 2 } <= WIDTH {
+        out.push_str(&head);
+    } else {
+        out.push_str(&format!("    pub fn {}{}(\n", member.name, generics));
+        for param in &member.params {
+            out.push_str(&format!("        {},\n", param));
+        }
+        out.push_str(&format!("    ){}", returns));
+    }
+
+    if where_clause {
+        out.push_str("\n    where\n        Self: Sized + FromId,\n    {\n");
+    } else {
+        out.push_str(" {\n");
+    }
+
+    out.push_str(&render_body(member));
+    out.push_str("    }\n");
+
+    Ok(())
+}
+
+fn render_body(member: &Member) -> String {
+    let parts: Vec<String> = member
+        .selector
+        .iter()
+        .map(|(part, arg)| match arg {
+            Some(arg) => format!("{}: {}", part, arg),
+            None => part.clone(),
+        })
+        .collect();
+
+    let args = format!("{}, {}", member.receiver, parts.join(" "));
+    let message = format!("msg_send![{}]", args);
+
+    // rustfmt leaves messages with arguments as they are, but lays out the others like calls.
+    let expr = wrap(member, &message);
+    let fits_call = member.selector.iter().any(|(_, arg)| arg.is_some())
+        || args.len() <= CALL_WIDTH && (expr == message || message.len() <= CALL_WIDTH);
+
+    let vertical = |indent: usize| {
+        let mut vertical = String::from("msg_send![\n");
+        vertical.push_str(&format!(
+            "{:indent$}{},\n",
+            "",
+            member.receiver,
+            indent = indent + 4
+        ));
+        for part in &parts {
+            vertical.push_str(&format!("{:indent$}{}\n", "", part, indent = indent + 4));
+        }
+        vertical.push_str(&format!("{:indent$}]", "", indent = indent));
+        vertical
+    };
+
+    if member.returns.is_none() {
+        let statement = format!("let _: () = {};", message);
+        if fits_call && statement.len() + 12 <= WIDTH {
+            return format!("        unsafe {{\n            {}\n        }}\n", statement);
+        }
+
+        return format!(
+            "        unsafe {{\n            let _: () = {};\n        }}\n",
+            vertical(12)
+        );
+    }
+
+    let alloc = if member.allocates {
+        "            let obj: id = msg_send![Self::m_class(), alloc];\n"
+    } else {
+        ""
+    };
+
+    if !member.allocates && fits_call && expr.len() + "        unsafe {  }".len() <= WIDTH {
+        return format!("        unsafe {{ {} }}\n", expr);
+    }
+
+    let expr = if fits_call && expr.len() + 12 <= WIDTH {
+        expr
+    } else if member.ownership == Ownership::Autoreleased {
+        // rustfmt puts each argument of a call that doesn't fit on its own line.
+        let message = if fits_call && message.len() + 17 <= WIDTH {
+            message
+        } else {
+            vertical(16)
+        };
+        format!(
+            "{}\n                {},\n                pool,\n            )",
+            autoreleased_constructor(member),
+            message
+        )
+    } else {
+        wrap(member, &vertical(12))
+    };
+
+    format!(
+        "        unsafe {{\n{}            {}\n        }}\n",
+        alloc, expr
+    )
+}
+
+/// Wraps the `msg_send!` of a method that returns a value in the value's Rust type.
+fn wrap(member: &Member, message: &str) -> String {
+    match member.ownership {
+        Ownership::Retain => member.ty.wrap(message, member.nullable),
+        Ownership::TakeOver => member.ty.take_over(message, member.nullable),
+        Ownership::Autoreleased => {
+            format!("{}{}, pool)", autoreleased_constructor(member), message)
+        }
+    }
+}
+
+/// The opening of the call that returns an autoreleased object, without its arguments.
+fn autoreleased_constructor(member: &Member) -> &'static str {
+    if member.nullable {
+        "Autoreleased::try_new("
+    } else {
+        "Autoreleased::new("
+    }
+}
+
+fn doc(out: &mut String, indent: &str, doc: Option<&str>) {
+    let doc = match doc {
+        Some(doc) if !doc.trim().is_empty() => doc.trim(),
+        _ => return,
+    };
+
+    for line in doc.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            out.push_str(&format!("{}///\n", indent));
+        } else {
+            out.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+}
+
+fn cfg(out: &mut String, indent: &str, platforms: &[String]) -> Result<(), Error> {
+    if let Some(platform) = platforms.iter().find(|p| !PLATFORMS.contains(&p.as_str())) {
+        return Err(Error::Invalid(format!(
+            "`{}` is not one of the platforms {:?}",
+            platform, PLATFORMS
+        )));
+    }
+
+    let predicates: Vec<String> = platforms
+        .iter()
+        .map(|p| format!("target_os = \"{}\"", p))
+        .collect();
+
+    match predicates.len() {
+        0 => {}
+        1 => out.push_str(&format!("{}#[cfg({})]\n", indent, predicates[0])),
+        _ => out.push_str(&format!(
+            "{}#[cfg(any({}))]\n",
+            indent,
+            predicates.join(", ")
+        )),
+    }
+
+    Ok(())
+}
+
+/// A `use` tree, such as `traits::{FromId, PNSObject}`.
+enum UseTree {
+    Path(String),
+    Group(String, Vec<UseTree>),
+}
+
+impl UseTree {
+    fn build(paths: &[Vec<String>]) -> Vec<Self> {
+        let mut groups: BTreeMap<&str, Vec<Vec<String>>> = BTreeMap::new();
+        for path in paths {
+            groups.entry(&path[0]).or_default().push(path[1..].to_vec());
+        }
+
+        let mut trees: Vec<Self> = groups
+            .into_iter()
+            .map(|(head, rests)| {
+                if rests.len() == 1 {
+                    let mut path = vec![head.to_string()];
+                    path.extend(rests[0].iter().cloned());
+                    Self::Path(path.join("::"))
+                } else {
+                    Self::Group(head.into(), Self::build(&rests))
+                }
+            })
+            .collect();
+
+        trees.sort_by(|a, b| sort_key(a.name()).cmp(&sort_key(b.name())));
+        trees
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::Path(name) | Self::Group(name, _) => name,
+        }
+    }
+
+    fn render(&self, indent: usize) -> String {
+        match self {
+            Self::Path(path) => path.clone(),
+            Self::Group(name, children) => {
+                render_list(&format!("{}::", name), children, indent, "")
+            }
+        }
+    }
+}
+
+/// Orders names the way rustfmt does, with modules and functions before types.
+fn sort_key(name: &str) -> (bool, &str) {
+    (name.starts_with(|c: char| c.is_ascii_uppercase()), name)
+}
+
+fn render_use(root: &str, paths: &[Vec<String>]) -> String {
+    let mut prefix = format!("use {}::", root);
+    let mut trees = UseTree::build(paths);
+
+    loop {
+        match trees.as_slice() {
+            [UseTree::Path(path)] => return format!("{}{};\n", prefix, path),
+            [UseTree::Group(..)] => {
+                if let Some(UseTree::Group(name, children)) = trees.pop() {
+                    prefix.push_str(&format!("{}::", name));
+                    trees = children;
+                }
+            }
+            _ => return format!("{}\n", render_list(&prefix, &trees, 0, ";")),
+        }
+    }
+}
+
+fn render_list(prefix: &str, trees: &[UseTree], indent: usize, end: &str) -> String {
+    let pad = " ".repeat(indent);
+    let inner = " ".repeat(indent + 4);
+
+    if trees.iter().any(|t| matches!(t, UseTree::Group(..))) {
+        // Paths take a line each, while runs of plain names share one, such as
+        // `AutoreleasePool, Autoreleased,`.
+        let mut out = format!("{}{{\n", prefix);
+        let mut names: Vec<&str> = Vec::new();
+        for tree in trees {
+            match tree {
+                UseTree::Path(name) if !name.contains("::") => names.push(name),
+                _ => {
+                    if !names.is_empty() {
+                        out.push_str(&format!("{}{},\n", inner, names.join(", ")));
+                        names.clear();
+                    }
+                    out.push_str(&format!("{}{},\n", inner, tree.render(indent + 4)));
+                }
+            }
+        }
+        if !names.is_empty() {
+            out.push_str(&format!("{}{},\n", inner, names.join(", ")));
+        }
+        out.push_str(&format!("{}}}{}", pad, end));
+        return out;
+    }
+
+    let names: Vec<&str> = trees.iter().map(UseTree::name).collect();
+    let line = format!("{}{{{}}}{}", prefix, names.join(", "), end);
+    if indent + line.len() + if end.is_empty() { 1 } else { 0 } <= WIDTH {
+        return line;
+    }
+
+    let mut out = format!("{}{{\n", prefix);
+    let mut current = String::new();
+    for name in names {
+        if !current.is_empty() && inner.len() + current.len() + name.len() + 3 > WIDTH {
+            out.push_str(&format!("{}{}\n", inner, current.trim_end()));
+            current.clear();
+        }
+        current.push_str(&format!("{}, ", name));
+    }
+    out.push_str(&format!(
+        "{}{},\n",
+        inner,
+        current.trim_end().trim_end_matches(',')
+    ));
+    out.push_str(&format!("{}}}{}", pad, end));
+    out
+}
@@ -0,0 +1,76 @@
+//! Generates Rust-MaciOS bindings from TOML descriptions of Objective-C APIs.
+//!
+//! An API description lists the enums and classes of one module of the crate:
+//!
+//! ```toml
+//! module = "foundation"
+//!
+//! [[class]]
+//! name = "NSURL"
+//! doc = "An object that represents the location of a resource."
+//!
+//! [[class.property]]
+//! name = "absoluteString"
+//! type = "NSString"
+//! nullable = true
+//! readonly = true
+//!
+//! [[class.method]]
+//! selector = "URLWithString:"
+//! class = true
+//! returns = "instancetype"
+//! nullable = true
+//! arg = [{ name = "string", type = "NSString" }]
+//! ```
+//!
+//! Each class becomes an `object!` with an `#[interface_impl]` block of `#[property]` and
+//! `#[method]` wrappers, laid out the way rustfmt would lay them out. Types are imported from the
+//! module their prefix belongs to, such as `foundation` for `NS`, unless `imports` says otherwise.
+//! Objects are passed as references and returned with `FromId`, or as `Option` when `nullable`.
+//! Integers, enums, structs and the names listed in `value_types` are passed by value.
+//! `platforms` gates a class or one of its members on `target_os`.
+
+mod api;
+mod codegen;
+mod naming;
+mod types;
+
+use std::fmt;
+
+pub use api::*;
+
+/// An error in an API description.
+#[derive(Debug)]
+pub enum Error {
+    /// The description is not valid TOML, or doesn't have the expected shape.
+    Parse(toml::de::Error),
+    /// The description is well-formed, but describes something that can't be generated.
+    Invalid(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<toml::de::Error> for Error {
+    fn from(error: toml::de::Error) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// Parses an API description.
+pub fn parse(source: &str) -> Result<Api, Error> {
+    Ok(toml::from_str(source)?)
+}
+
+/// Generates the Rust module for an API description.
+pub fn generate(source: &str) -> Result<String, Error> {
+    codegen::generate(&parse(source)?)
+}
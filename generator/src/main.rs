@@ -0,0 +1,38 @@
+use std::{env, fs, process};
+
+const USAGE: &str = "usage: rust-macios-generator <api.toml> [-o <output.rs>]";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut input = None;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => output = args.next(),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => fail(USAGE),
+        }
+    }
+
+    let input = input.unwrap_or_else(|| fail(USAGE));
+    let source = fs::read_to_string(&input)
+        .unwrap_or_else(|error| fail(&format!("cannot read {}: {}", input, error)));
+    let code = rust_macios_generator::generate(&source)
+        .unwrap_or_else(|error| fail(&format!("{}: {}", input, error)));
+
+    match output {
+        Some(output) => fs::write(&output, code)
+            .unwrap_or_else(|error| fail(&format!("cannot write {}: {}", output, error))),
+        None => print!("{}", code),
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
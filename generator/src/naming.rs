@@ -0,0 +1,141 @@
+//! Rust names for Objective-C ones.
+
+const KEYWORDS: &[&str] = &[
+    "as", "box", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "dyn",
+];
+
+/// The method families whose results the caller owns.
+const OWNING_FAMILIES: &[&str] = &["alloc", "new", "copy", "mutableCopy", "init"];
+
+/// Converts a camel-case name to snake case, keeping acronyms together.
+///
+/// `absoluteURL` becomes `absolute_url` and `URLByAppendingPathComponent` becomes
+/// `url_by_appending_path_component`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let boundary = match i.checked_sub(1).map(|j| chars[j]) {
+                Some(prev) if prev.is_ascii_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_ascii_uppercase() => chars
+                    .get(i + 1)
+                    .is_some_and(|next| next.is_ascii_lowercase()),
+                _ => false,
+            };
+
+            if boundary && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
+
+/// The name of the Rust method for a selector, such as `url_with_string_relative_to_url` for
+/// `URLWithString:relativeToURL:`.
+pub fn method_name(selector: &str) -> String {
+    let parts: Vec<String> = selector
+        .split(':')
+        .filter(|part| !part.is_empty())
+        .map(snake_case)
+        .collect();
+
+    identifier(&parts.join("_"))
+}
+
+/// The name of the setter of a property, such as `set_title` for `title`.
+pub fn setter_name(property: &str) -> String {
+    format!("set_{}", snake_case(property))
+}
+
+/// The selector of the setter of a property, such as `setTitle:` for `title`.
+pub fn setter_selector(property: &str) -> String {
+    let mut chars = property.chars();
+    let first = chars.next().map(|c| c.to_ascii_uppercase());
+
+    format!(
+        "set{}{}:",
+        first.into_iter().collect::<String>(),
+        chars.as_str()
+    )
+}
+
+/// Whether a selector is in one of the families that return a +1 reference, such as
+/// `initWithString:` or `newObject`, but not `newline`.
+pub fn returns_retained(selector: &str) -> bool {
+    OWNING_FAMILIES.iter().any(|family| {
+        selector
+            .strip_prefix(family)
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_lowercase()))
+    })
+}
+
+/// Escapes names that are Rust keywords.
+pub fn identifier(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("title"), "title");
+        assert_eq!(snake_case("absoluteString"), "absolute_string");
+        assert_eq!(snake_case("absoluteURL"), "absolute_url");
+        assert_eq!(snake_case("isFileURL"), "is_file_url");
+        assert_eq!(
+            snake_case("URLByAppendingPathComponent"),
+            "url_by_appending_path_component"
+        );
+        assert_eq!(snake_case("UTF8String"), "utf8_string");
+    }
+
+    #[test]
+    fn test_method_name() {
+        assert_eq!(method_name("path"), "path");
+        assert_eq!(method_name("URLWithString:"), "url_with_string");
+        assert_eq!(
+            method_name("URLWithString:relativeToURL:"),
+            "url_with_string_relative_to_url"
+        );
+        assert_eq!(method_name("type"), "r#type");
+    }
+
+    #[test]
+    fn test_returns_retained() {
+        assert!(returns_retained("init"));
+        assert!(returns_retained("initWithString:relativeToURL:"));
+        assert!(returns_retained("new"));
+        assert!(returns_retained("mutableCopy"));
+        assert!(!returns_retained("URLWithString:"));
+        assert!(!returns_retained("copyright"));
+        assert!(!returns_retained("initials"));
+    }
+
+    #[test]
+    fn test_setter() {
+        assert_eq!(
+            setter_name("allowsCellularAccess"),
+            "set_allows_cellular_access"
+        );
+        assert_eq!(
+            setter_selector("allowsCellularAccess"),
+            "setAllowsCellularAccess:"
+        );
+    }
+}
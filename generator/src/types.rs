@@ -0,0 +1,179 @@
+//! How the types of an API description map to Rust.
+
+use crate::{api::Api, Error};
+
+/// Types that are passed by value, and the modules of the crate that declare them.
+const VALUE_TYPES: &[(&str, Option<&str>)] = &[
+    ("bool", None),
+    ("i8", None),
+    ("i16", None),
+    ("i32", None),
+    ("i64", None),
+    ("isize", None),
+    ("u8", None),
+    ("u16", None),
+    ("u32", None),
+    ("u64", None),
+    ("usize", None),
+    ("f32", None),
+    ("f64", None),
+    ("Int", Some("foundation")),
+    ("UInt", Some("foundation")),
+    ("NSTimeInterval", Some("foundation")),
+    ("NSRange", Some("foundation")),
+    ("NSRect", Some("foundation")),
+    ("NSPoint", Some("foundation")),
+    ("NSSize", Some("foundation")),
+    ("NSComparisonResult", Some("foundation")),
+    ("CGFloat", Some("core_graphics")),
+    ("CGRect", Some("core_graphics")),
+    ("CGPoint", Some("core_graphics")),
+    ("CGSize", Some("core_graphics")),
+];
+
+/// The modules of the crate that declare the types with a prefix.
+const PREFIXES: &[(&str, &str)] = &[
+    ("BG", "background_tasks"),
+    ("CF", "core_foundation"),
+    ("CG", "core_graphics"),
+    ("CL", "core_location"),
+    ("CN", "contacts"),
+    ("ML", "core_ml"),
+    ("NL", "natural_language"),
+    ("NS", "foundation"),
+    ("UI", "uikit"),
+    ("UN", "user_notifications"),
+];
+
+/// A type of the API description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    /// No value.
+    Void,
+    /// An Objective-C `BOOL`.
+    Bool,
+    /// An untyped object.
+    Id,
+    /// A selector.
+    Sel,
+    /// A type that is passed by value, such as an integer or an enum.
+    Value(String),
+    /// A class, which is passed by reference and returned with `FromId`.
+    Object(String),
+}
+
+impl Type {
+    /// Parses the name of a type of the API description.
+    pub fn parse(name: &str, api: &Api) -> Result<Self, Error> {
+        let ty = match name {
+            "" => return Err(Error::Invalid("a type is empty".into())),
+            "void" => Self::Void,
+            "BOOL" | "bool" => Self::Bool,
+            "id" => Self::Id,
+            "SEL" | "Sel" => Self::Sel,
+            _ if is_value_type(name, api) => Self::Value(name.into()),
+            _ => Self::Object(name.into()),
+        };
+
+        Ok(ty)
+    }
+
+    /// The name of a type that is declared elsewhere and must be imported.
+    pub fn imported_name(&self) -> Option<&str> {
+        match self {
+            Self::Value(name) | Self::Object(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The Rust type of an argument of this type.
+    pub fn argument(&self) -> String {
+        match self {
+            Self::Void => "()".into(),
+            Self::Bool => "bool".into(),
+            Self::Id => "id".into(),
+            Self::Sel => "Sel".into(),
+            Self::Value(name) => name.clone(),
+            Self::Object(name) => format!("&{}", name),
+        }
+    }
+
+    /// The expression that passes an argument of this type to `msg_send!`.
+    pub fn pass(&self, name: &str) -> String {
+        match self {
+            Self::Object(_) => format!("{}.m_self()", name),
+            _ => name.into(),
+        }
+    }
+
+    /// The Rust type of a return value of this type.
+    pub fn returns(&self, nullable: bool) -> Option<String> {
+        match self {
+            Self::Void => None,
+            Self::Object(name) if nullable => Some(format!("Option<{}>", name)),
+            Self::Object(name) => Some(name.clone()),
+            _ => Some(self.argument()),
+        }
+    }
+
+    /// Wraps the `msg_send!` of a method that returns this type.
+    pub fn wrap(&self, message: &str, nullable: bool) -> String {
+        match self {
+            Self::Bool => format!("to_bool({})", message),
+            Self::Object(name) if nullable => format!("{}::try_from_id({})", name, message),
+            Self::Object(name) => format!("{}::from_id({})", name, message),
+            _ => message.into(),
+        }
+    }
+
+    /// Wraps the `msg_send!` of a method that returns a +1 reference of this type.
+    pub fn take_over(&self, message: &str, nullable: bool) -> String {
+        match self {
+            Self::Object(name) if nullable => {
+                format!("{}::try_from_retained_id({})", name, message)
+            }
+            Self::Object(name) => format!("{}::from_retained_id({})", name, message),
+            _ => self.wrap(message, nullable),
+        }
+    }
+
+    /// Whether `nil` is a value of this type.
+    pub fn is_object(&self) -> bool {
+        matches!(self, Self::Id | Self::Object(_))
+    }
+}
+
+/// The module of the crate that declares a type, if any.
+pub fn module_of<'a>(name: &str, api: &'a Api) -> Option<&'a str> {
+    if let Some(module) = api.imports.get(name) {
+        return Some(module);
+    }
+
+    if let Some((_, module)) = VALUE_TYPES.iter().find(|(ty, _)| *ty == name) {
+        return *module;
+    }
+
+    if is_declared(name, api) {
+        return None;
+    }
+
+    PREFIXES
+        .iter()
+        .find(|(prefix, _)| {
+            name.strip_prefix(prefix)
+                .and_then(|rest| rest.chars().next())
+                .is_some_and(|c| c.is_ascii_uppercase())
+        })
+        .map(|(_, module)| *module)
+}
+
+/// Whether the API description declares the type itself.
+pub fn is_declared(name: &str, api: &Api) -> bool {
+    api.enums.iter().any(|e| e.name == name) || api.classes.iter().any(|c| c.name == name)
+}
+
+fn is_value_type(name: &str, api: &Api) -> bool {
+    VALUE_TYPES.iter().any(|(ty, _)| *ty == name)
+        || api.value_types.iter().any(|ty| ty == name)
+        || api.enums.iter().any(|e| e.name == name)
+}
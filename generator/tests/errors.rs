@@ -0,0 +1,74 @@
+use rust_macios_generator::{generate, Error};
+
+fn invalid(source: &str) -> String {
+    match generate(source) {
+        Err(Error::Invalid(message)) => message,
+        result => panic!("expected an invalid description, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_unknown_fields() {
+    let result =
+        generate("module = \"foundation\"\n\n[[class]]\nname = \"NSURL\"\nsuper = \"NSObject\"\n");
+
+    assert!(matches!(result, Err(Error::Parse(_))));
+}
+
+#[test]
+fn test_argument_count() {
+    let message = invalid(
+        r#"
+        module = "foundation"
+
+        [[class]]
+        name = "NSURL"
+
+        [[class.method]]
+        selector = "URLWithString:relativeToURL:"
+        arg = [{ name = "string", type = "NSString" }]
+        "#,
+    );
+
+    assert_eq!(
+        message,
+        "the method `URLWithString:relativeToURL:` of `NSURL` takes 2 arguments, but 1 are described"
+    );
+}
+
+#[test]
+fn test_nullable_values() {
+    let message = invalid(
+        r#"
+        module = "foundation"
+
+        [[class]]
+        name = "NSURL"
+
+        [[class.property]]
+        name = "port"
+        type = "Int"
+        nullable = true
+        "#,
+    );
+
+    assert_eq!(
+        message,
+        "the property `port` of `NSURL` is nullable, but `Int` is not an object type"
+    );
+}
+
+#[test]
+fn test_unknown_platforms() {
+    let message = invalid(
+        r#"
+        module = "foundation"
+
+        [[class]]
+        name = "NSURL"
+        platforms = ["linux"]
+        "#,
+    );
+
+    assert!(message.starts_with("`linux` is not one of the platforms"));
+}
@@ -0,0 +1,48 @@
+//! Checks the bindings generated from the descriptions in `api` against the crate's sources.
+//!
+//! Run with `UPDATE_GOLDEN=1` to regenerate the sources instead.
+
+use std::{env, fs, path::Path};
+
+#[test]
+fn test_generated_bindings_are_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+    let mut checked = 0;
+
+    for module in fs::read_dir(root.join("api")).unwrap() {
+        let module = module.unwrap().path();
+
+        for description in fs::read_dir(&module).unwrap() {
+            let description = description.unwrap().path();
+            if description.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+                continue;
+            }
+
+            let source = root
+                .join("../src")
+                .join(module.file_name().unwrap())
+                .join(description.file_stem().unwrap())
+                .with_extension("rs");
+
+            let generated =
+                rust_macios_generator::generate(&fs::read_to_string(&description).unwrap())
+                    .unwrap_or_else(|error| panic!("{}: {}", description.display(), error));
+
+            if update {
+                fs::write(&source, generated).unwrap();
+            } else {
+                assert_eq!(
+                    fs::read_to_string(&source).unwrap(),
+                    generated,
+                    "{} is out of date; rerun with UPDATE_GOLDEN=1",
+                    source.display()
+                );
+            }
+
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0);
+}
@@ -10,10 +10,11 @@ mod type_defs;
 
 pub use enums::*;
 pub use globals::*;
-pub use macros::*;
 pub use type_defs::*;
 
 mod action_handler;
+mod application_main;
+mod main_thread;
 mod ns_appearance;
 mod ns_application;
 mod ns_application_delegate;
@@ -49,6 +50,8 @@ mod ns_window;
 mod ns_window_delegate;
 
 pub use action_handler::*;
+pub use application_main::*;
+pub use main_thread::*;
 pub use ns_application::*;
 pub use ns_application_delegate::*;
 pub use ns_button::*;
@@ -67,6 +70,7 @@ pub use ns_nib::*;
 pub use ns_pasteboard::*;
 pub use ns_pasteboard_item::*;
 pub use ns_popover::*;
+pub use ns_popover_delegate::*;
 pub use ns_responder::*;
 pub use ns_running_application::*;
 pub use ns_screen::*;
@@ -79,3 +83,4 @@ pub use ns_toolbar::*;
 pub use ns_view::*;
 pub use ns_view_controller::*;
 pub use ns_window::*;
+pub use ns_window_delegate::*;
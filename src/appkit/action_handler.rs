@@ -1,13 +1,13 @@
 #![allow(trivial_casts)]
 
-use std::{fmt, sync::Once};
+use std::{fmt, ptr, sync::Once};
 
 use crate::{
     foundation::NSString,
     objective_c_runtime::{
         class,
         declare::ClassDecl,
-        id, msg_send,
+        exit_on_panic, id, msg_send,
         runtime::{Class, Object, Sel},
         sel, sel_impl,
         traits::FromId,
@@ -20,7 +20,7 @@ use crate::{
 pub static ACTION_CALLBACK_PTR: &str = "RUST_Action_Callback_Ptr";
 
 /// The action handler
-pub struct Action(Box<dyn Fn() + Send + Sync + 'static>);
+pub struct Action(Box<dyn Fn() + 'static>);
 
 /// A handler that contains the class for callback storage and invocation on
 pub struct ActionHandler {
@@ -32,13 +32,15 @@ pub struct ActionHandler {
 
 impl ActionHandler {
     /// Returns a new TargetEventHandler.
-    pub fn new<F: Fn() + Send + Sync + 'static>(control: &Object, action: F) -> Self {
+    ///
+    /// AppKit sends the action on the main thread, so `action` need not be `Send` or `Sync`.
+    pub fn new<F: Fn() + 'static>(control: &Object, action: F) -> Self {
         let block = Box::new(Action(Box::new(action)));
         let ptr = Box::into_raw(block);
 
         let invoker = unsafe {
             ShareId::from_ptr({
-                let invoker: id = msg_send![Self::register_handler_class::<F>(), alloc];
+                let invoker: id = msg_send![Self::register_handler_class(), alloc];
                 let invoker: id = msg_send![invoker, init];
                 (*invoker).set_ivar(ACTION_CALLBACK_PTR, ptr as usize);
                 let _: () = msg_send![control, setAction: sel!(perform:)];
@@ -53,21 +55,15 @@ impl ActionHandler {
         }
     }
 
-    fn register_handler_class<F>() -> *const Class
-    where
-        F: Fn() + 'static,
-    {
-        static mut CLASS: *const Class = 0 as *const Class;
+    fn register_handler_class() -> *const Class {
+        static mut CLASS: *const Class = ptr::null();
         static INIT: Once = Once::new();
 
         INIT.call_once(|| unsafe {
             let mut decl = ClassDecl::new("RUST_ActionHandler", class!(NSObject)).unwrap();
 
             decl.add_ivar::<usize>(ACTION_CALLBACK_PTR);
-            decl.add_method(
-                sel!(perform:),
-                perform::<F> as extern "C" fn(&mut Object, _, id),
-            );
+            decl.add_method(sel!(perform:), perform as extern "C" fn(&mut Object, _, id));
 
             CLASS = decl.register();
         });
@@ -91,7 +87,7 @@ impl fmt::Debug for ActionHandler {
 }
 
 /// This will fire for an NSButton callback.
-extern "C" fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id) {
+extern "C" fn perform(this: &mut Object, _: Sel, _sender: id) {
     let action = get_variable::<Action>(this, ACTION_CALLBACK_PTR);
-    (action.0)();
+    exit_on_panic(|| (action.0)());
 }
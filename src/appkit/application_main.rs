@@ -0,0 +1,73 @@
+use std::{
+    ffi::{CString, OsString},
+    os::unix::ffi::OsStringExt,
+    process, ptr,
+};
+
+use libc::{c_char, c_int};
+
+use super::{MainThreadMarker, NSApplicationMain};
+
+/// The arguments of a C `main`, with a null pointer after the last one.
+struct Argv {
+    _arguments: Vec<CString>,
+    pointers: Vec<*const c_char>,
+}
+
+impl Argv {
+    fn new<I>(arguments: I) -> Self
+    where
+        I: IntoIterator<Item = OsString>,
+    {
+        let arguments: Vec<CString> = arguments
+            .into_iter()
+            .map(|argument| CString::new(argument.into_vec()).expect("arguments have no NUL bytes"))
+            .collect();
+        let pointers = arguments
+            .iter()
+            .map(|argument| argument.as_ptr())
+            .chain(Some(ptr::null()))
+            .collect();
+
+        Self {
+            _arguments: arguments,
+            pointers,
+        }
+    }
+
+    fn argc(&self) -> c_int {
+        (self.pointers.len() - 1) as c_int
+    }
+}
+
+/// Runs the event loop of the shared application with the process's arguments, and exits
+/// with the status `NSApplicationMain` returns.
+///
+/// This is what [`ns_application_main`](super::ns_application_main) functions end with.
+pub fn application_main(_mtm: MainThreadMarker) -> ! {
+    let argv = Argv::new(std::env::args_os());
+
+    let status = unsafe { NSApplicationMain(argv.argc(), argv.pointers.as_ptr()) };
+    process::exit(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    #[test]
+    fn test_argv() {
+        let argv = Argv::new(["app".into(), "--flag".into(), "".into()]);
+
+        assert_eq!(argv.argc(), 3);
+        assert!(argv.pointers[3].is_null());
+
+        let arguments: Vec<&str> = argv.pointers[..3]
+            .iter()
+            .map(|&pointer| unsafe { CStr::from_ptr(pointer) }.to_str().unwrap())
+            .collect();
+        assert_eq!(arguments, ["app", "--flag", ""]);
+    }
+}
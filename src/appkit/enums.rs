@@ -166,7 +166,7 @@ pub enum NSEventMask {
     /// A mask for change-mode events.
     ChangeMode = 1 << NSEventType::ChangeMode as u64,
     /// A mask that matches any type of event.
-    AnyEvent = UInt::max_value(),
+    AnyEvent = UInt::MAX,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -211,6 +211,8 @@ unsafe impl Encode for NSApplicationTerminateReply {
     }
 }
 
+crate::__impl_objc_value!(NSApplicationTerminateReply);
+
 /// Constants that specify the style of a window, and that you can combine
 /// with the C bitwise OR operator.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -244,7 +246,7 @@ pub enum NSWindowStyleMask {
     /// gradient in the section below the window content.
     #[deprecated]
     TexturedBackground = 1 << 8,
-    ///
+    /// This constant has no effect.
     Unscaled = 1 << 11,
     /// This constant has no effect, because all windows that include a
     /// toolbar use the unified style.
@@ -356,9 +358,9 @@ pub enum NSWindowDepth {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u64)]
 pub enum NSWindowNumberListOptions {
-    ///
+    /// Lists the windows of all apps, not only those of the calling app.
     AllApplication = 1 << 0,
-    ///
+    /// Lists the windows on all Spaces, not only those on the active one.
     AllSpaces = 1 << 4,
 }
 
@@ -368,9 +370,9 @@ pub enum NSWindowNumberListOptions {
 pub enum NSWindowSharingType {
     /// The window’s contents cannot be read by another process.
     None,
-    ///
+    /// The window’s contents can be read but not modified by another process.
     ReadOnly,
-    ///
+    /// The window’s contents can be read and modified by another process.
     ReadWrite,
 }
 
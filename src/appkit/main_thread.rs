@@ -0,0 +1,136 @@
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    panic::{self, AssertUnwindSafe},
+    thread,
+};
+
+use crate::objective_c_runtime::msg_send;
+use objc::{class, sel, sel_impl};
+
+use crate::{
+    objective_c_runtime::{
+        traits::{FromId, PNSObject},
+        Allocated,
+    },
+    utils::to_bool,
+};
+
+#[repr(C)]
+struct DispatchQueue {
+    _private: [u8; 0],
+}
+
+extern "C" {
+    static _dispatch_main_q: DispatchQueue;
+
+    fn dispatch_sync_f(
+        queue: *const DispatchQueue,
+        context: *mut c_void,
+        work: extern "C" fn(*mut c_void),
+    );
+}
+
+/// A token that proves the code holding it runs on the main thread.
+///
+/// AppKit objects must only be used on the main thread, so their constructors take a
+/// marker and the types are neither `Send` nor `Sync`. Markers can't leave the main
+/// thread either. Code on other threads gets one through [`run_on_main`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MainThreadMarker {
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl MainThreadMarker {
+    /// Returns a marker if the current thread is the main thread.
+    pub fn new() -> Option<Self> {
+        let is_main_thread = unsafe { to_bool(msg_send![class!(NSThread), isMainThread]) };
+
+        // SAFETY: The current thread is the main thread.
+        is_main_thread.then(|| unsafe { Self::new_unchecked() })
+    }
+
+    /// Returns a marker without checking the current thread.
+    ///
+    /// # Safety
+    ///
+    /// The current thread must be the main thread.
+    pub unsafe fn new_unchecked() -> Self {
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// A class whose instances may only be used on the main thread.
+///
+/// These classes don't implement [`AnyThread`](crate::objective_c_runtime::traits::AnyThread),
+/// so they are created with a [`MainThreadMarker`] in place of [`PNSObject::m_new`] and
+/// [`PNSObject::m_alloc`].
+pub trait MainThreadOnly: PNSObject {
+    /// Allocates and initializes a new instance of the class on the main thread.
+    fn m_new_on_main(_mtm: MainThreadMarker) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe { Self::from_retained_id(msg_send![Self::m_class(), new]) }
+    }
+
+    /// Allocates a new instance of the class on the main thread.
+    fn m_alloc_on_main(_mtm: MainThreadMarker) -> Allocated<Self>
+    where
+        Self: Sized,
+    {
+        unsafe { Allocated::new(msg_send![Self::m_class(), alloc]) }
+    }
+}
+
+/// Runs `f` on the main thread with a marker, and returns its value.
+///
+/// On the main thread `f` runs right away. Other threads send it to the main dispatch
+/// queue and block until it returns, so this deadlocks if the main thread waits on the
+/// caller. A panic in `f` resumes on the calling thread.
+pub fn run_on_main<F, R>(f: F) -> R
+where
+    F: FnOnce(MainThreadMarker) -> R + Send,
+    R: Send,
+{
+    if let Some(mtm) = MainThreadMarker::new() {
+        return f(mtm);
+    }
+
+    struct Context<F, R> {
+        f: Option<F>,
+        result: Option<thread::Result<R>>,
+    }
+
+    extern "C" fn work<F, R>(context: *mut c_void)
+    where
+        F: FnOnce(MainThreadMarker) -> R,
+    {
+        let context = unsafe { &mut *(context as *mut Context<F, R>) };
+        let f = context.f.take().unwrap();
+
+        // SAFETY: The main queue runs its work on the main thread.
+        let mtm = unsafe { MainThreadMarker::new_unchecked() };
+        context.result = Some(panic::catch_unwind(AssertUnwindSafe(|| f(mtm))));
+    }
+
+    let mut context = Context {
+        f: Some(f),
+        result: None,
+    };
+
+    unsafe {
+        dispatch_sync_f(
+            &_dispatch_main_q,
+            &mut context as *mut Context<F, R> as *mut c_void,
+            work::<F, R>,
+        );
+    }
+
+    context
+        .result
+        .expect("the main queue did not run the closure")
+        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
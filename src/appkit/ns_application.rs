@@ -1,9 +1,9 @@
-use std::{fmt, marker::PhantomData, sync::Once};
+use std::{fmt, marker::PhantomData, mem::ManuallyDrop, ptr, sync::Once};
 
+use crate::objective_c_runtime::msg_send;
 use objc::{
     class,
     declare::ClassDecl,
-    msg_send,
     runtime::{Class, Object},
     sel, sel_impl,
 };
@@ -13,19 +13,20 @@ use crate::{
     objective_c_runtime::{
         id,
         traits::{FromId, PNSObject, ToId},
+        DeclareClassError,
     },
     utils::to_bool,
 };
 
 use super::{
     ns_application_delegate::PNSApplicationDelegate, register_app_delegate_class, INSResponder,
-    NSApplicationActivationPolicy, NSApplicationDelegateReply, NSMenu,
+    MainThreadMarker, NSApplicationActivationPolicy, NSApplicationDelegateReply, NSMenu,
 };
-///
+/// The name of the instance variable that holds the pointer to the Rust application.
 pub static NSAPPLICATION_PTR: &str = "rstNSApplicationPtr";
 
 fn register_app_class() -> *const Class {
-    static mut APP_CLASS: *const Class = 0 as *const Class;
+    static mut APP_CLASS: *const Class = ptr::null();
     static INIT: Once = Once::new();
 
     INIT.call_once(|| unsafe {
@@ -42,13 +43,14 @@ pub struct NSApplication<'app, M = ()> {
     /// The underlying Objective-C object.
     pub ptr: Id<Object>,
     _message: PhantomData<&'app M>,
+    main_thread: PhantomData<MainThreadMarker>,
 }
 
 /// An object that manages an app’s main event loop and resources used by all
 /// of that app’s objects.
 pub trait INSApplication: INSResponder {
     /// Returns the application instance, creating it if it doesn’t exist yet.
-    fn p_shared_application() -> Self
+    fn p_shared_application(_mtm: MainThreadMarker) -> Self
     where
         Self: Sized + FromId,
     {
@@ -61,17 +63,22 @@ pub trait INSApplication: INSResponder {
     }
 
     /// Sets the app delegate object.
-    fn p_set_delegate<'app, T>(&'app mut self, app_delegate: T)
+    ///
+    /// The delegate lives for the rest of the process, as the app does. Returns the error if
+    /// its class cannot be declared.
+    fn p_set_delegate<T>(&mut self, app_delegate: T) -> Result<(), DeclareClassError>
     where
-        T: PNSApplicationDelegate + 'app,
+        T: PNSApplicationDelegate + 'static,
     {
         unsafe {
-            let delegate_class = register_app_delegate_class::<T>();
+            let delegate_class = register_app_delegate_class::<T>()?;
             let delegate: id = msg_send![delegate_class, new];
-            let delegate_ptr: *const T = &app_delegate;
+            let delegate_ptr: *mut T = Box::into_raw(Box::new(app_delegate));
             (*delegate).set_ivar(NSAPPLICATION_PTR, delegate_ptr as usize);
-            msg_send![self.m_self(), setDelegate: delegate]
+            let _: () = msg_send![self.m_self(), setDelegate: delegate];
         }
+
+        Ok(())
     }
 
     /* Managing the Event Loop
@@ -187,8 +194,8 @@ pub trait INSApplication: INSResponder {
     /* Menu */
 
     /// The app’s main menu bar.
-    fn p_main_menu(&self) -> NSMenu {
-        unsafe { msg_send![self.m_self(), mainMenu] }
+    fn p_main_menu(&self) -> Option<NSMenu> {
+        unsafe { NSMenu::try_from_id(msg_send![self.m_self(), mainMenu]) }
     }
 
     /// Sets the app’s main menu bar.
@@ -199,8 +206,8 @@ pub trait INSApplication: INSResponder {
 
 impl<'app> NSApplication<'app> {
     /// Returns the application instance, creating it if it doesn’t exist yet.
-    pub fn shared_application() -> NSApplication<'app> {
-        NSApplication::p_shared_application()
+    pub fn shared_application(mtm: MainThreadMarker) -> NSApplication<'app> {
+        NSApplication::p_shared_application(mtm)
     }
 
     /// The app delegate object.
@@ -280,7 +287,7 @@ impl NSApplication<'_> {
     }
 
     /// The app’s main menu bar.
-    pub fn main_menu(&self) -> NSMenu {
+    pub fn main_menu(&self) -> Option<NSMenu> {
         self.p_main_menu()
     }
 
@@ -292,7 +299,7 @@ impl NSApplication<'_> {
 
 impl NSApplication<'_> {
     /// Creates a new [`NSApplication`],
-    pub fn new() -> Self {
+    pub fn new(_mtm: MainThreadMarker) -> Self {
         let ptr = unsafe {
             let app: id = msg_send![register_app_class(), sharedApplication];
             Id::from_ptr(app)
@@ -301,16 +308,11 @@ impl NSApplication<'_> {
         Self {
             ptr,
             _message: PhantomData,
+            main_thread: PhantomData,
         }
     }
 }
 
-impl Default for NSApplication<'_> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl PNSObject for NSApplication<'_> {
     fn m_class<'a>() -> &'a Class {
         unsafe { &*register_app_class() }
@@ -332,8 +334,10 @@ impl fmt::Debug for NSApplication<'_> {
 }
 
 impl ToId for NSApplication<'_> {
-    fn to_id(mut self) -> id {
-        &mut *self.ptr
+    fn to_id(self) -> id {
+        // Hand our reference to the pool instead of releasing it.
+        let this = ManuallyDrop::new(self);
+        unsafe { msg_send![this.m_self(), autorelease] }
     }
 }
 
@@ -342,6 +346,7 @@ impl FromId for NSApplication<'_> {
         Self {
             ptr: Id::from_ptr(ptr),
             _message: PhantomData,
+            main_thread: PhantomData,
         }
     }
 }
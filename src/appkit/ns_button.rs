@@ -1,22 +1,21 @@
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{foundation::NSString, objective_c_runtime::traits::FromId};
 pub(crate) use crate::{object, objective_c_runtime::macros::interface_impl};
 
-use super::{INSControl, INSResponder, INSView, NSImage};
+use super::{INSControl, MainThreadMarker, NSControl, NSImage};
 
 object! {
     /// A control that defines an area on the screen that a user clicks to trigger an action.
-    unsafe pub struct NSButton;
+    unsafe pub struct NSButton: NSControl {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
-impl INSResponder for NSButton {}
-
-impl INSView for NSButton {}
-
-impl INSControl for NSButton {}
-
-#[interface_impl(NSControl)]
+#[interface_impl(NSControl, subclasses)]
 impl NSButton {
     /* Configuring Buttons
      */
@@ -38,8 +37,8 @@ impl NSButton {
 
     /// The image that appears on the button when it’s in an off state, or nil if there is no such image.
     #[property]
-    pub fn image(&self) -> NSImage {
-        unsafe { NSImage::from_id(msg_send![self.m_self(), image]) }
+    pub fn image(&self) -> Option<NSImage> {
+        unsafe { NSImage::try_from_id(msg_send![self.m_self(), image]) }
     }
 
     /// Sets the image that appears on the button when it’s in an off state, or nil if there is no such image.
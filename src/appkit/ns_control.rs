@@ -1,19 +1,23 @@
-use objc::{msg_send, runtime::Sel, sel, sel_impl};
+use std::marker::PhantomData;
 
-use crate::{appkit::INSView, object};
+use crate::objective_c_runtime::msg_send;
+use objc::{runtime::Sel, sel, sel_impl};
 
-use super::{interface_impl, INSResponder};
+use crate::{
+    appkit::{INSView, NSView},
+    object,
+};
+
+use super::{interface_impl, MainThreadMarker};
 
 object! {
     /// A specialized view, such as a button or text field, that notifies your app of relevant events using the target-action design pattern.
-    unsafe pub struct NSControl;
+    unsafe pub struct NSControl: NSView {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
-impl INSResponder for NSControl {}
-
-impl INSView for NSControl {}
-
-#[interface_impl(NSView)]
+#[interface_impl(NSView, subclasses)]
 impl NSControl {
     /*  Implementing the Target-Action Mechanism */
 
@@ -1,8 +1,12 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use super::{interface_impl, NSImageName};
 
-use crate::{object, objective_c_runtime::traits::PNSObject};
+use crate::{
+    object,
+    objective_c_runtime::{traits::PNSObject, AutoreleasePool, Autoreleased},
+};
 
 object! {
     /// A high-level interface for manipulating image data.
@@ -16,7 +20,10 @@ impl NSImage {
 
     /// Returns the image object associated with the specified name.
     #[method]
-    pub fn image_named(name: NSImageName) -> NSImage {
-        unsafe { msg_send![NSImage::m_class(), imageNamed: name] }
+    pub fn image_named<'pool>(
+        name: NSImageName,
+        pool: &'pool AutoreleasePool,
+    ) -> Option<Autoreleased<'pool, NSImage>> {
+        unsafe { Autoreleased::try_new(msg_send![NSImage::m_class(), imageNamed: name], pool) }
     }
 }
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSArray,
@@ -1,4 +1,4 @@
-use crate::{object, objective_c_runtime::macros::{interface_impl}};
+use crate::{object, objective_c_runtime::macros::interface_impl};
 
 use super::INSLayoutAnchor;
 
@@ -1,24 +1,58 @@
-use objc::{class, msg_send, runtime::Sel, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{class, runtime::Sel, sel, sel_impl};
 
 use crate::{
     core_graphics::CGFloat,
-    foundation::{Int, NSString},
+    foundation::{Int, NSProcessInfo, NSString},
     object,
-    objective_c_runtime::traits::{FromId, PNSObject},
+    objective_c_runtime::{
+        id,
+        traits::{FromId, PNSObject},
+    },
     utils::to_bool,
 };
 
-use super::{interface_impl, NSMenuItem};
+use super::{interface_impl, MainThreadMarker, NSMenuItem};
 
 object! {
     /// An object that manages an app’s menus.
-    unsafe pub struct NSMenu;
+    unsafe pub struct NSMenu {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 impl NSMenu {
     /// Returns a new `NSMenu` instance.
-    pub fn new() -> Self {
-        unsafe { Self::from_id(msg_send![class!(NSMenu), new]) }
+    pub fn new(_mtm: MainThreadMarker) -> Self {
+        unsafe { Self::from_retained_id(msg_send![class!(NSMenu), new]) }
+    }
+
+    /// Returns a main menu with an app menu that hides and quits the app.
+    ///
+    /// The items are named after the process, as AppKit names the app menu.
+    pub fn default_main_menu(mtm: MainThreadMarker) -> Self {
+        let name = NSProcessInfo::process_info().process_name();
+
+        let mut app_menu = NSMenu::new(mtm);
+        app_menu.add_item_with_title_action_key_equivalent(
+            format!("Hide {name}").into(),
+            sel!(hide:),
+            "h".into(),
+        );
+        app_menu.add_item_with_title_action_key_equivalent(
+            format!("Quit {name}").into(),
+            sel!(terminate:),
+            "q".into(),
+        );
+
+        let mut app_menu_item = NSMenuItem::new(mtm);
+        app_menu_item.set_submenu(app_menu);
+
+        let mut main_menu = NSMenu::new(mtm);
+        main_menu.add_item(app_menu_item);
+        main_menu
     }
 }
 
@@ -51,11 +85,14 @@ impl NSMenu {
     /// Initializes and returns a menu having the specified title and with
     /// autoenabling of menu items turned on.
     #[method]
-    pub fn init_with_title(title: NSString) -> Self
+    pub fn init_with_title(title: NSString, _mtm: MainThreadMarker) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), initWithTitle: title]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithTitle: title])
+        }
     }
 
     /* Adding and Removing Menu Items
@@ -129,9 +166,3 @@ impl NSMenu {
         unsafe { msg_send![self.m_self(), removeAllItems] }
     }
 }
-
-impl Default for NSMenu {
-    fn default() -> Self {
-        Self::new()
-    }
-}
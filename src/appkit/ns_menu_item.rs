@@ -1,4 +1,7 @@
-use objc::{msg_send, runtime::Sel, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{runtime::Sel, sel, sel_impl};
 
 use crate::{
     foundation::NSString,
@@ -10,11 +13,13 @@ use crate::{
     utils::to_bool,
 };
 
-use super::{interface_impl, NSMenu};
+use super::{interface_impl, MainThreadMarker, MainThreadOnly, NSMenu};
 
 object! {
     /// A command item in an app menu.
-    unsafe pub struct NSMenuItem;
+    unsafe pub struct NSMenuItem {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 #[interface_impl(NSObject)]
@@ -101,8 +106,8 @@ impl NSMenuItem {
 
     /// The submenu of the menu item.
     #[property]
-    pub fn submenu(&self) -> NSMenu {
-        unsafe { NSMenu::from_id(msg_send![self.m_self(), submenu]) }
+    pub fn submenu(&self) -> Option<NSMenu> {
+        unsafe { NSMenu::try_from_id(msg_send![self.m_self(), submenu]) }
     }
 
     /// Sets the submenu of the menu item.
@@ -131,8 +136,9 @@ impl NSMenuItem {
     }
 }
 
-impl Default for NSMenuItem {
-    fn default() -> Self {
-        Self::m_new()
+impl NSMenuItem {
+    /// Returns a new `NSMenuItem` instance.
+    pub fn new(mtm: MainThreadMarker) -> Self {
+        Self::m_new_on_main(mtm)
     }
 }
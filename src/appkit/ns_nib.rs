@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSBundle, NSData, NSDictionary, NSString, NSURL},
@@ -27,32 +28,37 @@ impl NSNib {
     /// Returns an [`NSNib`] object initialized to the nib file at the specified URL.
     #[deprecated = "use [`init_with_nib_data_bundle`] instead."]
     #[method]
-    pub fn init_with_contents_of_url(&mut self, nib_file_url: NSURL) -> id {
-        unsafe { msg_send![self.m_self(), initWithContentsOfURL: nib_file_url] }
+    pub fn init_with_contents_of_url(nib_file_url: NSURL) -> id {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            msg_send![obj, initWithContentsOfURL: nib_file_url]
+        }
     }
 
     /// Returns an [`NSNib`] object initialized to the nib file in the specified bundle.
     #[method]
-    pub fn init_with_nib_named_bundle(&mut self, nib_name: NSNibName, bundle: &NSBundle) -> Self
+    pub fn init_with_nib_named_bundle(nib_name: NSNibName, bundle: &NSBundle) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithNibNamed: nib_name bundle: bundle.m_self()],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithNibNamed: nib_name bundle: bundle.m_self()],
             )
         }
     }
 
     /// Initializes an instance with nib data and specified bundle for locating resources.
     #[method]
-    pub fn init_with_nib_data_bundle(&mut self, nib_data: &NSData, bundle: &NSBundle) -> Self
+    pub fn init_with_nib_data_bundle(nib_data: &NSData, bundle: &NSBundle) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithNibData: nib_data.m_self() bundle: bundle.m_self()],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithNibData: nib_data.m_self() bundle: bundle.m_self()],
             )
         }
     }
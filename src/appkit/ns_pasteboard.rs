@@ -1,4 +1,5 @@
-use objc::{msg_send, runtime::Class, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{runtime::Class, sel, sel_impl};
 
 use super::{interface_impl, ns_pasteboard_item::NSPasteboardItem};
 
@@ -8,6 +9,7 @@ use crate::{
     objective_c_runtime::{
         id,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::{to_bool, to_optional},
 };
@@ -163,49 +165,68 @@ impl NSPasteboard {
 
     /// Creates a new pasteboard object that supplies the specified data in as many types as possible based on the available filter services.
     #[method]
-    pub fn pasteboard_by_filtering_data_of_type(
+    pub fn pasteboard_by_filtering_data_of_type<'pool>(
         data: NSData,
         r#type: NSPasteboardType,
-    ) -> NSPasteboard {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPasteboard> {
         unsafe {
-            NSPasteboard::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), pasteboardByFilteringData: data ofType: r#type],
+                pool,
             )
         }
     }
 
     /// Creates a new pasteboard object that supplies the specified file in as many types as possible based on the available filter services.
     #[method]
-    pub fn pasteboard_by_filtering_file(filename: NSString) -> NSPasteboard {
+    pub fn pasteboard_by_filtering_file<'pool>(
+        filename: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPasteboard> {
         unsafe {
-            NSPasteboard::from_id(msg_send![
-                Self::m_class(),
-                pasteboardByFilteringFile: filename
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    pasteboardByFilteringFile: filename
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates a new pasteboard object that supplies the specified pasteboard data in as many types as possible based on the available filter services.
     #[method]
-    pub fn pasteboard_by_filtering_types_in_pasteboard(pboard: NSPasteboard) -> NSPasteboard {
+    pub fn pasteboard_by_filtering_types_in_pasteboard<'pool>(
+        pboard: NSPasteboard,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPasteboard> {
         unsafe {
-            NSPasteboard::from_id(msg_send![
-                Self::m_class(),
-                pasteboardByFilteringTypesInPasteboard: pboard
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    pasteboardByFilteringTypesInPasteboard: pboard
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns the pasteboard with the specified name.
     #[method]
-    pub fn pasteboard_with_name(name: NSPasteboardName) -> NSPasteboard {
-        unsafe { NSPasteboard::from_id(msg_send![Self::m_class(), pasteboardWithName: name]) }
+    pub fn pasteboard_with_name<'pool>(
+        name: NSPasteboardName,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPasteboard> {
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), pasteboardWithName: name], pool) }
     }
 
     /// Creates and returns a new pasteboard with a name that is guaranteed to be unique with respect to other pasteboards in the system.
     #[method]
-    pub fn pasteboard_with_unique_name() -> NSPasteboard {
-        unsafe { NSPasteboard::from_id(msg_send![Self::m_class(), pasteboardWithUniqueName]) }
+    pub fn pasteboard_with_unique_name<'pool>(
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPasteboard> {
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), pasteboardWithUniqueName], pool) }
     }
 
     /// Releases the receiver’s resources in the pasteboard server.
@@ -342,8 +363,11 @@ impl NSPasteboard {
 
     /// Returns the data types that can be converted to the specified type using the available filter services.
     #[method]
-    pub fn types_filterable_to(r#type: NSPasteboardType) -> NSArray<NSPasteboardType> {
-        unsafe { NSArray::from_id(msg_send![Self::m_class(), typesFilterableTo: r#type]) }
+    pub fn types_filterable_to<'pool>(
+        r#type: NSPasteboardType,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSArray<NSPasteboardType>> {
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), typesFilterableTo: r#type], pool) }
     }
 
     /* Preparing the Pasteboard for Content
@@ -1,38 +1,61 @@
-use objc::{class, msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{class, sel, sel_impl};
 
 use crate::{
     core_graphics::CGRectEdge,
     foundation::{NSCoder, NSRect, NSSize},
     object,
-    objective_c_runtime::{id, traits::FromId},
+    objective_c_runtime::{id, traits::FromId, DeclareClassError},
     utils::to_bool,
 };
 
 use super::{
-    interface_impl, ns_appearance::NSAppearance, INSResponder, INSView, INSViewController,
-    NSPopoverBehavior, NSViewController,
+    interface_impl,
+    ns_appearance::NSAppearance,
+    ns_popover_delegate::{register_popover_delegate_class, NSPOPOVER_DELEGATE_PTR},
+    INSResponder, INSView, INSViewController, MainThreadMarker, NSPopoverBehavior, NSResponder,
+    NSViewController, PNSPopoverDelegate,
 };
 
 object! {
     /// A means to display additional content related to existing content on the screen.
-    unsafe pub struct NSPopover;
+    unsafe pub struct NSPopover: NSResponder {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 impl NSPopover {
     /// Creates a new popover.
-    pub fn new() -> Self {
-        unsafe { Self::from_id(msg_send![class!(NSPopover), new]) }
+    pub fn new(_mtm: MainThreadMarker) -> Self {
+        unsafe { Self::from_retained_id(msg_send![class!(NSPopover), new]) }
     }
-}
 
-impl Default for NSPopover {
-    fn default() -> Self {
-        Self::new()
+    /// Sets a Rust type as the delegate of the popover.
+    ///
+    /// The delegate lives for the rest of the process. Returns the error if its class cannot
+    /// be declared.
+    ///
+    /// # Arguments
+    ///
+    /// * `popover_delegate` - The delegate to use.
+    pub fn set_popover_delegate<T>(&self, popover_delegate: T) -> Result<(), DeclareClassError>
+    where
+        T: PNSPopoverDelegate + 'static,
+    {
+        unsafe {
+            let delegate_class = register_popover_delegate_class::<T>()?;
+            let delegate: id = msg_send![delegate_class, new];
+            let delegate_ptr: *mut T = Box::into_raw(Box::new(popover_delegate));
+            (*delegate).set_ivar(NSPOPOVER_DELEGATE_PTR, delegate_ptr as usize);
+            self.set_delegate(delegate);
+        }
+
+        Ok(())
     }
 }
 
-impl INSResponder for NSPopover {}
-
 #[interface_impl(NSResponder)]
 impl NSPopover {
     /* Accessing a Popover’s Content View Controller
@@ -40,8 +63,8 @@ impl NSPopover {
 
     /// The view controller that manages the content of the popover.
     #[property]
-    pub fn content_view_controller(&self) -> NSViewController {
-        unsafe { NSViewController::from_id(msg_send![self.m_self(), contentViewController]) }
+    pub fn content_view_controller(&self) -> Option<NSViewController> {
+        unsafe { NSViewController::try_from_id(msg_send![self.m_self(), contentViewController]) }
     }
 
     /// Sets the view controller that manages the content of the popover.
@@ -178,6 +201,8 @@ impl NSPopover {
 
     /// Sets the delegate of the popover.
     ///
+    /// The popover holds its delegate weakly, and stops calling it once it is deallocated.
+    ///
     /// # Arguments
     ///
     /// * `delegate` - The delegate to use.
@@ -191,13 +216,19 @@ impl NSPopover {
 
     /// Creates a new popover.
     #[method]
-    pub fn init(&self) -> NSPopover {
-        unsafe { msg_send![self.m_self(), init] }
+    pub fn init(_mtm: MainThreadMarker) -> NSPopover {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            NSPopover::from_retained_id(msg_send![obj, init])
+        }
     }
 
     /// Creates a new popover with `NSCoder`
     #[method]
-    pub fn init_with_coder(&self, coder: NSCoder) -> NSPopover {
-        unsafe { msg_send![self.m_self(), initWithCoder: coder] }
+    pub fn init_with_coder(coder: NSCoder, _mtm: MainThreadMarker) -> NSPopover {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            NSPopover::from_retained_id(msg_send![obj, initWithCoder: coder])
+        }
     }
 }
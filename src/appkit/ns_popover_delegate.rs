@@ -1,6 +1,19 @@
+#![allow(trivial_casts)]
+
+use crate::objective_c_runtime::protocol;
+
 use super::{NSPopover, NSWindow};
 
+/// The ivar that holds the pointer to the Rust delegate.
+pub(crate) static NSPOPOVER_DELEGATE_PTR: &str = "rstNSPopoverDelegatePtr";
+
 /// A set of optional methods that a popover delegate can implement to provide additional or custom functionality.
+#[protocol(
+    class = "RSTNSPopoverDelegate",
+    register = register_popover_delegate_class,
+    ivar = NSPOPOVER_DELEGATE_PTR,
+    protocols(NSPopoverDelegate)
+)]
 pub trait PNSPopoverDelegate {
     /* Popover Window
      */
@@ -14,6 +27,7 @@ pub trait PNSPopoverDelegate {
     /// # Returns
     ///
     /// The detached window.
+    #[objc_sel("detachableWindowForPopover:")]
     fn ip_detachable_window_for_popover(&self, popover: NSPopover) -> NSWindow;
 
     /* Popover Visibility
@@ -24,23 +38,30 @@ pub trait PNSPopoverDelegate {
     /// # Arguments
     ///
     /// * `popover` - The popover that is about to close.
+    #[objc_sel("popoverShouldClose:")]
     fn im_popover_should_close(&self, popover: NSPopover) -> bool;
 
     /// Invoked when the popover will show.
+    #[objc_sel("popoverWillShow:")]
     fn im_popover_will_show(&self) {}
 
     /// Invoked when the popover has been shown.
+    #[objc_sel("popoverDidShow:")]
     fn im_popover_did_show(&self) {}
 
     ///Invoked when the popover is about to close.
+    #[objc_sel("popoverWillClose:")]
     fn im_popover_will_close(&self) {}
 
     /// Invoked when the popover did close.
+    #[objc_sel("popoverDidClose:")]
     fn im_popover_did_close(&self) {}
 
     /// Indicates that a popover has been released while it's in an implicitly detached state.
+    #[objc_sel("popoverDidDetach:")]
     fn im_popover_did_detach(&self) {}
 
     /// Returns a Boolean value that indicates whether a popover should detach from its positioning view and become a separate window.
+    #[objc_sel("popoverShouldDetach:")]
     fn im_popover_should_detach(&self, popover: NSPopover) -> bool;
 }
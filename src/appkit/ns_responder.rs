@@ -1,12 +1,16 @@
+use std::marker::PhantomData;
+
 use crate::{object, objective_c_runtime::traits::PNSObject};
 
-use super::interface_impl;
+use super::{interface_impl, MainThreadMarker};
 
 object! {
     /// An abstract class that forms the basis of event and command processing
     /// in AppKit.
-    unsafe pub struct NSResponder;
+    unsafe pub struct NSResponder {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
-#[interface_impl(NSObject)]
+#[interface_impl(NSObject, subclasses)]
 impl NSResponder {}
@@ -1,10 +1,13 @@
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     core_graphics::CGFloat,
     foundation::{
-        Int, NSAlignmentOptions, NSDictionary, NSEdgeInsets, NSNotificationName, NSRect, NSString,
-        NSTimeInterval,
+        Int, NSAlignmentOptions, NSArray, NSDictionary, NSEdgeInsets, NSNotificationName, NSRect,
+        NSString, NSTimeInterval,
     },
     object,
     objective_c_runtime::{
@@ -17,7 +20,7 @@ use crate::{
 /// These constants are the keys for device description dictionaries.
 pub type NSDeviceDescriptionKey = NSString;
 
-use super::{interface_impl, NSColorSpace, NSWindowDepth};
+use super::{interface_impl, MainThreadMarker, NSColorSpace, NSWindowDepth};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 #[repr(u64)]
@@ -32,7 +35,9 @@ extern "C" {
 
 object! {
     /// An object that describes the attributes of a computer’s monitor or screen.
-    unsafe pub struct NSScreen;
+    unsafe pub struct NSScreen {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 #[interface_impl(NSObject)]
@@ -42,20 +47,20 @@ impl NSScreen {
 
     /// Returns the screen object containing the window with the keyboard focus.
     #[property]
-    pub fn main_screen() -> NSScreen {
-        unsafe { NSScreen::from_id(msg_send![Self::m_class(), mainScreen]) }
+    pub fn main_screen(_mtm: MainThreadMarker) -> Option<NSScreen> {
+        unsafe { NSScreen::try_from_id(msg_send![Self::m_class(), mainScreen]) }
     }
 
     /// Returns a screen object representing the screen that can best represent color.
     #[property]
-    pub fn deepest_screen() -> NSScreen {
-        unsafe { NSScreen::from_id(msg_send![Self::m_class(), deepestScreen]) }
+    pub fn deepest_screen(_mtm: MainThreadMarker) -> Option<NSScreen> {
+        unsafe { NSScreen::try_from_id(msg_send![Self::m_class(), deepestScreen]) }
     }
 
     /// Returns an array of screen objects representing all of the screens available on the system.
     #[property]
-    pub fn screens() -> NSScreen {
-        unsafe { NSScreen::from_id(msg_send![Self::m_class(), screens]) }
+    pub fn screens(_mtm: MainThreadMarker) -> NSArray<NSScreen> {
+        unsafe { NSArray::from_id(msg_send![Self::m_class(), screens]) }
     }
 
     /*  Getting Screen Information
@@ -163,7 +168,8 @@ impl NSScreen {
 
     /// The distances from the screen’s edges at which content isn’t obscured.
     #[property]
-    pub fn safe_area_insets(&self) -> NSEdgeInsets {
+    #[available(macos = "12.0")]
+    pub fn safe_area_insets(&self) -> Option<NSEdgeInsets> {
         unsafe { msg_send![self.m_self(), safeAreaInsets] }
     }
 
@@ -239,13 +245,17 @@ impl NSScreen {
     /* Instance Properties
      */
 
+    /// The unobscured portion of the area to the left of the camera housing.
     #[property]
-    pub fn auxiliary_top_left_area(&self) -> NSRect {
+    #[available(macos = "12.0")]
+    pub fn auxiliary_top_left_area(&self) -> Option<NSRect> {
         unsafe { msg_send![self.m_self(), auxiliaryTopLeftArea] }
     }
 
+    /// The unobscured portion of the area to the right of the camera housing.
     #[property]
-    pub fn auxiliary_top_right_area(&self) -> NSRect {
+    #[available(macos = "12.0")]
+    pub fn auxiliary_top_right_area(&self) -> Option<NSRect> {
         unsafe { msg_send![self.m_self(), auxiliaryTopRightArea] }
     }
 }
@@ -1,4 +1,7 @@
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     core_graphics::CGFloat,
@@ -6,11 +9,13 @@ use crate::{
     objective_c_runtime::traits::{FromId, PNSObject},
 };
 
-use super::{interface_impl, NSStatusItem};
+use super::{interface_impl, MainThreadMarker, NSStatusItem};
 
 object! {
      /// An individual element displayed in the system menu bar.
-    unsafe pub struct NSStatusBar;
+    unsafe pub struct NSStatusBar {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 #[interface_impl(NSObject)]
@@ -20,7 +25,7 @@ impl NSStatusBar {
 
     /// Returns the system-wide status bar located in the menu bar.
     #[property]
-    pub fn system_status_bar() -> Self
+    pub fn system_status_bar(_mtm: MainThreadMarker) -> Self
     where
         Self: Sized + FromId,
     {
@@ -1,14 +1,19 @@
 #![allow(trivial_casts)]
 
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{object, objective_c_runtime::traits::PNSObject, utils::to_bool};
 
-use super::{interface_impl, ActionHandler, INSButton, INSControl, INSResponder, INSView};
+use super::{interface_impl, ActionHandler, INSButton, MainThreadMarker, NSButton};
 
 object! {
     /// The appearance and behavior of an item in the systemwide menu bar.
-    unsafe pub struct NSStatusBarButton;
+    unsafe pub struct NSStatusBarButton: NSButton {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 /// This is an action handler for `NSStatusBarButton`
@@ -20,7 +25,7 @@ impl NSStatusBarButton {
     /// # Arguments
     ///
     /// * `action` - The new action-message.
-    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+    pub fn set_action<F: Fn() + 'static>(&mut self, action: F) {
         let this = self.m_self();
         let handler = ActionHandler::new(unsafe { &*this }, action);
         unsafe {
@@ -29,17 +34,9 @@ impl NSStatusBarButton {
     }
 }
 
-impl INSResponder for NSStatusBarButton {}
-
-impl INSView for NSStatusBarButton {}
-
-impl INSControl for NSStatusBarButton {}
-
-impl INSButton for NSStatusBarButton {}
-
 #[interface_impl(NSButton)]
 impl NSStatusBarButton {
-    ///
+    /// A Boolean value that indicates whether the button appears disabled.
     #[property]
     pub fn appears_disabled(&self) -> bool {
         unsafe { to_bool(msg_send![self.m_self(), appearsDisabled]) }
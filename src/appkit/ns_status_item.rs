@@ -1,4 +1,7 @@
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     core_graphics::CGFloat,
@@ -10,11 +13,15 @@ use crate::{
     utils::to_bool,
 };
 
-use super::{interface_impl, NSMenu, NSStatusBar, NSStatusBarButton, NSStatusItemBehavior};
+use super::{
+    interface_impl, MainThreadMarker, NSMenu, NSStatusBar, NSStatusBarButton, NSStatusItemBehavior,
+};
 
 object! {
     /// An individual element displayed in the system menu bar.
-    unsafe pub struct NSStatusItem;
+    unsafe pub struct NSStatusItem {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 impl NSStatusItem {
@@ -25,14 +32,8 @@ impl NSStatusItem {
     pub const NSVARIABLE_STATUS_ITEM_LENGTH: CGFloat = -1.0;
 
     /// Creates a new status item.
-    pub fn new() -> Self {
-        unsafe { Self::from_id(msg_send![NSStatusItem::m_class(), alloc]) }
-    }
-}
-
-impl Default for NSStatusItem {
-    fn default() -> Self {
-        Self::new()
+    pub fn new(_mtm: MainThreadMarker) -> Self {
+        unsafe { Self::from_retained_id(msg_send![NSStatusItem::m_class(), alloc]) }
     }
 }
 
@@ -71,8 +72,8 @@ impl NSStatusItem {
 
     /// The pull-down menu displayed when the user clicks the status item.
     #[property]
-    pub fn menu(&self) -> NSMenu {
-        unsafe { NSMenu::from_id(msg_send![self.m_self(), menu]) }
+    pub fn menu(&self) -> Option<NSMenu> {
+        unsafe { NSMenu::try_from_id(msg_send![self.m_self(), menu]) }
     }
 
     /* Configuring the Status Item’s Appearance
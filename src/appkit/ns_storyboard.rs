@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSBundle,
@@ -6,6 +7,7 @@ use crate::{
     objective_c_runtime::{
         id, nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -21,16 +23,21 @@ impl INSStoryboard for NSStoryboard {}
 /// An encapsulation of the design-time view controller and window controller graph represented in an Interface Builder storyboard resource file.
 pub trait INSStoryboard: PNSObject {
     /// Creates a storyboard based on the named storyboard file in the specified bundle.
-    fn tm_storyboard_with_name_bundle(name: NSStoryboardName, bundle: Option<NSBundle>) -> Self
+    fn tm_storyboard_with_name_bundle<'pool>(
+        name: NSStoryboardName,
+        bundle: Option<NSBundle>,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), storyboardWithName: name bundle: match bundle {
                     Some(val) => val.m_self(),
                     None => nil
                 }],
+                pool,
             )
         }
     }
@@ -1,20 +1,23 @@
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
 
-use crate::{foundation::NSString, objective_c_runtime::traits::FromId, object};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
-use super::{interface_impl,  INSControl, INSResponder, INSView};
+use crate::{
+    foundation::NSString,
+    object,
+    objective_c_runtime::{traits::FromId, AutoreleasePool, Autoreleased},
+};
+
+use super::{interface_impl, INSControl, MainThreadMarker, NSControl};
 
 object! {
     /// Text the user can select or edit to send an action message to a target when the user presses the Return key.
-    unsafe pub struct NSTextField;
+    unsafe pub struct NSTextField: NSControl {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
-impl INSResponder for NSTextField {}
-
-impl INSView for NSTextField {}
-
-impl INSControl for NSTextField {}
-
 #[interface_impl(NSControl)]
 impl NSTextField {
     /* Creating Text Fields
@@ -22,28 +25,45 @@ impl NSTextField {
 
     /// Initializes a text field for use as a static label that uses the system default font, doesn’t wrap, and doesn’t have selectable text.
     #[method]
-    pub fn label_with_string(string: NSString) -> Self
+    pub fn label_with_string<'pool>(
+        string: NSString,
+        _mtm: MainThreadMarker,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), labelWithString: string]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), labelWithString: string], pool) }
     }
 
     /// Initializes a single-line editable text field for user input using the system default font and standard visual appearance.
     #[method]
-    pub fn text_field_with_string(string: NSString) -> Self
+    pub fn text_field_with_string<'pool>(
+        string: NSString,
+        _mtm: MainThreadMarker,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), textFieldWithString: string]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), textFieldWithString: string],
+                pool,
+            )
+        }
     }
 
     /// Initializes a text field for use as a multiline static label with selectable text that uses the system default font.
     #[method]
-    pub fn text_view_with_string(string: NSString) -> Self
+    pub fn text_view_with_string<'pool>(
+        string: NSString,
+        _mtm: MainThreadMarker,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), textViewWithString: string]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), textViewWithString: string], pool) }
     }
 }
@@ -1,6 +1,12 @@
+use std::marker::PhantomData;
+
 use crate::object;
 
+use super::MainThreadMarker;
+
 object! {
     /// An object that manages the space above your app's custom content and either below or integrated with the window’s title bar.
-    unsafe pub struct NSToolbar;
+    unsafe pub struct NSToolbar {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
@@ -1,4 +1,7 @@
-use objc::{msg_send, sel, sel_impl};
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSCoder, NSRect},
@@ -7,39 +10,40 @@ use crate::{
 };
 
 use super::{
-    interface_impl, INSResponder, NSLayoutXAxisAnchor, NSLayoutYAxisAnchor, NSMenuItem, NSWindow,
+    interface_impl, INSResponder, MainThreadMarker, NSLayoutXAxisAnchor, NSLayoutYAxisAnchor,
+    NSMenuItem, NSResponder, NSWindow,
 };
 
 object! {
     /// The infrastructure for drawing, printing, and handling events in an app.
-    unsafe pub struct NSView;
+    unsafe pub struct NSView: NSResponder {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
-impl INSResponder for NSView {}
-
-#[interface_impl(NSResponder)]
+#[interface_impl(NSResponder, subclasses)]
 impl NSView {
     /// Initializes and returns a newly allocated NSView object with a specified frame rectangle.
     #[method]
-    pub fn init_with_frame(frame: NSRect) -> Self
+    pub fn init_with_frame(frame: NSRect, _mtm: MainThreadMarker) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
             let obj: id = msg_send![Self::m_class(), alloc];
-            Self::from_id(msg_send![obj, initWithFrame: frame])
+            Self::from_retained_id(msg_send![obj, initWithFrame: frame])
         }
     }
 
     /// Initializes a view using from data in the specified coder object.
     #[method]
-    pub fn init_with_coder(coder: NSCoder) -> Self
+    pub fn init_with_coder(coder: NSCoder, _mtm: MainThreadMarker) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
             let obj: id = msg_send![Self::m_class(), alloc];
-            Self::from_id(msg_send![obj, initWithCoder: coder])
+            Self::from_retained_id(msg_send![obj, initWithCoder: coder])
         }
     }
 
@@ -54,8 +58,8 @@ impl NSView {
 
     /// The view that is the parent of the current view.
     #[property]
-    pub fn superview(&self) -> NSView {
-        unsafe { NSView::from_id(msg_send![self.m_self(), superview]) }
+    pub fn superview(&self) -> Option<NSView> {
+        unsafe { NSView::try_from_id(msg_send![self.m_self(), superview]) }
     }
 
     /// The array of views embedded in the current view.
@@ -69,8 +73,8 @@ impl NSView {
 
     /// The view’s window object, if it is installed in a window.
     #[property]
-    pub fn window(&self) -> NSWindow {
-        unsafe { NSWindow::from_id(msg_send![self.m_self(), window]) }
+    pub fn window(&self) -> Option<NSWindow> {
+        unsafe { NSWindow::try_from_id(msg_send![self.m_self(), window]) }
     }
 
     /// The view’s closest opaque ancestor, which might be the view itself.
@@ -90,14 +94,14 @@ impl NSView {
 
     /// Returns the closest ancestor shared by the view and another specified view.
     #[property]
-    pub fn ancestor_shared_with_view(&self, view: NSView) -> NSView {
-        unsafe { NSView::from_id(msg_send![self.m_self(), ancestorSharedWithView: view]) }
+    pub fn ancestor_shared_with_view(&self, view: NSView) -> Option<NSView> {
+        unsafe { NSView::try_from_id(msg_send![self.m_self(), ancestorSharedWithView: view]) }
     }
 
     /// The menu item containing the view or any of its superviews in the view hierarchy.
     #[property]
-    pub fn enclosing_menu_item(&self) -> NSMenuItem {
-        unsafe { NSMenuItem::from_id(msg_send![self.m_self(), enclosingMenuItem]) }
+    pub fn enclosing_menu_item(&self) -> Option<NSMenuItem> {
+        unsafe { NSMenuItem::try_from_id(msg_send![self.m_self(), enclosingMenuItem]) }
     }
 
     /* Adding and Removing Subviews
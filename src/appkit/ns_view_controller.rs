@@ -1,5 +1,8 @@
+use std::marker::PhantomData;
+
+use crate::objective_c_runtime::msg_send;
 use bitflags::bitflags;
-use objc::{class, msg_send, sel, sel_impl};
+use objc::{class, sel, sel_impl};
 
 use crate::{
     foundation::{NSBundle, UInt},
@@ -7,7 +10,9 @@ use crate::{
     objective_c_runtime::{id, traits::FromId},
 };
 
-use super::{interface_impl, INSResponder, INSView, NSNibName, NSView};
+use super::{
+    interface_impl, INSResponder, INSView, MainThreadMarker, NSNibName, NSResponder, NSView,
+};
 
 bitflags! {
     pub struct NSViewControllerTransitionOptions: UInt {
@@ -25,24 +30,18 @@ bitflags! {
 
 object! {
     /// A controller that manages a view, typically loaded from a nib file.
-    unsafe pub struct NSViewController;
+    unsafe pub struct NSViewController: NSResponder {
+        main_thread: PhantomData<MainThreadMarker>,
+    }
 }
 
 impl NSViewController {
     /// Creates a new view controller.
-    pub fn new() -> Self {
-        unsafe { Self::from_id(msg_send![class!(NSViewController), new]) }
+    pub fn new(_mtm: MainThreadMarker) -> Self {
+        unsafe { Self::from_retained_id(msg_send![class!(NSViewController), new]) }
     }
 }
 
-impl Default for NSViewController {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl INSResponder for NSViewController {}
-
 #[interface_impl(NSResponder)]
 impl NSViewController {
     /* Creating A View Controller
@@ -50,13 +49,17 @@ impl NSViewController {
 
     /// Returns a view controller object initialized to the nib file in the specified bundle.
     #[method]
-    pub fn init_with_nib_name_bundle(nib_name: NSNibName, bundle: NSBundle) -> Self
+    pub fn init_with_nib_name_bundle(
+        nib_name: NSNibName,
+        bundle: NSBundle,
+        _mtm: MainThreadMarker,
+    ) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
             let obj: id = msg_send![Self::m_class(), alloc];
-            Self::from_id(msg_send![obj, initWithNibName: nib_name bundle: bundle])
+            Self::from_retained_id(msg_send![obj, initWithNibName: nib_name bundle: bundle])
         }
     }
 
@@ -1,6 +1,9 @@
+use std::{marker::PhantomData, mem::ManuallyDrop};
+
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock, RcBlock};
 use objc::{
-    class, msg_send,
+    class,
     runtime::{Class, Object},
     sel, sel_impl,
 };
@@ -13,16 +16,17 @@ use crate::{
         NSRectEdge, NSSize, NSString, NSTimeInterval, UInt,
     },
     objective_c_runtime::{
-        id, nil,
+        available, id, nil,
         traits::{FromId, PNSObject, ToId},
+        AutoreleasePool, Autoreleased, BlockDropped, BlockFuture, DeclareClassError, Unavailable,
     },
     utils::to_bool,
 };
 
 use super::{
     ns_window_delegate::{register_window_class_with_delegate, PNSWindowDelegate},
-    INSResponder, INSViewController, NSBackingStoreType, NSButton, NSColor, NSColorSpace,
-    NSDeviceDescriptionKey, NSDockTile, NSImage, NSModalResponse, NSScreen,
+    INSResponder, INSViewController, MainThreadMarker, NSBackingStoreType, NSButton, NSColor,
+    NSColorSpace, NSDeviceDescriptionKey, NSDockTile, NSImage, NSModalResponse, NSScreen,
     NSTitlebarSeparatorStyle, NSToolbar, NSUserInterfaceLayoutDirection, NSViewController,
     NSWindowButton, NSWindowCollectionBehavior, NSWindowDepth, NSWindowFrameAutosaveName,
     NSWindowLevel, NSWindowNumberListOptions, NSWindowOcclusionState, NSWindowOrderingMode,
@@ -116,17 +120,13 @@ pub struct NSWindow<T = ()> {
 
     /// A delegate for this window.
     pub delegate: Option<Box<T>>,
-}
 
-impl Default for NSWindow {
-    fn default() -> Self {
-        NSWindow::new(WindowConfig::default())
-    }
+    main_thread: PhantomData<MainThreadMarker>,
 }
 
 impl NSWindow {
     /// Constructs a new `NSWindow`
-    pub fn new(config: WindowConfig) -> NSWindow {
+    pub fn new(config: WindowConfig, _mtm: MainThreadMarker) -> NSWindow {
         unsafe {
             NSWindow::<()>::tm_set_allows_automatic_window_tabbing(false);
 
@@ -134,47 +134,40 @@ impl NSWindow {
 
             let dimensions: NSRect = config.initial_dimensions;
 
-            let mut window = NSWindow::from_id(alloc);
-
-            window = window.im_init_with_content_rect_style_mask_backing_defer(
-                dimensions,
-                config.style,
-                NSBackingStoreType::Buffered,
-                config.defer,
-            );
-
-            window = window.autorelease();
+            let window = NSWindow::from_retained_id(alloc)
+                .im_init_with_content_rect_style_mask_backing_defer(
+                    dimensions,
+                    config.style,
+                    NSBackingStoreType::Buffered,
+                    config.defer,
+                );
 
             window.ip_set_released_when_closed(false);
             window.ip_set_restorable(false);
 
-            let toolbar_style = config.toolbar_style;
-
-            window.ip_set_toolbar_style(toolbar_style);
+            // Windows have no toolbar styles before macOS 11.
+            let _ = window.ip_set_toolbar_style(config.toolbar_style);
 
             window
         }
     }
 
     /// Allocates a new `Window`
-    fn alloc<T>() -> Self
+    fn alloc<T>() -> Result<Self, DeclareClassError>
     where
         T: PNSWindowDelegate + 'static,
     {
         let objc = unsafe {
-            let class = register_window_class_with_delegate::<T>();
+            let class = register_window_class_with_delegate::<T>()?;
             let alloc: id = msg_send![class, alloc];
-            ShareId::from_ptr(alloc)
+            ShareId::from_retained_ptr(alloc)
         };
 
-        NSWindow {
+        Ok(NSWindow {
             ptr: objc,
             delegate: None,
-        }
-    }
-
-    fn autorelease(&self) -> Self {
-        unsafe { msg_send![&*self.ptr, autorelease] }
+            main_thread: PhantomData,
+        })
     }
 }
 
@@ -182,9 +175,13 @@ impl<T> NSWindow<T>
 where
     T: PNSWindowDelegate + 'static,
 {
-    /// Constructs a new NSWindow with a `config`
-    pub fn with(config: WindowConfig, delegate: T) -> Self {
-        let mut window = NSWindow::alloc::<T>();
+    /// Constructs a new NSWindow with a `config`, or returns the error if the class of the
+    /// delegate cannot be declared.
+    pub fn with(
+        config: WindowConfig,
+        delegate: T,
+        _mtm: MainThreadMarker,
+    ) -> Result<Self, DeclareClassError> {
         let mut delegate = Box::new(delegate);
 
         let objc = unsafe {
@@ -192,28 +189,28 @@ where
 
             let dimensions: NSRect = config.initial_dimensions;
 
-            window = window.im_init_with_content_rect_style_mask_backing_defer(
-                dimensions,
-                config.style,
-                NSBackingStoreType::Buffered,
-                config.defer,
-            );
+            let window = NSWindow::alloc::<T>()?
+                .im_init_with_content_rect_style_mask_backing_defer(
+                    dimensions,
+                    config.style,
+                    NSBackingStoreType::Buffered,
+                    config.defer,
+                );
 
             let delegate_ptr: *const T = &*delegate;
             let ptr: id = msg_send![&*window.ptr, self];
 
             (*ptr).set_ivar(NSWINDOW_DELEGATE_PTR, delegate_ptr as usize);
 
-            let mut window = NSWindow::from_id(ptr);
-
-            window = window.autorelease();
+            let window = NSWindow::<T>::from_id(ptr);
 
             window.ip_set_released_when_closed(false);
 
             window.ip_set_delegate(msg_send![&*window.ptr, self]);
             window.ip_set_restorable(false);
 
-            window.ip_set_toolbar_style(config.toolbar_style);
+            // Windows have no toolbar styles before macOS 11.
+            let _ = window.ip_set_toolbar_style(config.toolbar_style);
 
             window
         };
@@ -222,13 +219,15 @@ where
             delegate.did_load(NSWindow {
                 delegate: None,
                 ptr: objc.ptr.clone(),
+                main_thread: PhantomData,
             });
         }
 
-        NSWindow {
+        Ok(NSWindow {
             ptr: objc.ptr.clone(),
             delegate: Some(delegate),
-        }
+            main_thread: PhantomData,
+        })
     }
 }
 
@@ -375,7 +374,12 @@ impl<T> NSWindow<T> {
     }
 
     /// Sets the type of separator that the app displays between the title bar and content of a window.
-    pub fn set_titlebar_separator_style(&self, style: NSTitlebarSeparatorStyle) {
+    ///
+    /// Requires macOS 11.0 or later.
+    pub fn set_titlebar_separator_style(
+        &self,
+        style: NSTitlebarSeparatorStyle,
+    ) -> Result<(), Unavailable> {
         self.ip_set_titlebar_separator_style(style)
     }
 
@@ -396,6 +400,24 @@ impl<T> NSWindow<T> {
         self.im_begin_sheet_completion_handler(window, block)
     }
 
+    /// Starts a document-modal session and presents—or queues for presentation—a sheet,
+    /// resolving to the response that ends the session.
+    pub async fn begin_sheet<W>(
+        &self,
+        window: &NSWindow<W>,
+    ) -> Result<NSModalResponse, BlockDropped>
+    where
+        W: PNSWindowDelegate + 'static,
+    {
+        BlockFuture::send(
+            |completion| move |response: NSModalResponse| completion.complete(response),
+            |block| unsafe {
+                msg_send![self.m_self(), beginSheet: window completionHandler: block]
+            },
+        )
+        .await
+    }
+
     /// Ends a document-modal session and dismisses the specified sheet.
     pub fn end_sheet<W>(&self, window: &NSWindow<W>)
     where
@@ -423,22 +445,29 @@ pub trait INSWindow: INSResponder {
      */
 
     /// Creates a titled window that contains the specified content view controller.
-    fn tm_window_with_content_view_controller<V>(content_view_controller: V) -> Self
+    fn tm_window_with_content_view_controller<'pool, V>(
+        content_view_controller: V,
+        _mtm: MainThreadMarker,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
         V: INSViewController,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                windowWithContentViewController: content_view_controller
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    windowWithContentViewController: content_view_controller
+                ],
+                pool,
+            )
         }
     }
 
-    /// Initializes the window with the specified values.
+    /// Initializes the allocated window with the specified values, taking over its reference.
     fn im_init_with_content_rect_style_mask_backing_defer(
-        &self,
+        self,
         content_rect: NSRect,
         style: UInt,
         backing_store_type: NSBackingStoreType,
@@ -447,9 +476,11 @@ pub trait INSWindow: INSResponder {
     where
         Self: Sized + FromId,
     {
+        let this = ManuallyDrop::new(self);
+
         unsafe {
-            Self::from_id(msg_send![
-                self.m_self(),
+            Self::from_retained_id(msg_send![
+                this.m_self(),
                 initWithContentRect: content_rect
                 styleMask: style
                 backing: backing_store_type
@@ -458,9 +489,9 @@ pub trait INSWindow: INSResponder {
         }
     }
 
-    /// Initializes an allocated window with the specified values.
+    /// Initializes the allocated window with the specified values, taking over its reference.
     fn im_init_with_content_rect_style_mask_backing_defer_screen(
-        &self,
+        self,
         content_rect: NSRect,
         style: UInt,
         backing_store_type: NSBackingStoreType,
@@ -470,9 +501,11 @@ pub trait INSWindow: INSResponder {
     where
         Self: Sized + FromId,
     {
+        let this = ManuallyDrop::new(self);
+
         unsafe {
-            Self::from_id(msg_send![
-                self.m_self(),
+            Self::from_retained_id(msg_send![
+                this.m_self(),
                 initWithContentRect: content_rect
                 styleMask: style
                 backing: backing_store_type
@@ -492,6 +525,9 @@ pub trait INSWindow: INSResponder {
 
     /// Sets the window’s delegate.
     ///
+    /// The window holds its delegate weakly. Windows created with [`NSWindow::with`] are
+    /// their own delegate, and own the Rust delegate they forward to.
+    ///
     /// # Arguments
     ///
     /// * `delegate` - The delegate object.
@@ -503,8 +539,8 @@ pub trait INSWindow: INSResponder {
      */
 
     /// The main content view controller for the window.
-    fn ip_content_view_controller(&self) -> NSViewController {
-        unsafe { msg_send![self.m_self(), contentViewController] }
+    fn ip_content_view_controller(&self) -> Option<NSViewController> {
+        unsafe { NSViewController::try_from_id(msg_send![self.m_self(), contentViewController]) }
     }
 
     /* Configuring the Window's Appearance
@@ -745,12 +781,18 @@ pub trait INSWindow: INSResponder {
     }
 
     /// Returns the window numbers for all visible windows satisfying the specified options.
-    fn tm_window_numbers_with_options(options: NSWindowNumberListOptions) -> NSArray<NSNumber> {
+    fn tm_window_numbers_with_options<'pool>(
+        options: NSWindowNumberListOptions,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSArray<NSNumber>> {
         unsafe {
-            NSArray::from_id(msg_send![
-                Self::m_class(),
-                windowNumbersWithOptions: options
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    windowNumbersWithOptions: options
+                ],
+                pool,
+            )
         }
     }
 
@@ -834,8 +876,8 @@ pub trait INSWindow: INSResponder {
      */
 
     /// The sheet attached to the window.
-    fn ip_attached_sheet(&self) -> NSWindow {
-        unsafe { NSWindow::from_id(msg_send![self.m_self(), attachedSheet]) }
+    fn ip_attached_sheet(&self) -> Option<NSWindow> {
+        unsafe { NSWindow::try_from_id(msg_send![self.m_self(), attachedSheet]) }
     }
 
     /// The sheet attached to the window.
@@ -896,8 +938,8 @@ pub trait INSWindow: INSResponder {
     }
 
     /// The window to which the sheet is attached.
-    fn ip_sheet_parent(&self) -> NSWindow {
-        unsafe { NSWindow::from_id(msg_send![self.m_self(), sheetParent]) }
+    fn ip_sheet_parent(&self) -> Option<NSWindow> {
+        unsafe { NSWindow::try_from_id(msg_send![self.m_self(), sheetParent]) }
     }
 
     /// An array of the sheets currently attached to the window.
@@ -1463,14 +1505,16 @@ pub trait INSWindow: INSResponder {
      */
 
     /// Returns a new instance of a given standard window button, sized appropriately for a given window style.
-    fn tm_standard_window_button_for_style_mask(
+    fn tm_standard_window_button_for_style_mask<'pool>(
         &self,
         b: NSWindowButton,
         style: NSWindowStyleMask,
-    ) -> NSButton {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSButton> {
         unsafe {
-            NSButton::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), standardWindowButton: b forStyleMask: style],
+                pool,
             )
         }
     }
@@ -1509,7 +1553,8 @@ pub trait INSWindow: INSResponder {
     }
 
     /// The style that determines the appearance and location of the toolbar in relation to the title bar.
-    fn ip_toolbar_style(&self) -> NSWindowToolbarStyle {
+    #[available(macos = "11.0")]
+    fn ip_toolbar_style(&self) -> Result<NSWindowToolbarStyle, Unavailable> {
         unsafe { msg_send![self.m_self(), toolbarStyle] }
     }
 
@@ -1518,12 +1563,14 @@ pub trait INSWindow: INSResponder {
     /// # Arguments
     ///
     /// * `style` - The style that determines the appearance and location of the toolbar in relation to the title bar.
-    fn ip_set_toolbar_style(&self, style: NSWindowToolbarStyle) {
+    #[available(macos = "11.0")]
+    fn ip_set_toolbar_style(&self, style: NSWindowToolbarStyle) -> Result<(), Unavailable> {
         unsafe { msg_send![self.m_self(), setToolbarStyle: style] }
     }
 
     /// The type of separator that the app displays between the title bar and content of a window.
-    fn ip_titlebar_separator_style(&self) -> NSTitlebarSeparatorStyle {
+    #[available(macos = "11.0")]
+    fn ip_titlebar_separator_style(&self) -> Result<NSTitlebarSeparatorStyle, Unavailable> {
         unsafe { msg_send![self.m_self(), titlebarSeparatorStyle] }
     }
 
@@ -1532,7 +1579,11 @@ pub trait INSWindow: INSResponder {
     /// # Arguments
     ///
     /// * `style` - The type of separator that the app displays between the title bar and content of a window.
-    fn ip_set_titlebar_separator_style(&self, style: NSTitlebarSeparatorStyle) {
+    #[available(macos = "11.0")]
+    fn ip_set_titlebar_separator_style(
+        &self,
+        style: NSTitlebarSeparatorStyle,
+    ) -> Result<(), Unavailable> {
         unsafe { msg_send![self.m_self(), setTitlebarSeparatorStyle: style] }
     }
 
@@ -1680,13 +1731,13 @@ pub trait INSWindow: INSResponder {
      */
 
     /// The screen the window is on.
-    fn ip_screen(&self) -> NSScreen {
-        unsafe { NSScreen::from_id(msg_send![self.m_self(), screen]) }
+    fn ip_screen(&self) -> Option<NSScreen> {
+        unsafe { NSScreen::try_from_id(msg_send![self.m_self(), screen]) }
     }
 
     /// The deepest screen the window is on (it may be split over several screens).
-    fn ip_deepest_screen(&self) -> NSScreen {
-        unsafe { NSScreen::from_id(msg_send![self.m_self(), deepestScreen]) }
+    fn ip_deepest_screen(&self) -> Option<NSScreen> {
+        unsafe { NSScreen::try_from_id(msg_send![self.m_self(), deepestScreen]) }
     }
 
     /// A Boolean value that indicates whether the window context should be updated when the screen profile changes or when the window moves to a different screen.
@@ -1779,8 +1830,8 @@ pub trait INSWindow: INSResponder {
     }
 
     /// The custom miniaturized window image of the window.
-    fn ip_miniwindow_image(&self) -> NSImage {
-        unsafe { NSImage::from_id(msg_send![self.m_self(), miniwindowImage]) }
+    fn ip_miniwindow_image(&self) -> Option<NSImage> {
+        unsafe { NSImage::try_from_id(msg_send![self.m_self(), miniwindowImage]) }
     }
 
     /// The title displayed in the window’s minimized window.
@@ -1846,7 +1897,9 @@ impl<T> INSWindow for NSWindow<T> {}
 
 impl<T> ToId for NSWindow<T> {
     fn to_id(self) -> id {
-        unsafe { msg_send![&*self.ptr, self] }
+        // Hand our reference to the pool instead of releasing it.
+        let this = ManuallyDrop::new(self);
+        unsafe { msg_send![this.m_self(), autorelease] }
     }
 }
 
@@ -1855,6 +1908,7 @@ impl<T> FromId for NSWindow<T> {
         Self {
             ptr: ShareId::from_ptr(id),
             delegate: None,
+            main_thread: PhantomData,
         }
     }
 }
@@ -1867,6 +1921,7 @@ where
         Self {
             ptr: self.ptr.clone(),
             delegate: self.delegate.clone(),
+            main_thread: PhantomData,
         }
     }
 }
@@ -2,7 +2,29 @@ use proc_macro::TokenStream;
 
 mod ns_application_main;
 
+/// Turns a function into the entry point of an AppKit app.
+///
+/// The function runs on the main thread, taking a `MainThreadMarker` if it has an argument,
+/// and then `NSApplicationMain` runs the event loop with the process's arguments. It may
+/// return a `Result`, whose error ends the program before the event loop starts. A panic
+/// in a delegate method or an action exits the process once its message is printed.
+///
+/// The options set up the shared application before the function runs:
+///
+/// - `delegate = AppDelegate` sets a default `AppDelegate` as its delegate.
+/// - `activation_policy = Regular` sets its activation policy to `Regular`, `Accessory` or
+///   `Prohibited`.
+/// - `menu = default` installs a main menu whose app menu hides and quits the app.
+///   `menu = none`, the default, leaves the menu bar empty.
+///
+/// ```text
+/// #[ns_application_main(delegate = AppDelegate, activation_policy = Regular, menu = default)]
+/// fn main(mtm: MainThreadMarker) -> Result<(), Box<dyn Error>> {
+///     NSApplication::shared_application(mtm).m_activate_ignoring_other_apps(true);
+///     Ok(())
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn ns_application_main(_args: TokenStream, item: TokenStream) -> TokenStream {
-    ns_application_main::entry_point(item)
+pub fn ns_application_main(args: TokenStream, item: TokenStream) -> TokenStream {
+    ns_application_main::entry_point(args, item)
 }
@@ -1,29 +1,406 @@
-use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{parse_macro_input, ItemFn};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    FnArg, ItemFn, ReturnType, Token, Type,
+};
 
-pub fn entry_point(item: TokenStream) -> TokenStream {
+const ACTIVATION_POLICIES: &[&str] = &["Regular", "Accessory", "Prohibited"];
+
+/// The name of the user's function inside the generated one.
+const USER_MAIN: &str = "__ns_application_main";
+
+/// The arguments of `#[ns_application_main(...)]`.
+///
+/// ```text
+/// #[ns_application_main(delegate = AppDelegate, activation_policy = Regular, menu = default)]
+/// ```
+#[derive(Default)]
+struct Options {
+    delegate: Option<Type>,
+    activation_policy: Option<Ident>,
+    default_menu: bool,
+}
+
+impl Parse for Options {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Options::default();
+        let mut menu = None;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            let duplicate = || syn::Error::new(key.span(), format!("`{key}` is given twice"));
+
+            if key == "delegate" {
+                if options.delegate.is_some() {
+                    return Err(duplicate());
+                }
+                options.delegate = Some(input.parse()?);
+            } else if key == "activation_policy" {
+                if options.activation_policy.is_some() {
+                    return Err(duplicate());
+                }
+
+                let policy: Ident = input.parse()?;
+                if !ACTIVATION_POLICIES.iter().any(|name| policy == name) {
+                    return Err(syn::Error::new(
+                        policy.span(),
+                        "expected one of the activation policies `Regular`, `Accessory` and `Prohibited`",
+                    ));
+                }
+                options.activation_policy = Some(policy);
+            } else if key == "menu" {
+                if menu.is_some() {
+                    return Err(duplicate());
+                }
+
+                // `default` is a keyword, so it doesn't parse as a plain identifier.
+                let value = input.call(Ident::parse_any)?;
+                options.default_menu = match value.to_string().as_str() {
+                    "default" => true,
+                    "none" => false,
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "expected `menu = default` or `menu = none`",
+                        ))
+                    }
+                };
+                menu = Some(value);
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "expected `delegate = ...`, `activation_policy = ...` or `menu = ...`",
+                ));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+pub fn entry_point(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let options = parse_macro_input!(args as Options);
     let item = parse_macro_input!(item as ItemFn);
 
-    let stms = item.block.stmts;
+    expand(options, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(options: Options, item: ItemFn) -> syn::Result<TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        mut sig,
+        block,
+    } = item;
+
+    check_signature(&sig)?;
 
-    let user_code = quote! {
-        #(#stms)*
+    let name = sig.ident.clone();
+    let output = sig.output.clone();
+    let user_main = Ident::new(USER_MAIN, Span::call_site());
+    sig.ident = user_main.clone();
+
+    let marker = (!sig.inputs.is_empty()).then(|| quote!(mtm));
+    let call = if returns_value(&output) {
+        quote!(#user_main(#marker)?;)
+    } else {
+        quote!(#user_main(#marker);)
     };
 
-    let main_func = quote! {
-        fn main() {
-            #user_code
-            unsafe {
-                let args = std::env::args();
-                let argv = &args as *const _ as *const *const i8;
-                rust_macios::appkit::NSApplicationMain(args.len() as std::os::raw::c_int, argv);
-            }
+    let delegate = options.delegate.map(|delegate| {
+        quote! {
+            rust_macios::appkit::INSApplication::p_set_delegate(
+                &mut app,
+                <#delegate as ::core::default::Default>::default(),
+            )
+            .expect("the app delegate class is declared");
+        }
+    });
+    let activation_policy = options.activation_policy.map(|policy| {
+        quote! {
+            app.set_activation_policy(rust_macios::appkit::NSApplicationActivationPolicy::#policy);
+        }
+    });
+    let menu = options.default_menu.then(|| {
+        quote! {
+            app.set_main_menu(rust_macios::appkit::NSMenu::default_main_menu(mtm));
+        }
+    });
+
+    let app = (delegate.is_some() || activation_policy.is_some() || menu.is_some()).then(|| {
+        quote! {
+            let mut app = rust_macios::appkit::NSApplication::shared_application(mtm);
+            #delegate
+            #activation_policy
+            #menu
+        }
+    });
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis fn #name() #output {
+            #sig #block
+
+            let mtm = rust_macios::appkit::MainThreadMarker::new()
+                .expect("`main` runs on the main thread");
+
+            #app
+            #call
+            rust_macios::appkit::application_main(mtm)
         }
+    })
+}
+
+fn check_signature(sig: &syn::Signature) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
     };
 
-    quote! {
-        #main_func
+    if let Some(asyncness) = sig.asyncness {
+        push_error(syn::Error::new(
+            asyncness.span(),
+            "`#[ns_application_main]` does not support `async fn`",
+        ));
+    }
+
+    if !sig.generics.params.is_empty() {
+        push_error(syn::Error::new(
+            sig.generics.span(),
+            "`#[ns_application_main]` does not support generic functions",
+        ));
+    }
+
+    for input in sig.inputs.iter().skip(1) {
+        push_error(syn::Error::new(
+            input.span(),
+            "`#[ns_application_main]` functions take at most a `MainThreadMarker`",
+        ));
+    }
+
+    if let Some(FnArg::Receiver(receiver)) = sig.inputs.first() {
+        push_error(syn::Error::new(
+            receiver.span(),
+            "`#[ns_application_main]` functions take at most a `MainThreadMarker`",
+        ));
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Whether the function returns something other than `()`, whose error `?` propagates.
+fn returns_value(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) => !matches!(&**ty, Type::Tuple(tuple) if tuple.elems.is_empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::*;
+
+    /// Prints a function through `syn`, so that `>>` and `> >` compare equal.
+    fn normalize(tokens: TokenStream) -> String {
+        syn::parse2::<ItemFn>(tokens)
+            .unwrap()
+            .into_token_stream()
+            .to_string()
+    }
+
+    fn expand_main(options: TokenStream, item: TokenStream) -> syn::Result<String> {
+        let options = syn::parse2(options)?;
+        let item = syn::parse2(item)?;
+
+        expand(options, item).map(normalize)
+    }
+
+    #[test]
+    fn test_plain_main() {
+        let expanded = expand_main(
+            quote!(),
+            quote!(
+                fn main() {
+                    run();
+                }
+            ),
+        );
+
+        let expected = quote! {
+            fn main() {
+                fn __ns_application_main() { run(); }
+
+                let mtm = rust_macios::appkit::MainThreadMarker::new()
+                    .expect("`main` runs on the main thread");
+
+                __ns_application_main();
+                rust_macios::appkit::application_main(mtm)
+            }
+        };
+        assert_eq!(expanded.unwrap(), normalize(expected));
+    }
+
+    #[test]
+    fn test_options() {
+        let expanded = expand_main(
+            quote!(
+                delegate = AppDelegate,
+                activation_policy = Regular,
+                menu = default
+            ),
+            quote!(
+                fn main(mtm: MainThreadMarker) {}
+            ),
+        );
+
+        let expected = quote! {
+            fn main() {
+                fn __ns_application_main(mtm: MainThreadMarker) {}
+
+                let mtm = rust_macios::appkit::MainThreadMarker::new()
+                    .expect("`main` runs on the main thread");
+
+                let mut app = rust_macios::appkit::NSApplication::shared_application(mtm);
+                rust_macios::appkit::INSApplication::p_set_delegate(
+                    &mut app,
+                    <AppDelegate as ::core::default::Default>::default(),
+                )
+                .expect("the app delegate class is declared");
+                app.set_activation_policy(
+                    rust_macios::appkit::NSApplicationActivationPolicy::Regular
+                );
+                app.set_main_menu(rust_macios::appkit::NSMenu::default_main_menu(mtm));
+
+                __ns_application_main(mtm);
+                rust_macios::appkit::application_main(mtm)
+            }
+        };
+        assert_eq!(expanded.unwrap(), normalize(expected));
+    }
+
+    #[test]
+    fn test_menu_none() {
+        let options: Options = parse_quote!(menu = none);
+
+        assert!(!options.default_menu);
+        assert!(options.delegate.is_none());
+    }
+
+    #[test]
+    fn test_result() {
+        let item = quote! {
+            /// Runs the app.
+            pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+                Ok(())
+            }
+        };
+
+        let expected = quote! {
+            /// Runs the app.
+            pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+                fn __ns_application_main() -> Result<(), Box<dyn std::error::Error>> {
+                    Ok(())
+                }
+
+                let mtm = rust_macios::appkit::MainThreadMarker::new()
+                    .expect("`main` runs on the main thread");
+
+                __ns_application_main()?;
+                rust_macios::appkit::application_main(mtm)
+            }
+        };
+        assert_eq!(expand_main(quote!(), item).unwrap(), normalize(expected));
+    }
+
+    #[test]
+    fn test_unit_return() {
+        let expanded = expand_main(
+            quote!(),
+            quote!(
+                fn main() -> () {}
+            ),
+        )
+        .unwrap();
+
+        assert!(expanded.contains("__ns_application_main () ;"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |options, item| expand_main(options, item).unwrap_err().to_string();
+
+        assert_eq!(
+            error(
+                quote!(window = Main),
+                quote!(
+                    fn main() {}
+                )
+            ),
+            "expected `delegate = ...`, `activation_policy = ...` or `menu = ...`"
+        );
+        assert_eq!(
+            error(
+                quote!(activation_policy = Background),
+                quote!(
+                    fn main() {}
+                )
+            ),
+            "expected one of the activation policies `Regular`, `Accessory` and `Prohibited`"
+        );
+        assert_eq!(
+            error(
+                quote!(menu = full),
+                quote!(
+                    fn main() {}
+                )
+            ),
+            "expected `menu = default` or `menu = none`"
+        );
+        assert_eq!(
+            error(
+                quote!(menu = default, menu = none),
+                quote!(
+                    fn main() {}
+                )
+            ),
+            "`menu` is given twice"
+        );
+        assert_eq!(
+            error(
+                quote!(),
+                quote!(
+                    async fn main() {}
+                )
+            ),
+            "`#[ns_application_main]` does not support `async fn`"
+        );
+        assert_eq!(
+            error(
+                quote!(),
+                quote!(
+                    fn main(mtm: MainThreadMarker, argc: i32) {}
+                )
+            ),
+            "`#[ns_application_main]` functions take at most a `MainThreadMarker`"
+        );
     }
-    .into()
 }
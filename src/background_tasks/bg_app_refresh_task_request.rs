@@ -1,10 +1,12 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     background_tasks::IBGTaskRequest,
     foundation::NSString,
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
     },
@@ -19,11 +21,14 @@ object! {
 impl BGAppRefreshTaskRequest {
     /// Return a new refresh task request for the specified identifier.
     #[method]
-    pub fn init_with_identifier(&mut self, identifier: NSString) -> Self
+    pub fn init_with_identifier(identifier: NSString) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithIdentifier: identifier]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithIdentifier: identifier])
+        }
     }
 }
 
@@ -1,9 +1,11 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
-use crate::{object, 
+use crate::{
     foundation::NSString,
+    object,
     objective_c_runtime::{
-        macros::{interface_impl},
+        macros::interface_impl,
         traits::{FromId, PNSObject},
     },
 };
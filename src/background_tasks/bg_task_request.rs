@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSDate,
@@ -18,8 +19,8 @@ object! {
 impl BGTaskRequest {
     /// The earliest date and time at which to run the task.
     #[property]
-    pub fn earliest_begin_date(&self) -> NSDate {
-        unsafe { NSDate::from_id(msg_send![self.m_self(), earliestBeginDate]) }
+    pub fn earliest_begin_date(&self) -> Option<NSDate> {
+        unsafe { NSDate::try_from_id(msg_send![self.m_self(), earliestBeginDate]) }
     }
 
     /// Sets the earliest date and time at which to run the task.
@@ -11,6 +11,7 @@
 //! decompress the saved file when opened by the user.
 #![allow(non_camel_case_types)]
 
+#[cfg(target_vendor = "apple")]
 use std::os::raw::c_int;
 
 use libc::c_void;
@@ -83,6 +84,7 @@ pub struct compression_stream {
     pub state: *mut c_void,
 }
 
+#[cfg(target_vendor = "apple")]
 extern "C" {
     /// Initializes a compression stream for either compression or decompression.
     ///
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
@@ -9,7 +10,7 @@ use crate::{
 use super::{CNContact, ICNChangeHistoryEvent};
 
 object! {
-    ///
+    /// An event in the contact store’s history that records a contact being added.
     unsafe pub struct CNChangeHistoryAddContactEvent;
 }
 
@@ -17,13 +18,13 @@ impl ICNChangeHistoryEvent for CNChangeHistoryAddContactEvent {}
 
 #[interface_impl(CNChangeHistoryEvent)]
 impl CNChangeHistoryAddContactEvent {
-    ///
+    /// The contact that was added.
     #[property]
     pub fn contact(&self) -> CNContact {
         unsafe { CNContact::from_id(msg_send![self.m_self(), contact]) }
     }
 
-    ///
+    /// The identifier of the container the contact was added to.
     #[property]
     pub fn container_identifier(&self) -> NSString {
         unsafe { NSString::from_id(msg_send![self.m_self(), containerIdentifier]) }
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
@@ -9,7 +10,7 @@ use crate::{
 use super::{CNGroup, ICNChangeHistoryEvent};
 
 object! {
-    ///
+    /// An event in the contact store’s history that records a group being added.
     unsafe pub struct CNChangeHistoryAddGroupEvent;
 }
 
@@ -17,13 +18,13 @@ impl ICNChangeHistoryEvent for CNChangeHistoryAddGroupEvent {}
 
 #[interface_impl(CNChangeHistoryEvent)]
 impl CNChangeHistoryAddGroupEvent {
-    ///
+    /// The group that was added.
     #[property]
     pub fn group(&self) -> CNGroup {
         unsafe { CNGroup::from_id(msg_send![self.m_self(), group]) }
     }
 
-    ///
+    /// The identifier of the container the group was added to.
     #[property]
     pub fn container_identifier(&self) -> NSString {
         unsafe { NSString::from_id(msg_send![self.m_self(), containerIdentifier]) }
@@ -1,19 +1,19 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
-use crate::{object, objective_c_runtime::{
-    id,
-    macros::{interface_impl},
-    traits::PNSObject,
-}};
+use crate::{
+    object,
+    objective_c_runtime::{id, macros::interface_impl, traits::PNSObject},
+};
 
 object! {
-    ///
+    /// An abstract base class for the events in the contact store’s change history.
     unsafe pub struct CNChangeHistoryEvent;
 }
 
 #[interface_impl(NSObject)]
 impl CNChangeHistoryEvent {
-    ///
+    /// Calls the method of `visitor` that handles this kind of event.
     #[property]
     pub fn accept_event_visitor(&self, visitor: id) {
         unsafe { msg_send![self.m_self(), acceptEventVisitor: visitor] }
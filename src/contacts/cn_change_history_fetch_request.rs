@@ -1,9 +1,9 @@
-use crate::{object, objective_c_runtime::macros::{interface_impl}};
+use crate::{object, objective_c_runtime::macros::interface_impl};
 
 use super::ICNFetchRequest;
 
 object! {
-    ///
+    /// A request that fetches the contact store’s change history.
     unsafe pub struct CNChangeHistoryFetchRequest;
 }
 
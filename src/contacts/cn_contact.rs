@@ -1,17 +1,23 @@
 use std::fmt::Debug;
 
+use crate::objective_c_runtime::msg_send;
 use crate::{
-    foundation::{NSArray, NSComparator, NSData, NSDateComponents, NSPredicate, NSString},
+    foundation::{
+        NSArray, NSComparator, NSData, NSDateComponents, NSException, NSPredicate, NSString,
+    },
     object,
     objective_c_runtime::{
-        id,
+        catch_exception, id,
         macros::interface_impl,
         nil,
+        runtime::{Sel, BOOL},
+        send_message_unwind,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased, UnwindReturn,
     },
     utils::to_bool,
 };
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use super::{
     CNContactRelation, CNInstantMessageAddress, CNLabeledValue, CNPhoneNumber, CNPostalAddress,
@@ -28,6 +34,9 @@ pub enum CNContactType {
     Organization,
 }
 
+// SAFETY: `Person` is zero, and the type is returned in a register like the `i64` it is.
+unsafe impl UnwindReturn for CNContactType {}
+
 /// Indicates the sorting order for contacts.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(i64)]
@@ -44,6 +53,10 @@ pub enum CNContactSortOrder {
 
 object! {
     /// An immutable object that stores information about a single contact, such as the contact's first name, phone numbers, and addresses.
+    ///
+    /// The getters of properties that the fetch of a contact didn't ask for raise a
+    /// `CNPropertyNotFetchedException`, which aborts the process. Their `try_` variants
+    /// return it as an `Err` instead.
     unsafe pub struct CNContact;
 }
 
@@ -290,8 +303,16 @@ impl CNContact {
 
     /// Returns a string containing the localized contact property name.
     #[method]
-    pub fn localized_string_for_key(property: NSString) -> NSString {
-        unsafe { NSString::from_id(msg_send![Self::m_class(), localizedStringForKey: property]) }
+    pub fn localized_string_for_key<'pool>(
+        property: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), localizedStringForKey: property],
+                pool,
+            )
+        }
     }
 
     /* Comparing Contacts
@@ -337,74 +358,296 @@ impl CNContact {
 
     /// Returns a predicate to find the contacts matching the specified name.
     #[method]
-    pub fn predicate_for_contacts_matching_name(name: NSString) -> NSPredicate {
+    pub fn predicate_for_contacts_matching_name<'pool>(
+        name: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContactsMatchingName: name
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContactsMatchingName: name
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the contacts matching the specified identifiers.
     #[method]
-    pub fn predicate_for_contacts_with_identifiers(identifiers: NSArray<String>) -> NSPredicate {
+    pub fn predicate_for_contacts_with_identifiers<'pool>(
+        identifiers: NSArray<String>,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContactsWithIdentifiers: identifiers
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContactsWithIdentifiers: identifiers
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the contacts that are members in the specified group.
     #[method]
-    pub fn predicate_for_contacts_in_group_with_identifier(group: NSString) -> NSPredicate {
+    pub fn predicate_for_contacts_in_group_with_identifier<'pool>(
+        group: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContactsInGroupWithIdentifier: group
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContactsInGroupWithIdentifier: group
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the contacts in the specified container.
     #[method]
-    pub fn predicate_for_contacts_in_container_with_identifier(container: NSString) -> NSPredicate {
+    pub fn predicate_for_contacts_in_container_with_identifier<'pool>(
+        container: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContactsInContainerWithIdentifier: container
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContactsInContainerWithIdentifier: container
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the contacts whose phone number matches the specified value.
     #[method]
-    pub fn predicate_for_contacts_matching_phone_number(
+    pub fn predicate_for_contacts_matching_phone_number<'pool>(
         phone_number: CNPhoneNumber,
-    ) -> NSPredicate {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContactsMatchingPhoneNumber: phone_number
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContactsMatchingPhoneNumber: phone_number
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the contacts whose email address matches the specified value.
     #[method]
-    pub fn predicate_for_contacts_matching_email_address(email_address: NSString) -> NSPredicate {
+    pub fn predicate_for_contacts_matching_email_address<'pool>(
+        email_address: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContactsMatchingEmailAddress: email_address
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContactsMatchingEmailAddress: email_address
+                ],
+                pool,
+            )
         }
     }
 }
 
-#[cfg(test)]
+/// The fallible getters, which return the `CNPropertyNotFetchedException` raised for
+/// properties that the fetch of the contact didn't ask for.
+impl CNContact {
+    fn try_get<R: UnwindReturn>(&self, sel: Sel) -> Result<R, NSException> {
+        unsafe { catch_exception(|| send_message_unwind(self.m_self(), sel, ())) }
+    }
+
+    /// [`Self::contact_type`], or the exception raised if it wasn't fetched.
+    pub fn try_contact_type(&self) -> Result<CNContactType, NSException> {
+        self.try_get(sel!(contactType))
+    }
+
+    /// [`Self::name_prefix`], or the exception raised if it wasn't fetched.
+    pub fn try_name_prefix(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(namePrefix))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::given_name`], or the exception raised if it wasn't fetched.
+    pub fn try_given_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(givenName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::middle_name`], or the exception raised if it wasn't fetched.
+    pub fn try_middle_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(middleName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::family_name`], or the exception raised if it wasn't fetched.
+    pub fn try_family_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(familyName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::previous_family_name`], or the exception raised if it wasn't fetched.
+    pub fn try_previous_family_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(previousFamilyName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::name_suffix`], or the exception raised if it wasn't fetched.
+    pub fn try_name_suffix(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(nameSuffix))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::nickname`], or the exception raised if it wasn't fetched.
+    pub fn try_nickname(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(nickname))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::phonetic_given_name`], or the exception raised if it wasn't fetched.
+    pub fn try_phonetic_given_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(phoneticGivenName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::phonetic_middle_name`], or the exception raised if it wasn't fetched.
+    pub fn try_phonetic_middle_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(phoneticMiddleName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::phonetic_family_name`], or the exception raised if it wasn't fetched.
+    pub fn try_phonetic_family_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(phoneticFamilyName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::job_title`], or the exception raised if it wasn't fetched.
+    pub fn try_job_title(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(jobTitle))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::department_name`], or the exception raised if it wasn't fetched.
+    pub fn try_department_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(departmentName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::organization_name`], or the exception raised if it wasn't fetched.
+    pub fn try_organization_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(organizationName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::phonetic_organization_name`], or the exception raised if it wasn't fetched.
+    pub fn try_phonetic_organization_name(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(phoneticOrganizationName))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::postal_addresses`], or the exception raised if it wasn't fetched.
+    pub fn try_postal_addresses(
+        &self,
+    ) -> Result<NSArray<CNLabeledValue<CNPostalAddress>>, NSException> {
+        self.try_get::<id>(sel!(postalAddresses))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::email_addresses`], or the exception raised if it wasn't fetched.
+    pub fn try_email_addresses(&self) -> Result<NSArray<CNLabeledValue<NSString>>, NSException> {
+        self.try_get::<id>(sel!(emailAddresses))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::url_addresses`], or the exception raised if it wasn't fetched.
+    pub fn try_url_addresses(&self) -> Result<NSArray<CNLabeledValue<NSString>>, NSException> {
+        self.try_get::<id>(sel!(urlAddresses))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::phone_numbers`], or the exception raised if it wasn't fetched.
+    pub fn try_phone_numbers(&self) -> Result<NSArray<CNLabeledValue<CNPhoneNumber>>, NSException> {
+        self.try_get::<id>(sel!(phoneNumbers))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::social_profiles`], or the exception raised if it wasn't fetched.
+    pub fn try_social_profiles(
+        &self,
+    ) -> Result<NSArray<CNLabeledValue<CNSocialProfile>>, NSException> {
+        self.try_get::<id>(sel!(socialProfiles))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::birthday`], or the exception raised if it wasn't fetched.
+    pub fn try_birthday(&self) -> Result<Option<NSDateComponents>, NSException> {
+        self.try_get::<id>(sel!(birthday))
+            .map(|ptr| unsafe { NSDateComponents::try_from_id(ptr) })
+    }
+
+    /// [`Self::non_gregorian_birthday`], or the exception raised if it wasn't fetched.
+    pub fn try_non_gregorian_birthday(&self) -> Result<Option<NSDateComponents>, NSException> {
+        self.try_get::<id>(sel!(nonGregorianBirthday))
+            .map(|ptr| unsafe { NSDateComponents::try_from_id(ptr) })
+    }
+
+    /// [`Self::dates`], or the exception raised if it wasn't fetched.
+    pub fn try_dates(&self) -> Result<NSArray<CNLabeledValue<NSDateComponents>>, NSException> {
+        self.try_get::<id>(sel!(dates))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::note`], or the exception raised if it wasn't fetched.
+    pub fn try_note(&self) -> Result<NSString, NSException> {
+        self.try_get::<id>(sel!(note))
+            .map(|ptr| unsafe { NSString::from_id(ptr) })
+    }
+
+    /// [`Self::image_data`], or the exception raised if it wasn't fetched.
+    pub fn try_image_data(&self) -> Result<Option<NSData>, NSException> {
+        self.try_get::<id>(sel!(imageData))
+            .map(|ptr| unsafe { NSData::try_from_id(ptr) })
+    }
+
+    /// [`Self::thumbnail_image_data`], or the exception raised if it wasn't fetched.
+    pub fn try_thumbnail_image_data(&self) -> Result<Option<NSData>, NSException> {
+        self.try_get::<id>(sel!(thumbnailImageData))
+            .map(|ptr| unsafe { NSData::try_from_id(ptr) })
+    }
+
+    /// [`Self::image_data_available`], or the exception raised if it wasn't fetched.
+    pub fn try_image_data_available(&self) -> Result<bool, NSException> {
+        self.try_get::<BOOL>(sel!(imageDataAvailable)).map(to_bool)
+    }
+
+    /// [`Self::contact_relations`], or the exception raised if it wasn't fetched.
+    pub fn try_contact_relations(
+        &self,
+    ) -> Result<NSArray<CNLabeledValue<CNContactRelation>>, NSException> {
+        self.try_get::<id>(sel!(contactRelations))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+
+    /// [`Self::instant_messaging_addresses`], or the exception raised if it wasn't fetched.
+    pub fn try_instant_messaging_addresses(
+        &self,
+    ) -> Result<NSArray<CNLabeledValue<CNInstantMessageAddress>>, NSException> {
+        self.try_get::<id>(sel!(instantMessageAddresses))
+            .map(|ptr| unsafe { NSArray::from_id(ptr) })
+    }
+}
+
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
     use crate::{contacts::CNContactType, objective_c_runtime::traits::PNSObject};
 
@@ -444,4 +687,14 @@ mod tests {
         assert!(contact.thumbnail_image_data().is_none());
         assert!(contact.url_addresses().count() == 0);
     }
+
+    #[test]
+    fn test_fallible_getters_of_fetched_properties() {
+        let contact = CNContact::m_new();
+
+        assert_eq!(contact.try_given_name().unwrap(), "");
+        assert_eq!(contact.try_contact_type().unwrap(), CNContactType::Person);
+        assert!(contact.try_birthday().unwrap().is_none());
+        assert!(!contact.try_image_data_available().unwrap());
+    }
 }
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSPredicate},
@@ -23,11 +24,14 @@ impl CNContactFetchRequest {
 
     /// Creates a fetch request for the specified keys.
     #[method]
-    pub fn init_with_keys_to_fetch(&self, keys: NSArray<id>) -> Self
+    pub fn init_with_keys_to_fetch(keys: NSArray<id>) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithKeysToFetch: keys]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithKeysToFetch: keys])
+        }
     }
 
     /* Specifying the Search Predicate
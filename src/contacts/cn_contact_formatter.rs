@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
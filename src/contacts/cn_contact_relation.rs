@@ -1,11 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -18,20 +21,31 @@ object! {
 impl CNContactRelation {
     /// Creates an object with the name of the related contact.
     #[method]
-    pub fn init_with_name(&mut self, name: NSString) -> Self
+    pub fn init_with_name(name: NSString) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithName: name]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithName: name])
+        }
     }
 
     /// Instantiate a class instance with the name of the related contact.
     #[method]
-    pub fn m_contact_relation_with_name(name: NSString) -> Self
+    pub fn m_contact_relation_with_name<'pool>(
+        name: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), contactRelationWithName: name]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), contactRelationWithName: name],
+                pool,
+            )
+        }
     }
 
     /// The name of the related contact.
@@ -1,5 +1,6 @@
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock};
-use objc::{msg_send, sel, sel_impl};
+use objc::{runtime::BOOL, sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSData, NSEnumerator, NSError, NSPredicate, NSString},
@@ -9,8 +10,9 @@ use crate::{
         macros::interface_impl,
         nil,
         traits::{FromId, PNSObject},
+        BlockFuture, CompletionError,
     },
-    utils::to_bool,
+    utils::{to_bool, to_result},
 };
 
 use super::{
@@ -94,7 +96,7 @@ impl CNContactStore {
     where
         F: IntoConcreteBlock<(CNContact, *mut bool), Ret = ()> + 'static,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
         let block = ConcreteBlock::new(block);
         let block = block.copy();
 
@@ -106,10 +108,10 @@ impl CNContactStore {
                 usingBlock: block
             ];
 
-            if error.m_self() == nil {
+            if error.is_null() {
                 Ok(ptr)
             } else {
-                Err(error)
+                Err(NSError::from_out_param(error))
             }
         }
     }
@@ -120,20 +122,16 @@ impl CNContactStore {
         &self,
         keys: NSArray<id>,
     ) -> Result<CNContact, NSError> {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let result = CNContact::from_id(msg_send![
+            let result = CNContact::try_from_id(msg_send![
                 self.m_self(),
                 unifiedMeContactWithKeysToFetch: keys
                 error: &mut error
             ]);
 
-            if error.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -144,20 +142,16 @@ impl CNContactStore {
         identifier: NSString,
         keys: NSArray<id>,
     ) -> Result<CNContact, NSError> {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
         unsafe {
-            let result = CNContact::from_id(msg_send![
+            let result = CNContact::try_from_id(msg_send![
                 self.m_self(),
                 unifiedContactWithIdentifier: identifier
                 keysToFetch: keys
                 error: &mut error
             ]);
 
-            if error.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -169,19 +163,15 @@ impl CNContactStore {
         keys: NSArray<id>,
     ) -> Result<NSArray<CNContact>, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
-            let result = NSArray::from_id(msg_send![
+            let mut error: id = nil;
+            let result = NSArray::try_from_id(msg_send![
                 self.m_self(),
                 unifiedContactsMatchingPredicate: predicate
                 keysToFetch: keys
                 error: &mut error
             ]);
 
-            if error.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -192,18 +182,14 @@ impl CNContactStore {
         fetch_request: CNContactFetchRequest,
     ) -> Result<CNFetchResult<NSEnumerator<CNContact>>, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
-            let result = CNFetchResult::from_id(msg_send![
+            let mut error: id = nil;
+            let result = CNFetchResult::try_from_id(msg_send![
                 self.m_self(),
                 enumeratorForContactFetchRequest: fetch_request
                 error: &mut error
             ]);
 
-            if !result.m_self().is_null() {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -223,19 +209,15 @@ impl CNContactStore {
         predicate: NSPredicate,
     ) -> Result<NSArray<CNGroup>, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
-            let result = NSArray::from_id(msg_send![
+            let result = NSArray::try_from_id(msg_send![
                 self.m_self(),
                 groupsMatchingPredicate: predicate
                 error: &mut error
             ]);
 
-            if error.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -246,18 +228,14 @@ impl CNContactStore {
         predicate: NSPredicate,
     ) -> Result<NSArray<CNContainer>, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
-            let result = NSArray::from_id(msg_send![
+            let mut error: id = nil;
+            let result = NSArray::try_from_id(msg_send![
                 self.m_self(),
                 containersMatchingPredicate: predicate
                 error: &mut error
             ]);
 
-            if error.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -271,18 +249,14 @@ impl CNContactStore {
         request: CNChangeHistoryFetchRequest,
     ) -> Result<CNFetchResult<NSEnumerator<CNChangeHistoryEvent>>, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
-            let result = CNFetchResult::from_id(msg_send![
+            let mut error: id = nil;
+            let result = CNFetchResult::try_from_id(msg_send![
                 self.m_self(),
                 enumeratorForChangeHistoryFetchRequest: request
                 error: &mut error
             ]);
 
-            if result.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -299,13 +273,13 @@ impl CNContactStore {
     #[method]
     pub fn execute_save_request(&self, request: CNSaveRequest) -> Result<bool, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
             let ptr =
                 to_bool(msg_send![self.m_self(), executeSaveRequest: request error: &mut error]);
 
-            if error.m_self() != nil {
-                Err(error)
+            if !error.is_null() {
+                Err(NSError::from_out_param(error))
             } else {
                 Ok(ptr)
             }
@@ -317,3 +291,28 @@ extern "C" {
     /// The shared contact store.
     pub static CNContactStoreDidChangeNotification: *const NSString;
 }
+
+impl CNContactStore {
+    /// Requests access to the user's contacts, resolving to whether the user granted it.
+    pub async fn request_access_for_entity_type(
+        &self,
+        entity_type: CNEntityType,
+    ) -> Result<bool, CompletionError> {
+        BlockFuture::send(
+            |completion| {
+                move |granted: BOOL, error: id| {
+                    completion.complete(to_result(to_bool(granted), error))
+                }
+            },
+            |block| unsafe {
+                msg_send![
+                    self.m_self(),
+                    requestAccessForEntityType: entity_type
+                    completionHandler: block
+                ]
+            },
+        )
+        .await?
+        .map_err(CompletionError::Failed)
+    }
+}
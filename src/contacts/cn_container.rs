@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSPredicate, NSString},
@@ -6,6 +7,7 @@ use crate::{
     objective_c_runtime::{
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -13,7 +15,7 @@ use crate::{
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(i64)]
 pub enum CNContainerType {
-    ///
+    /// The container type has not been assigned.
     Unassigned = 0,
     /// A container for contacts only stored locally on the device. There is only one local container for a device.
     Local,
@@ -53,36 +55,52 @@ impl CNContainer {
 
     /// Returns a predicate to find the container of the specified contact.
     #[method]
-    pub fn predicate_for_container_of_contact_identifier(identifier: NSString) -> NSPredicate {
+    pub fn predicate_for_container_of_contact_identifier<'pool>(
+        identifier: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContainerOfContactWithIdentifier: identifier
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContainerOfContactWithIdentifier: identifier
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the containers with the specified identifiers.
     #[method]
-    pub fn predicate_for_containers_with_identifiers(
+    pub fn predicate_for_containers_with_identifiers<'pool>(
         identifiers: NSArray<NSString>,
-    ) -> NSPredicate {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContainersWithIdentifiers: identifiers
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContainersWithIdentifiers: identifiers
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find the container of the specified group.
     #[method]
-    pub fn predicate_for_container_of_group_with_identifier(identifier: NSString) -> NSPredicate {
+    pub fn predicate_for_container_of_group_with_identifier<'pool>(
+        identifier: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                Self::m_class(),
-                predicateForContainerOfGroupWithIdentifier: identifier
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    predicateForContainerOfGroupWithIdentifier: identifier
+                ],
+                pool,
+            )
         }
     }
 }
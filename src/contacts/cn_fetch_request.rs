@@ -1,10 +1,10 @@
-use crate::{object, objective_c_runtime::{
-    macros::{interface_impl},
-    traits::PNSObject,
-}};
+use crate::{
+    object,
+    objective_c_runtime::{macros::interface_impl, traits::PNSObject},
+};
 
 object! {
-    ///
+    /// An abstract base class for requests that fetch from the contact store.
     unsafe pub struct CNFetchRequest;
 }
 
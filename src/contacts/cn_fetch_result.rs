@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSData,
@@ -9,23 +10,23 @@ use crate::{
 };
 
 object! {
-    ///
+    /// The value of a fetch from the contact store, along with the history token at the time of the fetch.
     unsafe pub struct CNFetchResult<ValueType> {
         marker: PhantomData<ValueType>,
     }
 }
 
-///
+/// A trait containing all the methods for [`CNFetchResult`]
 pub trait ICNFetchResult<ValueType>: PNSObject
 where
     ValueType: PNSObject + FromId,
 {
-    ///
+    /// The change history token of the store when the value was fetched.
     fn p_current_history_token(&self) -> NSData {
         unsafe { NSData::from_id(msg_send![self.m_self(), currentHistoryToken]) }
     }
 
-    ///
+    /// The fetched value.
     fn p_value(&self) -> ValueType {
         unsafe { ValueType::from_id(msg_send![self.m_self(), value]) }
     }
@@ -40,12 +41,12 @@ impl<ValueType> CNFetchResult<ValueType>
 where
     ValueType: PNSObject + FromId,
 {
-    ///
+    /// The change history token of the store when the value was fetched.
     pub fn current_history_token(&self) -> NSData {
         self.p_current_history_token()
     }
 
-    ///
+    /// The fetched value.
     pub fn value(&self) -> ValueType {
         self.p_value()
     }
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSPredicate, NSString},
@@ -6,6 +7,7 @@ use crate::{
     objective_c_runtime::{
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -33,34 +35,52 @@ impl CNGroup {
 
     /// Returns a predicate to find groups with the specified identifiers.
     #[method]
-    pub fn predicate_for_groups_with_identifiers(identifiers: NSArray<NSString>) -> NSPredicate {
+    pub fn predicate_for_groups_with_identifiers<'pool>(
+        identifiers: NSArray<NSString>,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                CNGroup::m_class(),
-                predicateForGroupsWithIdentifiers: identifiers
-            ])
+            Autoreleased::new(
+                msg_send![
+                    CNGroup::m_class(),
+                    predicateForGroupsWithIdentifiers: identifiers
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find groups in the specified container.
     #[method]
-    pub fn predicate_for_groups_in_container_with_identifiers(container: NSString) -> NSPredicate {
+    pub fn predicate_for_groups_in_container_with_identifiers<'pool>(
+        container: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                CNGroup::m_class(),
-                predicateForGroupsInContainer: container
-            ])
+            Autoreleased::new(
+                msg_send![
+                    CNGroup::m_class(),
+                    predicateForGroupsInContainer: container
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a predicate to find subgroups in the specified parent group.
     #[method]
-    pub fn predicate_for_subgroups_in_group_with_identifier(parent_group: NSString) -> NSPredicate {
+    pub fn predicate_for_subgroups_in_group_with_identifier<'pool>(
+        parent_group: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSPredicate> {
         unsafe {
-            NSPredicate::from_id(msg_send![
-                CNGroup::m_class(),
-                predicateForSubgroupsInGroup: parent_group
-            ])
+            Autoreleased::new(
+                msg_send![
+                    CNGroup::m_class(),
+                    predicateForSubgroupsInGroup: parent_group
+                ],
+                pool,
+            )
         }
     }
 }
@@ -1,11 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -18,12 +21,13 @@ object! {
 impl CNInstantMessageAddress {
     /// Returns a CNInstantMessageAddress object initialized with the specified user name and service.
     #[method]
-    pub fn init_with_username_service(&mut self, username: NSString, service: NSString) -> Self
+    pub fn init_with_username_service(username: NSString, service: NSString) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![self.m_self(), initWithUsername: username service: service])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUsername: username service: service])
         }
     }
 
@@ -46,18 +50,27 @@ impl CNInstantMessageAddress {
 
     /// Returns a string containing the localized property name.
     #[method]
-    pub fn localized_string_for_key(key: NSString) -> NSString {
-        unsafe { NSString::from_id(msg_send![Self::m_class(), localizedStringForKey: key]) }
+    pub fn localized_string_for_key<'pool>(
+        key: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), localizedStringForKey: key], pool) }
     }
 
     /// Returns a string containing the localized name of the specified service.
     #[method]
-    pub fn localized_string_for_service(service: NSString) -> NSString {
+    pub fn localized_string_for_service<'pool>(
+        service: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                Self::m_class(),
-                localizedStringForService: service
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    localizedStringForService: service
+                ],
+                pool,
+            )
         }
     }
 }
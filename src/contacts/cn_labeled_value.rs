@@ -1,11 +1,16 @@
 use std::marker::{PhantomData, Sized};
 
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
     object,
-    objective_c_runtime::traits::{FromId, PNSObject},
+    objective_c_runtime::{
+        id,
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
 };
 
 object! {
@@ -21,27 +26,29 @@ where
     ValueType: PNSObject + FromId,
 {
     /// Returns a new labeled value identifier.
-    fn m_init_with_label_value(&mut self, label: &NSString, value: ValueType) -> Self
+    fn m_init_with_label_value(label: &NSString, value: ValueType) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                self.m_self(),
-                initWithLabel:label.m_self()
-                value:value
-            ])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithLabel:label.m_self() value:value])
         }
     }
 
     /// Returns a new labeled value identifier.
-    fn m_labeled_value_with_label_value(label: &NSString, value: ValueType) -> Self
+    fn m_labeled_value_with_label_value<'pool>(
+        label: &NSString,
+        value: ValueType,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), labeledValueWithLabel:label.m_self() value:value],
+                pool,
             )
         }
     }
@@ -98,9 +105,15 @@ where
      */
 
     /// Returns a localized string for the specified label.
-    fn m_localized_string_for_label(label: &NSString) -> NSString {
+    fn m_localized_string_for_label<'pool>(
+        label: &NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![Self::m_class(), localizedStringForLabel: label.m_self()])
+            Autoreleased::new(
+                msg_send![Self::m_class(), localizedStringForLabel: label.m_self()],
+                pool,
+            )
         }
     }
 
@@ -120,13 +133,17 @@ where
     ValueType: PNSObject + FromId,
 {
     /// Returns a new labeled value identifier.
-    pub fn init_with_label_value(&mut self, label: &NSString, value: ValueType) -> Self {
-        self.m_init_with_label_value(label, value)
+    pub fn init_with_label_value(label: &NSString, value: ValueType) -> Self {
+        Self::m_init_with_label_value(label, value)
     }
 
     /// Returns a new labeled value identifier.
-    pub fn labeled_value_with_label_value(label: &NSString, value: ValueType) -> Self {
-        Self::m_labeled_value_with_label_value(label, value)
+    pub fn labeled_value_with_label_value<'pool>(
+        label: &NSString,
+        value: ValueType,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self> {
+        Self::m_labeled_value_with_label_value(label, value, pool)
     }
 
     /* Getting the Label and Value
@@ -164,8 +181,11 @@ where
      */
 
     /// Returns a localized string for the specified label.
-    pub fn localized_string_for_label(label: &NSString) -> NSString {
-        Self::m_localized_string_for_label(label)
+    pub fn localized_string_for_label<'pool>(
+        label: &NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        Self::m_localized_string_for_label(label, pool)
     }
 
     /// A unique identifier for the labeled value object.
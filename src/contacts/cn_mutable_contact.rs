@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSData, NSDateComponents, NSString},
@@ -227,7 +228,7 @@ impl CNMutableContact {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod test {
     use crate::{
         contacts::{CNContactType, CNMutableContact, ICNContact},
@@ -1,11 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -21,24 +24,33 @@ impl CNPhoneNumber {
 
     /// Returns a new phone number object initialized with the specified phone number string.
     #[method]
-    pub fn init_with_string_value(&mut self, phone_number: NSString) -> Self
+    pub fn init_with_string_value(phone_number: NSString) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithString: phone_number]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithString: phone_number])
+        }
     }
 
     /// Returns a new phone number object initialized with the specified phone number string.
     #[method]
-    pub fn phone_number_with_string_value(phone_number: NSString) -> Self
+    pub fn phone_number_with_string_value<'pool>(
+        phone_number: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                phoneNumberWithString: phone_number
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    phoneNumberWithString: phone_number
+                ],
+                pool,
+            )
         }
     }
 
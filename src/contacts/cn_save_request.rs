@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
@@ -132,13 +133,13 @@ impl CNSaveRequest {
     /* Instance Properties
      */
 
-    ///
+    /// Whether the store fetches the saved contacts again after the save, rather than updating them in place.
     #[property]
     pub fn should_refetch_contacts(&self) -> bool {
         unsafe { to_bool(msg_send![self.m_self(), shouldRefetchContacts]) }
     }
 
-    ///
+    /// The name of the author that the store records in the change history for this save.
     #[property]
     pub fn transaction_author(&self) -> NSString {
         unsafe { NSString::from_id(msg_send![self.m_self(), transactionAuthor]) }
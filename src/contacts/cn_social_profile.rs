@@ -1,11 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -19,7 +22,6 @@ impl CNSocialProfile {
     /// Initializes a new social profile object with the specified URL.
     #[method]
     pub fn init_with_url_string_username_user_identifier_service(
-        &mut self,
         url_string: NSString,
         username: NSString,
         user_identifier: NSString,
@@ -29,8 +31,9 @@ impl CNSocialProfile {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithUrlString: url_string username: username userIdentifier: user_identifier service: service],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithUrlString: url_string username: username userIdentifier: user_identifier service: service],
             )
         }
     }
@@ -67,18 +70,27 @@ impl CNSocialProfile {
 
     /// Returns the localized name of the property for the specified key.
     #[method]
-    pub fn localized_string_for_key(key: NSString) -> NSString {
-        unsafe { NSString::from_id(msg_send![Self::m_class(), localizedStringForKey: key]) }
+    pub fn localized_string_for_key<'pool>(
+        key: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), localizedStringForKey: key], pool) }
     }
 
     /// Returns the localized name of the specified service.
     #[method]
-    pub fn localized_string_for_service(service: NSString) -> NSString {
+    pub fn localized_string_for_service<'pool>(
+        service: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                Self::m_class(),
-                localizedStringForService: service
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    localizedStringForService: service
+                ],
+                pool,
+            )
         }
     }
 }
@@ -25,7 +25,7 @@ pub type CFAllocatorReleaseCallBack = extern "C" fn(info: *mut c_void);
 pub type CFAllocatorCopyDescriptionCallBack = extern "C" fn(info: *mut c_void) -> CFStringRef;
 /// A prototype for a function callback that allocates memory of a requested size.
 pub type CFAllocatorAllocateCallBack =
-    extern "C" fn(allocSize: CFIndex, hint: CFOptionFlags, info: *mut c_void) -> *mut c_void;
+    extern "C" fn(alloc_size: CFIndex, hint: CFOptionFlags, info: *mut c_void) -> *mut c_void;
 /// A prototype for a function callback that reallocates memory of a requested size for an existing block of memory.
 pub type CFAllocatorReallocateCallBack = extern "C" fn(
     ptr: *mut c_void,
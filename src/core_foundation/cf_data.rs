@@ -25,9 +25,9 @@ declare_CFType! {
 #[derive(Debug)]
 #[repr(u64)]
 pub enum CFDataSearchFlags {
-    ///
+    /// Searches from the end of the data object instead of the start.
     KCFDataSearchBackwards = 1 << 0,
-    ///
+    /// Limits the search to the start of the range, or to its end when searching backwards.
     KCFDataSearchAnchored = 1 << 1,
 }
 
@@ -1631,7 +1631,7 @@ extern "C" {
     pub fn CFStringIsSurrogateLowCharacter(character: UniChar) -> Boolean;
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
 
     use crate::core_foundation::{kCFAllocatorDefault, CFRange, CFTypeObject};
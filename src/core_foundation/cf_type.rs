@@ -16,7 +16,7 @@ pub type CFTypeRef = *const c_void;
 pub type CFHashCode = c_ulong;
 
 declare_CFType! {
-    ///
+    /// The base type of all Core Foundation objects.
     CFType, CFTypeRef
 }
 
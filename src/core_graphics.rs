@@ -7,7 +7,9 @@ pub use type_defs::*;
 
 pub mod traits;
 
+#[cfg(target_vendor = "apple")]
 mod cg_function;
+#[cfg(target_vendor = "apple")]
 pub use cg_function::*;
 
 mod cg_point;
@@ -1,5 +1,7 @@
+use crate::objective_c_runtime::Encode;
+
 /// A structure that contains a point in a two-dimensional coordinate system.
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Encode)]
 #[repr(C)]
 pub struct CGPoint {
     /// The x-coordinate of the point.
@@ -1,7 +1,9 @@
+use crate::objective_c_runtime::Encode;
+
 use super::{CGPoint, CGSize};
 
 /// A structure that contains a rectangle in a two-dimensional coordinate system.
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Encode)]
 #[repr(C)]
 pub struct CGRect {
     /// A point that specifies the coordinates of the rectangle’s origin.
@@ -9,3 +11,18 @@ pub struct CGRect {
     /// A size that specifies the height and width of the rectangle.
     pub size: CGSize,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::objective_c_runtime::encoding::EncodeType;
+
+    use super::*;
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(
+            CGRect::type_encoding().to_string(),
+            "{CGRect={CGPoint=dd}{CGSize=dd}}"
+        );
+    }
+}
@@ -1,7 +1,7 @@
-use objc::Encode;
+use crate::objective_c_runtime::Encode;
 
 /// A structure that contains width and height values.
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Encode)]
 #[repr(C)]
 pub struct CGSize {
     /// A width value.
@@ -9,9 +9,3 @@ pub struct CGSize {
     /// A height value.
     pub height: f64,
 }
-
-unsafe impl Encode for CGSize {
-    fn encode() -> objc::Encoding {
-        unsafe { objc::Encoding::from_str("{CGSize=dd}") }
-    }
-}
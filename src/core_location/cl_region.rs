@@ -1,10 +1,168 @@
-use rust_macios_objective_c_runtime_proc_macros::interface_impl;
+// Generated by rust-macios-generator. Edit the API description instead of this file.
 
-use crate::{object, objective_c_runtime::traits::PNSObject};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
+
+use crate::{
+    foundation::NSString,
+    object,
+    objective_c_runtime::{
+        macros::interface_impl,
+        traits::{FromId, PNSObject, Subclass},
+    },
+    utils::to_bool,
+};
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+use crate::{
+    foundation::{NSMutableDictionary, NSNumber, NSUUID},
+    objective_c_runtime::id,
+};
+
+/// Constants that reflect the relationship of the current location to the boundaries of a region.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(i64)]
+pub enum CLRegionState {
+    /// It is unknown whether the location is inside or outside of the region.
+    Unknown = 0,
+    /// The location is inside the given region.
+    Inside = 1,
+    /// The location is outside of the given region.
+    Outside = 2,
+}
 
 object! {
+    /// The abstract base class for representing a geographic region.
     unsafe pub struct CLRegion;
 }
 
+impl<T> ICLRegion for T
+where
+    T: Subclass,
+    T::Super: ICLRegion,
+{
+}
+
+impl<T> From<T> for CLRegion
+where
+    T: Subclass,
+    T::Super: ICLRegion,
+{
+    fn from(object: T) -> Self {
+        unsafe { CLRegion::from_id(object.m_self()) }
+    }
+}
+
 #[interface_impl(NSObject)]
-impl CLRegion {}
+impl CLRegion {
+    /// The identifier for the region object.
+    #[property]
+    pub fn identifier(&self) -> NSString {
+        unsafe { NSString::from_id(msg_send![self.m_self(), identifier]) }
+    }
+
+    /// A Boolean indicating that notifications are generated upon entry into the region.
+    #[property]
+    pub fn notify_on_entry(&self) -> bool {
+        unsafe { to_bool(msg_send![self.m_self(), notifyOnEntry]) }
+    }
+
+    /// Sets the value of `notify_on_entry`.
+    #[property]
+    pub fn set_notify_on_entry(&mut self, notify_on_entry: bool) {
+        unsafe {
+            let _: () = msg_send![self.m_self(), setNotifyOnEntry: notify_on_entry];
+        }
+    }
+
+    /// A Boolean indicating that notifications are generated upon exit from the region.
+    #[property]
+    pub fn notify_on_exit(&self) -> bool {
+        unsafe { to_bool(msg_send![self.m_self(), notifyOnExit]) }
+    }
+
+    /// Sets the value of `notify_on_exit`.
+    #[property]
+    pub fn set_notify_on_exit(&mut self, notify_on_exit: bool) {
+        unsafe {
+            let _: () = msg_send![self.m_self(), setNotifyOnExit: notify_on_exit];
+        }
+    }
+}
+
+object! {
+    /// A circular geographic region, specified as a center point and radius.
+    unsafe pub struct CLCircularRegion: CLRegion;
+}
+
+#[interface_impl(CLRegion)]
+impl CLCircularRegion {
+    /// The radius (measured in meters) that defines the geographic area’s outer boundary.
+    #[property]
+    pub fn radius(&self) -> f64 {
+        unsafe { msg_send![self.m_self(), radius] }
+    }
+}
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+object! {
+    /// A region that detects the presence of a Bluetooth beacon.
+    unsafe pub struct CLBeaconRegion: CLRegion;
+}
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+#[interface_impl(CLRegion)]
+impl CLBeaconRegion {
+    /// The unique ID of the beacons being targeted.
+    #[property]
+    pub fn uuid(&self) -> NSUUID {
+        unsafe { NSUUID::from_id(msg_send![self.m_self(), UUID]) }
+    }
+
+    /// A Boolean value that indicates whether beacon notifications are sent when the device’s display is on.
+    #[property]
+    pub fn notify_entry_state_on_display(&self) -> bool {
+        unsafe { to_bool(msg_send![self.m_self(), notifyEntryStateOnDisplay]) }
+    }
+
+    /// Sets the value of `notify_entry_state_on_display`.
+    #[property]
+    pub fn set_notify_entry_state_on_display(&mut self, notify_entry_state_on_display: bool) {
+        unsafe {
+            let _: () = msg_send![
+                self.m_self(),
+                setNotifyEntryStateOnDisplay: notify_entry_state_on_display
+            ];
+        }
+    }
+
+    /// Creates a beacon region that detects beacons with the specified UUID.
+    #[method]
+    pub fn init_with_uuid_identifier(uuid: &NSUUID, identifier: &NSString) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![
+                obj,
+                initWithUUID: uuid.m_self()
+                identifier: identifier.m_self()
+            ])
+        }
+    }
+
+    /// Retrieves data that you can use to advertise the current device as a beacon.
+    #[method]
+    pub fn peripheral_data_with_measured_power(
+        &self,
+        measured_power: &NSNumber,
+    ) -> NSMutableDictionary {
+        unsafe {
+            NSMutableDictionary::from_id(msg_send![
+                self.m_self(),
+                peripheralDataWithMeasuredPower: measured_power.m_self()
+            ])
+        }
+    }
+}
@@ -16,89 +16,185 @@ Fundamentals
 */
 
 /// A type that represents a pointer to an Objective-C object.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub mod ns_array;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_array::NSArray;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod conversion;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_attributed_string;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_bundle;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_character_set;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_coder;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_data;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_date;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_date_components;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_decimal;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_decimal_number_behaviors;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_decimal_number_handler;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_dictionary;
 mod ns_edge_insets;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_enumerator;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_error;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod ns_exception;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_file_wrapper;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_formatter;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_index_set;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod ns_invocation;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod ns_key_value_observing;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_locale;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod ns_method_signature;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_mutable_array;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_mutable_dictionary;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_mutable_string;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_notification;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_null;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_number;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_number_formatter;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod ns_object;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_orthography;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_predicate;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_process_info;
 mod ns_range;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_set;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_spell_server;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_spell_server_delegate;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_string;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_text_checking_result;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_url;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_uuid;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod string_transform;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use conversion::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_attributed_string::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_bundle::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_character_set::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_coder::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_data::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_date::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_date_components::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_decimal::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_decimal_number_handler::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_dictionary::*;
 pub use ns_edge_insets::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_enumerator::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_error::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ns_exception::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_file_wrapper::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_formatter::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_index_set::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ns_invocation::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ns_key_value_observing::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_locale::*;
-pub use ns_mutable_array::NSMutableArray;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ns_method_signature::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ns_mutable_array::{INSMutableArray, NSMutableArray};
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_mutable_dictionary::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_mutable_string::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_notification::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_null::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_number::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_number_formatter::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ns_object::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_orthography::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_predicate::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_process_info::*;
 pub use ns_range::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_set::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_spell_server::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_spell_server_delegate::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_string::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_text_checking_result::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_url::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_uuid::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use string_transform::*;
 
 /* App Support
 */
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 #[allow(improper_ctypes)]
 extern "C" {
     /// Respond to problem situations in your interactions with APIs, and fine-tune your app for better debugging.
@@ -109,6 +205,8 @@ extern "C" {
 /*Low-Level Utilities
 */
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_autoreleasepool;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_autoreleasepool::*;
@@ -0,0 +1,621 @@
+use std::{
+    any::type_name,
+    collections::{BTreeMap, HashMap, HashSet},
+    error::Error,
+    ffi::CStr,
+    fmt,
+    hash::{BuildHasher, Hash},
+    slice,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use libc::c_void;
+
+use crate::objective_c_runtime::{
+    autoreleasepool,
+    traits::{FromId, PNSObject},
+};
+
+use super::{
+    INSDictionary, INSMutableDictionary, Int, NSArray, NSData, NSDate, NSDictionary,
+    NSMutableDictionary, NSNull, NSNumber, NSObject, NSSet, NSString, UInt,
+};
+
+/// A Rust value that converts to a Foundation object, converting the contents of
+/// collections recursively.
+///
+/// Numbers and `bool` convert to `NSNumber`, `String` and `&str` to `NSString`, `Vec<u8>`
+/// to `NSData` and `SystemTime` to `NSDate`. Other `Vec`s convert to `NSArray`, `HashSet`
+/// to `NSSet`, and `HashMap` and `BTreeMap` to `NSDictionary`. `Option` converts to an
+/// [`NSObject`], which is `NSNull` for `None`. Foundation objects convert to themselves.
+///
+/// ```no_run
+/// use std::collections::HashMap;
+///
+/// use rust_macios::foundation::{FromNS, IntoNS};
+///
+/// let readings = vec![HashMap::from([("celsius".to_string(), vec![21.5, 22.0])])];
+/// let array = readings.clone().into_ns();
+///
+/// assert_eq!(Vec::<HashMap<String, Vec<f64>>>::from_ns(&array), Ok(readings));
+/// ```
+pub trait IntoNS {
+    /// The Foundation type of the converted value.
+    type NS: PNSObject + FromId;
+
+    /// Converts the value to a Foundation object.
+    fn into_ns(self) -> Self::NS;
+}
+
+/// A Rust value that converts from a Foundation object, converting the contents of
+/// collections recursively.
+///
+/// The conversions are those of [`IntoNS`] in reverse, with `NSNull` converting to `None`.
+/// They check the class of every object they convert rather than trusting the type
+/// parameters of collections, so they also read untyped data such as property lists.
+pub trait FromNS: Sized {
+    /// Converts `object`, or returns an error if it or one of its contents doesn't
+    /// convert to the Rust type.
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject;
+}
+
+/// A type whose `Vec` converts to and from `NSArray`.
+///
+/// It is implemented for every [`IntoNS`] and [`FromNS`] type except `u8`, as `Vec<u8>`
+/// converts to and from `NSData`. Implement it for your own types along with the
+/// conversions to put them in arrays.
+pub trait ArrayElement {}
+
+/// The error returned when a Foundation object doesn't convert to a Rust type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromNSError {
+    /// An object is not an instance of the class the Rust type converts from.
+    UnexpectedClass {
+        /// The class the Rust type converts from.
+        expected: &'static str,
+        /// The class of the object.
+        found: String,
+    },
+    /// A number doesn't fit in the Rust type, or has a fraction that an integer type
+    /// would drop.
+    OutOfRange {
+        /// The number.
+        number: String,
+        /// The Rust type.
+        target: &'static str,
+    },
+}
+
+impl fmt::Display for FromNSError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromNSError::UnexpectedClass { expected, found } => {
+                write!(
+                    f,
+                    "expected an instance of {expected}, found an instance of {found}"
+                )
+            }
+            FromNSError::OutOfRange { number, target } => {
+                write!(f, "{number} does not fit in {target}")
+            }
+        }
+    }
+}
+
+impl Error for FromNSError {}
+
+/// Returns `object` as a `T`, or an error naming both classes.
+fn expect_class<T, O>(object: &O) -> Result<T, FromNSError>
+where
+    T: PNSObject + FromId,
+    O: PNSObject,
+{
+    object
+        .downcast()
+        .ok_or_else(|| FromNSError::UnexpectedClass {
+            expected: T::m_class().name(),
+            found: unsafe { (*object.m_self()).class() }.name().to_owned(),
+        })
+}
+
+fn upcast<T>(object: &T) -> NSObject
+where
+    T: PNSObject,
+{
+    unsafe { NSObject::from_id(object.m_self()) }
+}
+
+fn out_of_range<T>(number: impl fmt::Display) -> FromNSError {
+    FromNSError::OutOfRange {
+        number: number.to_string(),
+        target: type_name::<T>(),
+    }
+}
+
+/* Foundation objects
+ */
+
+impl<T> IntoNS for T
+where
+    T: PNSObject + FromId,
+{
+    type NS = T;
+
+    fn into_ns(self) -> T {
+        self
+    }
+}
+
+impl<T> FromNS for T
+where
+    T: PNSObject + FromId,
+{
+    fn from_ns<O>(object: &O) -> Result<Self, FromNSError>
+    where
+        O: PNSObject,
+    {
+        expect_class(object)
+    }
+}
+
+impl<T> ArrayElement for T where T: PNSObject {}
+
+/* Numbers
+ */
+
+/// Reads an integer, failing if the number doesn't fit in `T` or isn't whole.
+fn integer<T>(number: &NSNumber) -> Result<T, FromNSError>
+where
+    T: TryFrom<i64> + TryFrom<u64>,
+{
+    let double = number.double_value();
+    let value = if double < 0.0 {
+        T::try_from(number.long_long_value()).ok()
+    } else {
+        T::try_from(number.unsigned_long_long_value()).ok()
+    };
+
+    match value {
+        Some(value) if double.fract() == 0.0 => Ok(value),
+        _ => Err(out_of_range::<T>(number.string_value())),
+    }
+}
+
+macro_rules! impl_integer_conversions {
+    ($($t:ty => $constructor:ident($raw:ty)),* $(,)?) => {
+        $(
+            impl IntoNS for $t {
+                type NS = NSNumber;
+
+                fn into_ns(self) -> NSNumber {
+                    NSNumber::$constructor(self as $raw)
+                }
+            }
+
+            impl FromNS for $t {
+                fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+                where
+                    T: PNSObject,
+                {
+                    integer(&expect_class(object)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_conversions! {
+    i8 => init_with_char(libc::c_schar),
+    i16 => init_with_short(libc::c_short),
+    i32 => init_with_int(libc::c_int),
+    i64 => init_with_long_long(libc::c_longlong),
+    isize => init_with_integer(Int),
+    u8 => init_with_unsigned_char(libc::c_uchar),
+    u16 => init_with_unsigned_short(libc::c_ushort),
+    u32 => init_with_unsigned_int(libc::c_uint),
+    u64 => init_with_unsigned_long_long(libc::c_ulonglong),
+    usize => init_with_unsigned_integer(UInt),
+}
+
+impl ArrayElement for i8 {}
+impl ArrayElement for i16 {}
+impl ArrayElement for i32 {}
+impl ArrayElement for i64 {}
+impl ArrayElement for isize {}
+impl ArrayElement for u16 {}
+impl ArrayElement for u32 {}
+impl ArrayElement for u64 {}
+impl ArrayElement for usize {}
+
+impl IntoNS for f32 {
+    type NS = NSNumber;
+
+    fn into_ns(self) -> NSNumber {
+        NSNumber::init_with_float(self)
+    }
+}
+
+impl FromNS for f32 {
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject,
+    {
+        expect_class::<NSNumber, _>(object).map(|number| number.float_value())
+    }
+}
+
+impl ArrayElement for f32 {}
+
+impl IntoNS for f64 {
+    type NS = NSNumber;
+
+    fn into_ns(self) -> NSNumber {
+        NSNumber::init_with_double(self)
+    }
+}
+
+impl FromNS for f64 {
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject,
+    {
+        expect_class::<NSNumber, _>(object).map(|number| number.double_value())
+    }
+}
+
+impl ArrayElement for f64 {}
+
+impl IntoNS for bool {
+    type NS = NSNumber;
+
+    fn into_ns(self) -> NSNumber {
+        NSNumber::init_with_bool(self)
+    }
+}
+
+impl FromNS for bool {
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject,
+    {
+        expect_class::<NSNumber, _>(object).map(|number| number.bool_value())
+    }
+}
+
+impl ArrayElement for bool {}
+
+/* Strings, data and dates
+ */
+
+impl IntoNS for String {
+    type NS = NSString;
+
+    fn into_ns(self) -> NSString {
+        NSString::from(self)
+    }
+}
+
+impl IntoNS for &str {
+    type NS = NSString;
+
+    fn into_ns(self) -> NSString {
+        NSString::from(self)
+    }
+}
+
+impl FromNS for String {
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject,
+    {
+        let string: NSString = expect_class(object)?;
+
+        // The bytes may be autoreleased.
+        Ok(autoreleasepool(|_| {
+            unsafe { CStr::from_ptr(string.bytes()) }
+                .to_string_lossy()
+                .into_owned()
+        }))
+    }
+}
+
+impl ArrayElement for String {}
+impl ArrayElement for &str {}
+
+impl IntoNS for Vec<u8> {
+    type NS = NSData;
+
+    fn into_ns(self) -> NSData {
+        NSData::init_with_bytes_length(self.as_ptr() as *const c_void, self.len() as UInt)
+    }
+}
+
+impl FromNS for Vec<u8> {
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject,
+    {
+        let data: NSData = expect_class(object)?;
+        let length = data.length() as usize;
+
+        // An empty data object may have no buffer at all.
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+
+        Ok(unsafe { slice::from_raw_parts(data.bytes() as *const u8, length) }.to_vec())
+    }
+}
+
+impl IntoNS for SystemTime {
+    type NS = NSDate;
+
+    fn into_ns(self) -> NSDate {
+        let interval = match self.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs_f64(),
+            Err(before) => -before.duration().as_secs_f64(),
+        };
+
+        autoreleasepool(|pool| NSDate::date_with_time_interval_since1970(interval, pool).retain())
+    }
+}
+
+impl FromNS for SystemTime {
+    fn from_ns<T>(object: &T) -> Result<Self, FromNSError>
+    where
+        T: PNSObject,
+    {
+        let date: NSDate = expect_class(object)?;
+        let interval = date.time_interval_since_1970();
+
+        let time = Duration::try_from_secs_f64(interval.abs())
+            .ok()
+            .and_then(|duration| {
+                if interval < 0.0 {
+                    UNIX_EPOCH.checked_sub(duration)
+                } else {
+                    UNIX_EPOCH.checked_add(duration)
+                }
+            });
+
+        time.ok_or_else(|| out_of_range::<SystemTime>(interval))
+    }
+}
+
+impl ArrayElement for SystemTime {}
+
+/* Optional values
+ */
+
+impl<T> IntoNS for Option<T>
+where
+    T: IntoNS,
+{
+    type NS = NSObject;
+
+    fn into_ns(self) -> NSObject {
+        match self {
+            Some(value) => upcast(&value.into_ns()),
+            None => upcast(&NSNull::null()),
+        }
+    }
+}
+
+impl<T> FromNS for Option<T>
+where
+    T: FromNS,
+{
+    fn from_ns<O>(object: &O) -> Result<Self, FromNSError>
+    where
+        O: PNSObject,
+    {
+        if object.downcast::<NSNull>().is_some() {
+            Ok(None)
+        } else {
+            T::from_ns(object).map(Some)
+        }
+    }
+}
+
+impl<T> ArrayElement for Option<T> {}
+
+/* Collections
+ */
+
+impl<T> IntoNS for Vec<T>
+where
+    T: IntoNS + ArrayElement,
+{
+    type NS = NSArray<T::NS>;
+
+    fn into_ns(self) -> Self::NS {
+        let objects: Vec<T::NS> = self.into_iter().map(IntoNS::into_ns).collect();
+        NSArray::from(&objects[..])
+    }
+}
+
+impl<T> FromNS for Vec<T>
+where
+    T: FromNS + ArrayElement,
+{
+    fn from_ns<O>(object: &O) -> Result<Self, FromNSError>
+    where
+        O: PNSObject,
+    {
+        let array: NSArray<NSObject> = expect_class(object)?;
+        array.iter().map(|element| T::from_ns(&element)).collect()
+    }
+}
+
+impl<T> ArrayElement for Vec<T> {}
+
+impl<T, S> IntoNS for HashSet<T, S>
+where
+    T: IntoNS,
+{
+    type NS = NSSet<T::NS>;
+
+    fn into_ns(self) -> Self::NS {
+        let objects: Vec<T::NS> = self.into_iter().map(IntoNS::into_ns).collect();
+        NSSet::init_with_array(&NSArray::from(&objects[..]))
+    }
+}
+
+impl<T, S> FromNS for HashSet<T, S>
+where
+    T: FromNS + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_ns<O>(object: &O) -> Result<Self, FromNSError>
+    where
+        O: PNSObject,
+    {
+        let set: NSSet<NSObject> = expect_class(object)?;
+        set.all_objects()
+            .iter()
+            .map(|member| T::from_ns(&member))
+            .collect()
+    }
+}
+
+impl<T, S> ArrayElement for HashSet<T, S> {}
+
+fn into_dictionary<K, V, I>(entries: I) -> NSDictionary<K::NS, V::NS>
+where
+    K: IntoNS,
+    V: IntoNS,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut dictionary = NSMutableDictionary::default();
+
+    for (key, value) in entries {
+        dictionary.im_set_object_for_key(key.into_ns(), value.into_ns());
+    }
+
+    dictionary.into()
+}
+
+fn from_dictionary<K, V, O, C>(object: &O) -> Result<C, FromNSError>
+where
+    K: FromNS,
+    V: FromNS,
+    O: PNSObject,
+    C: FromIterator<(K, V)>,
+{
+    let dictionary: NSDictionary<NSObject, NSObject> = expect_class(object)?;
+
+    dictionary
+        .p_all_keys()
+        .iter()
+        .map(|key| {
+            let value = dictionary
+                .m_object_for_key(key.clone())
+                .expect("every key of a dictionary has a value");
+
+            Ok((K::from_ns(&key)?, V::from_ns(&value)?))
+        })
+        .collect()
+}
+
+impl<K, V, S> IntoNS for HashMap<K, V, S>
+where
+    K: IntoNS,
+    V: IntoNS,
+{
+    type NS = NSDictionary<K::NS, V::NS>;
+
+    fn into_ns(self) -> Self::NS {
+        into_dictionary(self)
+    }
+}
+
+impl<K, V, S> FromNS for HashMap<K, V, S>
+where
+    K: FromNS + Eq + Hash,
+    V: FromNS,
+    S: BuildHasher + Default,
+{
+    fn from_ns<O>(object: &O) -> Result<Self, FromNSError>
+    where
+        O: PNSObject,
+    {
+        from_dictionary(object)
+    }
+}
+
+impl<K, V, S> ArrayElement for HashMap<K, V, S> {}
+
+impl<K, V> IntoNS for BTreeMap<K, V>
+where
+    K: IntoNS,
+    V: IntoNS,
+{
+    type NS = NSDictionary<K::NS, V::NS>;
+
+    fn into_ns(self) -> Self::NS {
+        into_dictionary(self)
+    }
+}
+
+impl<K, V> FromNS for BTreeMap<K, V>
+where
+    K: FromNS + Ord,
+    V: FromNS,
+{
+    fn from_ns<O>(object: &O) -> Result<Self, FromNSError>
+    where
+        O: PNSObject,
+    {
+        from_dictionary(object)
+    }
+}
+
+impl<K, V> ArrayElement for BTreeMap<K, V> {}
+
+#[cfg(all(test, not(feature = "test-runtime")))]
+mod tests {
+    use super::*;
+
+    fn round_trip<T>(value: T) -> Result<T, FromNSError>
+    where
+        T: IntoNS + FromNS,
+    {
+        T::from_ns(&value.into_ns())
+    }
+
+    #[test]
+    fn test_nested_collections() {
+        let readings = vec![
+            HashMap::from([("celsius".to_string(), vec![21.5, 22.0])]),
+            HashMap::new(),
+        ];
+        assert_eq!(round_trip(readings.clone()), Ok(readings));
+
+        let scores = BTreeMap::from([(1u32, Some(true)), (2, None)]);
+        assert_eq!(round_trip(scores.clone()), Ok(scores));
+
+        let tags: HashSet<String> = ["a".to_string(), "b".to_string()].into();
+        assert_eq!(round_trip(tags.clone()), Ok(tags));
+    }
+
+    #[test]
+    fn test_bytes_and_dates() {
+        assert_eq!(round_trip(vec![0u8, 1, 255]), Ok(vec![0, 1, 255]));
+        assert_eq!(round_trip(Vec::<u8>::new()), Ok(Vec::new()));
+
+        let time = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        assert_eq!(round_trip(time), Ok(time));
+    }
+
+    #[test]
+    fn test_typed_containers() {
+        let array: NSArray<NSString> = vec!["foo", "bar"].into_ns();
+        assert_eq!(array.count(), 2);
+
+        let numbers: NSArray<NSNumber> = vec![1u16, 2].into();
+        assert_eq!(Vec::<i32>::from_ns(&numbers), Ok(vec![1, 2]));
+        assert!(Vec::<String>::from_ns(&numbers).is_err());
+    }
+}
@@ -369,7 +369,7 @@ pub enum NSUrlError {
     DownloadDecodingFailedToComplete = -3007,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u64)]
 pub enum NSKeyValueObservingOptions {
     New = 1,
@@ -379,7 +379,7 @@ pub enum NSKeyValueObservingOptions {
     Prior = 8,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u64)]
 pub enum NSKeyValueChange {
     Setting = 1,
@@ -388,7 +388,7 @@ pub enum NSKeyValueChange {
     Replacement,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u64)]
 pub enum NSKeyValueSetMutationKind {
     UnionSet = 1,
@@ -970,7 +970,12 @@ pub enum NSDateComponentsFormatterUnitsStyle {
 }
 
 /// The formatting context for a formatter.
-#[cfg(any(target_os = "ios", target_os = "macos"))]
+#[cfg(any(
+    target_os = "ios",
+    target_os = "macos",
+    feature = "gnustep",
+    feature = "test-runtime"
+))]
 #[derive(Debug)]
 #[repr(i64)]
 pub enum NSFormattingContext {
@@ -1134,7 +1139,12 @@ pub enum NSPersonNameComponentsFormatterStyle {
     Abbreviated,
 }
 
-#[cfg(any(target_os = "ios", target_os = "macos"))]
+#[cfg(any(
+    target_os = "ios",
+    target_os = "macos",
+    feature = "gnustep",
+    feature = "test-runtime"
+))]
 #[derive(Debug)]
 #[repr(i64)]
 pub enum NSDecodingFailurePolicy {
@@ -1225,34 +1235,34 @@ pub enum NSStringEncodingConversionOptions {
     ExternalRepresentation = 2,
 }
 
-///
+/// Coordinates that specify the edges of a rectangle.
 #[derive(Debug)]
 #[repr(u64)]
 pub enum NSRectEdge {
-    ///
+    /// The minimum value for the x-coordinate of the rectangle.
     MinXEdge,
-    ///
+    /// The minimum value for the y-coordinate of the rectangle.
     MinYEdge,
-    ///
+    /// The maximum value for the x-coordinate of the rectangle.
     MaxXEdge,
-    ///
+    /// The maximum value for the y-coordinate of the rectangle.
     MaxYEdge,
 }
 
 impl NSRectEdge {
-    ///
+    /// The right edge of the rectangle.
     #[allow(non_upper_case_globals)]
     pub const MaxX: CGRectEdge = CGRectEdge::MaxXEdge;
 
-    ///
+    /// The top edge of the rectangle.
     #[allow(non_upper_case_globals)]
     pub const MaxY: CGRectEdge = CGRectEdge::MaxYEdge;
 
-    ///
+    /// The left edge of the rectangle.
     #[allow(non_upper_case_globals)]
     pub const MinX: CGRectEdge = CGRectEdge::MinXEdge;
 
-    ///
+    /// The bottom edge of the rectangle.
     #[allow(non_upper_case_globals)]
     pub const MinY: CGRectEdge = CGRectEdge::MinYEdge;
 }
@@ -1,9 +1,11 @@
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 use super::NSEdgeInsets;
 
 /// Size of UTF8 encoding
 pub const UTF8_ENCODING: usize = 4;
 
 /// The keys used to access components of a locale.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub mod ns_localekey {
     use crate::foundation::NSLocaleKey;
 
@@ -86,6 +88,68 @@ pub mod ns_localekey {
     }
 }
 
+/// The names of the exceptions Foundation raises.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub mod ns_exception_name {
+    use crate::foundation::NSExceptionName;
+
+    extern "C" {
+        /// A generic name for an exception.
+        #[link_name = "NSGenericException"]
+        pub static GenericException: NSExceptionName;
+
+        /// The name of an exception that occurs when attempting to access outside the bounds
+        /// of some data, such as beyond the end of a string or an array.
+        #[link_name = "NSRangeException"]
+        pub static RangeException: NSExceptionName;
+
+        /// The name of an exception that occurs when you pass an invalid argument to a method,
+        /// such as a nil pointer where a non-nil object is required.
+        #[link_name = "NSInvalidArgumentException"]
+        pub static InvalidArgumentException: NSExceptionName;
+
+        /// The name of an exception that occurs when an internal assertion fails and implies
+        /// an unexpected condition within the called code.
+        #[link_name = "NSInternalInconsistencyException"]
+        pub static InternalInconsistencyException: NSExceptionName;
+
+        /// The name of an exception that occurs when a remote object is accessed from a
+        /// thread that should not access it.
+        #[link_name = "NSObjectInaccessibleException"]
+        pub static ObjectInaccessibleException: NSExceptionName;
+    }
+}
+
+/// The keys of the change dictionary passed to key-value observers.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub mod ns_key_value_change_key {
+    use crate::foundation::NSKeyValueChangeKey;
+
+    extern "C" {
+        /// An `NSNumber` holding the `NSKeyValueChange` that describes the change.
+        #[link_name = "NSKeyValueChangeKindKey"]
+        pub static KindKey: NSKeyValueChangeKey;
+
+        /// The new value of the attribute.
+        #[link_name = "NSKeyValueChangeNewKey"]
+        pub static NewKey: NSKeyValueChangeKey;
+
+        /// The value of the attribute before the change.
+        #[link_name = "NSKeyValueChangeOldKey"]
+        pub static OldKey: NSKeyValueChangeKey;
+
+        /// An `NSIndexSet` holding the indexes of the inserted, removed or replaced objects
+        /// of a to-many relationship.
+        #[link_name = "NSKeyValueChangeIndexesKey"]
+        pub static IndexesKey: NSKeyValueChangeKey;
+
+        /// An `NSNumber` holding `YES` when the notification is sent before the change.
+        #[link_name = "NSKeyValueChangeNotificationIsPriorKey"]
+        pub static NotificationIsPriorKey: NSKeyValueChangeKey;
+    }
+}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 extern "C" {
     /// A zero initialized `NSEdgeInsets`.
     pub static NSEdgeInsetsZero: NSEdgeInsets;
@@ -1,7 +1,8 @@
 use std::{marker::PhantomData, slice};
 
+use crate::objective_c_runtime::msg_send;
 use libc::c_char;
-use objc::{class, msg_send, runtime::Object, sel, sel_impl};
+use objc::{class, runtime::Object, sel, sel_impl};
 
 use crate::{
     foundation::NSString,
@@ -10,12 +11,16 @@ use crate::{
         id,
         traits::{FromId, PNSObject},
     },
+    try_msg_send,
     utils::to_bool,
 };
 
 use self::iter::Iter;
 
-use super::{ns_mutable_array::NSMutableArray, NSLocale, NSNumber, NSRange, UInt, UInt8};
+use super::{
+    ns_mutable_array::NSMutableArray, ArrayElement, IntoNS, NSException, NSLocale, NSNumber,
+    NSRange, UInt, UInt8,
+};
 
 /// Iterator for Array
 pub mod iter;
@@ -122,6 +127,18 @@ pub trait INSArray<T>: PNSObject {
         unsafe { T::from_id(msg_send![self.m_self(), objectAtIndex: index]) }
     }
 
+    /// The object at the specified index, or the `NSRangeException` raised when `index` is
+    /// beyond the end of the array.
+    fn m_try_object_at_index(&self, index: UInt) -> Result<T, NSException>
+    where
+        T: PNSObject + FromId,
+    {
+        unsafe {
+            let id: id = try_msg_send![self.m_self(), objectAtIndex: index]?;
+            Ok(T::from_id(id))
+        }
+    }
+
     /// The index of the specified object.
     fn m_object_at_indexed_subscript(&self, index: UInt) -> Option<id> {
         unsafe {
@@ -215,8 +232,6 @@ pub trait INSArray<T>: PNSObject {
     /* Creating a Description
      */
 
-    /// A string that represents the contents of the array, formatted as a property list.
-
     /// Returns a string that represents the contents of the array, formatted as a property list.
     fn m_description_with_locale(&self, locale: &NSLocale) -> NSString {
         unsafe { msg_send![self.m_self(), descriptionWithLocale: locale.m_self()] }
@@ -277,80 +292,18 @@ where
     }
 }
 
-impl<T> From<Vec<T>> for NSArray<T>
+impl<T> From<Vec<T>> for NSArray<T::NS>
 where
-    T: PNSObject,
+    T: IntoNS + ArrayElement,
 {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
+    /// Converts the elements with [`IntoNS`], and creates an `Array` that holds them.
     fn from(objects: Vec<T>) -> Self {
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<i8>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<i8>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<i16>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<i16>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<i32>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<i32>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<i64>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<i64>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
+        objects.into_ns()
     }
 }
 
 impl From<Vec<u8>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
+    /// Creates an `Array` of `NSNumber`s, whereas [`IntoNS`] converts bytes to `NSData`.
     fn from(objects: Vec<u8>) -> Self {
         let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
         unsafe {
@@ -363,48 +316,6 @@ impl From<Vec<u8>> for NSArray<NSNumber> {
     }
 }
 
-impl From<Vec<u16>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<u16>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<u32>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<u32>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<u64>> for NSArray<NSNumber> {
-    /// Given a set of `Object`s, creates an `Array` that holds them.
-    fn from(objects: Vec<u64>) -> Self {
-        let objects: Vec<NSNumber> = objects.iter().map(|i| NSNumber::from(*i)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
 impl From<&[u8]> for NSArray<u8> {
     /// Given a set of `Object`s, creates an `Array` that holds them.
     fn from(objects: &[u8]) -> Self {
@@ -444,32 +355,6 @@ impl From<Vec<id>> for NSArray<id> {
     }
 }
 
-impl From<Vec<&str>> for NSArray<NSString> {
-    fn from(objects: Vec<&str>) -> Self {
-        let objects: Vec<NSString> = objects.iter().map(|s| NSString::from(*s)).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
-impl From<Vec<String>> for NSArray<NSString> {
-    fn from(objects: Vec<String>) -> Self {
-        let objects: Vec<NSString> = objects.iter().map(|s| NSString::from(s.clone())).collect();
-        unsafe {
-            let cls: id = msg_send![class!(NSArray),
-                arrayWithObjects:objects.as_ptr()
-                count:objects.len()
-            ];
-            NSArray::from(cls)
-        }
-    }
-}
-
 impl<T> From<NSMutableArray<T>> for NSArray<T>
 where
     T: PNSObject,
@@ -485,7 +370,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
     use super::*;
 
@@ -40,7 +40,7 @@ where
     where
         I: IntoIterator<Item = &'a T>,
     {
-        let mut mut_arr = NSMutableArray::tm_array_with_capacity(0);
+        let mut mut_arr = NSMutableArray::im_init_with_capacity(0);
         for item in iter {
             mut_arr.im_add_object(item);
         }
@@ -54,7 +54,7 @@ impl FromIterator<NSString> for NSArray<NSString> {
     where
         I: IntoIterator<Item = NSString>,
     {
-        let mut mut_arr = NSMutableArray::tm_array_with_capacity(0);
+        let mut mut_arr = NSMutableArray::im_init_with_capacity(0);
         for item in iter {
             mut_arr.im_add_object(&item);
         }
@@ -68,7 +68,7 @@ impl FromIterator<NSNumber> for NSArray<NSNumber> {
     where
         I: IntoIterator<Item = NSNumber>,
     {
-        let mut mut_arr = NSMutableArray::tm_array_with_capacity(0);
+        let mut mut_arr = NSMutableArray::im_init_with_capacity(0);
         for item in iter {
             mut_arr.im_add_object(&item);
         }
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
@@ -33,7 +34,10 @@ impl NSAttributedString {
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), initWithString: string.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithString: string.m_self()])
+        }
     }
 
     /// Creates an attributed string with the specified string and attributes.
@@ -46,8 +50,9 @@ impl NSAttributedString {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![Self::m_class(), initWithString: string.m_self() attributes: attributes.m_self()],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithString: string.m_self() attributes: attributes.m_self()],
             )
         }
     }
@@ -59,10 +64,8 @@ impl NSAttributedString {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                initWithAttributedString: attr_string.m_self()
-            ])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithAttributedString: attr_string.m_self()])
         }
     }
 
@@ -77,16 +80,13 @@ impl NSAttributedString {
         Self: Sized + FromId,
     {
         unsafe {
-            let mut error = NSError::m_alloc();
-            let result = Self::from_id(
-                msg_send![Self::m_class(), initWithData: data.m_self() options: options.m_self() documentAttributes: document.m_self() error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let mut error: id = nil;
+            let result = Self::try_from_retained_id(
+                msg_send![obj, initWithData: data.m_self() options: options.m_self() documentAttributes: document.m_self() error: &mut error],
             );
 
-            if error.m_self() == nil {
-                Ok(result)
-            } else {
-                Err(error)
-            }
+            result.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -1,13 +1,16 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
-use crate::{object, objective_c_runtime::{
-    id,
-    macros::{interface_impl},
-    traits::PNSObject,
-}};
+use crate::{
+    object,
+    objective_c_runtime::{id, macros::interface_impl, traits::PNSObject},
+};
 
 object! {
     /// An object that supports Cocoa’s reference-counted memory management system.
+    ///
+    /// [`autoreleasepool`](crate::objective_c_runtime::autoreleasepool) scopes a pool to a
+    /// closure and ties autoreleased values to it, which this type cannot do.
     unsafe pub struct NSAutoreleasePool;
 }
 
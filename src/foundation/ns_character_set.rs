@@ -1,9 +1,13 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
-use crate::{object, 
+use crate::{
+    object,
     objective_c_runtime::{
-        macros::{interface_impl},
+        id,
+        macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_bool,
 };
@@ -162,28 +166,45 @@ impl NSCharacterSet {
 
     /// Initializing with coder
     #[method]
-    pub fn init_with_coder(mut self, coder: NSCoder) -> Self
+    pub fn init_with_coder(coder: NSCoder) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithCoder: coder]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCoder: coder])
+        }
     }
 
     /// Returns a character set containing the characters in a given string.
     #[method]
-    pub fn character_set_with_characters_in_string(string: NSString) -> NSCharacterSet {
+    pub fn character_set_with_characters_in_string<'pool>(
+        string: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSCharacterSet> {
         unsafe {
-            NSCharacterSet::from_id(msg_send![
-                Self::m_class(),
-                characterSetWithCharactersInString: string
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    characterSetWithCharactersInString: string
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a character set containing characters with Unicode values in a given range.
     #[method]
-    pub fn character_set_with_range(range: NSRange) -> NSCharacterSet {
-        unsafe { NSCharacterSet::from_id(msg_send![Self::m_class(), characterSetWithRange: range]) }
+    pub fn character_set_with_range<'pool>(
+        range: NSRange,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSCharacterSet> {
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), characterSetWithRange: range],
+                pool,
+            )
+        }
     }
 
     /* Creating and Managing Character Sets as Bitmap Representations
@@ -191,23 +212,35 @@ impl NSCharacterSet {
 
     /// Returns a character set containing characters determined by a given bitmap representation.
     #[method]
-    pub fn character_set_with_bitmap_representation(data: NSData) -> NSCharacterSet {
+    pub fn character_set_with_bitmap_representation<'pool>(
+        data: NSData,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSCharacterSet> {
         unsafe {
-            NSCharacterSet::from_id(msg_send![
-                Self::m_class(),
-                characterSetWithBitmapRepresentation: data
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    characterSetWithBitmapRepresentation: data
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a character set read from the bitmap representation stored in the file a given path.
     #[method]
-    pub fn character_set_with_contents_of_file(path: NSString) -> NSCharacterSet {
+    pub fn character_set_with_contents_of_file<'pool>(
+        path: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSCharacterSet> {
         unsafe {
-            NSCharacterSet::from_id(msg_send![
-                Self::m_class(),
-                characterSetWithContentsOfFile: path
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    characterSetWithContentsOfFile: path
+                ],
+                pool,
+            )
         }
     }
 
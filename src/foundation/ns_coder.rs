@@ -1,11 +1,13 @@
+use crate::objective_c_runtime::msg_send;
 use libc::{c_char, c_double, c_float, c_void};
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::core_graphics::{CGPoint, CGRect, CGSize};
-use crate::{object, 
+use crate::{
+    object,
     objective_c_runtime::{
         id,
-        macros::{interface_impl},
+        macros::interface_impl,
         traits::{PNSObject, ToId},
     },
     utils::to_bool,
@@ -1,11 +1,16 @@
+use crate::objective_c_runtime::msg_send;
 use block::IntoConcreteBlock;
 use libc::c_void;
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
-use crate::{object, 
+use crate::{
+    object,
     objective_c_runtime::{
-        macros::{interface_impl},
+        id,
+        macros::interface_impl,
+        nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_bool,
 };
@@ -103,84 +108,108 @@ impl NSData {
 
     /// Creates an empty data object.
     #[method]
-    pub fn data() -> Self
+    pub fn data<'pool>(pool: &'pool AutoreleasePool) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), data]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), data], pool) }
     }
 
     /// Creates a data object containing a given number of bytes copied from a given buffer.
     #[method]
-    pub fn data_with_bytes_length(bytes: *const c_void, length: UInt) -> Self
+    pub fn data_with_bytes_length<'pool>(
+        bytes: *const c_void,
+        length: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), dataWithBytes: bytes length: length]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), dataWithBytes: bytes length: length],
+                pool,
+            )
+        }
     }
 
     /// Creates a data object that holds a given number of bytes from a given buffer.
     #[method]
-    pub fn data_with_bytes_no_copy_length(bytes: *mut c_void, length: UInt) -> Self
+    pub fn data_with_bytes_no_copy_length<'pool>(
+        bytes: *mut c_void,
+        length: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![Self::m_class(), dataWithBytesNoCopy: bytes
-                                                                  length: length])
+            Autoreleased::new(
+                msg_send![Self::m_class(), dataWithBytesNoCopy: bytes
+                                                                  length: length],
+                pool,
+            )
         }
     }
 
     /// Creates a data object that holds a given number of bytes from a given buffer.
     #[method]
-    pub fn data_with_bytes_no_copy_length_free_when_done(
+    pub fn data_with_bytes_no_copy_length_free_when_done<'pool>(
         bytes: *mut c_void,
         length: UInt,
         b: bool,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), dataWithBytesNoCopy:bytes length:length freeWhenDone:b],
+                pool,
             )
         }
     }
 
     /// Creates a data object containing the contents of another data object.
     #[method]
-    pub fn data_with_data(data: NSData) -> Self
+    pub fn data_with_data<'pool>(
+        data: NSData,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), dataWithData: data]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), dataWithData: data], pool) }
     }
 
     /// Initializes a data object filled with a given number of bytes copied from a given buffer.
     #[method]
-    pub fn init_with_bytes_length(&mut self, bytes: *const c_void, length: UInt) -> Self
+    pub fn init_with_bytes_length(bytes: *const c_void, length: UInt) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithBytes: bytes length: length]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithBytes: bytes length: length])
+        }
     }
 
     /// Initializes a data object filled with a given number of bytes of data from a given buffer.
     #[method]
-    pub fn init_with_bytes_no_copy_length(&mut self, bytes: *mut c_void, length: UInt) -> Self
+    pub fn init_with_bytes_no_copy_length(bytes: *mut c_void, length: UInt) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![self.m_self(), initWithBytesNoCopy: bytes length: length])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithBytesNoCopy: bytes length: length])
         }
     }
 
     /// Initializes a data object filled with a given number of bytes of data from a given buffer, with a custom deallocator block.
     #[method]
     pub fn init_with_bytes_no_copy_length_deallocator<F>(
-        &mut self,
         bytes: *mut c_void,
         length: UInt,
         deallocator: F,
@@ -193,8 +222,9 @@ impl NSData {
         let deallocator = deallocator.copy();
 
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithBytesNoCopy: bytes length: length deallocator: deallocator],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithBytesNoCopy: bytes length: length deallocator: deallocator],
             )
         }
     }
@@ -202,7 +232,6 @@ impl NSData {
     /// Initializes a newly allocated data object by adding the given number of bytes from the given buffer.
     #[method]
     pub fn init_with_bytes_no_copy_length_free_when_done(
-        &mut self,
         bytes: *mut c_void,
         length: UInt,
         b: bool,
@@ -211,19 +240,23 @@ impl NSData {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithBytesNoCopy: bytes length: length freeWhenDone:b],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithBytesNoCopy: bytes length: length freeWhenDone:b],
             )
         }
     }
 
     /// Initializes a data object with the contents of another data object.
     #[method]
-    pub fn init_with_data(&mut self, data: &NSData) -> Self
+    pub fn init_with_data(data: &NSData) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithData: data.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithData: data.m_self()])
+        }
     }
 
     /*Reading Data from a File
@@ -231,134 +264,144 @@ impl NSData {
 
     /// Creates a data object by reading every byte from the file at a given path.
     #[method]
-    pub fn data_with_contents_of_file(path: &NSString) -> Self
+    pub fn data_with_contents_of_file<'pool>(
+        path: &NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), dataWithContentsOfFile: path.m_self()]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), dataWithContentsOfFile: path.m_self()],
+                pool,
+            )
+        }
     }
 
     /// Creates a data object by reading every byte from the file at a given path.
     #[method]
-    pub fn data_with_contents_of_file_options(
+    pub fn data_with_contents_of_file_options<'pool>(
         path: &NSString,
         read_options_mask: NSDataReadingOptions,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let ptr = Self::from_id(
+            let ptr = Autoreleased::try_new(
                 msg_send![Self::m_class(), dataWithContentsOfFile: path.m_self() options: read_options_mask error: &mut error],
+                pool,
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
     /// Creates a data object containing the data from the location specified by a given URL.
     #[method]
-    pub fn data_with_contents_of_url(url: &NSURL) -> Self
+    pub fn data_with_contents_of_url<'pool>(
+        url: &NSURL,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), dataWithContentsOfURL: url.m_self()]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), dataWithContentsOfURL: url.m_self()],
+                pool,
+            )
+        }
     }
 
     /// Creates a data object containing the data from the location specified by a given URL.
     #[method]
-    pub fn data_with_contents_of_url_options(
+    pub fn data_with_contents_of_url_options<'pool>(
         url: &NSURL,
         read_options_mask: NSDataReadingOptions,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
         unsafe {
-            let ptr = Self::from_id(
+            let ptr = Autoreleased::try_new(
                 msg_send![Self::m_class(), dataWithContentsOfURL: url.m_self() options: read_options_mask error: &mut error],
+                pool,
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
     /// Initializes a data object with the content of the file at a given path.
     #[method]
-    pub fn init_with_contents_of_file(&mut self, path: &NSString) -> Self
+    pub fn init_with_contents_of_file(path: &NSString) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithContentsOfFile: path.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithContentsOfFile: path.m_self()])
+        }
     }
 
     /// Initializes a data object with the content of the file at a given path.
     #[method]
     pub fn init_with_contents_of_file_options(
-        &mut self,
         path: &NSString,
         read_options_mask: NSDataReadingOptions,
     ) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let ptr = Self::from_id(
-                msg_send![self.m_self(), initWithContentsOfFile: path.m_self() options: read_options_mask error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let ptr = Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfFile: path.m_self() options: read_options_mask error: &mut error],
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
     /// Initializes a data object with the data from the location specified by a given URL.
     #[method]
-    pub fn init_with_contents_of_url(&mut self, url: &NSURL) -> Self
+    pub fn init_with_contents_of_url(url: &NSURL) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithContentsOfURL: url.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithContentsOfURL: url.m_self()])
+        }
     }
 
     /// Initializes a data object with the data from the location specified by a given URL.
     #[method]
     pub fn init_with_contents_of_url_options(
-        &mut self,
         url: &NSURL,
         read_options_mask: NSDataReadingOptions,
     ) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let ptr = Self::from_id(
-                msg_send![self.m_self(), initWithContentsOfURL: url.m_self() options: read_options_mask error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let ptr = Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfURL: url.m_self() options: read_options_mask error: &mut error],
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -383,16 +426,16 @@ impl NSData {
         write_options: NSDataWritingOptions,
     ) -> Result<bool, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
             let ptr = to_bool(
                 msg_send![self.m_self(), writeToFile: path.m_self() options: write_options error: &mut error],
             );
 
-            if error.m_self().is_null() {
+            if error.is_null() {
                 Ok(ptr)
             } else {
-                Err(error)
+                Err(NSError::from_out_param(error))
             }
         }
     }
@@ -413,16 +456,16 @@ impl NSData {
         write_options: NSDataWritingOptions,
     ) -> Result<bool, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
             let ptr = to_bool(
                 msg_send![self.m_self(), writeToURL: url.m_self() options: write_options error: &mut error],
             );
 
-            if error.m_self().is_null() {
+            if error.is_null() {
                 Ok(ptr)
             } else {
-                Err(error)
+                Err(NSError::from_out_param(error))
             }
         }
     }
@@ -433,7 +476,6 @@ impl NSData {
     /// Initializes a data object with the given Base64 encoded data.
     #[method]
     pub fn init_with_base64_encoded_data_options(
-        &mut self,
         base64_data: &NSData,
         options: NSDataBase64DecodingOptions,
     ) -> Self
@@ -441,8 +483,9 @@ impl NSData {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithBase64EncodedData: base64_data.m_self() options: options],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithBase64EncodedData: base64_data.m_self() options: options],
             )
         }
     }
@@ -450,7 +493,6 @@ impl NSData {
     /// Initializes a data object with the given Base64 encoded string.
     #[method]
     pub fn init_with_base64_encoded_string_options(
-        &mut self,
         base64_string: &NSString,
         options: NSDataBase64DecodingOptions,
     ) -> Self
@@ -458,34 +500,43 @@ impl NSData {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithBase64EncodedString: base64_string.m_self() options: options],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithBase64EncodedString: base64_string.m_self() options: options],
             )
         }
     }
 
     /// Creates a Base64, UTF-8 encoded data object from the string using the given options.
     #[method]
-    pub fn base64_encoded_data_with_options(&self, options: NSDataBase64EncodingOptions) -> NSData {
+    pub fn base64_encoded_data_with_options<'pool>(
+        &self,
+        options: NSDataBase64EncodingOptions,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSData> {
         unsafe {
-            NSData::from_id(msg_send![
-                self.m_self(),
-                base64EncodedDataWithOptions: options
-            ])
+            Autoreleased::new(
+                msg_send![
+                    self.m_self(),
+                    base64EncodedDataWithOptions: options
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates a Base64 encoded string from the string using the given options.
     #[method]
-    pub fn base64_encoded_string_with_options(
+    pub fn base64_encoded_string_with_options<'pool>(
         &self,
         options: NSDataBase64EncodingOptions,
-    ) -> NSString {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                base64EncodedStringWithOptions: options
-            ])
+            Autoreleased::new(
+                msg_send![self.m_self(), base64EncodedStringWithOptions: options],
+                pool,
+            )
         }
     }
 
@@ -530,8 +581,12 @@ impl NSData {
 
     /// Returns a new data object containing the data object's bytes that fall within the limits specified by a given range.
     #[method]
-    pub fn subdata_with_range(&self, range: NSRange) -> NSData {
-        unsafe { NSData::from_id(msg_send![self.m_self(), subdataWithRange: range]) }
+    pub fn subdata_with_range<'pool>(
+        &self,
+        range: NSRange,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSData> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), subdataWithRange: range], pool) }
     }
 
     /// Finds and returns the range of the first occurrence of the given data, within the given range, subject to given options.
@@ -571,18 +626,14 @@ impl NSData {
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let ptr = Self::from_id(
+            let ptr = Self::try_from_id(
                 msg_send![self.m_self(), compressedDataUsingAlgorithm: algorithm error: &mut error],
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -595,18 +646,14 @@ impl NSData {
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let ptr = Self::from_id(
+            let ptr = Self::try_from_id(
                 msg_send![self.m_self(), decompressedDataUsingAlgorithm: algorithm error: &mut error],
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 }
@@ -1,9 +1,12 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
-use crate::{object, 
+use crate::{
+    object,
     objective_c_runtime::{
-        macros::{interface_impl},
+        macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_bool,
 };
@@ -22,70 +25,92 @@ impl NSDate {
 
     /// Creates and returns a new date object set to the current date and time.
     #[method]
-    pub fn date() -> Self
+    pub fn date<'pool>(pool: &'pool AutoreleasePool) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), date]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), date], pool) }
     }
 
     /// Creates and returns a date object set to a given number of seconds from the current date and time.
     #[method]
-    pub fn date_with_time_interval_since_now(time_interval: NSTimeInterval) -> Self
+    pub fn date_with_time_interval_since_now<'pool>(
+        time_interval: NSTimeInterval,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                dateWithTimeIntervalSinceNow: time_interval
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    dateWithTimeIntervalSinceNow: time_interval
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates and returns a date object set to a given number of seconds from the specified date.
     #[method]
-    pub fn date_with_time_interval_since_date(
+    pub fn date_with_time_interval_since_date<'pool>(
         secs_to_be_added: NSTimeInterval,
         date: NSDate,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                dateWithTimeInterval: secs_to_be_added
-                sinceDate: date
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    dateWithTimeInterval: secs_to_be_added
+                    sinceDate: date
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates and returns a date object set to a given number of seconds from 00:00:00 UTC on 1 January 2001.
     #[method]
-    pub fn date_with_time_interval_since_reference_date(secs_to_be_added: NSTimeInterval) -> Self
+    pub fn date_with_time_interval_since_reference_date<'pool>(
+        secs_to_be_added: NSTimeInterval,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                dateWithTimeIntervalSinceReferenceDate: secs_to_be_added
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    dateWithTimeIntervalSinceReferenceDate: secs_to_be_added
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates and returns a date object set to the given number of seconds from 00:00:00 UTC on 1 January 1970.
     #[method]
-    pub fn date_with_time_interval_since1970(secs_to_be_added: NSTimeInterval) -> Self
+    pub fn date_with_time_interval_since1970<'pool>(
+        secs_to_be_added: NSTimeInterval,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                dateWithTimeIntervalSince1970: secs_to_be_added
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    dateWithTimeIntervalSince1970: secs_to_be_added
+                ],
+                pool,
+            )
         }
     }
 
@@ -109,8 +134,8 @@ impl NSDate {
 
     /// The current date and time, as of the time of access.
     #[property]
-    pub fn now() -> NSDate {
-        unsafe { NSDate::from_id(msg_send![Self::m_class(), now]) }
+    pub fn now<'pool>(pool: &'pool AutoreleasePool) -> Autoreleased<'pool, NSDate> {
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), now], pool) }
     }
 
     /* Comparing Dates
@@ -163,14 +188,14 @@ impl NSDate {
 
     /// The interval between the date object and 00:00:00 UTC on 1 January 2001.
     #[property]
-    pub fn time_interval_since_reference_date() -> NSTimeInterval {
-        unsafe { msg_send![Self::m_class(), timeIntervalSinceReferenceDate] }
+    pub fn time_interval_since_reference_date(&self) -> NSTimeInterval {
+        unsafe { msg_send![self.m_self(), timeIntervalSinceReferenceDate] }
     }
 
     /// The interval between the date object and 00:00:00 UTC on 1 January 1970.
     #[property]
-    pub fn time_interval_since_1970() -> NSTimeInterval {
-        unsafe { msg_send![Self::m_class(), timeIntervalSince1970] }
+    pub fn time_interval_since_1970(&self) -> NSTimeInterval {
+        unsafe { msg_send![self.m_self(), timeIntervalSince1970] }
     }
 
     /* Adding Time Intervals
@@ -4,13 +4,16 @@ use std::{
     sync::Arc,
 };
 
+use crate::objective_c_runtime::msg_send;
 use libc::{c_char, c_double, c_int, c_long, c_short, c_uint, c_ulong, c_ulonglong, c_ushort};
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{INSLocale, INSNumber, INSString, NSComparisonResult, NSLocale, NSString},
     object,
-    objective_c_runtime::{macros::interface_impl, traits::FromId, INSValue},
+    objective_c_runtime::{
+        id, macros::interface_impl, traits::FromId, AutoreleasePool, Autoreleased, INSValue,
+    },
 };
 
 use super::{
@@ -18,7 +21,7 @@ use super::{
     NSRoundingMode,
 };
 
-#[link(name = "Foundation", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Foundation", kind = "framework"))]
 extern "C" {
     /* Creating a Decimal from Another Decimal
      */
@@ -124,55 +127,80 @@ impl NSDecimalNumber {
 
     /// Creates and returns a decimal number equivalent to a given decimal structure.
     #[method]
-    pub fn decimal_number_with_decimal(decimal: NSDecimalNumber) -> Self
+    pub fn decimal_number_with_decimal<'pool>(
+        decimal: NSDecimalNumber,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                decimalNumberWithDecimal: decimal
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    decimalNumberWithDecimal: decimal
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates and returns a decimal number equivalent to the number specified by the arguments.
     #[method]
-    pub fn decimal_number_with_mantissa(
+    pub fn decimal_number_with_mantissa<'pool>(
         mantissa: c_ulonglong,
         exponent: c_short,
         is_negative: bool,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                decimalNumberWithMantissa: mantissa
-                exponent: exponent
-                isNegative: is_negative
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    decimalNumberWithMantissa: mantissa
+                    exponent: exponent
+                    isNegative: is_negative
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates a decimal number whose value is equivalent to that in a given numeric string.
     #[method]
-    pub fn decimal_number_with_string(string: NSString) -> Self
+    pub fn decimal_number_with_string<'pool>(
+        string: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), decimalNumberWithString: string]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), decimalNumberWithString: string],
+                pool,
+            )
+        }
     }
 
     /// Creates a decimal number whose value is equivalent to that in a given numeric string, interpreted using a given locale.
     #[method]
-    pub fn decimal_number_with_string_locale(string: NSString, locale: NSLocale) -> Self
+    pub fn decimal_number_with_string_locale<'pool>(
+        string: NSString,
+        locale: NSLocale,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![Self::m_class(), decimalNumberWithString:string locale:locale])
+            Autoreleased::new(
+                msg_send![Self::m_class(), decimalNumberWithString:string locale:locale],
+                pool,
+            )
         }
     }
 
@@ -208,42 +236,59 @@ impl NSDecimalNumber {
 
     /// Initializes a decimal number to represent a given decimal.
     #[method]
-    pub fn init_with_decimal(&mut self, decimal: NSDecimalNumber) {
-        unsafe { msg_send![self.m_self(), initWithDecimal: decimal] }
+    pub fn init_with_decimal(decimal: NSDecimalNumber) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithDecimal: decimal])
+        }
     }
 
     /// Initializes a decimal number using the given mantissa, exponent, and sign.
     #[method]
     pub fn init_with_mantissa_exponent_is_negative(
-        &mut self,
         mantissa: c_ulonglong,
         exponent: c_short,
         is_negative: bool,
-    ) {
+    ) -> Self
+    where
+        Self: Sized + FromId,
+    {
         unsafe {
-            msg_send![self.m_self(), initWithMantissa: mantissa
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithMantissa: mantissa
                 exponent: exponent
-                isNegative: is_negative]
+                isNegative: is_negative])
         }
     }
 
     /// Initializes a decimal number so that its value is equivalent to that in a given numeric string.
     #[method]
-    pub fn init_with_string<S>(&mut self, string: S)
+    pub fn init_with_string<S>(string: S) -> Self
     where
+        Self: Sized + FromId,
         S: INSString,
     {
-        unsafe { msg_send![self.m_self(), initWithString: string] }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithString: string])
+        }
     }
 
     /// Initializes a decimal number so that its value is equivalent to that in a given numeric string, interpreted using a given locale.
     #[method]
-    pub fn init_with_string_locale<S, L>(&mut self, string: S, locale: L)
+    pub fn init_with_string_locale<S, L>(string: S, locale: L) -> Self
     where
+        Self: Sized + FromId,
         S: INSString,
         L: INSLocale,
     {
-        unsafe { msg_send![self.m_self(), initWithString: string locale: locale] }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithString: string locale: locale])
+        }
     }
 
     /* Performing Arithmetic
@@ -509,49 +554,49 @@ impl NSDecimalNumber {
 
 impl From<c_uint> for NSDecimalNumber {
     fn from(value: c_uint) -> Self {
-        NSDecimalNumber::m_number_with_unsigned_int(value)
+        NSDecimalNumber::m_init_with_unsigned_int(value)
     }
 }
 
 impl From<c_int> for NSDecimalNumber {
     fn from(value: c_int) -> Self {
-        NSDecimalNumber::m_number_with_int(value)
+        NSDecimalNumber::m_init_with_int(value)
     }
 }
 
 impl From<c_short> for NSDecimalNumber {
     fn from(value: c_short) -> Self {
-        NSDecimalNumber::m_number_with_short(value)
+        NSDecimalNumber::m_init_with_short(value)
     }
 }
 
 impl From<c_ushort> for NSDecimalNumber {
     fn from(value: c_ushort) -> Self {
-        NSDecimalNumber::m_number_with_unsigned_short(value)
+        NSDecimalNumber::m_init_with_unsigned_short(value)
     }
 }
 
 impl From<c_long> for NSDecimalNumber {
     fn from(value: c_long) -> Self {
-        NSDecimalNumber::m_number_with_long(value)
+        NSDecimalNumber::m_init_with_long(value)
     }
 }
 
 impl From<c_ulong> for NSDecimalNumber {
     fn from(value: c_ulong) -> Self {
-        NSDecimalNumber::m_number_with_unsigned_long(value)
+        NSDecimalNumber::m_init_with_unsigned_long(value)
     }
 }
 
 impl From<c_float> for NSDecimalNumber {
     fn from(value: c_float) -> Self {
-        NSDecimalNumber::m_number_with_float(value)
+        NSDecimalNumber::m_init_with_float(value)
     }
 }
 
 impl From<c_double> for NSDecimalNumber {
     fn from(value: c_double) -> Self {
-        NSDecimalNumber::m_number_with_double(value)
+        NSDecimalNumber::m_init_with_double(value)
     }
 }
 
@@ -560,7 +605,7 @@ where
     S: Into<NSString>,
 {
     fn from(value: S) -> Self {
-        NSDecimalNumber::m_decimal_number_with_string(value.into())
+        NSDecimalNumber::m_init_with_string(value.into())
     }
 }
 
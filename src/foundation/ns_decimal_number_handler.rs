@@ -1,11 +1,14 @@
+use crate::objective_c_runtime::msg_send;
 use libc::c_short;
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -26,20 +29,24 @@ impl NSDecimalNumberHandler {
 
     /// Returns an [`NSDecimalNumberHandler`] object with customized behavior.
     #[method]
-    pub fn decimal_number_handler_with_rounding_mode_scale_raise_on_exactness_raise_on_overflow_raise_on_underflow_raise_on_divide_by_zero(
+    pub fn decimal_number_handler_with_rounding_mode_scale_raise_on_exactness_raise_on_overflow_raise_on_underflow_raise_on_divide_by_zero<
+        'pool,
+    >(
         rounding_mode: NSRoundingMode,
         scale: c_short,
         exact: bool,
         overflow: bool,
         underflow: bool,
         divide_by_zero: bool,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), decimalNumberHandlerWithRoundingMode: rounding_mode scale: scale raiseOnExactness: exact raiseOnOverflow: overflow raiseOnUnderflow: underflow raiseOnDivideByZero: divide_by_zero ],
+                pool,
             )
         }
     }
@@ -47,7 +54,6 @@ impl NSDecimalNumberHandler {
     /// Returns an [`NSDecimalNumberHandler`] object initialized so it behaves as specified by the method’s arguments.
     #[method]
     pub fn init_with_rounding_mode_scale_raise_on_exactness_raise_on_overflow_raise_on_underflow_raise_on_divide_by_zero(
-        &mut self,
         rounding_mode: NSRoundingMode,
         scale: c_short,
         exact: bool,
@@ -59,8 +65,9 @@ impl NSDecimalNumberHandler {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithRoundingMode: rounding_mode scale: scale raiseOnExactness: exact raiseOnOverflow: overflow raiseOnUnderflow: underflow raiseOnDivideByZero: divide_by_zero ],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithRoundingMode: rounding_mode scale: scale raiseOnExactness: exact raiseOnOverflow: overflow raiseOnUnderflow: underflow raiseOnDivideByZero: divide_by_zero ],
             )
         }
     }
@@ -1,20 +1,19 @@
 use std::{collections::HashMap, marker::PhantomData};
 
-use objc::{class, msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{class, sel, sel_impl};
 
 use crate::{
     objective_c_runtime::{
         self, id, nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     shared_object,
     utils::to_bool,
 };
 
-use super::{
-    INSMutableDictionary, Int, Int16, Int32, Int8, NSArray, NSMutableDictionary, NSNumber,
-    NSString, UInt, UInt16, UInt32, UInt8,
-};
+use super::{IntoNS, NSArray, NSMutableDictionary, UInt};
 
 shared_object! {
     /// A static collection of objects associated with unique keys.
@@ -27,7 +26,7 @@ shared_object! {
 impl<K, V> NSDictionary<K, V> {
     /// Returns an empty dictionary.
     pub fn new() -> Self {
-        Self::m_dictionary()
+        Self::m_init()
     }
 
     /// The number of entries in the dictionary.
@@ -51,11 +50,11 @@ pub trait INSDictionary<K, V>: PNSObject {
      */
 
     /// Creates an empty dictionary.
-    fn m_dictionary() -> Self
+    fn m_dictionary<'pool>(pool: &'pool AutoreleasePool) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), dictionary]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), dictionary], pool) }
     }
 
     /// Initializes a newly allocated dictionary.
@@ -63,34 +62,52 @@ pub trait INSDictionary<K, V>: PNSObject {
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), new]) }
+        unsafe { Self::from_retained_id(msg_send![Self::m_class(), new]) }
     }
 
     /* Creating a Dictionary from Another Dictionary
      */
 
     /// Creates a dictionary containing the keys and values from another given dictionary.
-    fn m_dictionary_with_dictionary<D>(dictionary: D) -> Self
+    fn m_dictionary_with_dictionary<'pool, D>(
+        dictionary: D,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
         D: INSDictionary<K, V>,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                dictionaryWithDictionary: dictionary
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    dictionaryWithDictionary: dictionary
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates and initialize a dictionary
-    fn m_init_with_dictionary(&mut self, dictionary: NSDictionary<K, V>) {
-        unsafe { msg_send![self.m_self(), initWithDictionary: dictionary] }
+    fn m_init_with_dictionary(dictionary: NSDictionary<K, V>) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithDictionary: dictionary])
+        }
     }
 
     /// Initializes a newly allocated dictionary using the objects contained in another given dictionary.
-    fn m_init_with_dictionary_copy_items(&mut self, dictionary: NSDictionary<K, V>, flag: bool) {
-        unsafe { msg_send![self.m_self(), initWithDictionary: dictionary copyItems: flag] }
+    fn m_init_with_dictionary_copy_items(dictionary: NSDictionary<K, V>, flag: bool) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithDictionary: dictionary copyItems: flag])
+        }
     }
 
     /* Counting Entries
@@ -193,7 +210,7 @@ unsafe impl<K, V> objective_c_runtime::Encode for NSDictionary<K, V> {
 
 impl<K, V> Default for NSDictionary<K, V> {
     fn default() -> Self {
-        unsafe { Self::from_id(msg_send![class!(NSDictionary), dictionary]) }
+        Self::m_init()
     }
 }
 
@@ -221,1184 +238,13 @@ where
     }
 }
 
-impl<K, V> From<HashMap<K, V>> for NSDictionary<K, V>
-where
-    K: PNSObject,
-    V: PNSObject,
-{
-    fn from(map: HashMap<K, V>) -> NSDictionary<K, V> {
-        let mut dictionary = NSMutableDictionary::<K, V>::default();
-
-        for (key, value) in map {
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl<K> From<HashMap<K, &str>> for NSDictionary<K, NSString>
-where
-    K: PNSObject,
-{
-    fn from(map: HashMap<K, &str>) -> NSDictionary<K, NSString> {
-        let mut dictionary = NSMutableDictionary::<K, NSString>::default();
-
-        for (key, value) in map {
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl<V> From<HashMap<&str, V>> for NSDictionary<NSString, V>
+impl<K, V, S> From<HashMap<K, V, S>> for NSDictionary<K::NS, V::NS>
 where
-    V: PNSObject,
+    K: IntoNS,
+    V: IntoNS,
 {
-    fn from(map: HashMap<&str, V>) -> NSDictionary<NSString, V> {
-        let mut dictionary = NSMutableDictionary::<NSString, V>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, &str>> for NSDictionary<NSString, NSString> {
-    fn from(map: HashMap<&str, &str>) -> NSDictionary<NSString, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, UInt>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, UInt>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, UInt8>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, UInt8>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, UInt16>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, UInt16>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, UInt32>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, UInt32>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, Int>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, Int>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, Int8>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, Int8>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, Int16>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, Int16>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<&str, Int32>> for NSDictionary<NSString, NSNumber> {
-    fn from(map: HashMap<&str, Int32>) -> NSDictionary<NSString, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSString, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSString::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<UInt, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<UInt8, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt8, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt8, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<UInt16, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt16, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt16, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<UInt32, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<UInt32, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<UInt32, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<Int, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<Int8, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int8, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int8, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<Int16, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int16, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int16, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, &str>> for NSDictionary<NSNumber, NSString> {
-    fn from(map: HashMap<Int32, &str>) -> NSDictionary<NSNumber, NSString> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSString>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSString::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, UInt>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, UInt>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, UInt8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, UInt8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, UInt16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, UInt16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, UInt32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, UInt32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, Int>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, Int>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, Int8>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, Int8>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, Int16>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, Int16>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
-    }
-}
-
-impl From<HashMap<Int32, Int32>> for NSDictionary<NSNumber, NSNumber> {
-    fn from(map: HashMap<Int32, Int32>) -> NSDictionary<NSNumber, NSNumber> {
-        let mut dictionary = NSMutableDictionary::<NSNumber, NSNumber>::default();
-
-        for (key, value) in map {
-            let key = NSNumber::from(key);
-            let value = NSNumber::from(value);
-            dictionary.im_set_object_for_key(key, value);
-        }
-
-        dictionary.into()
+    /// Converts the keys and values with [`IntoNS`], and creates a dictionary that holds them.
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_ns()
     }
 }
@@ -1,7 +1,7 @@
-use crate::core_graphics::CGFloat;
+use crate::{core_graphics::CGFloat, objective_c_runtime::Encode};
 
 /// A description of the distance between the edges of two rectangles.
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Encode)]
 #[repr(C)]
 pub struct NSEdgeInsets {
     /// The distance from the top of the source rectangle to the top of the result rectangle.
@@ -14,6 +14,7 @@ pub struct NSEdgeInsets {
     pub right: CGFloat,
 }
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 extern "C" {
     /// Checks if the receiver is equal to the given edge insets.
     pub fn NSEdgeInsetsEqual(insets1: NSEdgeInsets, insets2: NSEdgeInsets) -> bool;
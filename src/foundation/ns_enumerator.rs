@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
@@ -21,9 +22,10 @@ pub trait INSEnumerator<ObjectType>: PNSObject
 where
     ObjectType: PNSObject + FromId,
 {
-    /// Returns the next object from the collection being enumerated.
-    fn m_next_object(&self) -> ObjectType {
-        unsafe { ObjectType::from_id(msg_send![self.m_self(), nextObject]) }
+    /// Returns the next object from the collection being enumerated, or `None` when all
+    /// objects have been enumerated.
+    fn m_next_object(&self) -> Option<ObjectType> {
+        unsafe { ObjectType::try_from_id(msg_send![self.m_self(), nextObject]) }
     }
 
     /// The array of unenumerated objects.
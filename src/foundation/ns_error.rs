@@ -1,10 +1,15 @@
-use objc::{msg_send, sel, sel_impl};
+use std::error::Error;
 
-use crate::{object, 
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
+
+use crate::{
+    object,
     objective_c_runtime::{
         id,
-        macros::{interface_impl},
+        macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_bool,
 };
@@ -17,6 +22,31 @@ object! {
     unsafe pub struct NSError;
 }
 
+impl NSError {
+    /// The domain of [`NSError::unreported`].
+    pub const UNREPORTED_DOMAIN: &'static str = "RustMaciOSUnreportedErrorDomain";
+
+    /// Returns the error for a method that failed without setting its error out-parameter,
+    /// which Cocoa allows.
+    pub fn unreported() -> Self {
+        Self::init_with_domain_code_user_info(
+            NSString::from(Self::UNREPORTED_DOMAIN),
+            0,
+            NSDictionary::new(),
+        )
+    }
+
+    /// Returns the error a method set in its out-parameter, or [`NSError::unreported`] if it
+    /// left it `nil`.
+    ///
+    /// # Safety
+    ///
+    /// `error` must be `nil` or an `NSError`.
+    pub unsafe fn from_out_param(error: id) -> Self {
+        Self::try_from_id(error).unwrap_or_else(Self::unreported)
+    }
+}
+
 #[interface_impl(NSObject)]
 impl NSError {
     /* Creating Error Objects
@@ -24,17 +54,19 @@ impl NSError {
 
     /// Creates and initializes an NSError object for a given domain and code with a given userInfo dictionary.
     #[method]
-    pub fn error_with_domain_code_user_info(
+    pub fn error_with_domain_code_user_info<'pool>(
         domain: NSErrorDomain,
         code: Int,
         dict: NSDictionary<NSErrorUserInfoKey, id>,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), errorWithDomain: domain code: code userInfo: dict],
+                pool,
             )
         }
     }
@@ -42,7 +74,6 @@ impl NSError {
     /// Returns an NSError object initialized for a given domain and code with a given userInfo dictionary.
     #[method]
     pub fn init_with_domain_code_user_info(
-        &mut self,
         domain: NSErrorDomain,
         code: Int,
         dict: NSDictionary<NSErrorUserInfoKey, id>,
@@ -51,9 +82,8 @@ impl NSError {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithDomain: domain code: code userInfo: dict],
-            )
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithDomain: domain code: code userInfo: dict])
         }
     }
 
@@ -88,20 +118,20 @@ impl NSError {
 
     /// An array containing the localized titles of buttons appropriate for displaying in an alert panel.
     #[property]
-    pub fn localized_recovery_options(&self) -> NSArray<NSString> {
-        unsafe { NSArray::from_id(msg_send![self.m_self(), localizedRecoveryOptions]) }
+    pub fn localized_recovery_options(&self) -> Option<NSArray<NSString>> {
+        unsafe { NSArray::try_from_id(msg_send![self.m_self(), localizedRecoveryOptions]) }
     }
 
     /// A string containing the localized recovery suggestion for the error.
     #[property]
-    pub fn localized_recovery_suggestion(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), localizedRecoverySuggestion]) }
+    pub fn localized_recovery_suggestion(&self) -> Option<NSString> {
+        unsafe { NSString::try_from_id(msg_send![self.m_self(), localizedRecoverySuggestion]) }
     }
 
     /// A string containing the localized explanation of the reason for the error.
     #[property]
-    pub fn localized_failure_reason(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), localizedFailureReason]) }
+    pub fn localized_failure_reason(&self) -> Option<NSString> {
+        unsafe { NSString::try_from_id(msg_send![self.m_self(), localizedFailureReason]) }
     }
 
     /// The object in the user info dictionary corresponding to the NSRecoveryAttempterErrorKey key.
@@ -126,13 +156,15 @@ impl NSError {
 
     /// A string to display in response to an alert panel help anchor button being pressed.
     #[method]
-    pub fn help_anchor(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), helpAnchor]) }
+    pub fn help_anchor(&self) -> Option<NSString> {
+        unsafe { NSString::try_from_id(msg_send![self.m_self(), helpAnchor]) }
     }
 
-    ///
+    /// The underlying errors that caused this error.
     #[property]
     pub fn underlying_errors(&self) -> NSArray<NSError> {
         unsafe { NSArray::from_id(msg_send![self.m_self(), underlyingErrors]) }
     }
 }
+
+impl Error for NSError {}
@@ -0,0 +1,128 @@
+use std::error::Error;
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
+
+use crate::{
+    object,
+    objective_c_runtime::{
+        id,
+        macros::interface_impl,
+        send_message_unwind,
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
+    utils::to_optional,
+};
+
+use super::{NSArray, NSDictionary, NSExceptionName, NSNumber, NSString};
+
+object! {
+    /// An object that represents a special condition that interrupts the normal flow of
+    /// program execution.
+    ///
+    /// Exceptions are caught with
+    /// [`catch_exception`](crate::objective_c_runtime::catch_exception) or
+    /// [`try_msg_send!`](crate::try_msg_send).
+    unsafe pub struct NSException;
+}
+
+impl NSException {
+    /// The name of [`NSException::unreported`].
+    pub const UNREPORTED_NAME: &'static str = "RustMaciOSUnreportedException";
+
+    /// Returns the exception for code that threw `nil`, which `@throw` allows.
+    pub fn unreported() -> Self {
+        Self::init_with_name_reason_user_info(NSString::from(Self::UNREPORTED_NAME), None, None)
+    }
+}
+
+#[interface_impl(NSObject)]
+impl NSException {
+    /* Creating and Raising an NSException Object
+     */
+
+    /// Creates and returns an exception object.
+    #[method]
+    pub fn exception_with_name_reason_user_info<'pool>(
+        name: NSExceptionName,
+        reason: Option<NSString>,
+        user_info: Option<NSDictionary<id, id>>,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), exceptionWithName: name reason: reason userInfo: user_info],
+                pool,
+            )
+        }
+    }
+
+    /// Initializes and returns a newly allocated exception object.
+    #[method]
+    pub fn init_with_name_reason_user_info(
+        name: NSExceptionName,
+        reason: Option<NSString>,
+        user_info: Option<NSDictionary<id, id>>,
+    ) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithName: name reason: reason userInfo: user_info],
+            )
+        }
+    }
+
+    /// Raises the exception, causing program flow to jump to the local exception handler.
+    ///
+    /// An exception that is not caught by
+    /// [`catch_exception`](crate::objective_c_runtime::catch_exception) aborts the process.
+    #[method]
+    pub fn raise(&self) {
+        unsafe { send_message_unwind(self.m_self(), sel!(raise), ()) }
+    }
+
+    /* Querying an NSException Object
+     */
+
+    /// A string used to uniquely identify the exception.
+    #[property]
+    pub fn name(&self) -> NSExceptionName {
+        unsafe { NSString::from_id(msg_send![self.m_self(), name]) }
+    }
+
+    /// A string containing a “human-readable” reason for the exception.
+    #[property]
+    pub fn reason(&self) -> Option<NSString> {
+        unsafe { to_optional(msg_send![self.m_self(), reason]) }
+    }
+
+    /// A dictionary containing application-specific data pertaining to the exception.
+    #[property]
+    pub fn user_info(&self) -> Option<NSDictionary<id, id>> {
+        unsafe { to_optional(msg_send![self.m_self(), userInfo]) }
+    }
+
+    /* Getting Exception Stack Frames
+     */
+
+    /// The call return addresses related to a raised exception.
+    #[property]
+    pub fn call_stack_return_addresses(&self) -> NSArray<NSNumber> {
+        unsafe { NSArray::from_id(msg_send![self.m_self(), callStackReturnAddresses]) }
+    }
+
+    /// An array containing the current call stack symbols.
+    #[property]
+    pub fn call_stack_symbols(&self) -> NSArray<NSString> {
+        unsafe { NSArray::from_id(msg_send![self.m_self(), callStackSymbols]) }
+    }
+}
+
+impl Error for NSException {}
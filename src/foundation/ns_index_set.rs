@@ -1,7 +1,7 @@
-use crate::{object, objective_c_runtime::{
-    macros::{interface_impl},
-    traits::PNSObject,
-}};
+use crate::{
+    object,
+    objective_c_runtime::{macros::interface_impl, traits::PNSObject},
+};
 
 object! {
     /// An immutable collection of unique integer values that represent indexes in another collection.
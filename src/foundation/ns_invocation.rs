@@ -0,0 +1,165 @@
+use std::{ffi::c_void, mem::MaybeUninit};
+
+use crate::objective_c_runtime::msg_send;
+use objc::{runtime::Sel, sel, sel_impl};
+
+use crate::{
+    object,
+    objective_c_runtime::{
+        id,
+        macros::interface_impl,
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
+    utils::to_bool,
+};
+
+use super::{Int, NSMethodSignature};
+
+object! {
+    /// An Objective-C message rendered as an object.
+    ///
+    /// The arguments are indexed from 2, as indices 0 and 1 hold the target and the
+    /// selector.
+    unsafe pub struct NSInvocation;
+}
+
+#[interface_impl(NSObject)]
+impl NSInvocation {
+    /* Creating NSInvocation Objects
+     */
+
+    /// Returns an invocation able to construct messages using a given method signature.
+    #[method]
+    pub fn invocation_with_method_signature<'pool>(
+        signature: &NSMethodSignature,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSInvocation> {
+        unsafe {
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    invocationWithMethodSignature: signature.m_self()
+                ],
+                pool,
+            )
+        }
+    }
+
+    /* Configuring an Invocation Object
+     */
+
+    /// The receiver’s selector.
+    #[property]
+    pub fn selector(&self) -> Sel {
+        unsafe { msg_send![self.m_self(), selector] }
+    }
+
+    /// Sets the receiver’s selector.
+    #[property]
+    pub fn set_selector(&self, selector: Sel) {
+        unsafe { msg_send![self.m_self(), setSelector: selector] }
+    }
+
+    /// The receiver’s target, or `nil` if the receiver has no target.
+    #[property]
+    pub fn target(&self) -> id {
+        unsafe { msg_send![self.m_self(), target] }
+    }
+
+    /// Sets the receiver’s target.
+    #[property]
+    pub fn set_target(&self, target: id) {
+        unsafe { msg_send![self.m_self(), setTarget: target] }
+    }
+
+    /// If the receiver hasn’t already done so, retains the target and all object arguments
+    /// of the receiver and copies all of its C-string arguments and blocks.
+    #[method]
+    pub fn retain_arguments(&self) {
+        unsafe { msg_send![self.m_self(), retainArguments] }
+    }
+
+    /// A Boolean value that indicates whether the receiver has retained its arguments.
+    #[property]
+    pub fn arguments_retained(&self) -> bool {
+        unsafe { to_bool(msg_send![self.m_self(), argumentsRetained]) }
+    }
+
+    /* Dispatching an Invocation
+     */
+
+    /// Sends the receiver’s message (with arguments) to its target and sets the return
+    /// value.
+    #[method]
+    pub fn invoke(&self) {
+        unsafe { msg_send![self.m_self(), invoke] }
+    }
+
+    /// Sets the receiver’s target, sends the receiver’s message (with arguments) to that
+    /// target, and sets the return value.
+    #[method]
+    pub fn invoke_with_target(&self, target: id) {
+        unsafe { msg_send![self.m_self(), invokeWithTarget: target] }
+    }
+
+    /* Getting the Method Signature
+     */
+
+    /// The receiver’s method signature.
+    #[property]
+    pub fn method_signature(&self) -> NSMethodSignature {
+        unsafe { NSMethodSignature::from_id(msg_send![self.m_self(), methodSignature]) }
+    }
+}
+
+impl NSInvocation {
+    /// Returns the argument at `index`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must have the size and layout of the argument's type.
+    pub unsafe fn argument<T>(&self, index: Int) -> T {
+        let mut value = MaybeUninit::<T>::uninit();
+        let _: () = msg_send![
+            self.m_self(),
+            getArgument: value.as_mut_ptr() as *mut c_void
+            atIndex: index
+        ];
+        value.assume_init()
+    }
+
+    /// Sets the argument at `index`, copying `value`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must have the size and layout of the argument's type.
+    pub unsafe fn set_argument<T>(&self, value: &T, index: Int) {
+        msg_send![
+            self.m_self(),
+            setArgument: value as *const T as *mut c_void
+            atIndex: index
+        ]
+    }
+
+    /// Returns the return value set by [`NSInvocation::invoke`] or
+    /// [`NSInvocation::set_return_value`].
+    ///
+    /// # Safety
+    ///
+    /// `T` must have the size and layout of the method's return type.
+    pub unsafe fn return_value<T>(&self) -> T {
+        let mut value = MaybeUninit::<T>::uninit();
+        let _: () = msg_send![self.m_self(), getReturnValue: value.as_mut_ptr() as *mut c_void];
+        value.assume_init()
+    }
+
+    /// Sets the return value, copying `value`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must have the size and layout of the method's return type.
+    pub unsafe fn set_return_value<T>(&self, value: &T) {
+        msg_send![self.m_self(), setReturnValue: value as *const T as *mut c_void]
+    }
+}
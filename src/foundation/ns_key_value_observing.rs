@@ -0,0 +1,247 @@
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock, PoisonError,
+    },
+};
+
+use crate::objective_c_runtime::msg_send;
+use lazy_static::lazy_static;
+use objc::{
+    class,
+    runtime::{Object, Sel},
+    sel, sel_impl,
+};
+
+use crate::{
+    objective_c_runtime::{
+        id, nil, register_class_for_type,
+        traits::{FromId, PNSObject},
+    },
+    utils::to_bool,
+};
+
+use super::{
+    ns_key_value_change_key, NSIndexSet, NSKeyValueChange, NSKeyValueObservingOptions, NSNull,
+    NSNumber, NSString, UInt,
+};
+
+type Handler = dyn Fn(id) + Send + Sync;
+
+lazy_static! {
+    /// The handlers of the live observations, by the context they were added with.
+    static ref HANDLERS: Mutex<HashMap<usize, Arc<Handler>>> = Mutex::new(HashMap::new());
+}
+
+/// The context of the next observation. Zero is left for observers that pass no context.
+static NEXT_CONTEXT: AtomicUsize = AtomicUsize::new(1);
+
+/// A change to an observed key path, decoded from the change dictionary.
+#[derive(Debug)]
+pub struct KVOChange<T> {
+    /// The kind of change.
+    pub kind: NSKeyValueChange,
+    /// The value before the change, if [`NSKeyValueObservingOptions::Old`] was requested.
+    ///
+    /// Like `new`, it is `None` when the value was `nil`, `NSNull` or not an instance of `T`.
+    pub old: Option<T>,
+    /// The value after the change, if [`NSKeyValueObservingOptions::New`] was requested.
+    pub new: Option<T>,
+    /// The indexes of the inserted, removed or replaced objects of a to-many relationship.
+    pub indexes: Option<NSIndexSet>,
+    /// Whether the handler is called before the change, if
+    /// [`NSKeyValueObservingOptions::Prior`] was requested.
+    pub is_prior: bool,
+}
+
+impl<T> KVOChange<T>
+where
+    T: PNSObject + FromId,
+{
+    /// Decodes a change dictionary.
+    ///
+    /// # Safety
+    ///
+    /// `change` must be a change dictionary, as passed to `observeValueForKeyPath:ofObject:change:context:`.
+    pub unsafe fn from_change_dictionary(change: id) -> Self {
+        let value_for = |key: &NSString| -> Option<id> {
+            let value: id = msg_send![change, objectForKey: key.m_self()];
+            let null: id = NSNull::null().m_self();
+
+            (value != nil && value != null).then_some(value)
+        };
+        let decode = |value: id| -> Option<T> {
+            to_bool(msg_send![value, isKindOfClass: T::m_class()]).then(|| T::from_id(value))
+        };
+
+        let kind = value_for(&ns_key_value_change_key::KindKey)
+            .map(|kind| NSNumber::from_id(kind).unsigned_integer_value())
+            .unwrap_or(NSKeyValueChange::Setting as UInt);
+
+        Self {
+            kind: match kind {
+                2 => NSKeyValueChange::Insertion,
+                3 => NSKeyValueChange::Removal,
+                4 => NSKeyValueChange::Replacement,
+                _ => NSKeyValueChange::Setting,
+            },
+            old: value_for(&ns_key_value_change_key::OldKey).and_then(decode),
+            new: value_for(&ns_key_value_change_key::NewKey).and_then(decode),
+            indexes: value_for(&ns_key_value_change_key::IndexesKey)
+                .map(|indexes| NSIndexSet::from_id(indexes)),
+            is_prior: value_for(&ns_key_value_change_key::NotificationIsPriorKey)
+                .map(|is_prior| NSNumber::from_id(is_prior).bool_value())
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// An observation started by [`observe`], which stops observing when dropped.
+#[must_use = "the observation stops when this is dropped"]
+pub struct KVOObservation {
+    object: id,
+    context: usize,
+    key_path: NSString,
+}
+
+impl Drop for KVOObservation {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![
+                self.object,
+                removeObserver: observer()
+                forKeyPath: self.key_path.m_self()
+                context: self.context as *mut c_void
+            ];
+        }
+
+        // A notification in flight on another thread holds its own reference to the handler.
+        HANDLERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.context);
+
+        unsafe {
+            let _: () = msg_send![self.object, release];
+        }
+    }
+}
+
+impl std::fmt::Debug for KVOObservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KVOObservation")
+            .field("key_path", &self.key_path)
+            .finish_non_exhaustive()
+    }
+}
+
+extern "C" fn observe_value_for_key_path(
+    _: &Object,
+    _: Sel,
+    _key_path: id,
+    _object: id,
+    change: id,
+    context: *mut c_void,
+) {
+    let handler = HANDLERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&(context as usize))
+        .cloned();
+
+    if let Some(handler) = handler {
+        handler(change);
+    }
+}
+
+/// The object that observes on behalf of every [`KVOObservation`].
+///
+/// It lives for the rest of the process, so a notification that races with the end of an
+/// observation never reaches a freed observer.
+fn observer() -> id {
+    static OBSERVER: OnceLock<usize> = OnceLock::new();
+
+    *OBSERVER.get_or_init(|| {
+        let class = register_class_for_type::<KVOObservation, _>(
+            "RSTKVOObserver",
+            class!(NSObject),
+            |decl| unsafe {
+                decl.add_method(
+                    sel!(observeValueForKeyPath:ofObject:change:context:),
+                    observe_value_for_key_path as extern "C" fn(&Object, _, _, _, _, _),
+                );
+            },
+        )
+        .unwrap_or_else(|error| panic!("{error}"));
+
+        unsafe {
+            let observer: id = msg_send![class, new];
+            observer as usize
+        }
+    }) as id
+}
+
+/// Calls `handler` with every change to the value at `key_path` of `object`.
+///
+/// The values of the change are decoded as `T`, and values of other classes are reported
+/// as `None`. `handler` is called on the thread that changes the value, so it must be
+/// `Send` and `Sync`. The observation keeps `object` alive, and stops when the returned
+/// [`KVOObservation`] is dropped.
+///
+/// ```no_run
+/// use rust_macios::foundation::{observe, NSKeyValueObservingOptions, NSNumber, NSProcessInfo};
+///
+/// let process_info = NSProcessInfo::process_info();
+///
+/// let _observation = observe(
+///     &process_info,
+///     "thermalState",
+///     &[NSKeyValueObservingOptions::New],
+///     |change: rust_macios::foundation::KVOChange<NSNumber>| {
+///         println!("thermal state changed to {:?}", change.new);
+///     },
+/// );
+/// ```
+pub fn observe<O, T, F>(
+    object: &O,
+    key_path: &str,
+    options: &[NSKeyValueObservingOptions],
+    handler: F,
+) -> KVOObservation
+where
+    O: PNSObject,
+    T: PNSObject + FromId + 'static,
+    F: Fn(KVOChange<T>) + Send + Sync + 'static,
+{
+    let handler: Arc<Handler> =
+        Arc::new(move |change| unsafe { handler(KVOChange::from_change_dictionary(change)) });
+    let options = options
+        .iter()
+        .fold(0, |options, &option| options | option as UInt);
+    let key_path = NSString::from(key_path);
+    let context = NEXT_CONTEXT.fetch_add(1, Ordering::Relaxed);
+
+    HANDLERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(context, handler);
+
+    unsafe {
+        let object: id = msg_send![object.m_self(), retain];
+        let _: () = msg_send![
+            object,
+            addObserver: observer()
+            forKeyPath: key_path.m_self()
+            options: options
+            context: context as *mut c_void
+        ];
+
+        KVOObservation {
+            object,
+            context,
+            key_path,
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
@@ -7,6 +8,7 @@ use crate::{
         macros::interface_impl,
         nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_bool,
 };
@@ -55,24 +57,27 @@ impl NSLocale {
 
     /// Initializes a locale using a given locale identifier.
     #[method]
-    pub fn init_with_locale_identifier(&mut self, locale_identifier: &NSString) -> Self
+    pub fn init_with_locale_identifier(locale_identifier: &NSString) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            let ptr =
-                msg_send![self.m_self(), initWithLocaleIdentifier: locale_identifier.m_self()];
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let ptr = msg_send![obj, initWithLocaleIdentifier: locale_identifier.m_self()];
             FromId::from_id(ptr)
         }
     }
 
     /// Returns a locale initialized from data in the given unarchiver.
     #[method]
-    pub fn init_with_coder(&mut self, coder: &NSCoder) -> Self
+    pub fn init_with_coder(coder: &NSCoder) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithCoder: coder.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCoder: coder.m_self()])
+        }
     }
 
     /* Getting the User's Locale
@@ -134,47 +139,69 @@ impl NSLocale {
 
     /// Returns the canonical identifier for a given locale identification string.
     #[method]
-    pub fn canonical_locale_identifier_from_string(string: &NSString) -> NSString {
+    pub fn canonical_locale_identifier_from_string<'pool>(
+        string: &NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                Self::m_class(),
-                canonicalLocaleIdentifierFromString: string.m_self()
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    canonicalLocaleIdentifierFromString: string.m_self()
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a dictionary that is the result of parsing a locale ID.
     #[method]
-    pub fn components_from_locale_identifier(
+    pub fn components_from_locale_identifier<'pool>(
         string: &NSString,
-    ) -> NSDictionary<NSString, NSString> {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSDictionary<NSString, NSString>> {
         unsafe {
-            NSDictionary::from_id(msg_send![
-                Self::m_class(),
-                componentsFromLocaleIdentifier: string.m_self()
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    componentsFromLocaleIdentifier: string.m_self()
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a locale identifier from the components specified in a given dictionary.
     #[method]
-    pub fn locale_identifier_from_components(dict: &NSDictionary<NSString, NSString>) -> NSString {
+    pub fn locale_identifier_from_components<'pool>(
+        dict: &NSDictionary<NSString, NSString>,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                Self::m_class(),
-                localeIdentifierFromComponents: dict.m_self()
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    localeIdentifierFromComponents: dict.m_self()
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a canonical language identifier by mapping an arbitrary locale identification string to the canonical identifier.
     #[method]
-    pub fn canonical_language_identifier_from_string(string: &NSString) -> NSString {
+    pub fn canonical_language_identifier_from_string<'pool>(
+        string: &NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                Self::m_class(),
-                canonicalLanguageIdentifierFromString: string.m_self()
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    canonicalLanguageIdentifierFromString: string.m_self()
+                ],
+                pool,
+            )
         }
     }
 
@@ -566,7 +593,7 @@ extern "C" {
     pub static NSCurrentLocaleDidChangeNotification: NSNotificationName;
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
     use super::NSLocale;
 
@@ -586,23 +613,17 @@ mod tests {
         let ident = NSLocale::current_locale().locale_identifier();
 
         assert!(
-            NSLocale::m_alloc()
-                .init_with_locale_identifier(&ident)
-                .locale_identifier()
+            NSLocale::init_with_locale_identifier(&ident).locale_identifier()
                 == NSLocale::current_locale().locale_identifier()
         );
 
         assert!(
-            NSLocale::m_alloc()
-                .init_with_locale_identifier(&ident)
-                .locale_identifier()
+            NSLocale::init_with_locale_identifier(&ident).locale_identifier()
                 == NSLocale::current_locale().locale_identifier()
         );
 
         assert!(
-            NSLocale::m_alloc()
-                .init_with_locale_identifier(&ident)
-                .locale_identifier()
+            NSLocale::init_with_locale_identifier(&ident).locale_identifier()
                 == NSLocale::current_locale().locale_identifier()
         );
     }
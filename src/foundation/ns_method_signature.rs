@@ -0,0 +1,106 @@
+use std::ffi::{CStr, CString};
+
+use crate::objective_c_runtime::msg_send;
+use libc::c_char;
+use objc::{sel, sel_impl};
+
+use crate::{
+    object,
+    objective_c_runtime::{
+        encoding::{MethodEncoding, TypeEncoding},
+        macros::interface_impl,
+        traits::PNSObject,
+        AutoreleasePool, Autoreleased,
+    },
+    utils::to_bool,
+};
+
+use super::UInt;
+
+object! {
+    /// A record of the type information for the return value and parameters of a method.
+    unsafe pub struct NSMethodSignature;
+}
+
+/// Parses a type returned by a method signature, which may be followed by a stack offset.
+unsafe fn parse_type(types: *const c_char) -> TypeEncoding {
+    let types = CStr::from_ptr(types).to_string_lossy();
+
+    TypeEncoding::parse_prefix(&types)
+        .map(|(encoding, _)| encoding)
+        .unwrap_or(TypeEncoding::Unknown)
+}
+
+#[interface_impl(NSObject)]
+impl NSMethodSignature {
+    /* Creating a Method Signature Object
+     */
+
+    /// Returns a method signature for the given Objective-C method type string, or `None`
+    /// if the string is not a valid encoding.
+    #[method]
+    pub fn signature_with_objc_types<'pool>(
+        types: &str,
+        pool: &'pool AutoreleasePool,
+    ) -> Option<Autoreleased<'pool, NSMethodSignature>> {
+        let types = CString::new(types).ok()?;
+
+        unsafe {
+            Autoreleased::try_new(
+                msg_send![Self::m_class(), signatureWithObjCTypes: types.as_ptr()],
+                pool,
+            )
+        }
+    }
+
+    /* Getting Information on Argument Types
+     */
+
+    /// The number of arguments recorded in the method signature, including the receiver
+    /// and the selector.
+    #[property]
+    pub fn number_of_arguments(&self) -> UInt {
+        unsafe { msg_send![self.m_self(), numberOfArguments] }
+    }
+
+    /// Returns the type encoding for the argument at a given index.
+    #[method]
+    pub fn get_argument_type_at_index(&self, index: UInt) -> TypeEncoding {
+        unsafe { parse_type(msg_send![self.m_self(), getArgumentTypeAtIndex: index]) }
+    }
+
+    /// The number of bytes that the arguments, taken together, occupy on the stack.
+    #[property]
+    pub fn frame_length(&self) -> UInt {
+        unsafe { msg_send![self.m_self(), frameLength] }
+    }
+
+    /// Returns a Boolean value that indicates whether the receiver is asynchronous when
+    /// invoked through distributed objects.
+    #[method]
+    pub fn is_oneway(&self) -> bool {
+        unsafe { to_bool(msg_send![self.m_self(), isOneway]) }
+    }
+
+    /// The type encoding for the return value of the method.
+    #[property]
+    pub fn method_return_type(&self) -> TypeEncoding {
+        unsafe { parse_type(msg_send![self.m_self(), methodReturnType]) }
+    }
+
+    /// The number of bytes required for the return value.
+    #[property]
+    pub fn method_return_length(&self) -> UInt {
+        unsafe { msg_send![self.m_self(), methodReturnLength] }
+    }
+}
+
+impl NSMethodSignature {
+    /// Returns the encoding of the return value and every argument.
+    pub fn encoding(&self) -> MethodEncoding {
+        MethodEncoding::new(
+            self.method_return_type(),
+            (0..self.number_of_arguments()).map(|index| self.get_argument_type_at_index(index)),
+        )
+    }
+}
@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
-use objc::{class, msg_send, runtime::Object, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{class, runtime::Object, sel, sel_impl};
 
 use crate::{
     foundation::UInt,
@@ -8,11 +9,12 @@ use crate::{
     objective_c_runtime::{
         id,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
-    utils::to_bool,
+    try_msg_send,
 };
 
-use super::{ns_array::INSArray, NSArray, NSRange, NSString};
+use super::{ns_array::INSArray, NSArray, NSException, NSRange, NSString};
 
 object! {
     /// A dynamic ordered collection of objects.
@@ -30,27 +32,51 @@ where
      */
 
     /// Creates and returns an NSMutableArray object with enough allocated memory to initially hold a given number of objects.
-    fn tm_array_with_capacity(capacity: usize) -> Self
+    fn tm_array_with_capacity<'pool>(
+        capacity: usize,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), arrayWithCapacity: capacity]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), arrayWithCapacity: capacity],
+                pool,
+            )
+        }
     }
 
     /// Creates and returns a mutable array containing the contents of the file specified by the given path.
-    fn tm_array_with_contents_of_file(path: NSString) -> Self
+    fn tm_array_with_contents_of_file<'pool>(
+        path: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), arrayWithContentsOfFile: path]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), arrayWithContentsOfFile: path],
+                pool,
+            )
+        }
     }
 
     /// Creates and returns a mutable array containing the contents specified by a given URL.
-    fn tm_array_with_contents_of_url(url: NSString) -> Self
+    fn tm_array_with_contents_of_url<'pool>(
+        url: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), arrayWithContentsOfURL: url]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), arrayWithContentsOfURL: url],
+                pool,
+            )
+        }
     }
 
     /// Returns an array, initialized with enough memory to initially hold a given number of objects.
@@ -60,15 +86,21 @@ where
     {
         unsafe {
             let cls = Self::m_class();
-            let alloc: id = msg_send![cls, new];
+            let alloc: id = msg_send![cls, alloc];
             let init = msg_send![alloc, initWithCapacity: capacity];
-            Self::from_id(init)
+            Self::from_retained_id(init)
         }
     }
 
     /// Initializes a newly allocated mutable array with the contents of the file specified by a given path
-    fn im_init_with_contents_of_file(&mut self, path: NSString) -> bool {
-        unsafe { to_bool(msg_send![self.m_self(), initWithContentsOfFile: path]) }
+    fn im_init_with_contents_of_file(path: NSString) -> Option<Self>
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::try_from_retained_id(msg_send![obj, initWithContentsOfFile: path])
+        }
     }
 
     /* Adding Objects
@@ -89,6 +121,16 @@ where
         unsafe { msg_send![self.m_self(), insertObject: object atIndex: index] }
     }
 
+    /// Inserts a given object into the array’s contents at a given index, or returns the
+    /// `NSRangeException` raised when `index` is greater than the number of elements.
+    fn im_try_insert_object_at_index(
+        &mut self,
+        index: UInt,
+        object: &T,
+    ) -> Result<(), NSException> {
+        unsafe { try_msg_send![self.m_self(), insertObject: object atIndex: index] }
+    }
+
     /* Removing Objects
      */
 
@@ -117,6 +159,12 @@ where
         unsafe { msg_send![self.m_self(), removeObjectAtIndex: index] }
     }
 
+    /// Removes the object at index, or returns the `NSRangeException` raised when `index` is
+    /// beyond the end of the array.
+    fn im_try_remove_object_at_index(&mut self, index: UInt) -> Result<(), NSException> {
+        unsafe { try_msg_send![self.m_self(), removeObjectAtIndex: index] }
+    }
+
     /// Removes all occurrences of a given object in the array.
     fn im_remove_object_identical_to(&mut self, object: &T) {
         unsafe { msg_send![self.m_self(), removeObjectIdenticalTo: object] }
@@ -145,6 +193,16 @@ where
         unsafe { msg_send![self.m_self(), replaceObjectAtIndex: index withObject: object] }
     }
 
+    /// Replaces the object at index with anObject, or returns the `NSRangeException` raised
+    /// when `index` is beyond the end of the array.
+    fn im_try_replace_object_at_index_with_object(
+        &mut self,
+        index: UInt,
+        object: &T,
+    ) -> Result<(), NSException> {
+        unsafe { try_msg_send![self.m_self(), replaceObjectAtIndex: index withObject: object] }
+    }
+
     /// Sets the receiving array’s elements to those in another given array.
     fn im_set_array(&mut self, other_array: &NSArray<T>) {
         unsafe { msg_send![self.m_self(), setArray: other_array.m_self()] }
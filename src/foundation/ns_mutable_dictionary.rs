@@ -1,12 +1,14 @@
 use std::{borrow::Borrow, collections::HashMap, marker::PhantomData};
 
-use objc::{class, msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
     objective_c_runtime::{
         id,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -29,11 +31,30 @@ pub trait INSMutableDictionary<K, V>: INSDictionary<K, V> {
      */
 
     /// Creates and returns a mutable dictionary, initially giving it enough allocated memory to hold a given number of entries.
-    fn tm_dictionary_with_capacity(capacity: UInt) -> Self
+    fn tm_dictionary_with_capacity<'pool>(
+        capacity: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), dictionaryWithCapacity: capacity]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), dictionaryWithCapacity: capacity],
+                pool,
+            )
+        }
+    }
+
+    /// Initializes a newly allocated mutable dictionary, allocating enough memory to hold a given number of entries.
+    fn im_init_with_capacity(capacity: UInt) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCapacity: capacity])
+        }
     }
 
     /* Adding Entries to a Mutable Dictionary
@@ -109,7 +130,7 @@ impl<K, V> NSMutableDictionary<K, V> {
 
     /// Creates a new dictionary with the specified capacity.
     pub fn with_capacity(capacity: UInt) -> Self {
-        Self::tm_dictionary_with_capacity(capacity)
+        Self::im_init_with_capacity(capacity)
     }
 
     /// Creates a new dictionary with the specified capacity and load factor.
@@ -128,7 +149,7 @@ impl<K, V> INSMutableDictionary<K, V> for NSMutableDictionary<K, V> {}
 
 impl<K, V> Default for NSMutableDictionary<K, V> {
     fn default() -> Self {
-        Self::tm_dictionary_with_capacity(0)
+        Self::im_init_with_capacity(0)
     }
 }
 
@@ -154,12 +175,7 @@ where
     V: PNSObject,
 {
     fn from(capacity: UInt) -> Self {
-        unsafe {
-            Self::from_id(msg_send![
-                class!(NSMutableDictionary),
-                dictionaryWithCapacity: capacity
-            ])
-        }
+        Self::im_init_with_capacity(capacity)
     }
 }
 
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::INSString,
@@ -7,6 +8,7 @@ use crate::{
         id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -35,9 +37,15 @@ impl NSMutableString {
     ///
     /// An empty NSMutableString object with initial storage for a given number of characters.
     #[method]
-    pub fn string_with_capacity(capacity: UInt) -> NSMutableString {
+    pub fn string_with_capacity<'pool>(
+        capacity: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSMutableString> {
         unsafe {
-            NSMutableString::from_id(msg_send![Self::m_class(), stringWithCapacity: capacity])
+            Autoreleased::new(
+                msg_send![Self::m_class(), stringWithCapacity: capacity],
+                pool,
+            )
         }
     }
 
@@ -48,11 +56,14 @@ impl NSMutableString {
     ///
     /// * `capacity` - The number of characters to allocate space for.
     #[method]
-    pub fn init_with_capacity(mut self, capacity: UInt) -> NSMutableString
+    pub fn init_with_capacity(capacity: UInt) -> NSMutableString
     where
         Self: Sized,
     {
-        unsafe { msg_send![self.m_self(), initWithCapacity: capacity] }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            msg_send![obj, initWithCapacity: capacity]
+        }
     }
 
     /* Modifying a String
@@ -189,7 +200,7 @@ impl INSString for NSMutableString {}
 
 impl Default for NSMutableString {
     fn default() -> Self {
-        Self::m_string()
+        Self::init_with_capacity(0)
     }
 }
 
@@ -210,34 +221,45 @@ impl From<NSString> for NSMutableString {
         unsafe {
             let ptr: id = msg_send![NSString::m_class(), alloc];
             let ptr: id = msg_send![ptr, initWithString: string];
-            NSMutableString::from_id(ptr)
+            NSMutableString::from_retained_id(ptr)
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
 
-    use crate::foundation::{string::Encoding, LatinToKatakana, NSStringCompareOptions};
+    use crate::{
+        foundation::{string::Encoding, LatinToKatakana, NSStringCompareOptions},
+        objective_c_runtime::autoreleasepool,
+    };
 
     use super::*;
 
     #[test]
     fn test_tm_string_with_capacity() {
-        let string = NSMutableString::string_with_capacity(10);
+        autoreleasepool(|pool| {
+            let string = NSMutableString::string_with_capacity(10, pool);
+            assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 0);
+        });
+    }
+
+    #[test]
+    fn test_tm_init_with_capacity() {
+        let string = NSMutableString::init_with_capacity(10);
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 0);
     }
 
     #[test]
     fn test_im_append_string() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
     }
 
     #[test]
     fn test_im_apply_transform_reverse_range_updated_range() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
         assert!(string.m_apply_transform_reverse_range_updated_range(
@@ -250,7 +272,7 @@ mod tests {
 
     #[test]
     fn test_im_delete_characters_in_range() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
         string.m_delete_characters_in_range((0..5).into());
@@ -259,7 +281,7 @@ mod tests {
 
     #[test]
     fn test_im_insert_string_at_index() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
         string.m_insert_string_at_index(NSString::from("World"), 0);
@@ -268,7 +290,7 @@ mod tests {
 
     #[test]
     fn test_im_replace_characters_in_range_with_string() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
         string.m_replace_characters_in_range_with_string((0..5).into(), NSString::from("World"));
@@ -277,7 +299,7 @@ mod tests {
 
     #[test]
     fn test_im_replace_occurrences_of_string_with_string_options_range() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
         assert_eq!(
@@ -293,7 +315,7 @@ mod tests {
 
     #[test]
     fn test_im_set_string() {
-        let mut string = NSMutableString::string_with_capacity(10);
+        let mut string = NSMutableString::init_with_capacity(10);
         string.append_string(NSString::from("Hello"));
         assert_eq!(string.m_length_of_bytes_using_encoding(Encoding::UTF8), 5);
         string.set_string(NSString::from("World"));
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
@@ -6,6 +7,7 @@ use crate::{
         id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -23,49 +25,62 @@ impl NSNotification {
 
     /// Initializes an empty notification.
     #[method]
-    pub fn init(&mut self) -> Self
+    pub fn init() -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            let ptr = msg_send![self.m_self(), init];
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let ptr = msg_send![obj, init];
             FromId::from_id(ptr)
         }
     }
 
     /// Initializes a notification with the data from an unarchiver.
     #[method]
-    pub fn init_with_coder(&mut self, coder: &NSCoder) -> Self
+    pub fn init_with_coder(coder: &NSCoder) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { FromId::from_id(msg_send![self.m_self(), initWithCoder: coder.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            FromId::from_retained_id(msg_send![obj, initWithCoder: coder.m_self()])
+        }
     }
 
     /// Returns a new notification object with a specified name and object.
     #[method]
-    pub fn notification_with_name_object(name: NSNotificationName, object: id) -> Self
+    pub fn notification_with_name_object<'pool>(
+        name: NSNotificationName,
+        object: id,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            FromId::from_id(msg_send![Self::m_class(), notificationWithName: name object: object])
+            Autoreleased::new(
+                msg_send![Self::m_class(), notificationWithName: name object: object],
+                pool,
+            )
         }
     }
 
     /// Returns a notification object with a specified name, object, and user information.
     #[method]
-    pub fn notification_with_name_object_user_info(
+    pub fn notification_with_name_object_user_info<'pool>(
         name: NSNotificationName,
         object: id,
         user_info: NSDictionary<id, id>,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            FromId::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), notificationWithName: name object: object userInfo: user_info],
+                pool,
             )
         }
     }
@@ -73,7 +88,6 @@ impl NSNotification {
     /// Initializes a notification with a specified name, object, and user information.
     #[method]
     pub fn init_with_name_object_user_info(
-        &mut self,
         name: &NSNotificationName,
         object: id,
         user_info: &NSDictionary<id, id>,
@@ -82,8 +96,9 @@ impl NSNotification {
         Self: Sized + FromId,
     {
         unsafe {
-            FromId::from_id(
-                msg_send![self.m_self(), initWithName: name.m_self() object: object userInfo: user_info.m_self()],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            FromId::from_retained_id(
+                msg_send![obj, initWithName: name.m_self() object: object userInfo: user_info.m_self()],
             )
         }
     }
@@ -1,17 +1,19 @@
 use std::ops::Add;
 
+use crate::objective_c_runtime::msg_send;
 use libc::{
     c_double, c_float, c_int, c_long, c_longlong, c_schar, c_short, c_uchar, c_uint, c_ulong,
     c_ulonglong, c_ushort,
 };
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
-        INSValue,
+        AutoreleasePool, Autoreleased, INSValue,
     },
     utils::to_bool,
 };
@@ -40,11 +42,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_bool(value: bool) -> Self
+    pub fn number_with_bool<'pool>(
+        value: bool,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + 'static + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithBool: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithBool: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as a signed char.
@@ -57,11 +62,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_char(value: c_schar) -> Self
+    pub fn number_with_char<'pool>(
+        value: c_schar,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithChar: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithChar: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it a double.
@@ -74,11 +82,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_double(value: c_double) -> Self
+    pub fn number_with_double<'pool>(
+        value: c_double,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithDouble: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithDouble: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as a float.
@@ -91,11 +102,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_float(value: c_float) -> Self
+    pub fn number_with_float<'pool>(
+        value: c_float,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithFloat: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithFloat: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as a signed int.
@@ -108,11 +122,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_int(value: c_int) -> Self
+    pub fn number_with_int<'pool>(
+        value: c_int,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithInt: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithInt: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as an NSInteger.
@@ -125,11 +142,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_integer(value: Int) -> Self
+    pub fn number_with_integer<'pool>(
+        value: Int,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithInteger: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithInteger: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as a signed long.
@@ -142,11 +162,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_long(value: c_long) -> Self
+    pub fn number_with_long<'pool>(
+        value: c_long,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithLong: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithLong: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as a signed long long.
@@ -159,11 +182,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_long_long(value: c_longlong) -> Self
+    pub fn number_with_long_long<'pool>(
+        value: c_longlong,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithLongLong: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithLongLong: value], pool) }
     }
     /// Creates and returns an NSNumber object containing value, treating it as a signed short.
     ///
@@ -175,11 +201,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_short(value: c_short) -> Self
+    pub fn number_with_short<'pool>(
+        value: c_short,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithShort: value]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), numberWithShort: value], pool) }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as an unsigned char.
@@ -192,11 +221,19 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_unsigned_char(value: c_uchar) -> Self
+    pub fn number_with_unsigned_char<'pool>(
+        value: c_uchar,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithUnsignedChar: value]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), numberWithUnsignedChar: value],
+                pool,
+            )
+        }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as an unsigned int.
@@ -209,11 +246,19 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_unsigned_int(value: c_uint) -> Self
+    pub fn number_with_unsigned_int<'pool>(
+        value: c_uint,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithUnsignedInt: value]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), numberWithUnsignedInt: value],
+                pool,
+            )
+        }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as an NSUInteger.
@@ -226,11 +271,19 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_unsigned_integer(value: UInt) -> Self
+    pub fn number_with_unsigned_integer<'pool>(
+        value: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithUnsignedInteger: value]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), numberWithUnsignedInteger: value],
+                pool,
+            )
+        }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as an unsigned long.
@@ -243,11 +296,19 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_unsigned_long(value: c_ulong) -> Self
+    pub fn number_with_unsigned_long<'pool>(
+        value: c_ulong,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithUnsignedLong: value]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), numberWithUnsignedLong: value],
+                pool,
+            )
+        }
     }
 
     /// Creates and returns an NSNumber object containing a given value, treating it as an unsigned long long.
@@ -260,15 +321,21 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_unsigned_long_long(value: c_ulonglong) -> Self
+    pub fn number_with_unsigned_long_long<'pool>(
+        value: c_ulonglong,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                numberWithUnsignedLongLong: value
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    numberWithUnsignedLongLong: value
+                ],
+                pool,
+            )
         }
     }
 
@@ -282,11 +349,19 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn number_with_unsigned_short(value: c_ushort) -> Self
+    pub fn number_with_unsigned_short<'pool>(
+        value: c_ushort,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), numberWithUnsignedShort: value]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), numberWithUnsignedShort: value],
+                pool,
+            )
+        }
     }
 
     /* Initializing an NSNumber Object
@@ -298,11 +373,14 @@ impl NSNumber {
     ///
     /// * `value` - The value to store in the NSNumber object.
     #[method]
-    pub fn init_with_bool(&mut self, value: bool) -> Self
+    pub fn init_with_bool(value: bool) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithBool: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithBool: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as a signed char.
@@ -315,11 +393,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_char(&mut self, value: c_schar) -> Self
+    pub fn init_with_char(value: c_schar) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithChar: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithChar: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain value, treated as a double.
@@ -332,11 +413,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_double(&mut self, value: c_double) -> Self
+    pub fn init_with_double(value: c_double) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithDouble: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithDouble: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as a float.
@@ -349,11 +433,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_float(&mut self, value: c_float) -> Self
+    pub fn init_with_float(value: c_float) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithFloat: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithFloat: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as a signed int.
@@ -366,11 +453,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_int(&mut self, value: c_int) -> Self
+    pub fn init_with_int(value: c_int) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithInt: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithInt: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an NSInteger.
@@ -383,11 +473,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_integer(&mut self, value: Int) -> Self
+    pub fn init_with_integer(value: Int) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithInteger: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithInteger: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as a signed long.
@@ -400,11 +493,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_long(&mut self, value: c_long) -> Self
+    pub fn init_with_long(value: c_long) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithLong: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithLong: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain value, treated as a signed long long.
@@ -417,11 +513,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_long_long(&mut self, value: c_longlong) -> Self
+    pub fn init_with_long_long(value: c_longlong) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithLongLong: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithLongLong: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as a signed short.
@@ -434,11 +533,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_short(&mut self, value: c_short) -> Self
+    pub fn init_with_short(value: c_short) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithShort: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithShort: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an unsigned char.
@@ -451,11 +553,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_unsigned_char(&mut self, value: c_uchar) -> Self
+    pub fn init_with_unsigned_char(value: c_uchar) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnsignedChar: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnsignedChar: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an unsigned int.
@@ -468,11 +573,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_unsigned_int(&mut self, value: c_uint) -> Self
+    pub fn init_with_unsigned_int(value: c_uint) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnsignedInt: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnsignedInt: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an NSUInteger.
@@ -485,11 +593,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_unsigned_integer(&mut self, value: c_uint) -> Self
+    pub fn init_with_unsigned_integer(value: UInt) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnsignedInteger: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnsignedInteger: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an unsigned long.
@@ -502,11 +613,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_unsigned_long(&mut self, value: c_ulong) -> Self
+    pub fn init_with_unsigned_long(value: c_ulong) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnsignedLong: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnsignedLong: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an unsigned long long.
@@ -519,11 +633,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_unsigned_long_long(&mut self, value: c_ulonglong) -> Self
+    pub fn init_with_unsigned_long_long(value: c_ulonglong) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnsignedLongLong: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnsignedLongLong: value])
+        }
     }
 
     /// Returns an NSNumber object initialized to contain a given value, treated as an unsigned short.
@@ -536,11 +653,14 @@ impl NSNumber {
     ///
     /// Returns an `NSNumber` object containing the value.
     #[method]
-    pub fn init_with_unsigned_short(&mut self, value: c_ushort) -> Self
+    pub fn init_with_unsigned_short(value: c_ushort) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnsignedShort: value]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnsignedShort: value])
+        }
     }
 
     /* Accessing Numeric Values
@@ -705,13 +825,16 @@ impl NSNumber {
 
     /* Initializers */
 
-    ///
+    /// Returns an `NSNumber` object initialized from data in a given unarchiver.
     #[method]
-    fn init_with_coder(&mut self, coder: &NSCoder) -> Self
+    fn init_with_coder(coder: &NSCoder) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithCoder: coder.m_self()]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCoder: coder.m_self()])
+        }
     }
 }
 
@@ -750,60 +873,60 @@ where
 
 impl From<c_float> for NSNumber {
     fn from(value: c_float) -> Self {
-        NSNumber::number_with_float(value)
+        NSNumber::init_with_float(value)
     }
 }
 
 impl From<c_double> for NSNumber {
     fn from(value: c_double) -> Self {
-        NSNumber::number_with_double(value)
+        NSNumber::init_with_double(value)
     }
 }
 
 impl From<c_schar> for NSNumber {
     fn from(value: c_schar) -> Self {
-        NSNumber::number_with_char(value)
+        NSNumber::init_with_char(value)
     }
 }
 
 impl From<c_uchar> for NSNumber {
     fn from(value: c_uchar) -> Self {
-        NSNumber::number_with_unsigned_char(value)
+        NSNumber::init_with_unsigned_char(value)
     }
 }
 
 impl From<c_short> for NSNumber {
     fn from(value: c_short) -> Self {
-        NSNumber::number_with_short(value)
+        NSNumber::init_with_short(value)
     }
 }
 
 impl From<c_ushort> for NSNumber {
     fn from(value: c_ushort) -> Self {
-        NSNumber::number_with_unsigned_short(value)
+        NSNumber::init_with_unsigned_short(value)
     }
 }
 
 impl From<c_int> for NSNumber {
     fn from(value: c_int) -> Self {
-        NSNumber::number_with_int(value)
+        NSNumber::init_with_int(value)
     }
 }
 
 impl From<c_uint> for NSNumber {
     fn from(value: c_uint) -> Self {
-        NSNumber::number_with_unsigned_int(value)
+        NSNumber::init_with_unsigned_int(value)
     }
 }
 
 impl From<c_long> for NSNumber {
     fn from(value: c_long) -> Self {
-        NSNumber::number_with_long(value)
+        NSNumber::init_with_long(value)
     }
 }
 
 impl From<c_ulong> for NSNumber {
     fn from(value: c_ulong) -> Self {
-        NSNumber::number_with_unsigned_long(value)
+        NSNumber::init_with_unsigned_long(value)
     }
 }
@@ -1,8 +1,11 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
-    objective_c_runtime::{id, macros::interface_impl, traits::FromId},
+    objective_c_runtime::{
+        id, macros::interface_impl, traits::FromId, AutoreleasePool, Autoreleased,
+    },
     utils::to_bool,
 };
 
@@ -106,13 +109,15 @@ impl NSNumberFormatter {
 
     /// Returns a localized number string with the specified style.
     #[method]
-    pub fn localized_string_from_number_number_style(
+    pub fn localized_string_from_number_number_style<'pool>(
         num: NSNumber,
         nstyle: NSNumberFormatterStyle,
-    ) -> NSString {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), localizedStringFromNumber: num numberStyle: nstyle],
+                pool,
             )
         }
     }
@@ -202,7 +207,7 @@ impl NSNumberFormatter {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use rust_macios::objective_c_runtime::traits::PNSObject;
     /// use rust_macios::foundation::NSNumberFormatter;
     ///
@@ -229,7 +234,7 @@ impl NSNumberFormatter {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use rust_macios::objective_c_runtime::traits::PNSObject;
     /// use rust_macios::foundation::NSNumberFormatter;
     ///
@@ -256,7 +261,7 @@ impl NSNumberFormatter {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use rust_macios::objective_c_runtime::traits::PNSObject;
     /// use rust_macios::foundation::NSNumberFormatter;
     ///
@@ -283,7 +288,7 @@ impl NSNumberFormatter {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use rust_macios::objective_c_runtime::traits::PNSObject;
     /// use rust_macios::foundation::NSNumberFormatter;
     ///
@@ -312,7 +317,7 @@ impl NSNumberFormatter {
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use rust_macios::objective_c_runtime::traits::PNSObject;
     /// use rust_macios::foundation::NSNumberFormatter;
     ///
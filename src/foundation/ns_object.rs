@@ -0,0 +1,6 @@
+use crate::object;
+
+object! {
+    /// The root class of most Objective-C class hierarchies, for objects of any class.
+    unsafe pub struct NSObject;
+}
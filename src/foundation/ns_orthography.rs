@@ -1,10 +1,13 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -22,22 +25,27 @@ impl NSOrthography {
 
     /// Creates and returns an orthography object with the default language map for the specified language.
     #[method]
-    pub fn default_orthography_for_language(language: NSString) -> Self
+    pub fn default_orthography_for_language<'pool>(
+        language: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                defaultOrthographyForLanguage: language
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    defaultOrthographyForLanguage: language
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates an orthography object with the specified dominant script and language map.
     #[method]
     pub fn init_with_dominant_script_language_map(
-        &mut self,
         script: NSString,
         map: NSDictionary<NSString, NSArray<NSString>>,
     ) -> Self
@@ -45,22 +53,25 @@ impl NSOrthography {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![self.m_self(), initWithDominantScript: script languageMap: map])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithDominantScript: script languageMap: map])
         }
     }
 
     /// Creates and returns an orthography object with the specified dominant script and language map.
     #[method]
-    pub fn orthography_with_dominant_script(
+    pub fn orthography_with_dominant_script<'pool>(
         script: NSString,
         map: NSDictionary<NSString, NSArray<NSString>>,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), orthographyWithDominantScript: script languageMap: map],
+                pool,
             )
         }
     }
@@ -112,11 +123,14 @@ impl NSOrthography {
 
     /// Initialize with [`NSCoder`]
     #[method]
-    pub fn init_with_coder(&mut self, coder: NSCoder) -> Self
+    pub fn init_with_coder(coder: NSCoder) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithCoder: coder]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCoder: coder])
+        }
     }
 }
 
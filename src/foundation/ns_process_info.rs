@@ -1,8 +1,9 @@
 use std::fmt::Display;
 
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock};
 use libc::c_ulonglong;
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
@@ -18,6 +19,7 @@ use super::{Int, NSArray, NSDictionary, NSNotificationName, NSString, NSTimeInte
 
 /// A structure that contains version information about the currently executing operating system, including major, minor, and patch version numbers.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[repr(C)]
 pub struct NSOperatingSystemVersion {
     /// The major release number, such as 10 in version 10.9.3.
     pub major: isize,
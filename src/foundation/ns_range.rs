@@ -1,13 +1,14 @@
 use std::ops::Range;
 
-use objc::Encoding;
+use crate::objective_c_runtime::Encode;
 
-use crate::objective_c_runtime;
-
-use super::{NSString, UInt};
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+use super::NSString;
+use super::UInt;
 
 /// A structure used to describe a portion of a series, such as characters in a string or objects in an array.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Encode)]
+#[encoding(name = "_NSRange")]
 #[repr(C)]
 pub struct NSRange {
     /// The number of items in the range (can be 0). For type compatibility with the rest of the system, LONG_MAX is the maximum value you should use for length.
@@ -16,12 +17,7 @@ pub struct NSRange {
     pub length: UInt,
 }
 
-unsafe impl objective_c_runtime::Encode for NSRange {
-    fn encode() -> objc::Encoding {
-        unsafe { Encoding::from_str("{location=I, length=I}") }
-    }
-}
-
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 extern "C" {
     /// Creates a new NSRange from the specified values.
     pub fn NSMakeRange(loc: UInt, len: UInt) -> NSRange;
@@ -62,3 +58,27 @@ impl From<NSRange> for Range<usize> {
         range.location as usize..(range.location + range.length) as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_range() {
+        let range = NSRange::from(3..8);
+
+        assert_eq!(range.location, 3);
+        assert_eq!(range.length, 5);
+        assert_eq!(Range::<usize>::from(range), 3..8);
+    }
+
+    #[test]
+    fn test_encoding() {
+        use crate::objective_c_runtime::encoding::EncodeType;
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(NSRange::type_encoding().to_string(), "{_NSRange=QQ}");
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(NSRange::type_encoding().to_string(), "{_NSRange=II}");
+    }
+}
@@ -1,6 +1,18 @@
 use std::marker::PhantomData;
 
-use crate::object;
+use crate::objective_c_runtime::msg_send;
+use objc::{class, sel, sel_impl};
+
+use crate::{
+    object,
+    objective_c_runtime::{
+        id,
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
+};
+
+use super::{NSArray, UInt};
 
 object! {
     /// A static, unordered collection of unique objects.
@@ -8,3 +20,31 @@ object! {
         marker: PhantomData<T>,
     }
 }
+
+impl<T> NSSet<T> {
+    /// Creates and returns a set containing a uniqued collection of the objects in an array.
+    pub fn set_with_array<'pool>(
+        array: &NSArray<T>,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self> {
+        unsafe { Autoreleased::new(msg_send![class!(NSSet), setWithArray: array.m_self()], pool) }
+    }
+
+    /// Initializes a newly allocated set with the objects that are contained in a given array.
+    pub fn init_with_array(array: &NSArray<T>) -> Self {
+        unsafe {
+            let obj: id = msg_send![class!(NSSet), alloc];
+            Self::from_retained_id(msg_send![obj, initWithArray: array.m_self()])
+        }
+    }
+
+    /// The number of members in the set.
+    pub fn count(&self) -> UInt {
+        unsafe { msg_send![self.m_self(), count] }
+    }
+
+    /// An array containing the set’s members, or an empty array if the set has no members.
+    pub fn all_objects(&self) -> NSArray<T> {
+        unsafe { NSArray::from_id(msg_send![self.m_self(), allObjects]) }
+    }
+}
@@ -1,9 +1,9 @@
-use std::sync::Once;
+use std::{mem::ManuallyDrop, sync::Once};
 
+use crate::objective_c_runtime::msg_send;
 use objc::{
     class,
     declare::ClassDecl,
-    msg_send,
     runtime::{Class, Object},
     sel, sel_impl,
 };
@@ -30,7 +30,7 @@ pub struct NSSpellServer {
 
 impl PNSObject for NSSpellServer {
     fn m_class<'a>() -> &'a Class {
-        static mut NSSPELL_SERVER_CLASS: *const Class = 0 as *const Class;
+        static mut NSSPELL_SERVER_CLASS: *const Class = std::ptr::null();
         static INIT: Once = Once::new();
 
         INIT.call_once(|| unsafe {
@@ -60,8 +60,10 @@ impl core::fmt::Display for NSSpellServer {
 }
 
 impl ToId for NSSpellServer {
-    fn to_id(mut self) -> id {
-        &mut *self.ptr
+    fn to_id(self) -> id {
+        // Hand our reference to the pool instead of releasing it.
+        let this = ManuallyDrop::new(self);
+        unsafe { msg_send![this.m_self(), autorelease] }
     }
 }
 
@@ -83,8 +85,6 @@ impl NSSpellServer {
         unsafe { msg_send![self.m_self(), delegate] }
     }
 
-    /// Sets the receiver's delegate.
-
     /* Providing Spelling Services
      */
 
@@ -2,12 +2,14 @@ use std::{
     ffi::{CStr, CString},
     fmt,
     marker::PhantomData,
+    mem::ManuallyDrop,
     string::String,
 };
 
+use crate::objective_c_runtime::msg_send;
 use libc::{c_char, c_void};
 use objc::{
-    class, msg_send,
+    class,
     runtime::{Class, Object},
     sel, sel_impl,
 };
@@ -16,10 +18,11 @@ use objc_id::{Id, ShareId};
 use crate::{
     foundation::NSComparisonResult,
     objective_c_runtime::{
-        self, id,
+        self, autoreleasepool, id,
         macros::interface_impl,
         nil,
-        traits::{FromId, PNSObject, ToId},
+        traits::{AnyThread, FromId, PNSObject, ToId},
+        AutoreleasePool, Autoreleased,
     },
     utils::{to_bool, to_optional},
 };
@@ -52,7 +55,15 @@ impl NSString {
     }
 
     /// Convert this `NSString` into a `&str`.
-    pub fn as_str(&self) -> Result<&str, std::str::Utf8Error> {
+    ///
+    /// The bytes may be autoreleased, so the result borrows `pool` as well as the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pool` is not the innermost pool.
+    pub fn as_str<'a>(&'a self, pool: &'a AutoreleasePool) -> Result<&'a str, std::str::Utf8Error> {
+        pool.assert_innermost();
+
         let bytes = self.bytes();
 
         unsafe {
@@ -69,20 +80,23 @@ impl NSString {
 
     /// Returns an empty string.
     #[method]
-    pub fn string() -> Self
+    pub fn string<'pool>(pool: &'pool AutoreleasePool) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), string]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), string], pool) }
     }
 
     /// Returns an initialized NSString object that contains no characters.
     #[method]
-    pub fn init(mut self) -> Self
+    pub fn init() -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), init]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, init])
+        }
     }
 
     /// Returns an initialized NSString object containing a given number of bytes from a given
@@ -102,7 +116,6 @@ impl NSString {
     /// encoding. The returned object may be different from the original receiver.
     #[method]
     pub fn init_with_bytes_length_encoding(
-        mut self,
         bytes: *const c_void,
         len: UInt,
         encoding: Encoding,
@@ -111,8 +124,9 @@ impl NSString {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithBytes: bytes length: len encoding: encoding],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(
+                msg_send![obj, initWithBytes: bytes length: len encoding: encoding],
             )
         }
     }
@@ -131,7 +145,6 @@ impl NSString {
     /// An initialized NSString object containing length bytes from bytes interpreted using the encoding encoding. The returned object may be different from the original receiver.
     #[method]
     pub fn init_with_bytes_no_copy_length_encoding_free_when_done(
-        mut self,
         bytes: *mut c_void,
         len: UInt,
         encoding: Encoding,
@@ -141,8 +154,9 @@ impl NSString {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                self.m_self(),
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![
+                obj,
                 initWithBytesNoCopy: bytes length: len encoding: encoding freeWhenDone: free_buffer
             ])
         }
@@ -160,12 +174,13 @@ impl NSString {
     ///
     /// An initialized NSString object containing length characters from characters.
     #[method]
-    pub fn init_with_characters_length(mut self, characters: *const unichar, len: UInt) -> Self
+    pub fn init_with_characters_length(characters: *const unichar, len: UInt) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![self.m_self(), initWithCharacters: characters length: len])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCharacters: characters length: len])
         }
     }
 
@@ -183,7 +198,6 @@ impl NSString {
     /// An initialized NSString object containing length characters from characters.
     #[method]
     pub fn init_with_characters_no_copy_length_free_when_done(
-        mut self,
         characters: unichar,
         length: UInt,
         free_buffer: bool,
@@ -192,8 +206,9 @@ impl NSString {
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                self.m_self(),
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![
+                obj,
                 initWithCharactersNoCopy: characters length: length freeWhenDone: free_buffer
             ])
         }
@@ -209,12 +224,15 @@ impl NSString {
     ///
     /// An NSString object initialized by copying the characters from s.
     #[method]
-    pub fn init_with_string<S>(mut self, s: S) -> Self
+    pub fn init_with_string<S>(s: S) -> Self
     where
         Self: Sized + FromId,
         S: INSString,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithString: s]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithString: s])
+        }
     }
     /// Returns an NSString object initialized using the characters in a given C array,
     /// interpreted according to a given encoding.
@@ -229,12 +247,13 @@ impl NSString {
     /// An NSString object initialized using the characters in c_str,
     /// interpreted according to encoding.
     #[method]
-    pub fn init_with_cstring_encoding(mut self, c_str: *const c_char, encoding: Encoding) -> Self
+    pub fn init_with_cstring_encoding(c_str: *const c_char, encoding: Encoding) -> Self
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![self.m_self(), initWithCString: c_str encoding: encoding])
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithCString: c_str encoding: encoding])
         }
     }
 
@@ -250,11 +269,14 @@ impl NSString {
     /// An NSString object initialized using the characters in c_str,
     /// interpreted as a UTF8 string.
     #[method]
-    pub fn init_with_utf8_string(mut self, c_str: *const c_char) -> Self
+    pub fn init_with_utf8_string(c_str: *const c_char) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUTF8String: c_str]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUTF8String: c_str])
+        }
     }
 
     /// Returns an NSString object initialized by converting given data into
@@ -269,11 +291,14 @@ impl NSString {
     ///
     /// An NSString object initialized by converting data into UTF-16 code units using encoding.
     #[method]
-    pub fn init_with_data_encoding(mut self, data: NSData, encoding: Encoding) -> Self
+    pub fn init_with_data_encoding(data: NSData, encoding: Encoding) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithData: data encoding: encoding]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithData: data encoding: encoding])
+        }
     }
 
     /// Returns a localized string intended for display in a notification alert.
@@ -307,12 +332,19 @@ impl NSString {
     ///
     /// An NSString object containing length characters from characters.
     #[method]
-    pub fn string_with_characters_length(characters: *const unichar, length: UInt) -> Self
+    pub fn string_with_characters_length<'pool>(
+        characters: *const unichar,
+        length: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![Self::m_class(), stringWithCharacters:characters length:length])
+            Autoreleased::new(
+                msg_send![Self::m_class(), stringWithCharacters:characters length:length],
+                pool,
+            )
         }
     }
 
@@ -326,11 +358,14 @@ impl NSString {
     ///
     /// An NSString object initialized by copying the characters from s.
     #[method]
-    pub fn string_with_string(s: NSString) -> Self
+    pub fn string_with_string<'pool>(
+        s: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + 'static + FromId,
     {
-        unsafe { msg_send![Self::m_class(), stringWithString: s] }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), stringWithString: s], pool) }
     }
 
     /// Returns a string containing the bytes in a given C array, interpreted
@@ -345,12 +380,19 @@ impl NSString {
     ///
     /// An NSString object containing the bytes in c_str, interpreted according to encoding.
     #[method]
-    pub fn string_with_cstring_encoding(c_str: *const c_char, encoding: Encoding) -> Self
+    pub fn string_with_cstring_encoding<'pool>(
+        c_str: *const c_char,
+        encoding: Encoding,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![Self::m_class(), stringWithCString:c_str encoding:encoding])
+            Autoreleased::new(
+                msg_send![Self::m_class(), stringWithCString:c_str encoding:encoding],
+                pool,
+            )
         }
     }
 
@@ -365,11 +407,19 @@ impl NSString {
     ///
     /// An NSString object containing the bytes in c_str, interpreted as a UTF8 string.
     #[method]
-    pub fn string_with_utf8_string(c_str: *const c_char) -> Self
+    pub fn string_with_utf8_string<'pool>(
+        c_str: *const c_char,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), stringWithUTF8String: c_str]) }
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), stringWithUTF8String: c_str],
+                pool,
+            )
+        }
     }
 
     /* Creating and Initializing a String from a File
@@ -377,100 +427,88 @@ impl NSString {
 
     /// Returns a string created by reading data from the file at a given path interpreted using a given encoding.
     #[method]
-    pub fn string_with_contents_of_file_encoding(
+    pub fn string_with_contents_of_file_encoding<'pool>(
         path: &NSString,
         enc: Encoding,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
+            Autoreleased::try_new(
                 msg_send![Self::m_class(), stringWithContentsOfFile: path.m_self() encoding: enc error: &mut error],
+                pool,
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /// Returns an [`NSString`] object initialized by reading data from the file at a given path using a given encoding.
     #[method]
     pub fn init_with_contents_of_file_encoding(
-        &mut self,
         path: &NSString,
         enc: Encoding,
     ) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithContentsOfFile: path.m_self() encoding: enc error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfFile: path.m_self() encoding: enc error: &mut error],
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /// Returns an [`NSString`] object initialized by reading data from the file at a given path using a given encoding.
     #[method]
-    pub fn string_with_contents_of_file_used_encoding(
+    pub fn string_with_contents_of_file_used_encoding<'pool>(
         path: &NSString,
         enc: Encoding,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
+            Autoreleased::try_new(
                 msg_send![Self::m_class(), stringWithContentsOfFile: path.m_self() usedEncoding: enc error: &mut error],
+                pool,
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /// Returns a string created by reading data from the file at a given path and returns by reference the encoding used to interpret the file.
     #[method]
     pub fn init_with_contents_of_file_used_encoding(
-        &mut self,
         path: &NSString,
         enc: Encoding,
     ) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithContentsOfFile: path.m_self() usedEncoding: enc error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfFile: path.m_self() usedEncoding: enc error: &mut error],
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /* Creating and Initializing a String from an URL
@@ -478,100 +516,85 @@ impl NSString {
 
     /// Returns a string created by reading data from a given URL interpreted using a given encoding.
     #[method]
-    pub fn string_with_contents_of_url_encoding(
+    pub fn string_with_contents_of_url_encoding<'pool>(
         path: &NSURL,
         enc: Encoding,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
+            Autoreleased::try_new(
                 msg_send![Self::m_class(), stringWithContentsOfURL: path.m_self() encoding: enc error: &mut error],
+                pool,
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /// Returns an [`NSString`] object initialized by reading data from a given URL interpreted using a given encoding.
     #[method]
-    pub fn init_with_contents_of_url_encoding(
-        &mut self,
-        path: &NSURL,
-        enc: Encoding,
-    ) -> Result<Self, NSError>
+    pub fn init_with_contents_of_url_encoding(path: &NSURL, enc: Encoding) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithContentsOfURL: path.m_self() encoding: enc error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfURL: path.m_self() encoding: enc error: &mut error],
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /// Returns a string created by reading data from a given URL and returns by reference the encoding used to interpret the data.
     #[method]
-    pub fn string_with_contents_of_url_used_encoding(
+    pub fn string_with_contents_of_url_used_encoding<'pool>(
         path: &NSURL,
         enc: Encoding,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
+            Autoreleased::try_new(
                 msg_send![Self::m_class(), stringWithContentsOfURL: path.m_self() usedEncoding: enc error: &mut error],
+                pool,
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /// Returns an [`NSString`] object initialized by reading data from a given URL and returns by reference the encoding used to interpret the data.
     #[method]
     pub fn init_with_contents_of_url_used_encoding(
-        &mut self,
         path: &NSURL,
         enc: Encoding,
     ) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let result = unsafe {
-            Self::from_id(
-                msg_send![self.m_self(), initWithContentsOfURL: path.m_self() usedEncoding: enc error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfURL: path.m_self() usedEncoding: enc error: &mut error],
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(result)
-        }
+        result.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /* Getting a String’s Length
@@ -955,11 +978,20 @@ impl NSString {
     ///
     /// * `string` - The string to append to the receiver. This value must not be nil.
     #[method]
-    pub fn string_by_appending_string<S>(&self, string: S) -> NSString
+    pub fn string_by_appending_string<'pool, S>(
+        &self,
+        string: S,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString>
     where
         S: INSString,
     {
-        unsafe { msg_send![self.m_self(), stringByAppendingString: string] }
+        unsafe {
+            Autoreleased::new(
+                msg_send![self.m_self(), stringByAppendingString: string],
+                pool,
+            )
+        }
     }
 
     /// Returns a new string formed from the receiver by either removing characters from the end, or by appending as many occurrences as necessary of a given pad string.
@@ -974,18 +1006,20 @@ impl NSString {
     ///
     /// A new string formed from the receiver by either removing characters from the end, or by appending as many occurrences of `pad_string` as necessary.
     #[method]
-    pub fn string_by_padding_to_length_with_string_starting_at_index<S>(
+    pub fn string_by_padding_to_length_with_string_starting_at_index<'pool, S>(
         &self,
         new_length: UInt,
         pad_string: S,
         starting_at: UInt,
-    ) -> NSString
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString>
     where
         S: INSString,
     {
         unsafe {
-            NSString::from_id(
+            Autoreleased::new(
                 msg_send![self.m_self(), stringByPaddingToLength: new_length withString: pad_string startingAtIndex: starting_at],
+                pool,
             )
         }
     }
@@ -995,15 +1029,21 @@ impl NSString {
 
     /// A lowercase representation of the string.
     #[property]
-    pub fn lowercase_string(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), lowercaseString]) }
+    pub fn lowercase_string<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), lowercaseString], pool) }
     }
 
     /// Returns a version of the string with all letters converted to lowercase,
     /// taking into account the current locale.
     #[property]
-    pub fn localized_lowercase_string(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), localizedLowercaseString]) }
+    pub fn localized_lowercase_string<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), localizedLowercaseString], pool) }
     }
 
     /// Returns a version of the string with all letters converted to
@@ -1017,21 +1057,36 @@ impl NSString {
     ///
     /// A new string with all letters converted to lowercase.
     #[method]
-    pub fn lowercase_string_with_locale(&self, locale: NSLocale) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), lowercaseStringWithLocale: locale]) }
+    pub fn lowercase_string_with_locale<'pool>(
+        &self,
+        locale: NSLocale,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe {
+            Autoreleased::new(
+                msg_send![self.m_self(), lowercaseStringWithLocale: locale],
+                pool,
+            )
+        }
     }
 
     /// An uppercase representation of the string.
     #[property]
-    pub fn uppercase_string(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), uppercaseString]) }
+    pub fn uppercase_string<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), uppercaseString], pool) }
     }
 
     /// Returns a version of the string with all letters converted to uppercase,
     /// taking into account the current locale.
     #[property]
-    pub fn localized_uppercase_string(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), localizedUppercaseString]) }
+    pub fn localized_uppercase_string<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), localizedUppercaseString], pool) }
     }
 
     /// Returns a version of the string with all letters converted to uppercase,
@@ -1045,32 +1100,54 @@ impl NSString {
     ///
     /// A new string with all letters converted to uppercase.
     #[method]
-    pub fn uppercase_string_with_locale(&self, locale: NSLocale) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), uppercaseStringWithLocale: locale]) }
+    pub fn uppercase_string_with_locale<'pool>(
+        &self,
+        locale: NSLocale,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe {
+            Autoreleased::new(
+                msg_send![self.m_self(), uppercaseStringWithLocale: locale],
+                pool,
+            )
+        }
     }
 
     /// A capitalized representation of the string.
     #[property]
-    pub fn capitalized_string(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), capitalizedString]) }
+    pub fn capitalized_string<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), capitalizedString], pool) }
     }
 
     /// Returns a capitalized representation of the receiver using the current
     /// locale.
     #[property]
-    pub fn localized_capitalized_string(&self) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), localizedCapitalizedString]) }
+    pub fn localized_capitalized_string<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), localizedCapitalizedString], pool) }
     }
 
     /// Returns a capitalized representation of the receiver using the
     /// specified locale.
     #[method]
-    pub fn capitalized_string_with_locale(&self, locale: NSLocale) -> NSString {
+    pub fn capitalized_string_with_locale<'pool>(
+        &self,
+        locale: NSLocale,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                capitalizedStringWithLocale: locale
-            ])
+            Autoreleased::new(
+                msg_send![
+                    self.m_self(),
+                    capitalizedStringWithLocale: locale
+                ],
+                pool,
+            )
         }
     }
 
@@ -1079,59 +1156,89 @@ impl NSString {
 
     /// Returns an array containing substrings from the receiver that have been divided by a given separator.
     #[method]
-    pub fn components_separated_by_string<S>(&self, separator: S) -> NSArray<NSString>
+    pub fn components_separated_by_string<'pool, S>(
+        &self,
+        separator: S,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSArray<NSString>>
     where
         S: INSString,
     {
         unsafe {
-            NSArray::from_id(msg_send![
-                self.m_self(),
-                componentsSeparatedByString: separator
-            ])
+            Autoreleased::new(
+                msg_send![
+                    self.m_self(),
+                    componentsSeparatedByString: separator
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns an array containing substrings from the receiver that have been divided by characters in a given set.
     #[method]
-    pub fn components_separated_by_characters_in_set(
+    pub fn components_separated_by_characters_in_set<'pool>(
         &self,
         separator: &NSCharacterSet,
-    ) -> NSArray<NSString> {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSArray<NSString>> {
         unsafe {
-            NSArray::from_id(msg_send![
-                self.m_self(),
-                componentsSeparatedByCharactersInSet: separator.m_self()
-            ])
+            Autoreleased::new(
+                msg_send![
+                    self.m_self(),
+                    componentsSeparatedByCharactersInSet: separator.m_self()
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a new string made by removing from both ends of the receiver characters contained in a given character set.
     #[method]
-    pub fn string_by_trimming_characters_in_set(&self, set: &NSCharacterSet) -> NSString {
+    pub fn string_by_trimming_characters_in_set<'pool>(
+        &self,
+        set: &NSCharacterSet,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                stringByTrimmingCharactersInSet: set.m_self()
-            ])
+            Autoreleased::new(
+                msg_send![
+                    self.m_self(),
+                    stringByTrimmingCharactersInSet: set.m_self()
+                ],
+                pool,
+            )
         }
     }
 
     /// Returns a new string containing the characters of the receiver from the one at a given index to the end.
     #[method]
-    pub fn substring_from_index(&self, from: UInt) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), substringFromIndex: from]) }
+    pub fn substring_from_index<'pool>(
+        &self,
+        from: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), substringFromIndex: from], pool) }
     }
 
     /// Returns a string object containing the characters of the receiver that lie within a given range.
     #[method]
-    pub fn substring_with_range(&self, range: NSRange) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), substringWithRange: range]) }
+    pub fn substring_with_range<'pool>(
+        &self,
+        range: NSRange,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), substringWithRange: range], pool) }
     }
 
     /// Returns a new string containing the characters of the receiver up to, but not including, the one at a given index.
     #[method]
-    pub fn substring_to_index(&self, to: UInt) -> NSString {
-        unsafe { NSString::from_id(msg_send![self.m_self(), substringToIndex: to]) }
+    pub fn substring_to_index<'pool>(
+        &self,
+        to: UInt,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), substringToIndex: to], pool) }
     }
 
     /* Normalizing Strings
@@ -1139,45 +1246,57 @@ impl NSString {
 
     /// A string made by normalizing the string’s contents using the Unicode Normalization Form D.
     #[property]
-    pub fn decomposed_string_with_canonical_mapping(&self) -> NSString {
+    pub fn decomposed_string_with_canonical_mapping<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                decomposedStringWithCanonicalMapping
-            ])
+            Autoreleased::new(
+                msg_send![self.m_self(), decomposedStringWithCanonicalMapping],
+                pool,
+            )
         }
     }
 
     /// A string made by normalizing the receiver’s contents using the Unicode Normalization Form KD.
     #[property]
-    pub fn decomposed_string_with_compatibility_mapping(&self) -> NSString {
+    pub fn decomposed_string_with_compatibility_mapping<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                decomposedStringWithCompatibilityMapping
-            ])
+            Autoreleased::new(
+                msg_send![self.m_self(), decomposedStringWithCompatibilityMapping],
+                pool,
+            )
         }
     }
 
     /// A string made by normalizing the string’s contents using the Unicode Normalization Form C.
     #[property]
-    pub fn precomposed_string_with_canonical_mapping(&self) -> NSString {
+    pub fn precomposed_string_with_canonical_mapping<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                precomposedStringWithCanonicalMapping
-            ])
+            Autoreleased::new(
+                msg_send![self.m_self(), precomposedStringWithCanonicalMapping],
+                pool,
+            )
         }
     }
 
     /// A string made by normalizing the receiver’s contents using the Unicode Normalization Form KC.
     #[property]
-    pub fn precomposed_string_with_compatibility_mapping(&self) -> NSString {
+    pub fn precomposed_string_with_compatibility_mapping<'pool>(
+        &self,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(msg_send![
-                self.m_self(),
-                precomposedStringWithCompatibilityMapping
-            ])
+            Autoreleased::new(
+                msg_send![self.m_self(), precomposedStringWithCompatibilityMapping],
+                pool,
+            )
         }
     }
 
@@ -1186,14 +1305,16 @@ impl NSString {
 
     /// Creates a string suitable for comparison by removing the specified character distinctions from a string.
     #[method]
-    pub fn string_by_folding_with_options_locale(
+    pub fn string_by_folding_with_options_locale<'pool>(
         &mut self,
         options: NSStringCompareOptions,
         locale: &NSLocale,
-    ) -> NSString {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSString> {
         unsafe {
-            NSString::from_id(
+            Autoreleased::new(
                 msg_send![self.m_self(), stringByFoldingWithOptions: options locale: locale.m_self() ],
+                pool,
             )
         }
     }
@@ -1263,8 +1384,12 @@ impl NSString {
 
     /// Returns an NSData object containing a representation of the receiver encoded using a given encoding.
     #[method]
-    pub fn data_using_encoding(&self, encoding: Encoding) -> NSData {
-        unsafe { NSData::from_id(msg_send![self.m_self(), dataUsingEncoding: encoding]) }
+    pub fn data_using_encoding<'pool>(
+        &self,
+        encoding: Encoding,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSData> {
+        unsafe { Autoreleased::new(msg_send![self.m_self(), dataUsingEncoding: encoding], pool) }
     }
 }
 
@@ -1278,6 +1403,8 @@ impl PNSObject for NSString {
     }
 }
 
+unsafe impl AnyThread for NSString {}
+
 unsafe impl objective_c_runtime::Encode for NSString {
     fn encode() -> objc::Encoding {
         unsafe { objective_c_runtime::Encoding::from_str("@") }
@@ -1286,31 +1413,33 @@ unsafe impl objective_c_runtime::Encode for NSString {
 
 impl Default for NSString {
     fn default() -> Self {
-        Self::string()
+        Self::init()
     }
 }
 
 impl fmt::Debug for NSString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.p_description().as_str().unwrap())
+        autoreleasepool(|pool| write!(f, "{}", self.p_description().as_str(pool).unwrap()))
     }
 }
 
 impl fmt::Display for NSString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.p_description().as_str().unwrap())
+        autoreleasepool(|pool| write!(f, "{}", self.p_description().as_str(pool).unwrap()))
     }
 }
 
 impl Clone for NSString {
     fn clone(&self) -> Self {
-        unsafe { msg_send![&*self.ptr, retain] }
+        unsafe { NSString::from_id(self.m_self()) }
     }
 }
 
 impl ToId for NSString {
     fn to_id(self) -> id {
-        unsafe { &mut *self.m_self() }
+        // Hand our reference to the pool instead of releasing it.
+        let this = ManuallyDrop::new(self);
+        unsafe { msg_send![this.m_self(), autorelease] }
     }
 }
 
@@ -1321,6 +1450,13 @@ impl FromId for NSString {
             marker: PhantomData,
         }
     }
+
+    unsafe fn from_retained_id(id: id) -> Self {
+        NSString {
+            ptr: Id::from_retained_ptr(id),
+            marker: PhantomData,
+        }
+    }
 }
 
 impl From<NSMutableString> for NSString {
@@ -1328,7 +1464,7 @@ impl From<NSMutableString> for NSString {
         unsafe {
             let ptr: id = msg_send![NSString::m_class(), alloc];
             let ptr = msg_send![ptr, initWithString: string];
-            NSString::from_id(ptr)
+            NSString::from_retained_id(ptr)
         }
     }
 }
@@ -1340,7 +1476,7 @@ impl From<String> for NSString {
         NSString {
             ptr: unsafe {
                 let nsstring: id = msg_send![class!(NSString), alloc];
-                Id::from_ptr(
+                Id::from_retained_ptr(
                     msg_send![nsstring, initWithBytes:c_string.into_raw() as *mut Object
                         length:s.len()
                         encoding:UTF8_ENCODING
@@ -1358,7 +1494,7 @@ impl From<&str> for NSString {
     fn from(s: &str) -> Self {
         let objc = unsafe {
             let nsstring: *mut Object = msg_send![class!(NSString), alloc];
-            Id::from_ptr(
+            Id::from_retained_ptr(
                 msg_send![nsstring, initWithBytes: CString::new(s).unwrap().into_raw() as *mut Object
                     length:s.len()
                     encoding:UTF8_ENCODING
@@ -1378,7 +1514,7 @@ impl From<&&str> for NSString {
     fn from(s: &&str) -> Self {
         let objc = unsafe {
             let nsstring: *mut Object = msg_send![class!(NSString), alloc];
-            Id::from_ptr(
+            Id::from_retained_ptr(
                 msg_send![nsstring, initWithBytes: CString::new(*s).unwrap().into_raw() as *mut Object
                     length:s.len()
                     encoding:UTF8_ENCODING
@@ -1398,7 +1534,7 @@ impl From<char> for NSString {
     fn from(c: char) -> Self {
         let objc = unsafe {
             let nsstring: *mut Object = msg_send![class!(NSString), alloc];
-            Id::from_ptr(
+            Id::from_retained_ptr(
                 msg_send![nsstring, initWithBytes: c.encode_utf8(&mut [0; 4]).as_ptr()
                     length:1
                     encoding:UTF8_ENCODING
@@ -1428,7 +1564,7 @@ impl From<(&str, Encoding)> for NSString {
     fn from((s, encoding): (&str, Encoding)) -> Self {
         let objc = unsafe {
             let nsstring: *mut Object = msg_send![class!(NSString), alloc];
-            Id::from_ptr(msg_send![nsstring, initWithBytes:s.as_ptr()
+            Id::from_retained_ptr(msg_send![nsstring, initWithBytes:s.as_ptr()
                 length:s.len()
                 encoding:encoding
             ])
@@ -1453,11 +1589,11 @@ impl Eq for NSString {}
 impl PartialEq<&str> for NSString {
     /// Checks if a `NSString` is equal to a `&str`.
     fn eq(&self, other: &&str) -> bool {
-        self.as_str().unwrap() == *other
+        autoreleasepool(|pool| self.as_str(pool).unwrap() == *other)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
     use crate::foundation::{
         string::Encoding, string_transform::LatinToKatakana, NSComparisonResult,
@@ -1487,7 +1623,7 @@ mod tests {
     #[test]
     fn test_as_str() {
         let s = NSString::from("Hello, World!");
-        assert_eq!(s, "Hello, World!");
+        autoreleasepool(|pool| assert_eq!(s.as_str(pool), Ok("Hello, World!")));
     }
 
     #[test]
@@ -1627,9 +1763,16 @@ mod tests {
     fn test_string_by_padding_to_length_with_string_starting_at_index() {
         let s = NSString::from("Hello, World!");
 
-        assert_eq!(
-            s.string_by_padding_to_length_with_string_starting_at_index(20, NSString::from("."), 0),
-            "Hello, World!......."
-        );
+        autoreleasepool(|pool| {
+            assert_eq!(
+                *s.string_by_padding_to_length_with_string_starting_at_index(
+                    20,
+                    NSString::from("."),
+                    0,
+                    pool
+                ),
+                "Hello, World!......."
+            );
+        });
     }
 }
@@ -16,6 +16,8 @@ unsafe impl Encode for NSTextCheckingTypes {
     }
 }
 
+crate::__impl_objc_value!(NSTextCheckingTypes);
+
 object! {
     unsafe pub struct NSTextCheckingResult;
 }
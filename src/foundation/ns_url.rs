@@ -1,12 +1,153 @@
+// Generated by rust-macios-generator. Edit the API description instead of this file.
+
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
+
 use crate::{
     object,
-    objective_c_runtime::{macros::interface_impl, traits::PNSObject},
+    objective_c_runtime::{
+        id,
+        macros::interface_impl,
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
+    utils::to_bool,
 };
 
+use super::{NSNumber, NSString};
+
+/// Options used when resolving bookmark data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u64)]
+pub enum NSURLBookmarkResolutionOptions {
+    /// Don't show any user interface while resolving the bookmark.
+    WithoutUI = 1 << 8,
+    /// Don't mount a volume while resolving the bookmark.
+    WithoutMounting = 1 << 9,
+    /// Resolve a security-scoped bookmark.
+    WithSecurityScope = 1 << 10,
+}
+
 object! {
     /// An object that represents the location of a resource, such as an item on a remote server or the path to a local file.
     unsafe pub struct NSURL;
 }
 
 #[interface_impl(NSObject)]
-impl NSURL {}
+impl NSURL {
+    /* Querying the URL */
+
+    /// The URL string for the receiver as an absolute URL.
+    #[property]
+    pub fn absolute_string(&self) -> Option<NSString> {
+        unsafe { NSString::try_from_id(msg_send![self.m_self(), absoluteString]) }
+    }
+
+    /// A Boolean value that determines whether the URL uses the file scheme.
+    #[property]
+    pub fn file_url(&self) -> bool {
+        unsafe { to_bool(msg_send![self.m_self(), isFileURL]) }
+    }
+
+    /* Accessing the Parts of the URL */
+
+    /// The last path component of the URL.
+    #[property]
+    pub fn last_path_component(&self) -> Option<NSString> {
+        unsafe { NSString::try_from_id(msg_send![self.m_self(), lastPathComponent]) }
+    }
+
+    /// The port number of the URL.
+    #[property]
+    pub fn port(&self) -> Option<NSNumber> {
+        unsafe { NSNumber::try_from_id(msg_send![self.m_self(), port]) }
+    }
+
+    /* Creating a URL */
+
+    /// Creates and returns an URL object initialized with a provided URL string.
+    ///
+    /// Returns `None` if the string doesn't represent a valid URL.
+    #[method]
+    pub fn url_with_string<'pool>(
+        url_string: &NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Option<Autoreleased<'pool, Self>>
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            Autoreleased::try_new(
+                msg_send![Self::m_class(), URLWithString: url_string.m_self()],
+                pool,
+            )
+        }
+    }
+
+    /// Initializes and returns a newly created URL object as a file URL with a specified path.
+    #[method]
+    pub fn file_url_with_path_is_directory<'pool>(
+        path: &NSString,
+        is_dir: bool,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSURL> {
+        unsafe {
+            Autoreleased::new(
+                msg_send![Self::m_class(), fileURLWithPath: path.m_self() isDirectory: is_dir],
+                pool,
+            )
+        }
+    }
+
+    /// Initializes an URL object with a base URL and a relative string.
+    #[method]
+    pub fn init_with_string_relative_to_url(url_string: &NSString, base_url: &NSURL) -> Option<Self>
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::try_from_retained_id(msg_send![
+                obj,
+                initWithString: url_string.m_self()
+                relativeToURL: base_url.m_self()
+            ])
+        }
+    }
+
+    /* Modifying and Converting a URL */
+
+    /// Returns a new URL made by appending a path component to the original URL.
+    #[method]
+    pub fn url_by_appending_path_component(&self, path_component: &NSString) -> Option<NSURL> {
+        unsafe {
+            NSURL::try_from_id(msg_send![
+                self.m_self(),
+                URLByAppendingPathComponent: path_component.m_self()
+            ])
+        }
+    }
+
+    /* Working with Bookmark Data */
+
+    /// In an app that has adopted App Sandbox, makes the resource pointed to by a security-scoped URL available to the app.
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[method]
+    pub fn start_accessing_security_scoped_resource(&self) -> bool {
+        unsafe {
+            to_bool(msg_send![
+                self.m_self(),
+                startAccessingSecurityScopedResource
+            ])
+        }
+    }
+
+    /// In an app that adopts App Sandbox, revokes access to the resource pointed to by a security-scoped URL.
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    #[method]
+    pub fn stop_accessing_security_scoped_resource(&self) {
+        unsafe {
+            let _: () = msg_send![self.m_self(), stopAccessingSecurityScopedResource];
+        }
+    }
+}
@@ -3,9 +3,11 @@ use std::ffi::c_uchar;
 use crate::{
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         msg_send, sel, sel_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -23,29 +25,35 @@ impl NSUUID {
 
     /// Create and returns a new UUID with RFC 4122 version 4 random bytes.
     #[method]
-    pub fn uuid() -> Self
+    pub fn uuid<'pool>(pool: &'pool AutoreleasePool) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), UUID]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), UUID], pool) }
     }
 
     /// Initializes a new UUID with RFC 4122 version 4 random bytes.
     #[method]
-    pub fn init(&mut self) -> Self
+    pub fn init() -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), init]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, init])
+        }
     }
 
     /// Initializes a new UUID with the formatted string.
     #[method]
-    pub fn init_with_uuid_string(&mut self, string: NSString) -> Self
+    pub fn init_with_uuid_string(string: NSString) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUUIDString: string]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUUIDString: string])
+        }
     }
 
     /// Initializes a new UUID with the given bytes.
@@ -58,11 +66,14 @@ impl NSUUID {
     ///
     /// A new UUID object.
     #[method]
-    pub fn init_with_uuid_bytes(&mut self, bytes: *const c_uchar) -> Self
+    pub fn init_with_uuid_bytes(bytes: *const c_uchar) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUUIDBytes: bytes]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUUIDBytes: bytes])
+        }
     }
 
     /* Get UUID Values
@@ -1,6 +1,6 @@
 use super::NSStringTransform;
 
-#[link(name = "Foundation", kind = "framework")]
+#[cfg_attr(target_vendor = "apple", link(name = "Foundation", kind = "framework"))]
 extern "C" {
     /* Transliteration
      */
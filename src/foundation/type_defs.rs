@@ -3,12 +3,13 @@
 
 use libc::c_double;
 
-use crate::{
-    core_graphics::{CGPoint, CGRect, CGSize},
-    objective_c_runtime::id,
-};
+use crate::core_graphics::{CGPoint, CGRect, CGSize};
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+use crate::objective_c_runtime::id;
 
-use super::{NSComparisonResult, NSDecimalNumber, NSRange, NSString};
+use super::NSRange;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+use super::{NSComparisonResult, NSDecimalNumber, NSString};
 
 /// Describes an integer.
 #[cfg(target_pointer_width = "32")]
@@ -54,6 +55,7 @@ pub type UInt64 = libc::c_ulonglong;
 pub type Double = c_double;
 
 /// Type alias for `NSDecimalNumber`.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSDecimal = NSDecimalNumber;
 
 /// A rectangle.
@@ -66,8 +68,10 @@ pub type NSSize = CGSize;
 */
 
 /// Constants representing an ICU string transform.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSStringTransform = *const NSString;
 /// The keys used to access components of a locale.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSLocaleKey = *mut NSString;
 
 /// These constants specify mutability options in property lists.
@@ -86,25 +90,40 @@ pub type NSTimeInterval = c_double;
 pub type NSPoint = CGPoint;
 
 /// A structure that defines the name of a notification.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSNotificationName = NSString;
 
-///
+/// A string that identifies an error domain.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSErrorDomain = NSString;
 
 /// These keys may exist in the user info dictionary.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSErrorUserInfoKey = NSString;
 
+/// A string that identifies the kind of an exception.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub type NSExceptionName = NSString;
+
+/// The keys that can appear in the change dictionary of a key-value observer.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub type NSKeyValueChangeKey = NSString;
+
 /// Attributes that you can apply to text in an attributed string.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSAttributedStringKey = NSString;
 
 /// Type indicating a parameter is a pointer to an NSRange structure.
 pub type NSRangePointer = *mut NSRange;
 
 /// Options for importing documents.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSAttributedStringDocumentReadingOptionKey = NSString;
 
 /// Attributes that apply to a document.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSAttributedStringDocumentAttributeKey = NSString;
 
 /// Defines the signature for a block object used for comparison operations.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub type NSComparator = fn(a: id, b: id) -> NSComparisonResult;
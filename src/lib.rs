@@ -1,21 +1,50 @@
 //! # A library to use Apple frameworks in Rust
+//!
+//! The value types and codecs (for example [`core_graphics::CGRect`],
+//! [`foundation::NSRange`], the [`compression`] enums and the [`kernel`] types)
+//! build on every target. The wrappers that call into the Objective-C runtime
+//! or an Apple framework are only available on Apple targets.
+//!
+//! Enabling the `gnustep` feature links [`objective_c_runtime`] against
+//! GNUstep's libobjc2 and [`foundation`] against gnustep-base instead, which
+//! makes both modules usable on targets such as Linux.
+//!
+//! Enabling the `test-runtime` feature on a target other than Apple's replaces
+//! the runtime with the fake in [`test_runtime`], which records every message
+//! and answers it with canned responses. Every wrapper is available with it, so
+//! that they can be unit-tested without macOS.
 #![warn(clippy::all)]
 #![allow(improper_ctypes, deprecated, improper_ctypes_definitions)]
 
+// Lets the proc-macros, which name the crate `rust_macios`, be used inside it.
+extern crate self as rust_macios;
+
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod appkit;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod background_tasks;
 pub mod compression;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod contacts;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod core_foundation;
 pub mod core_graphics;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod core_location;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod core_ml;
 pub mod foundation;
 pub mod kernel;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod natural_language;
 pub mod objective_c_runtime;
+#[cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+pub mod test_runtime;
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod uikit;
 
+#[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
 pub mod user_notifications;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub(crate) mod utils;
@@ -1,14 +1,17 @@
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock};
 use libc::{c_double, c_float};
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSDictionary, NSError, NSIndexSet, NSNumber, NSString, UInt, NSURL},
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::{to_bool, to_optional},
 };
@@ -62,23 +65,23 @@ impl NLEmbedding {
 
     /// Creates a word embedding from a model file.
     #[method]
-    pub fn embedding_with_contents_of_url(url: &NSURL) -> Result<Self, NSError>
+    pub fn embedding_with_contents_of_url<'pool>(
+        url: &NSURL,
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let ptr = unsafe {
-            Self::from_id(
+            Autoreleased::try_new(
                 msg_send![Self::m_class(), embeddingWithContentsOfURL:url.m_self() error: &mut error],
+                pool,
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
-        } else {
-            Ok(ptr)
-        }
+        ptr.ok_or_else(|| unsafe { NSError::from_out_param(error) })
     }
 
     /* Creating a sentence embedding
@@ -325,7 +328,7 @@ impl NLEmbedding {
         revision: UInt,
         url: &NSURL,
     ) -> Result<bool, NSError> {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         let ptr = unsafe {
             to_bool(
@@ -333,8 +336,8 @@ impl NLEmbedding {
             )
         };
 
-        if error.m_self() != nil {
-            Err(error)
+        if !error.is_null() {
+            Err(unsafe { NSError::from_out_param(error) })
         } else {
             Ok(ptr)
         }
@@ -351,12 +354,18 @@ impl NLEmbedding {
 
     /// Retrieves all version numbers of a word embedding for the given language.
     #[method]
-    pub fn supported_revisions_for_language(language: NLLanguage) -> NSIndexSet {
+    pub fn supported_revisions_for_language<'pool>(
+        language: NLLanguage,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSIndexSet> {
         unsafe {
-            NSIndexSet::from_id(msg_send![
-                Self::m_class(),
-                supportedRevisionsForLanguage: language
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    supportedRevisionsForLanguage: language
+                ],
+                pool,
+            )
         }
     }
 
@@ -373,12 +382,18 @@ impl NLEmbedding {
 
     /// Retrieves all version numbers of a sentence embedding for the given language.
     #[method]
-    pub fn supported_sentence_embedding_revisions_for_language(language: NLLanguage) -> NSIndexSet {
+    pub fn supported_sentence_embedding_revisions_for_language<'pool>(
+        language: NLLanguage,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSIndexSet> {
         unsafe {
-            NSIndexSet::from_id(msg_send![
-                Self::m_class(),
-                supportedSentenceEmbeddingRevisionsForLanguage: language
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    supportedSentenceEmbeddingRevisionsForLanguage: language
+                ],
+                pool,
+            )
         }
     }
 }
@@ -1,9 +1,11 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSData, NSDictionary, NSError, NSString, NSURL},
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         nil,
         traits::{FromId, PNSObject},
@@ -30,45 +32,38 @@ impl NLGazetteer {
         Self: Sized + FromId,
     {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let mut error: id = nil;
 
-            let ptr = Self::from_id(
-                msg_send![Self::m_class(), initWithContentsOfURL:url.m_self() error: &mut error],
+            let ptr = Self::try_from_retained_id(
+                msg_send![obj, initWithContentsOfURL:url.m_self() error: &mut error],
             );
 
-            if error.m_self() != nil {
-                Err(error)
-            } else {
-                Ok(ptr)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
     /// Creates a gazetteer from a data instance.
     #[method]
-    pub fn init_with_data(&mut self, data: &NSData) -> Result<Self, NSError>
+    pub fn init_with_data(data: &NSData) -> Result<Self, NSError>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let mut error: id = nil;
 
-            let ptr = Self::from_id(
-                msg_send![self.m_self(), initWithData: data.m_self() error: &mut error],
+            let ptr = Self::try_from_retained_id(
+                msg_send![obj, initWithData: data.m_self() error: &mut error],
             );
 
-            if error.m_self() != nil {
-                Err(error)
-            } else {
-                Ok(ptr)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
     /// Creates a gazetteer from a set of labels for terms represented by a dictionary.
     #[method]
     pub fn init_with_dictionary_language(
-        &mut self,
         dictionary: &NSDictionary<NSString, NSArray<NSString>>,
         language: Option<NLLanguage>,
     ) -> Result<Self, NSError>
@@ -80,18 +75,15 @@ impl NLGazetteer {
             None => nil,
         };
 
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
-            let ptr = Self::from_id(
-                msg_send![self.m_self(),  initWithDictionary:dictionary.m_self() language: language error: &mut error],
+            let obj: id = msg_send![Self::m_class(), alloc];
+            let ptr = Self::try_from_retained_id(
+                msg_send![obj,  initWithDictionary:dictionary.m_self() language: language error: &mut error],
             );
 
-            if error.m_self() != nil {
-                Err(error)
-            } else {
-                Ok(ptr)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -102,15 +94,15 @@ impl NLGazetteer {
         language: Option<NLLanguage>,
         url: &NSURL,
     ) -> Result<bool, NSError> {
-        let mut error = NSError::m_alloc();
+        let mut error: id = nil;
 
         unsafe {
             let ptr = to_bool(
                 msg_send![Self::m_class(),  writeGazetteerForDictionary:dictionary.m_self() language: language toURL: url.m_self() error: &mut error],
             );
 
-            if error.m_self() != nil {
-                Err(error)
+            if !error.is_null() {
+                Err(NSError::from_out_param(error))
             } else {
                 Ok(ptr)
             }
@@ -1,11 +1,13 @@
 //! The language of a body of text.
 
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSDictionary, NSNumber, NSString, UInt},
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
     },
@@ -26,11 +28,14 @@ impl NLLanguageRecognizer {
 
     /// Creates a recognizer that you can customize.
     #[method]
-    pub fn init(&mut self) -> Self
+    pub fn init() -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), init]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, init])
+        }
     }
 
     /* Determining the Language
@@ -113,7 +118,7 @@ impl Default for NLLanguageRecognizer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
     use crate::{natural_language::English, nsarray, nsdictionary};
 
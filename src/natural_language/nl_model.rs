@@ -1,13 +1,16 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     core_ml::MLModel,
     foundation::{NSArray, NSDictionary, NSError, NSNumber, NSString, UInt, NSURL},
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_optional,
 };
@@ -26,43 +29,43 @@ impl NLModel {
 
     /// Creates a new natural language model based on the given Core ML model instance.
     #[method]
-    pub fn model_with_mlmodel(model: &MLModel) -> Result<Self, NSError>
+    pub fn model_with_mlmodel<'pool>(
+        model: &MLModel,
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
-            let ptr = Self::from_id(
+            let ptr = Autoreleased::try_new(
                 msg_send![Self::m_class(), modelWithMLModel: model.m_self() error: &mut error],
+                pool,
             );
 
-            if error.m_self() != nil {
-                Err(error)
-            } else {
-                Ok(ptr)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
     /// Creates a new natural language model based on the given Core ML model instance.
     #[method]
-    pub fn model_with_contents_of_url(url: &NSURL) -> Result<Self, NSError>
+    pub fn model_with_contents_of_url<'pool>(
+        url: &NSURL,
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
-            let ptr = Self::from_id(
+            let ptr = Autoreleased::try_new(
                 msg_send![Self::m_class(), modelWithContentsOfURL: url.m_self() error: &mut error],
+                pool,
             );
 
-            if error.m_self() != nil {
-                Err(error)
-            } else {
-                Ok(ptr)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -1,11 +1,11 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSIndexSet, UInt},
     object,
     objective_c_runtime::{
-        macros::interface_impl,
-        traits::{FromId, PNSObject},
+        macros::interface_impl, traits::PNSObject, AutoreleasePool, Autoreleased,
     },
     utils::to_optional,
 };
@@ -46,12 +46,18 @@ impl NLModelConfiguration {
 
     /// Returns the versions of the Natural Language framework the OS supports.
     #[method]
-    pub fn supported_revisions_for_type(r#type: NLModelType) -> NSIndexSet {
+    pub fn supported_revisions_for_type<'pool>(
+        r#type: NLModelType,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSIndexSet> {
         unsafe {
-            NSIndexSet::from_id(msg_send![
-                Self::m_class(),
-                supportedRevisionsForType: r#type
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    supportedRevisionsForType: r#type
+                ],
+                pool,
+            )
         }
     }
 
@@ -1,5 +1,6 @@
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock};
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{
@@ -12,9 +13,9 @@ use crate::{
         macros::interface_impl,
         nil,
         traits::{FromId, PNSObject},
-        NSValue,
+        AutoreleasePool, Autoreleased, BlockFuture, CompletionError, NSValue,
     },
-    utils::to_optional,
+    utils::{to_optional, to_result},
 };
 
 use super::{NLGazetteer, NLLanguage, NLModel};
@@ -264,11 +265,14 @@ impl NLTagger {
 
     /// Creates a linguistic tagger instance using the specified tag schemes and options.
     #[method]
-    pub fn init_with_tag_schemes(&mut self, tag_schemes: NSArray<NLTagScheme>) -> Self
+    pub fn init_with_tag_schemes(tag_schemes: NSArray<NLTagScheme>) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithTagSchemes: tag_schemes]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithTagSchemes: tag_schemes])
+        }
     }
 
     /// The string being analyzed by the linguistic tagger.
@@ -290,13 +294,15 @@ impl NLTagger {
 
     /// Retrieves the tag schemes available for a particular unit (like word or sentence) and language on the current device.
     #[method]
-    pub fn available_tag_schemes_for_unit_language(
+    pub fn available_tag_schemes_for_unit_language<'pool>(
         unit: NLTokenUnit,
         language: &NLLanguage,
-    ) -> NSArray<NLTagScheme> {
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, NSArray<NLTagScheme>> {
         unsafe {
-            NSArray::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), availableTagSchemesForUnit: unit language: language.m_self()],
+                pool,
             )
         }
     }
@@ -516,7 +522,34 @@ impl NLTagger {
     }
 }
 
-#[cfg(test)]
+impl NLTagger {
+    /// Asks the Natural Language framework to load any missing assets for a tag scheme onto
+    /// the device for the given language.
+    pub async fn request_assets_for_language_tag_scheme(
+        language: &NLLanguage,
+        tag_scheme: &NLTagScheme,
+    ) -> Result<NLTaggerAssetsResult, CompletionError> {
+        BlockFuture::send(
+            |completion| {
+                move |result: NLTaggerAssetsResult, error: id| {
+                    completion.complete(to_result(result, error))
+                }
+            },
+            |block| unsafe {
+                msg_send![
+                    Self::m_class(),
+                    requestAssetsForLanguage: language.m_self()
+                    tagScheme: tag_scheme.m_self()
+                    completionHandler: block
+                ]
+            },
+        )
+        .await?
+        .map_err(CompletionError::Failed)
+    }
+}
+
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
     use std::ptr::addr_of_mut;
 
@@ -524,7 +557,6 @@ mod tests {
         foundation::{ns_array::INSArray, INSDictionary, NSNumber, NSRange, UInt},
         natural_language::{nl_language, NLTokenUnit},
         nsarray,
-        objective_c_runtime::traits::PNSObject,
     };
 
     use super::{nl_tag_scheme, NLTagger};
@@ -536,7 +568,7 @@ mod tests {
     fn test_get_tag() {
         unsafe {
             let mut tagger =
-                NLTagger::m_new().init_with_tag_schemes(nsarray![nl_tag_scheme::Lemma.clone()]);
+                NLTagger::init_with_tag_schemes(nsarray![nl_tag_scheme::Lemma.clone()]);
 
             tagger.set_string(Some(TEXT.into()));
 
@@ -562,8 +594,8 @@ mod tests {
     #[test]
     fn test_get_tag_hypotheses() {
         unsafe {
-            let mut tagger = NLTagger::m_new()
-                .init_with_tag_schemes(nsarray![nl_tag_scheme::LexicalClass.clone()]);
+            let mut tagger =
+                NLTagger::init_with_tag_schemes(nsarray![nl_tag_scheme::LexicalClass.clone()]);
 
             tagger.set_string(Some(TEXT.into()));
 
@@ -584,8 +616,8 @@ mod tests {
     #[test]
     fn test_get_tag_hypotheses_range() {
         unsafe {
-            let mut tagger = NLTagger::m_new()
-                .init_with_tag_schemes(nsarray![nl_tag_scheme::LexicalClass.clone()]);
+            let mut tagger =
+                NLTagger::init_with_tag_schemes(nsarray![nl_tag_scheme::LexicalClass.clone()]);
 
             tagger.set_string(Some(TEXT.into()));
 
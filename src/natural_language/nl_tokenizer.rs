@@ -1,10 +1,12 @@
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock};
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::{NSArray, NSRange, NSString, UInt},
     object,
     objective_c_runtime::{
+        id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
         INSValue,
@@ -39,11 +41,14 @@ impl NLTokenizer {
 
     /// Creates a tokenizer with the specified unit.
     #[method]
-    pub fn init_with_unit(&mut self, unit: NLTokenUnit) -> Self
+    pub fn init_with_unit(unit: NLTokenUnit) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![self.m_self(), initWithUnit: unit]) }
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithUnit: unit])
+        }
     }
 
     /* Configuring a Tokenizer
@@ -121,7 +126,7 @@ impl Default for NLTokenizer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "test-runtime")))]
 mod tests {
 
     use crate::natural_language::English;
@@ -130,16 +135,14 @@ mod tests {
 
     #[test]
     fn test_init() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         assert_ne!(tokenizer.unit(), super::NLTokenUnit::Sentence);
         assert_eq!(tokenizer.unit(), super::NLTokenUnit::Word);
     }
 
     #[test]
     fn test_string() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         tokenizer.set_string("Hello, world!".into());
         assert_ne!(tokenizer.string(), "Goodbye, world!");
         assert_eq!(tokenizer.string(), "Hello, world!");
@@ -147,8 +150,7 @@ mod tests {
 
     #[test]
     fn test_set_string() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         tokenizer.set_string("Hello, world!".into());
         assert_ne!(tokenizer.string(), "Goodbye, world!");
         assert_eq!(tokenizer.string(), "Hello, world!");
@@ -156,31 +158,27 @@ mod tests {
 
     #[test]
     fn test_set_language() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         tokenizer.set_language(unsafe { English.clone() });
     }
 
     #[test]
     fn test_unit() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         assert_ne!(tokenizer.unit(), NLTokenUnit::Sentence);
         assert_eq!(tokenizer.unit(), NLTokenUnit::Word);
     }
 
     #[test]
     fn test_token_range_at_index() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         tokenizer.set_string("Hello, world!".into());
         assert_eq!(tokenizer.token_range_at_index(0), (0..5).into());
     }
 
     #[test]
     fn test_token_range_for_range() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         tokenizer.set_string("Hello, world!".into());
         assert_eq!(
             tokenizer.token_range_for_range((0..5).into()),
@@ -190,8 +188,7 @@ mod tests {
 
     #[test]
     fn test_enumerate_tokens_in_range() {
-        let mut tokenizer = NLTokenizer::default();
-        tokenizer = tokenizer.init_with_unit(NLTokenUnit::Word);
+        let mut tokenizer = NLTokenizer::init_with_unit(NLTokenUnit::Word);
         let text = "Hello";
         tokenizer.set_string(text.into());
         tokenizer.enumerate_tokens_in_range_using_block((0..text.len()).into(), |_, attr, _| {
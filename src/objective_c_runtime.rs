@@ -1,19 +1,74 @@
 //! Gain low-level access to the Objective-C runtime and the Objective-C root types.
 
 /// Traits for the Objective-C runtime.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub mod traits;
 
 /// Macros for working with the Objective-C runtime
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub mod macros;
 
+pub mod encoding;
+
+mod block_future;
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod associated_objects;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod autoreleasepool;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod availability;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod class_registry;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod exception;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod forwarding;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod globals;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod introspection;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod ns_value;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod objc_value;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod ownership;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod reexports;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod swizzle;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 mod type_defs;
 
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use associated_objects::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use autoreleasepool::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use availability::*;
+pub use block_future::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use class_registry::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use exception::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use forwarding::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use globals::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use introspection::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use ns_value::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use objc_value::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use ownership::{Allocated, Autoreleased, Weak};
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use reexports::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+pub use swizzle::*;
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
 pub use type_defs::*;
 
 pub use rust_macios_objective_c_runtime_proc_macros::*;
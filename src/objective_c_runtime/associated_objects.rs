@@ -0,0 +1,225 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    ffi::c_void,
+    fmt,
+    marker::PhantomData,
+    ops::Deref,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::objective_c_runtime::msg_send;
+use lazy_static::lazy_static;
+use objc::{
+    class,
+    runtime::{Object, Sel},
+    sel, sel_impl,
+};
+
+use super::{id, nil, register_class_for_type, traits::PNSObject};
+
+extern "C" {
+    fn objc_setAssociatedObject(object: id, key: *const c_void, value: id, policy: usize);
+    fn objc_getAssociatedObject(object: id, key: *const c_void) -> id;
+}
+
+/// The ivar of the box class that holds the value.
+static ASSOCIATED_VALUE_PTR: &str = "rstAssociatedValuePtr";
+
+/// An associated value, which the object may free or hand out on any thread.
+type Value = Box<dyn Any + Send + Sync>;
+
+lazy_static! {
+    static ref KEYS: Mutex<HashMap<TypeId, usize>> = Mutex::new(HashMap::new());
+}
+
+/// How an object holds on to a value associated with it.
+///
+/// `OBJC_ASSOCIATION_ASSIGN` is not offered, as the object has to own a Rust value to free
+/// it when it deallocates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(usize)]
+pub enum AssociationPolicy {
+    /// `OBJC_ASSOCIATION_RETAIN_NONATOMIC`: the value is retained without a lock.
+    #[default]
+    RetainNonatomic = 1,
+    /// `OBJC_ASSOCIATION_COPY_NONATOMIC`: the value is copied without a lock.
+    CopyNonatomic = 3,
+    /// `OBJC_ASSOCIATION_RETAIN`: the value is retained, and reads and writes are atomic.
+    Retain = 0o1401,
+    /// `OBJC_ASSOCIATION_COPY`: the value is copied, and reads and writes are atomic.
+    Copy = 0o1403,
+}
+
+/// Returns a key unique to `T`, as the runtime compares keys by address.
+fn key<T>() -> *const c_void
+where
+    T: Any,
+{
+    let mut keys = KEYS.lock().unwrap_or_else(PoisonError::into_inner);
+
+    *keys
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::leak(Box::new(0u8)) as *const u8 as usize) as *const c_void
+}
+
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        let value_ptr: usize = *this.get_ivar(ASSOCIATED_VALUE_PTR);
+        drop(Box::from_raw(value_ptr as *mut Value));
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+// The box is immutable, so a copy can share it.
+extern "C" fn copy_with_zone(this: &Object, _: Sel, _zone: *mut c_void) -> id {
+    unsafe { msg_send![this, retain] }
+}
+
+/// Creates an Objective-C object that owns `value` and frees it when deallocated.
+fn new_box(value: Value) -> id {
+    let class = register_class_for_type::<Value, _>(
+        "RSTAssociatedValue",
+        class!(NSObject),
+        |decl| unsafe {
+            decl.add_ivar::<usize>(ASSOCIATED_VALUE_PTR);
+
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, Sel));
+            decl.add_method(
+                sel!(copyWithZone:),
+                copy_with_zone as extern "C" fn(&Object, Sel, *mut c_void) -> id,
+            );
+        },
+    )
+    .unwrap_or_else(|error| panic!("{error}"));
+
+    unsafe {
+        let object: id = msg_send![class, new];
+        (*object).set_ivar::<usize>(
+            ASSOCIATED_VALUE_PTR,
+            Box::into_raw(Box::new(value)) as usize,
+        );
+        object
+    }
+}
+
+/// A value associated with an object, as returned by [`AssociatedObjects::associated`].
+///
+/// It keeps the value alive even if the object replaces or drops it.
+pub struct Associated<T> {
+    object: id,
+    value: *const T,
+    marker: PhantomData<T>,
+}
+
+impl<T> Deref for Associated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.value }
+    }
+}
+
+impl<T> Drop for Associated<T> {
+    fn drop(&mut self) {
+        unsafe { msg_send![self.object, release] }
+    }
+}
+
+impl<T> fmt::Debug for Associated<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Attaches Rust values to an Objective-C object, one value per type.
+///
+/// The values are freed when the object deallocates, on whichever thread releases it last,
+/// and any thread holding the object can read them. They must therefore be `Send` and
+/// `Sync`, and are only handed out by shared reference, so use an atomic or a `Mutex` for
+/// state that changes.
+///
+/// ```no_run
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// use rust_macios::{
+///     foundation::{NSMutableArray, NSString},
+///     objective_c_runtime::{traits::PNSObject, AssociatedObjects},
+/// };
+///
+/// struct ClickCount(AtomicU32);
+///
+/// let array = NSMutableArray::<NSString>::m_new();
+/// array.set_associated(ClickCount(AtomicU32::new(0)));
+///
+/// let count = array.associated::<ClickCount>().unwrap();
+/// count.0.fetch_add(1, Ordering::Relaxed);
+/// ```
+pub trait AssociatedObjects: PNSObject {
+    /// Associates `value` with the object, replacing any value of the same type.
+    fn set_associated<T>(&self, value: T)
+    where
+        T: Any + Send + Sync,
+    {
+        self.set_associated_with_policy(value, AssociationPolicy::default());
+    }
+
+    /// Associates `value` with the object using `policy`, replacing any value of the same
+    /// type.
+    fn set_associated_with_policy<T>(&self, value: T, policy: AssociationPolicy)
+    where
+        T: Any + Send + Sync,
+    {
+        let value = new_box(Box::new(value));
+
+        unsafe {
+            objc_setAssociatedObject(self.m_self(), key::<T>(), value, policy as usize);
+            let _: () = msg_send![value, release];
+        }
+    }
+
+    /// Returns the value of type `T` associated with the object, if any.
+    fn associated<T>(&self) -> Option<Associated<T>>
+    where
+        T: Any,
+    {
+        unsafe {
+            let object = objc_getAssociatedObject(self.m_self(), key::<T>());
+
+            if object == nil {
+                return None;
+            }
+
+            let value_ptr: usize = *(*object).get_ivar(ASSOCIATED_VALUE_PTR);
+            let value = (*(value_ptr as *const Value)).downcast_ref::<T>()?;
+
+            Some(Associated {
+                object: msg_send![object, retain],
+                value,
+                marker: PhantomData,
+            })
+        }
+    }
+
+    /// Removes the value of type `T` associated with the object, freeing it once no
+    /// [`Associated`] refers to it.
+    fn remove_associated<T>(&self)
+    where
+        T: Any,
+    {
+        unsafe {
+            objc_setAssociatedObject(
+                self.m_self(),
+                key::<T>(),
+                nil,
+                AssociationPolicy::default() as usize,
+            );
+        }
+    }
+}
+
+impl<O> AssociatedObjects for O where O: PNSObject {}
@@ -0,0 +1,96 @@
+use std::{cell::Cell, ffi::c_void, marker::PhantomData};
+
+extern "C" {
+    fn objc_autoreleasePoolPush() -> *mut c_void;
+    fn objc_autoreleasePoolPop(context: *mut c_void);
+}
+
+thread_local! {
+    /// The number of pools pushed by [`autoreleasepool`] on this thread.
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// An autorelease pool pushed by [`autoreleasepool`].
+///
+/// Autoreleased values borrow the pool, so the compiler rejects any use of them after the
+/// pool drains. The pool belongs to the thread that pushed it.
+#[derive(Debug)]
+pub struct AutoreleasePool {
+    context: *mut c_void,
+    depth: usize,
+    // Pools are per thread.
+    marker: PhantomData<*mut c_void>,
+}
+
+impl AutoreleasePool {
+    unsafe fn push() -> Self {
+        let depth = DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+
+        Self {
+            context: objc_autoreleasePoolPush(),
+            depth,
+            marker: PhantomData,
+        }
+    }
+
+    /// Whether objects autoreleased now are added to this pool, rather than to one nested
+    /// inside it.
+    pub fn is_innermost(&self) -> bool {
+        DEPTH.with(|depth| depth.get() == self.depth)
+    }
+
+    /// Checks that objects autoreleased now end up in this pool, as handing them the
+    /// lifetime of an outer pool would let them outlive the inner one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pool is nested inside this one.
+    pub(crate) fn assert_innermost(&self) {
+        assert!(
+            self.is_innermost(),
+            "objects can only be autoreleased into the innermost pool"
+        );
+    }
+}
+
+impl Drop for AutoreleasePool {
+    fn drop(&mut self) {
+        // `autoreleasepool` scopes pools to a closure, so they always drain innermost first.
+        debug_assert!(self.is_innermost());
+
+        unsafe { objc_autoreleasePoolPop(self.context) };
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Runs `f` inside a new autorelease pool, draining the pool when `f` returns.
+///
+/// Objects autoreleased in `f` are released when the pool drains rather than when an
+/// outer pool does, which keeps loops that create many temporary objects from growing
+/// without bound. Values tied to `pool`, such as [`Autoreleased`](super::Autoreleased)
+/// references, cannot be returned from `f`. Pools may be nested, and only the innermost
+/// pool can be handed to functions that autorelease objects into it: they panic when given
+/// an outer one.
+///
+/// ```no_run
+/// use rust_macios::{foundation::NSString, objective_c_runtime::autoreleasepool};
+///
+/// let mut total = 0;
+///
+/// for _ in 0..1_000_000 {
+///     autoreleasepool(|pool| {
+///         let string = NSString::from("Hello, World!");
+///         total += string.as_str(pool).unwrap().len();
+///     });
+/// }
+/// ```
+pub fn autoreleasepool<T, F>(f: F) -> T
+where
+    F: for<'pool> FnOnce(&'pool AutoreleasePool) -> T,
+{
+    let pool = unsafe { AutoreleasePool::push() };
+    f(&pool)
+}
@@ -0,0 +1,180 @@
+use std::{error::Error, fmt};
+
+use crate::foundation::{NSOperatingSystemVersion, NSProcessInfo};
+
+/// An operating system that an API can require a version of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// macOS, named `macos` in requirements.
+    MacOS,
+    /// iOS and iPadOS, named `ios` in requirements.
+    IOS,
+    /// tvOS, named `tvos` in requirements.
+    TvOS,
+    /// watchOS, named `watchos` in requirements.
+    WatchOS,
+}
+
+impl Platform {
+    /// The platform the crate is compiled for.
+    ///
+    /// Targets other than Apple's, such as GNUstep and the fake runtime, check the `macos`
+    /// requirements against the version that `NSProcessInfo` reports.
+    pub const CURRENT: Platform = if cfg!(target_os = "ios") {
+        Platform::IOS
+    } else if cfg!(target_os = "tvos") {
+        Platform::TvOS
+    } else if cfg!(target_os = "watchos") {
+        Platform::WatchOS
+    } else {
+        Platform::MacOS
+    };
+
+    /// Returns the platform named `name` in a requirement, such as `macos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not the name of a platform, at compile time in a constant.
+    pub const fn from_name(name: &str) -> Platform {
+        match name.as_bytes() {
+            b"macos" => Platform::MacOS,
+            b"ios" => Platform::IOS,
+            b"tvos" => Platform::TvOS,
+            b"watchos" => Platform::WatchOS,
+            _ => panic!("expected one of the platforms `macos`, `ios`, `tvos` and `watchos`"),
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Platform::MacOS => "macOS",
+            Platform::IOS => "iOS",
+            Platform::TvOS => "tvOS",
+            Platform::WatchOS => "watchOS",
+        })
+    }
+}
+
+/// The minimum version of a platform that an API needs, as in `macos = "12.0"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Requirement {
+    /// The platform.
+    pub platform: Platform,
+    /// The first version of the platform with the API.
+    pub version: NSOperatingSystemVersion,
+}
+
+impl Requirement {
+    /// Creates a requirement from the name of a platform and a version such as `12.0` or
+    /// `10.15.4`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the platform or the version is malformed, at compile time in a constant.
+    pub const fn new(platform: &str, version: &str) -> Requirement {
+        let bytes = version.as_bytes();
+        let mut parts = [0isize; 3];
+        let mut part = 0;
+        let mut digits = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => {
+                    parts[part] = parts[part] * 10 + (bytes[i] - b'0') as isize;
+                    digits += 1;
+                }
+                b'.' if digits > 0 && part < 2 => {
+                    part += 1;
+                    digits = 0;
+                }
+                _ => panic!("expected a version such as `12.0` or `10.15.4`"),
+            }
+            i += 1;
+        }
+
+        if digits == 0 {
+            panic!("expected a version such as `12.0` or `10.15.4`");
+        }
+
+        Requirement {
+            platform: Platform::from_name(platform),
+            version: NSOperatingSystemVersion {
+                major: parts[0],
+                minor: parts[1],
+                patch_version: parts[2],
+            },
+        }
+    }
+}
+
+/// The error returned instead of sending a message that the running OS doesn't implement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unavailable {
+    /// The platform the process is running on.
+    pub platform: Platform,
+    /// The version the API needs, or `None` if it isn't available on the platform at all.
+    pub required: Option<NSOperatingSystemVersion>,
+    /// The version the process is running on.
+    pub running: NSOperatingSystemVersion,
+}
+
+impl fmt::Display for Unavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.required {
+            Some(required) => write!(
+                f,
+                "requires {} {}, but the process is running on {}",
+                self.platform, required, self.running
+            ),
+            None => write!(f, "not available on {}", self.platform),
+        }
+    }
+}
+
+impl Error for Unavailable {}
+
+#[cfg(not(all(feature = "test-runtime", not(target_vendor = "apple"))))]
+lazy_static::lazy_static! {
+    static ref OS_VERSION: NSOperatingSystemVersion =
+        NSProcessInfo::process_info().operating_system_version();
+}
+
+/// Returns the version of the operating system the process is running on.
+///
+/// The version is read from `NSProcessInfo` on the first call and cached, except with the
+/// fake runtime, where tests set it with `test_runtime::set_os_version`.
+pub fn os_version() -> NSOperatingSystemVersion {
+    #[cfg(not(all(feature = "test-runtime", not(target_vendor = "apple"))))]
+    {
+        *OS_VERSION
+    }
+
+    #[cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+    {
+        NSProcessInfo::process_info().operating_system_version()
+    }
+}
+
+/// Checks that the running OS meets the requirement for its platform.
+///
+/// An API is unavailable on the platforms without a requirement. This is what
+/// `#[available]` methods and [`available!`](crate::available) call.
+pub fn check_availability(requirements: &[Requirement]) -> Result<(), Unavailable> {
+    let running = os_version();
+    let required = requirements
+        .iter()
+        .find(|requirement| requirement.platform == Platform::CURRENT)
+        .map(|requirement| requirement.version);
+
+    match required {
+        Some(required) if running >= required => Ok(()),
+        required => Err(Unavailable {
+            platform: Platform::CURRENT,
+            required,
+            running,
+        }),
+    }
+}
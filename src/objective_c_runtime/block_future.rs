@@ -0,0 +1,275 @@
+use std::{
+    error::Error,
+    fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll, Waker},
+};
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+use block::{Block, BlockArguments, ConcreteBlock, IntoConcreteBlock};
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+use crate::foundation::NSError;
+
+/// Creates a future that resolves when a completion handler is called.
+///
+/// The [`BlockCompletion`] goes into the completion handler block, which calls
+/// [`BlockCompletion::complete`] with the result. If the block is released without being
+/// called, dropping the `BlockCompletion` resolves the future to [`BlockDropped`], so the
+/// future never hangs. The future does not depend on any executor.
+///
+/// The future resolves as soon as the handler is called. The caller should still drop its
+/// copy of the block before awaiting, as a handler that is never called only resolves the
+/// future once every copy of the block is released. [`BlockFuture::send`] takes care of this.
+pub fn block_future<T>() -> (BlockCompletion<T>, BlockFuture<T>) {
+    let state = Arc::new(Mutex::new(State::Pending(None)));
+
+    (
+        BlockCompletion {
+            state: state.clone(),
+        },
+        BlockFuture { state },
+    )
+}
+
+enum State<T> {
+    /// Waiting for the handler, holding the waker of the last poll.
+    Pending(Option<Waker>),
+    /// The handler was called, or was dropped with `Err`.
+    Ready(Result<T, BlockDropped>),
+    /// The future returned the result.
+    Taken,
+}
+
+/// The sending half of [`block_future`], owned by a completion handler block.
+pub struct BlockCompletion<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+impl<T> BlockCompletion<T> {
+    /// Resolves the future with `value`. Later calls are ignored, as some handlers are
+    /// called more than once.
+    pub fn complete(&self, value: T) {
+        self.resolve(Ok(value));
+    }
+
+    fn resolve(&self, result: Result<T, BlockDropped>) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let State::Pending(waker) = &mut *state {
+            let waker = waker.take();
+            *state = State::Ready(result);
+            drop(state);
+
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T> Drop for BlockCompletion<T> {
+    fn drop(&mut self) {
+        self.resolve(Err(BlockDropped));
+    }
+}
+
+impl<T> fmt::Debug for BlockCompletion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockCompletion").finish_non_exhaustive()
+    }
+}
+
+/// A future for the result of a completion handler. See [`block_future`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct BlockFuture<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+impl<T: Send + 'static> BlockFuture<T> {
+    /// Sends a message that takes a completion handler, and returns a future for the value
+    /// the handler completes with.
+    ///
+    /// `handler` turns the [`BlockCompletion`] into the closure of the block, and `send`
+    /// sends the message with the block. The block is released when `send` returns, so the
+    /// receiver holds the only copies of it.
+    ///
+    /// Completion handlers run on whichever queue the receiver chooses, so the closure and
+    /// the value it completes with must be `Send`.
+    pub fn send<A, H, F, S>(handler: H, send: S) -> Self
+    where
+        A: BlockArguments,
+        H: FnOnce(BlockCompletion<T>) -> F,
+        F: IntoConcreteBlock<A, Ret = ()> + Send + 'static,
+        S: FnOnce(&Block<A, ()>),
+    {
+        let (completion, future) = block_future();
+        let block = ConcreteBlock::new(handler(completion)).copy();
+
+        send(&block);
+
+        future
+    }
+}
+
+impl<T> Future for BlockFuture<T> {
+    type Output = Result<T, BlockDropped>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match &mut *state {
+            State::Pending(waker) => {
+                *waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            State::Ready(_) => match mem::replace(&mut *state, State::Taken) {
+                State::Ready(result) => Poll::Ready(result),
+                _ => unreachable!(),
+            },
+            State::Taken => panic!("`BlockFuture` polled after completion"),
+        }
+    }
+}
+
+impl<T> fmt::Debug for BlockFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockFuture").finish_non_exhaustive()
+    }
+}
+
+/// The error a [`BlockFuture`] resolves to when its completion handler is released without
+/// being called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockDropped;
+
+impl fmt::Display for BlockDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the completion handler was released without being called")
+    }
+}
+
+impl Error for BlockDropped {}
+
+/// The error of a completion handler that reports failure with an `NSError`.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+#[derive(Debug, Clone)]
+pub enum CompletionError {
+    /// The handler was called with the error.
+    Failed(NSError),
+    /// The handler was released without being called.
+    Dropped(BlockDropped),
+}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+impl fmt::Display for CompletionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompletionError::Failed(error) => error.fmt(f),
+            CompletionError::Dropped(dropped) => dropped.fmt(f),
+        }
+    }
+}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+impl Error for CompletionError {}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+impl From<NSError> for CompletionError {
+    fn from(error: NSError) -> Self {
+        CompletionError::Failed(error)
+    }
+}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+impl From<BlockDropped> for CompletionError {
+    fn from(dropped: BlockDropped) -> Self {
+        CompletionError::Dropped(dropped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        task::Wake,
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn poll<T>(
+        future: &mut BlockFuture<T>,
+        waker: &Arc<CountingWaker>,
+    ) -> Poll<Result<T, BlockDropped>> {
+        let waker = Waker::from(waker.clone());
+        Pin::new(future).poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn test_complete_before_poll() {
+        let (completion, mut future) = block_future();
+        completion.complete(42);
+
+        assert_eq!(poll(&mut future, &Arc::default()), Poll::Ready(Ok(42)));
+    }
+
+    #[test]
+    fn test_complete_wakes_the_task() {
+        let (completion, mut future) = block_future();
+        let waker = Arc::<CountingWaker>::default();
+
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+        completion.complete("done");
+
+        assert_eq!(waker.0.load(Ordering::SeqCst), 1);
+        assert_eq!(poll(&mut future, &waker), Poll::Ready(Ok("done")));
+    }
+
+    #[test]
+    fn test_later_calls_are_ignored() {
+        let (completion, mut future) = block_future();
+        completion.complete(1);
+        completion.complete(2);
+        drop(completion);
+
+        assert_eq!(poll(&mut future, &Arc::default()), Poll::Ready(Ok(1)));
+    }
+
+    #[test]
+    fn test_dropped_completion_resolves_to_an_error() {
+        let (completion, mut future) = block_future::<()>();
+        let waker = Arc::<CountingWaker>::default();
+
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+        drop(completion);
+
+        assert_eq!(waker.0.load(Ordering::SeqCst), 1);
+        assert_eq!(poll(&mut future, &waker), Poll::Ready(Err(BlockDropped)));
+    }
+
+    #[test]
+    fn test_completion_from_another_thread() {
+        let (completion, mut future) = block_future();
+        let waker = Arc::<CountingWaker>::default();
+
+        assert_eq!(poll(&mut future, &waker), Poll::Pending);
+        std::thread::spawn(move || completion.complete(7))
+            .join()
+            .unwrap();
+
+        assert_eq!(poll(&mut future, &waker), Poll::Ready(Ok(7)));
+    }
+}
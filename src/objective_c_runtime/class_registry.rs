@@ -0,0 +1,134 @@
+use std::{
+    any::{type_name, TypeId},
+    collections::HashMap,
+    error::Error,
+    fmt, iter,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use lazy_static::lazy_static;
+use objc::{declare::ClassDecl, runtime::Class};
+
+type ClassCell = OnceLock<Result<&'static Class, DeclareClassError>>;
+
+lazy_static! {
+    static ref CLASSES: Mutex<HashMap<(TypeId, &'static str), &'static ClassCell>> =
+        Mutex::new(HashMap::new());
+}
+
+/// The number of numbered names tried once the name of a type is taken.
+const MAX_RENAMES: usize = 64;
+
+/// The error returned when the class of a type cannot be declared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeclareClassError {
+    /// The name of the type and all of its numbered variants are taken by other classes.
+    NamesTaken(String),
+    /// The runtime refused to declare the class for a reason other than its name, for
+    /// example because the superclass is still being declared.
+    Refused(String),
+}
+
+impl fmt::Display for DeclareClassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeclareClassError::NamesTaken(name) => {
+                write!(f, "`{name}` and its numbered variants are taken")
+            }
+            DeclareClassError::Refused(name) => {
+                write!(f, "the runtime refused to declare `{name}`")
+            }
+        }
+    }
+}
+
+impl Error for DeclareClassError {}
+
+/// Returns the Objective-C class that backs the Rust type `T`, declaring it on first use.
+///
+/// Delegate classes hold function pointers that are monomorphized for one implementing
+/// type, so every `T` gets a class of its own. The class is a subclass of `superclass`
+/// named after `name` and the type. `declare` adds the ivars and methods, and is called
+/// once per combination of `T` and `name`. It may register classes itself.
+///
+/// The outcome is remembered, so a class that couldn't be declared returns the same
+/// error every time.
+pub fn register_class_for_type<T, F>(
+    name: &'static str,
+    superclass: &Class,
+    declare: F,
+) -> Result<&'static Class, DeclareClassError>
+where
+    T: 'static,
+    F: FnOnce(&mut ClassDecl),
+{
+    // The lock only guards the lookup, so that `declare` can register other classes.
+    let cell: &'static ClassCell = CLASSES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry((TypeId::of::<T>(), name))
+        .or_insert_with(|| Box::leak(Box::default()));
+
+    cell.get_or_init(|| {
+        let mut decl = declare_class(&class_name::<T>(name), superclass)?;
+        declare(&mut decl);
+        Ok(decl.register())
+    })
+    .clone()
+}
+
+fn declare_class(class_name: &str, superclass: &Class) -> Result<ClassDecl, DeclareClassError> {
+    // `type_name` is not guaranteed to be unique, so fall back to numbered names.
+    let names = iter::once(class_name.to_owned())
+        .chain((1..=MAX_RENAMES).map(|n| format!("{class_name}_{n}")));
+
+    for name in names {
+        if let Some(decl) = ClassDecl::new(&name, superclass) {
+            return Ok(decl);
+        }
+
+        // Any other failure would repeat under every name.
+        if Class::get(&name).is_none() {
+            return Err(DeclareClassError::Refused(name));
+        }
+    }
+
+    Err(DeclareClassError::NamesTaken(class_name.to_owned()))
+}
+
+fn class_name<T>(name: &str) -> String {
+    type_name::<T>()
+        .chars()
+        .fold(format!("{name}_"), |mut class_name, c| {
+            class_name.push(if c.is_ascii_alphanumeric() { c } else { '_' });
+            class_name
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `type_name` is unstable across compilers, so only the properties of the names are
+    // checked.
+    #[test]
+    fn test_class_name() {
+        let name = class_name::<Vec<u8>>("RSTDelegate");
+
+        assert!(name.starts_with("RSTDelegate_"));
+        assert!(name.len() > "RSTDelegate_".len());
+        assert!(name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    }
+
+    #[test]
+    fn test_class_names_differ_between_types() {
+        assert_ne!(
+            class_name::<Vec<u8>>("RSTDelegate"),
+            class_name::<Vec<u16>>("RSTDelegate")
+        );
+        assert_ne!(
+            class_name::<Vec<u8>>("RSTDelegate"),
+            class_name::<Vec<u8>>("RSTObserver")
+        );
+    }
+}
@@ -0,0 +1,1075 @@
+//! A typed representation of Objective-C type encodings.
+//!
+//! The runtime describes the type of every method argument, instance variable and
+//! property with a short string such as `"{CGRect={CGPoint=dd}{CGSize=dd}}"`.
+//! [`TypeEncoding`] models those strings as a tree that can be parsed, built up
+//! programmatically and printed back, and [`EncodeType`] computes the encoding of
+//! a Rust type for the current target.
+
+use std::{
+    ffi::{c_int, c_long, c_longlong},
+    fmt, mem,
+    str::FromStr,
+};
+
+/// A type qualifier that may precede a type in a method encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Qualifier {
+    /// `r`: the value is `const`.
+    Const,
+    /// `n`: the argument is only read by the receiver.
+    In,
+    /// `N`: the argument is read and written by the receiver.
+    InOut,
+    /// `o`: the argument is only written by the receiver.
+    Out,
+    /// `O`: the argument is copied when sent to a remote object.
+    ByCopy,
+    /// `R`: the argument is passed by reference to a remote object.
+    ByRef,
+    /// `V`: the method does not wait for a reply.
+    OneWay,
+}
+
+impl Qualifier {
+    /// Returns the qualifier represented by `code`, if any.
+    pub fn from_code(code: char) -> Option<Self> {
+        match code {
+            'r' => Some(Qualifier::Const),
+            'n' => Some(Qualifier::In),
+            'N' => Some(Qualifier::InOut),
+            'o' => Some(Qualifier::Out),
+            'O' => Some(Qualifier::ByCopy),
+            'R' => Some(Qualifier::ByRef),
+            'V' => Some(Qualifier::OneWay),
+            _ => None,
+        }
+    }
+
+    /// The character used for the qualifier in an encoding string.
+    pub fn code(self) -> char {
+        match self {
+            Qualifier::Const => 'r',
+            Qualifier::In => 'n',
+            Qualifier::InOut => 'N',
+            Qualifier::Out => 'o',
+            Qualifier::ByCopy => 'O',
+            Qualifier::ByRef => 'R',
+            Qualifier::OneWay => 'V',
+        }
+    }
+}
+
+/// A parsed Objective-C type encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TypeEncoding {
+    /// `c`: a `char`.
+    Char,
+    /// `s`: a `short`.
+    Short,
+    /// `i`: an `int`.
+    Int,
+    /// `l`: a `long`. Only used by 32-bit targets, 64-bit `long` is encoded as `q`.
+    Long,
+    /// `q`: a `long long`.
+    LongLong,
+    /// `t`: an `__int128`.
+    Int128,
+    /// `C`: an `unsigned char`.
+    UChar,
+    /// `S`: an `unsigned short`.
+    UShort,
+    /// `I`: an `unsigned int`.
+    UInt,
+    /// `L`: an `unsigned long`.
+    ULong,
+    /// `Q`: an `unsigned long long`.
+    ULongLong,
+    /// `T`: an `unsigned __int128`.
+    UInt128,
+    /// `f`: a `float`.
+    Float,
+    /// `d`: a `double`.
+    Double,
+    /// `D`: a `long double`.
+    LongDouble,
+    /// `B`: a C++ `bool` or C99 `_Bool`.
+    Bool,
+    /// `v`: `void`.
+    Void,
+    /// `*`: a C string (`char *`).
+    String,
+    /// `@`: an object, optionally with the name of its class (`@"NSString"`).
+    Object(Option<String>),
+    /// `@?`: a block.
+    Block,
+    /// `#`: a class object.
+    Class,
+    /// `:`: a method selector.
+    Sel,
+    /// `?`: an unknown type, such as a function pointer's pointee.
+    Unknown,
+    /// `bN`: a bit field of `N` bits.
+    BitField(u8),
+    /// `^type`: a pointer to a type.
+    Pointer(Box<TypeEncoding>),
+    /// `[Ntype]`: an array of `N` elements.
+    Array(usize, Box<TypeEncoding>),
+    /// `{name=types}`: a structure. The fields are `None` when only the name is encoded.
+    Struct(String, Option<Vec<TypeEncoding>>),
+    /// `(name=types)`: a union. The fields are `None` when only the name is encoded.
+    Union(String, Option<Vec<TypeEncoding>>),
+    /// A type preceded by a qualifier, such as `r*` for a `const char *`.
+    Qualified(Qualifier, Box<TypeEncoding>),
+}
+
+impl TypeEncoding {
+    /// Returns the encoding of `T`.
+    pub fn of<T>() -> Self
+    where
+        T: EncodeType + ?Sized,
+    {
+        T::type_encoding()
+    }
+
+    /// Creates a pointer to `pointee`.
+    pub fn pointer(pointee: TypeEncoding) -> Self {
+        TypeEncoding::Pointer(Box::new(pointee))
+    }
+
+    /// Creates an array of `len` elements of type `element`.
+    pub fn array(len: usize, element: TypeEncoding) -> Self {
+        TypeEncoding::Array(len, Box::new(element))
+    }
+
+    /// Creates a structure named `name` with the given fields.
+    pub fn structure<I>(name: &str, fields: I) -> Self
+    where
+        I: IntoIterator<Item = TypeEncoding>,
+    {
+        TypeEncoding::Struct(name.to_string(), Some(fields.into_iter().collect()))
+    }
+
+    /// Creates a union named `name` with the given fields.
+    pub fn union<I>(name: &str, fields: I) -> Self
+    where
+        I: IntoIterator<Item = TypeEncoding>,
+    {
+        TypeEncoding::Union(name.to_string(), Some(fields.into_iter().collect()))
+    }
+
+    /// Creates an object of the class named `class_name`.
+    pub fn object_of_class(class_name: &str) -> Self {
+        TypeEncoding::Object(Some(class_name.to_string()))
+    }
+
+    /// Prefixes the encoding with `qualifier`.
+    pub fn qualified(self, qualifier: Qualifier) -> Self {
+        TypeEncoding::Qualified(qualifier, Box::new(self))
+    }
+
+    /// Returns the encoding with any leading qualifiers removed.
+    pub fn unqualified(&self) -> &TypeEncoding {
+        match self {
+            TypeEncoding::Qualified(_, inner) => inner.unqualified(),
+            _ => self,
+        }
+    }
+
+    /// Returns the size and signedness of the `int`, `long` and `long long` encodings.
+    fn integer_layout(&self) -> Option<(usize, bool)> {
+        use TypeEncoding::*;
+
+        // Apple's compilers only use `l` for 32-bit values, encoding a 64-bit `long` as `q`.
+        let long = if cfg!(target_vendor = "apple") {
+            mem::size_of::<i32>()
+        } else {
+            mem::size_of::<c_long>()
+        };
+
+        match self {
+            Int => Some((mem::size_of::<c_int>(), true)),
+            Long => Some((long, true)),
+            LongLong => Some((mem::size_of::<c_longlong>(), true)),
+            UInt => Some((mem::size_of::<c_int>(), false)),
+            ULong => Some((long, false)),
+            ULongLong => Some((mem::size_of::<c_longlong>(), false)),
+            _ => None,
+        }
+    }
+
+    /// Returns whether two encodings describe the same type.
+    ///
+    /// Qualifiers, the class names of objects and the fields of a named structure or
+    /// union that is only encoded by name on one side are ignored, matching what the
+    /// runtime considers compatible. So are the differences between the `int`, `long` and
+    /// `long long` encodings of integers of the same size, as compilers disagree on which
+    /// one `NSInteger` is encoded as.
+    pub fn is_compatible_with(&self, other: &TypeEncoding) -> bool {
+        use TypeEncoding::*;
+
+        match (self.unqualified(), other.unqualified()) {
+            (Object(_), Object(_)) => true,
+            (Pointer(a), Pointer(b)) => a.is_compatible_with(b),
+            (Array(a_len, a), Array(b_len, b)) => a_len == b_len && a.is_compatible_with(b),
+            (Struct(a_name, a_fields), Struct(b_name, b_fields))
+            | (Union(a_name, a_fields), Union(b_name, b_fields)) => {
+                let names_match = a_name == b_name || a_name == "?" || b_name == "?";
+
+                match (a_fields, b_fields) {
+                    (Some(a), Some(b)) => {
+                        (names_match || a_name.is_empty() || b_name.is_empty())
+                            && a.len() == b.len()
+                            && a.iter().zip(b).all(|(a, b)| a.is_compatible_with(b))
+                    }
+                    _ => names_match,
+                }
+            }
+            (a, b) if a.integer_layout().is_some() => a.integer_layout() == b.integer_layout(),
+            (a, b) => a == b,
+        }
+    }
+
+    /// Parses a single type from the start of `input`, returning it and the rest of the string.
+    pub fn parse_prefix(input: &str) -> Result<(TypeEncoding, &str), ParseEncodingError> {
+        let mut parser = Parser::new(input);
+        let encoding = parser.parse_type()?;
+        Ok((encoding, &input[parser.position..]))
+    }
+}
+
+impl FromStr for TypeEncoding {
+    type Err = ParseEncodingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let encoding = parser.parse_type()?;
+        parser.expect_end()?;
+        Ok(encoding)
+    }
+}
+
+impl fmt::Display for TypeEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TypeEncoding::*;
+
+        let code = match self {
+            Char => "c",
+            Short => "s",
+            Int => "i",
+            Long => "l",
+            LongLong => "q",
+            Int128 => "t",
+            UChar => "C",
+            UShort => "S",
+            UInt => "I",
+            ULong => "L",
+            ULongLong => "Q",
+            UInt128 => "T",
+            Float => "f",
+            Double => "d",
+            LongDouble => "D",
+            Bool => "B",
+            Void => "v",
+            String => "*",
+            Block => "@?",
+            Class => "#",
+            Sel => ":",
+            Unknown => "?",
+            Object(None) => "@",
+            Object(Some(class_name)) => return write!(f, "@\"{class_name}\""),
+            BitField(bits) => return write!(f, "b{bits}"),
+            Pointer(pointee) => return write!(f, "^{pointee}"),
+            Array(len, element) => return write!(f, "[{len}{element}]"),
+            Struct(name, fields) => return write_aggregate(f, '{', '}', name, fields),
+            Union(name, fields) => return write_aggregate(f, '(', ')', name, fields),
+            Qualified(qualifier, inner) => return write!(f, "{}{inner}", qualifier.code()),
+        };
+
+        f.write_str(code)
+    }
+}
+
+fn write_aggregate(
+    f: &mut fmt::Formatter<'_>,
+    open: char,
+    close: char,
+    name: &str,
+    fields: &Option<Vec<TypeEncoding>>,
+) -> fmt::Result {
+    write!(f, "{open}{name}")?;
+
+    if let Some(fields) = fields {
+        f.write_str("=")?;
+        for field in fields {
+            write!(f, "{field}")?;
+        }
+    }
+
+    write!(f, "{close}")
+}
+
+/// The encoding of a method: its return type followed by the types of its arguments.
+///
+/// The arguments include the receiver (`@`) and the selector (`:`) that every method
+/// implementation takes first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MethodEncoding {
+    /// The type the method returns.
+    pub return_type: TypeEncoding,
+    /// The types of the arguments, starting with the receiver and the selector.
+    pub arguments: Vec<TypeEncoding>,
+}
+
+impl MethodEncoding {
+    /// Creates a method encoding from its return and argument types.
+    pub fn new<I>(return_type: TypeEncoding, arguments: I) -> Self
+    where
+        I: IntoIterator<Item = TypeEncoding>,
+    {
+        Self {
+            return_type,
+            arguments: arguments.into_iter().collect(),
+        }
+    }
+
+    /// Returns whether every type of the two encodings is compatible.
+    ///
+    /// See [`TypeEncoding::is_compatible_with`].
+    pub fn is_compatible_with(&self, other: &MethodEncoding) -> bool {
+        self.return_type.is_compatible_with(&other.return_type)
+            && self.arguments.len() == other.arguments.len()
+            && self
+                .arguments
+                .iter()
+                .zip(&other.arguments)
+                .all(|(a, b)| a.is_compatible_with(b))
+    }
+}
+
+impl FromStr for MethodEncoding {
+    type Err = ParseEncodingError;
+
+    /// Parses a method encoding such as `"v24@0:8@16"`. The stack offsets are optional
+    /// and are not kept.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+
+        let return_type = parser.parse_type()?;
+        parser.skip_offset();
+
+        let mut arguments = Vec::new();
+        while !parser.is_at_end() {
+            arguments.push(parser.parse_type()?);
+            parser.skip_offset();
+        }
+
+        Ok(Self {
+            return_type,
+            arguments,
+        })
+    }
+}
+
+impl fmt::Display for MethodEncoding {
+    /// Prints the encoding without stack offsets, which is what `class_addMethod` expects.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.return_type)?;
+        for argument in &self.arguments {
+            write!(f, "{argument}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How a property's setter treats the new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PropertySemantics {
+    /// The value is assigned without being retained.
+    #[default]
+    Assign,
+    /// `&`: the value is retained.
+    Retain,
+    /// `C`: the value is copied.
+    Copy,
+    /// `W`: the value is referenced weakly.
+    Weak,
+}
+
+/// The attributes of a property, as returned by `property_getAttributes`.
+///
+/// The runtime describes a property with a string such as `T@"NSString",R,C,N,V_name`,
+/// which starts with the property's type followed by comma-separated flags.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyAttributes {
+    /// `T`: the type of the property.
+    pub type_encoding: TypeEncoding,
+    /// `R`: the property has no setter.
+    pub read_only: bool,
+    /// How the setter treats the new value.
+    pub semantics: PropertySemantics,
+    /// `N`: the accessors are not atomic.
+    pub nonatomic: bool,
+    /// `G`: the name of a custom getter.
+    pub getter: Option<String>,
+    /// `S`: the name of a custom setter.
+    pub setter: Option<String>,
+    /// `D`: the accessors are provided at runtime (`@dynamic`).
+    pub dynamic: bool,
+    /// `V`: the name of the instance variable that backs the property.
+    pub ivar: Option<String>,
+}
+
+impl FromStr for PropertyAttributes {
+    type Err = ParseEncodingError;
+
+    /// Parses attributes such as `T@"NSString",R,C,N,V_name`. Unknown flags are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |position: usize, message: &str| ParseEncodingError {
+            position,
+            message: message.to_string(),
+        };
+
+        let type_attribute = s
+            .strip_prefix('T')
+            .ok_or_else(|| error(0, "expected the `T` attribute"))?;
+        let (type_encoding, mut rest) =
+            TypeEncoding::parse_prefix(type_attribute).map_err(|mut error| {
+                error.position += 1;
+                error
+            })?;
+
+        let mut attributes = PropertyAttributes {
+            type_encoding,
+            read_only: false,
+            semantics: PropertySemantics::Assign,
+            nonatomic: false,
+            getter: None,
+            setter: None,
+            dynamic: false,
+            ivar: None,
+        };
+
+        while !rest.is_empty() {
+            rest = rest
+                .strip_prefix(',')
+                .ok_or_else(|| error(s.len() - rest.len(), "expected `,`"))?;
+
+            let end = rest.find(',').unwrap_or(rest.len());
+            let (attribute, after) = rest.split_at(end);
+            rest = after;
+
+            let mut chars = attribute.chars();
+            let code = chars.next();
+            let value = || Some(chars.as_str().to_string());
+
+            match code {
+                Some('R') => attributes.read_only = true,
+                Some('&') => attributes.semantics = PropertySemantics::Retain,
+                Some('C') => attributes.semantics = PropertySemantics::Copy,
+                Some('W') => attributes.semantics = PropertySemantics::Weak,
+                Some('N') => attributes.nonatomic = true,
+                Some('D') => attributes.dynamic = true,
+                Some('G') => attributes.getter = value(),
+                Some('S') => attributes.setter = value(),
+                Some('V') => attributes.ivar = value(),
+                Some(_) => {}
+                None => return Err(error(s.len() - rest.len(), "expected an attribute")),
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+impl fmt::Display for PropertyAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "T{}", self.type_encoding)?;
+
+        if self.read_only {
+            f.write_str(",R")?;
+        }
+
+        match self.semantics {
+            PropertySemantics::Assign => {}
+            PropertySemantics::Retain => f.write_str(",&")?,
+            PropertySemantics::Copy => f.write_str(",C")?,
+            PropertySemantics::Weak => f.write_str(",W")?,
+        }
+
+        if self.nonatomic {
+            f.write_str(",N")?;
+        }
+        if let Some(getter) = &self.getter {
+            write!(f, ",G{getter}")?;
+        }
+        if let Some(setter) = &self.setter {
+            write!(f, ",S{setter}")?;
+        }
+        if self.dynamic {
+            f.write_str(",D")?;
+        }
+        if let Some(ivar) = &self.ivar {
+            write!(f, ",V{ivar}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when an encoding string is malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEncodingError {
+    /// The byte offset in the input at which the error was found.
+    pub position: usize,
+    /// A description of what was expected.
+    pub message: String,
+}
+
+impl fmt::Display for ParseEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid type encoding at byte {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseEncodingError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.input.len()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, ParseEncodingError> {
+        Err(ParseEncodingError {
+            position: self.position,
+            message: message.into(),
+        })
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseEncodingError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => self.error(format!("expected `{expected}`, found `{c}`")),
+            None => self.error(format!("expected `{expected}`, found end of input")),
+        }
+    }
+
+    fn expect_end(&self) -> Result<(), ParseEncodingError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(c) => self.error(format!("unexpected trailing `{c}`")),
+        }
+    }
+
+    fn parse_number(&mut self) -> Option<usize> {
+        let digits = self.rest().len()
+            - self
+                .rest()
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            return None;
+        }
+
+        let number = self.rest()[..digits].parse().ok();
+        self.position += digits;
+        number
+    }
+
+    fn skip_offset(&mut self) {
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        self.parse_number();
+    }
+
+    fn parse_quoted(&mut self) -> Result<String, ParseEncodingError> {
+        self.expect('"')?;
+        match self.rest().find('"') {
+            Some(end) => {
+                let name = self.rest()[..end].to_string();
+                self.position += end + 1;
+                Ok(name)
+            }
+            None => self.error("unterminated quoted name"),
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<TypeEncoding, ParseEncodingError> {
+        use TypeEncoding::*;
+
+        let start = self.position;
+        let c = match self.bump() {
+            Some(c) => c,
+            None => return self.error("expected a type, found end of input"),
+        };
+
+        if let Some(qualifier) = Qualifier::from_code(c) {
+            return Ok(self.parse_type()?.qualified(qualifier));
+        }
+
+        let encoding = match c {
+            'c' => Char,
+            's' => Short,
+            'i' => Int,
+            'l' => Long,
+            'q' => LongLong,
+            't' => Int128,
+            'C' => UChar,
+            'S' => UShort,
+            'I' => UInt,
+            'L' => ULong,
+            'Q' => ULongLong,
+            'T' => UInt128,
+            'f' => Float,
+            'd' => Double,
+            'D' => LongDouble,
+            'B' => Bool,
+            'v' => Void,
+            '*' => String,
+            '#' => Class,
+            ':' => Sel,
+            '?' => Unknown,
+            '@' => match self.peek() {
+                Some('?') => {
+                    self.bump();
+                    Block
+                }
+                Some('"') => Object(Some(self.parse_quoted()?)),
+                _ => Object(None),
+            },
+            '^' => TypeEncoding::pointer(self.parse_type()?),
+            'b' => match self.parse_number() {
+                Some(bits) if bits <= u8::MAX as usize => BitField(bits as u8),
+                _ => return self.error("expected the width of a bit field"),
+            },
+            '[' => {
+                let len = match self.parse_number() {
+                    Some(len) => len,
+                    None => return self.error("expected the length of an array"),
+                };
+                let element = self.parse_type()?;
+                self.expect(']')?;
+                TypeEncoding::array(len, element)
+            }
+            '{' => {
+                let (name, fields) = self.parse_aggregate('}')?;
+                Struct(name, fields)
+            }
+            '(' => {
+                let (name, fields) = self.parse_aggregate(')')?;
+                Union(name, fields)
+            }
+            c => {
+                self.position = start;
+                return self.error(format!("unknown type code `{c}`"));
+            }
+        };
+
+        Ok(encoding)
+    }
+
+    fn parse_aggregate(
+        &mut self,
+        close: char,
+    ) -> Result<(String, Option<Vec<TypeEncoding>>), ParseEncodingError> {
+        let name_len = match self.rest().find(['=', close]) {
+            Some(len) => len,
+            None => return self.error(format!("expected `=` or `{close}`")),
+        };
+        let name = self.rest()[..name_len].to_string();
+        self.position += name_len;
+
+        if self.peek() == Some(close) {
+            self.bump();
+            return Ok((name, None));
+        }
+
+        self.expect('=')?;
+
+        let mut fields = Vec::new();
+        loop {
+            match self.peek() {
+                Some(c) if c == close => {
+                    self.bump();
+                    return Ok((name, Some(fields)));
+                }
+                // Instance variable and property encodings name each field.
+                Some('"') => {
+                    self.parse_quoted()?;
+                }
+                Some(_) => fields.push(self.parse_type()?),
+                None => return self.error(format!("expected `{close}`, found end of input")),
+            }
+        }
+    }
+}
+
+/// Types whose Objective-C type encoding is known.
+///
+/// Implement it with `#[derive(Encode)]` for `#[repr(C)]` structures and unions.
+/// The derive also implements the runtime's [`Encode`](objc::Encode) trait when
+/// the runtime is available, so that the type can be passed to `msg_send!` and
+/// used in declared methods.
+pub trait EncodeType {
+    /// Returns the encoding of `Self` for the current target.
+    fn type_encoding() -> TypeEncoding;
+}
+
+macro_rules! encode_type_impls {
+    ($($t:ty => $encoding:expr,)*) => {
+        $(
+            impl EncodeType for $t {
+                fn type_encoding() -> TypeEncoding {
+                    $encoding
+                }
+            }
+        )*
+    };
+}
+
+encode_type_impls! {
+    i8 => TypeEncoding::Char,
+    i16 => TypeEncoding::Short,
+    i32 => TypeEncoding::Int,
+    i64 => TypeEncoding::LongLong,
+    i128 => TypeEncoding::Int128,
+    u8 => TypeEncoding::UChar,
+    u16 => TypeEncoding::UShort,
+    u32 => TypeEncoding::UInt,
+    u64 => TypeEncoding::ULongLong,
+    u128 => TypeEncoding::UInt128,
+    f32 => TypeEncoding::Float,
+    f64 => TypeEncoding::Double,
+    bool => TypeEncoding::Bool,
+    () => TypeEncoding::Void,
+    std::ffi::c_void => TypeEncoding::Void,
+}
+
+// `isize` and `usize` are `NSInteger` and `NSUInteger`, which are `long` except on 32-bit
+// Apple targets. Apple's compilers encode a 64-bit `long` as `q`, and the others as `l`.
+impl EncodeType for isize {
+    fn type_encoding() -> TypeEncoding {
+        if cfg!(target_vendor = "apple") {
+            if cfg!(target_pointer_width = "64") {
+                TypeEncoding::LongLong
+            } else {
+                TypeEncoding::Int
+            }
+        } else if mem::size_of::<c_long>() == mem::size_of::<isize>() {
+            TypeEncoding::Long
+        } else {
+            TypeEncoding::LongLong
+        }
+    }
+}
+
+impl EncodeType for usize {
+    fn type_encoding() -> TypeEncoding {
+        match isize::type_encoding() {
+            TypeEncoding::LongLong => TypeEncoding::ULongLong,
+            TypeEncoding::Int => TypeEncoding::UInt,
+            _ => TypeEncoding::ULong,
+        }
+    }
+}
+
+impl<T> EncodeType for *const T
+where
+    T: EncodeType + ?Sized,
+{
+    fn type_encoding() -> TypeEncoding {
+        TypeEncoding::pointer(T::type_encoding())
+    }
+}
+
+impl<T> EncodeType for *mut T
+where
+    T: EncodeType + ?Sized,
+{
+    fn type_encoding() -> TypeEncoding {
+        TypeEncoding::pointer(T::type_encoding())
+    }
+}
+
+impl<T, const N: usize> EncodeType for [T; N]
+where
+    T: EncodeType,
+{
+    fn type_encoding() -> TypeEncoding {
+        TypeEncoding::array(N, T::type_encoding())
+    }
+}
+
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+mod runtime_impls {
+    use objc::runtime::{Class, Object, Sel};
+
+    use super::{EncodeType, TypeEncoding};
+
+    // `id` is a pointer to an `Object`, which is encoded as `@` rather than `^@`.
+    impl EncodeType for *mut Object {
+        fn type_encoding() -> TypeEncoding {
+            TypeEncoding::Object(None)
+        }
+    }
+
+    impl EncodeType for *const Class {
+        fn type_encoding() -> TypeEncoding {
+            TypeEncoding::Class
+        }
+    }
+
+    impl EncodeType for Sel {
+        fn type_encoding() -> TypeEncoding {
+            TypeEncoding::Sel
+        }
+    }
+
+    impl From<&TypeEncoding> for objc::Encoding {
+        fn from(encoding: &TypeEncoding) -> Self {
+            // SAFETY: the string is printed from a well-formed encoding tree.
+            unsafe { objc::Encoding::from_str(&encoding.to_string()) }
+        }
+    }
+
+    impl TryFrom<&objc::Encoding> for TypeEncoding {
+        type Error = super::ParseEncodingError;
+
+        fn try_from(encoding: &objc::Encoding) -> Result<Self, Self::Error> {
+            encoding.as_str().parse()
+        }
+    }
+}
+
+/// Implements the runtime's `Encode` trait for a type implementing [`EncodeType`], and
+/// lets the type be passed to and from methods by value.
+///
+/// This is emitted by `#[derive(Encode)]` and expands to nothing when the crate is
+/// built without an Objective-C runtime.
+#[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_runtime_encode {
+    ($t:ty) => {
+        unsafe impl $crate::objective_c_runtime::Encode for $t {
+            fn encode() -> $crate::objective_c_runtime::Encoding {
+                let encoding =
+                    <$t as $crate::objective_c_runtime::encoding::EncodeType>::type_encoding();
+                $crate::objective_c_runtime::Encoding::from(&encoding)
+            }
+        }
+
+        $crate::__impl_objc_value!($t);
+    };
+}
+
+/// Implements the runtime's `Encode` trait for a type implementing [`EncodeType`].
+///
+/// This is emitted by `#[derive(Encode)]` and expands to nothing when the crate is
+/// built without an Objective-C runtime.
+#[cfg(not(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_runtime_encode {
+    ($t:ty) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("c"; "char")]
+    #[test_case("@"; "object")]
+    #[test_case("@?"; "block")]
+    #[test_case("@\"NSString\""; "object with class")]
+    #[test_case("^v"; "void pointer")]
+    #[test_case("^?"; "function pointer")]
+    #[test_case("r*"; "const string")]
+    #[test_case("b5"; "bit field")]
+    #[test_case("[12^f]"; "array of pointers")]
+    #[test_case("{CGRect={CGPoint=dd}{CGSize=dd}}"; "nested structs")]
+    #[test_case("{_NSRange=QQ}"; "ns range")]
+    #[test_case("^{__CFString}"; "opaque struct pointer")]
+    #[test_case("(?=iQ)"; "anonymous union")]
+    #[test_case("{?=[4{?=ff}]}"; "anonymous struct array")]
+    fn test_round_trip(encoding: &str) {
+        let parsed: TypeEncoding = encoding.parse().unwrap();
+        assert_eq!(parsed.to_string(), encoding);
+    }
+
+    #[test]
+    fn test_parse_nested_struct() {
+        let parsed: TypeEncoding = "{CGRect={CGPoint=dd}{CGSize=dd}}".parse().unwrap();
+
+        let expected = TypeEncoding::structure(
+            "CGRect",
+            [
+                TypeEncoding::structure("CGPoint", [TypeEncoding::Double, TypeEncoding::Double]),
+                TypeEncoding::structure("CGSize", [TypeEncoding::Double, TypeEncoding::Double]),
+            ],
+        );
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn test_parse_named_fields() {
+        let parsed: TypeEncoding = "{CGPoint=\"x\"d\"y\"d}".parse().unwrap();
+        assert_eq!(parsed.to_string(), "{CGPoint=dd}");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<TypeEncoding>().unwrap_err().position, 0);
+        assert_eq!(
+            "{CGPoint=dd".parse::<TypeEncoding>().unwrap_err().position,
+            11
+        );
+        assert_eq!("dd".parse::<TypeEncoding>().unwrap_err().position, 1);
+        assert_eq!("[d]".parse::<TypeEncoding>().unwrap_err().position, 1);
+        assert_eq!("x".parse::<TypeEncoding>().unwrap_err().position, 0);
+    }
+
+    #[test]
+    fn test_parse_method_encoding() {
+        let method: MethodEncoding = "v24@0:8@16".parse().unwrap();
+
+        assert_eq!(method.return_type, TypeEncoding::Void);
+        assert_eq!(
+            method.arguments,
+            [
+                TypeEncoding::Object(None),
+                TypeEncoding::Sel,
+                TypeEncoding::Object(None)
+            ]
+        );
+        assert_eq!(method.to_string(), "v@:@");
+    }
+
+    #[test]
+    fn test_parse_property_attributes() {
+        let attributes: PropertyAttributes =
+            "T@\"NSString\",R,C,N,GgetName,V_name".parse().unwrap();
+
+        assert_eq!(
+            attributes.type_encoding,
+            TypeEncoding::object_of_class("NSString")
+        );
+        assert!(attributes.read_only);
+        assert_eq!(attributes.semantics, PropertySemantics::Copy);
+        assert!(attributes.nonatomic);
+        assert!(!attributes.dynamic);
+        assert_eq!(attributes.getter.as_deref(), Some("getName"));
+        assert_eq!(attributes.setter, None);
+        assert_eq!(attributes.ivar.as_deref(), Some("_name"));
+    }
+
+    #[test_case("Ti"; "type only")]
+    #[test_case("T{CGRect={CGPoint=dd}{CGSize=dd}},N,V_frame"; "struct")]
+    #[test_case("T@,&,SsetDelegate:,D"; "retained with setter")]
+    #[test_case("T@\"NSObject\",W,N"; "weak")]
+    fn test_property_attributes_round_trip(attributes: &str) {
+        let parsed: PropertyAttributes = attributes.parse().unwrap();
+        assert_eq!(parsed.to_string(), attributes);
+    }
+
+    #[test]
+    fn test_property_attribute_errors() {
+        assert_eq!("R,N".parse::<PropertyAttributes>().unwrap_err().position, 0);
+        assert_eq!(
+            "T{CGPoint=dd"
+                .parse::<PropertyAttributes>()
+                .unwrap_err()
+                .position,
+            12
+        );
+        assert_eq!(
+            "Ti;N".parse::<PropertyAttributes>().unwrap_err().position,
+            2
+        );
+        assert_eq!(
+            "Ti,,N".parse::<PropertyAttributes>().unwrap_err().position,
+            3
+        );
+    }
+
+    #[test]
+    fn test_compatibility() {
+        let opaque: TypeEncoding = "{CGPoint}".parse().unwrap();
+        let full: TypeEncoding = "{CGPoint=dd}".parse().unwrap();
+        let string: TypeEncoding = "@\"NSString\"".parse().unwrap();
+
+        assert!(opaque.is_compatible_with(&full));
+        assert!(string.is_compatible_with(&TypeEncoding::Object(None)));
+        assert!(TypeEncoding::Double
+            .qualified(Qualifier::Const)
+            .is_compatible_with(&TypeEncoding::Double));
+        assert!(!full.is_compatible_with(&"{CGSize=dd}".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_integers_of_the_same_size_are_compatible() {
+        assert!(TypeEncoding::of::<isize>().is_compatible_with(&TypeEncoding::of::<i64>()));
+        assert!(TypeEncoding::of::<usize>().is_compatible_with(&TypeEncoding::of::<u64>()));
+        assert!(!TypeEncoding::of::<isize>().is_compatible_with(&TypeEncoding::of::<usize>()));
+        assert!(!TypeEncoding::Int.is_compatible_with(&TypeEncoding::LongLong));
+        assert!(!TypeEncoding::Int.is_compatible_with(&TypeEncoding::Float));
+
+        #[cfg(all(
+            not(target_vendor = "apple"),
+            target_os = "linux",
+            target_pointer_width = "64"
+        ))]
+        assert!(TypeEncoding::Long.is_compatible_with(&TypeEncoding::LongLong));
+        #[cfg(target_vendor = "apple")]
+        assert!(TypeEncoding::Long.is_compatible_with(&TypeEncoding::Int));
+    }
+
+    #[test]
+    fn test_primitive_encodings() {
+        assert_eq!(TypeEncoding::of::<i8>().to_string(), "c");
+        assert_eq!(TypeEncoding::of::<u64>().to_string(), "Q");
+        assert_eq!(TypeEncoding::of::<*const f32>().to_string(), "^f");
+        assert_eq!(TypeEncoding::of::<[u16; 3]>().to_string(), "[3S]");
+
+        #[cfg(all(target_vendor = "apple", target_pointer_width = "64"))]
+        assert_eq!(TypeEncoding::of::<usize>().to_string(), "Q");
+        #[cfg(all(target_vendor = "apple", target_pointer_width = "32"))]
+        assert_eq!(TypeEncoding::of::<usize>().to_string(), "I");
+        #[cfg(all(not(target_vendor = "apple"), target_os = "linux"))]
+        assert_eq!(TypeEncoding::of::<usize>().to_string(), "L");
+    }
+}
@@ -0,0 +1,19 @@
+// Catches Objective-C exceptions on behalf of `objective_c_runtime::catch_exception`, as
+// Rust cannot unwind into `@catch` itself.
+
+#include <objc/objc.h>
+
+// Exported by both Apple's runtime and libobjc2, but not declared by every header set.
+id objc_retain(id value);
+
+/// Calls `f(context)`. Returns 0 if it returned normally, or 1 if it threw, in which case
+/// `exception` holds a +1 reference to the thrown object.
+int rust_macios_try_catch(void (*f)(void *), void *context, id *exception) {
+    @try {
+        f(context);
+        return 0;
+    } @catch (id thrown) {
+        *exception = objc_retain(thrown);
+        return 1;
+    }
+}
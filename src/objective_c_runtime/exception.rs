@@ -0,0 +1,219 @@
+use std::{
+    ffi::{c_int, c_void},
+    mem,
+    panic::{self, AssertUnwindSafe},
+    process,
+};
+
+use objc::{
+    runtime::{Object, Sel},
+    Message,
+};
+
+use crate::foundation::NSException;
+
+use super::{id, nil, traits::FromId};
+
+extern "C-unwind" {
+    fn rust_macios_try_catch(
+        f: extern "C-unwind" fn(*mut c_void),
+        context: *mut c_void,
+        exception: *mut id,
+    ) -> c_int;
+}
+
+#[cfg(target_vendor = "apple")]
+extern "C" {
+    fn objc_msgSend();
+}
+
+#[cfg(all(not(target_vendor = "apple"), not(feature = "test-runtime")))]
+extern "C" {
+    fn objc_msg_lookup(receiver: id, sel: Sel) -> unsafe extern "C" fn();
+}
+
+/// Returns the function that sends `sel` to `receiver`.
+///
+/// [`UnwindReturn`] types are all returned in registers, so `objc_msgSend_stret` is never
+/// needed.
+#[cfg(target_vendor = "apple")]
+unsafe fn msg_send_fn(_receiver: id, _sel: Sel) -> unsafe extern "C" fn() {
+    objc_msgSend
+}
+
+/// Returns the function that sends `sel` to `receiver`.
+#[cfg(all(not(target_vendor = "apple"), not(feature = "test-runtime")))]
+unsafe fn msg_send_fn(receiver: id, sel: Sel) -> unsafe extern "C" fn() {
+    objc_msg_lookup(receiver, sel)
+}
+
+/// The return values of a message sent by [`send_message_unwind`].
+///
+/// Structures are not supported, as which of them the runtime returns through memory
+/// differs between architectures.
+///
+/// # Safety
+///
+/// The type must be returned in registers, and all zeroes must be a valid value of it, as
+/// that is what a message to `nil` returns.
+pub unsafe trait UnwindReturn: Sized + 'static {}
+
+macro_rules! unwind_return {
+    ($($t:ty),*) => {
+        $(unsafe impl UnwindReturn for $t {})*
+    };
+}
+
+unwind_return!(
+    (),
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    Sel
+);
+
+unsafe impl<T: 'static> UnwindReturn for *const T {}
+unsafe impl<T: 'static> UnwindReturn for *mut T {}
+
+/// The arguments of a message sent by [`send_message_unwind`].
+pub trait UnwindArguments: Sized {
+    /// Sends `sel` to `receiver`, which is not `nil`, with the arguments.
+    ///
+    /// # Safety
+    ///
+    /// The same as [`send_message_unwind`].
+    unsafe fn send<R: UnwindReturn>(receiver: id, sel: Sel, arguments: Self) -> R;
+}
+
+macro_rules! unwind_arguments {
+    ($($a:ident: $t:ident),*) => {
+        impl<$($t),*> UnwindArguments for ($($t,)*) {
+            unsafe fn send<R: UnwindReturn>(receiver: id, sel: Sel, ($($a,)*): Self) -> R {
+                #[cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+                {
+                    crate::test_runtime::send_message(receiver, sel, ($($a,)*))
+                }
+
+                #[cfg(not(all(feature = "test-runtime", not(target_vendor = "apple"))))]
+                {
+                    let send: unsafe extern "C-unwind" fn(id, Sel $(, $t)*) -> R =
+                        mem::transmute(msg_send_fn(receiver, sel));
+                    send(receiver, sel $(, $a)*)
+                }
+            }
+        }
+    };
+}
+
+unwind_arguments!();
+unwind_arguments!(a: A);
+unwind_arguments!(a: A, b: B);
+unwind_arguments!(a: A, b: B, c: C);
+unwind_arguments!(a: A, b: B, c: C, d: D);
+unwind_arguments!(a: A, b: B, c: C, d: D, e: E);
+unwind_arguments!(a: A, b: B, c: C, d: D, e: E, f: F);
+unwind_arguments!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
+unwind_arguments!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
+
+/// Sends a message like `msg_send!`, but through a `C-unwind` function pointer, so that an
+/// Objective-C exception thrown by the method may unwind into [`catch_exception`].
+///
+/// `msg_send!` calls `objc_msgSend` as an `extern "C"` function, which must not unwind.
+/// [`try_msg_send!`] sends its message with this function.
+///
+/// # Safety
+///
+/// The same as `msg_send!`: the receiver must respond to the selector, and the types of the
+/// arguments and the return value must match those of the method.
+///
+/// A message to `nil` returns zero, which [`UnwindReturn`] guarantees is a valid `R`.
+///
+/// [`try_msg_send!`]: crate::try_msg_send
+pub unsafe fn send_message_unwind<T, A, R>(receiver: *const T, sel: Sel, arguments: A) -> R
+where
+    T: Message,
+    A: UnwindArguments,
+    R: UnwindReturn,
+{
+    let receiver = receiver as *mut T as *mut Object;
+
+    if receiver.is_null() {
+        return mem::zeroed();
+    }
+
+    A::send(receiver, sel, arguments)
+}
+
+/// Calls `f`, turning an Objective-C exception thrown by it into an `Err`.
+///
+/// Without this, an exception such as the `NSRangeException` raised by an out-of-bounds
+/// `objectAtIndex:` unwinds through Rust frames and aborts the process. [`try_msg_send!`]
+/// wraps a single message send in it.
+///
+/// Objects thrown with `@throw` that are not `NSException`s are returned as one all the same,
+/// so only the methods of `NSObject` can be relied upon for them. Throwing `nil` returns
+/// [`NSException::unreported`].
+///
+/// The exception unwinds the Rust frames between the throw and this call, running the
+/// destructors of their values. The Objective-C frames it unwinds are not exception-safe,
+/// so the objects they hold leak, and the receiver may be left in an inconsistent state.
+///
+/// # Safety
+///
+/// An exception must only unwind through `C-unwind` calls, so `f` must send the messages
+/// that may throw with [`send_message_unwind`] rather than `msg_send!`. Unwinding out of the
+/// `extern "C"` call that `msg_send!` makes is undefined behavior.
+///
+/// [`try_msg_send!`]: crate::try_msg_send
+pub unsafe fn catch_exception<R, F>(f: F) -> Result<R, NSException>
+where
+    F: FnOnce() -> R,
+{
+    extern "C-unwind" fn trampoline<R, F>(context: *mut c_void)
+    where
+        F: FnOnce() -> R,
+    {
+        let (f, result) = unsafe { &mut *(context as *mut (Option<F>, Option<R>)) };
+        *result = f.take().map(|f| f());
+    }
+
+    let mut context: (Option<F>, Option<R>) = (Some(f), None);
+    let mut exception: id = nil;
+
+    let thrown = rust_macios_try_catch(
+        trampoline::<R, F>,
+        &mut context as *mut _ as *mut c_void,
+        &mut exception,
+    );
+
+    match context.1 {
+        Some(result) if thrown == 0 => Ok(result),
+        _ => {
+            Err(NSException::try_from_retained_id(exception)
+                .unwrap_or_else(NSException::unreported))
+        }
+    }
+}
+
+/// Calls `f`, exiting the process with status 101 if it panics.
+///
+/// A panic must not unwind out of a method that Objective-C calls, so the methods that
+/// `#[protocol]` and `#[objc_sel]` declare, and the targets of actions, call the Rust code
+/// through this. The panic hook prints the message first, as for a panic in `main`. Panics
+/// caught before they reach the method are unaffected.
+pub fn exit_on_panic<R, F>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| process::exit(101))
+}
@@ -0,0 +1,539 @@
+use std::{
+    ffi::{c_void, CString},
+    fmt,
+    marker::PhantomData,
+    mem, ptr,
+};
+
+use crate::objective_c_runtime::msg_send;
+use libc::{c_char, c_long, c_ulong};
+use objc::{
+    class,
+    runtime::{Class, Object, Sel, BOOL, NO, YES},
+    sel, sel_impl,
+};
+
+use crate::foundation::{Int, NSInvocation, NSMethodSignature, UInt};
+
+use super::{
+    autoreleasepool,
+    encoding::{MethodEncoding, TypeEncoding},
+    id, register_class_for_type,
+    traits::{FromId, PNSObject, ToId},
+    ObjcValue,
+};
+
+#[cfg_attr(target_vendor = "apple", link(name = "Foundation", kind = "framework"))]
+extern "C" {
+    fn NSGetSizeAndAlignment(
+        type_ptr: *const c_char,
+        sizep: *mut UInt,
+        alignp: *mut UInt,
+    ) -> *const c_char;
+}
+
+/// The ivar of the proxy class that holds the target.
+static PROXY_TARGET_PTR: &str = "rstProxyTargetPtr";
+
+type Target = Box<dyn ForwardingTarget>;
+
+/// An argument or return value of a forwarded message, tagged with its type.
+///
+/// The variants are named after the [`TypeEncoding`] they are decoded from. Objects are
+/// not retained, so they are only valid for the duration of the message unless retained.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvocationValue {
+    /// The value of a method that returns `void`.
+    Void,
+    /// A `char`, which is also how `BOOL` is encoded on some targets.
+    Char(i8),
+    /// A `short`.
+    Short(i16),
+    /// An `int`.
+    Int(i32),
+    /// A `long`.
+    Long(c_long),
+    /// A `long long`.
+    LongLong(i64),
+    /// An `unsigned char`.
+    UChar(u8),
+    /// An `unsigned short`.
+    UShort(u16),
+    /// An `unsigned int`.
+    UInt(u32),
+    /// An `unsigned long`.
+    ULong(c_ulong),
+    /// An `unsigned long long`.
+    ULongLong(u64),
+    /// A `float`.
+    Float(f32),
+    /// A `double`.
+    Double(f64),
+    /// A C++ `bool` or C99 `_Bool`.
+    Bool(bool),
+    /// An object or a block, which may be `nil`.
+    Object(id),
+    /// A class object.
+    Class(*const Class),
+    /// A method selector.
+    Sel(Sel),
+    /// A C string.
+    String(*const c_char),
+    /// A pointer.
+    Pointer(*mut c_void),
+    /// Any other type, such as a structure, as the bytes of the value.
+    Other(TypeEncoding, Vec<u8>),
+}
+
+impl InvocationValue {
+    /// Creates an object value, autoreleasing the reference held by `object`.
+    pub fn from_object<T>(object: T) -> Self
+    where
+        T: PNSObject + FromId,
+    {
+        InvocationValue::Object(object.into_raw())
+    }
+
+    /// Returns the object held by an [`InvocationValue::Object`], or `None` for `nil` and
+    /// every other variant.
+    ///
+    /// # Safety
+    ///
+    /// The object must be an instance of `T`.
+    pub unsafe fn to_object<T>(&self) -> Option<T>
+    where
+        T: FromId,
+    {
+        match self {
+            InvocationValue::Object(object) => T::try_from_id(*object),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the value can be passed as the type `encoding`.
+    ///
+    /// Integers may be passed as any compatible encoding of their size, such as a `LongLong`
+    /// for the `l` that GNUstep encodes `NSInteger` as.
+    pub fn matches(&self, encoding: &TypeEncoding) -> bool {
+        use InvocationValue as V;
+        use TypeEncoding as T;
+
+        match (self, encoding.unqualified()) {
+            (V::Other(own, _), encoding) => own.is_compatible_with(encoding),
+            (V::Int(_), encoding) => T::Int.is_compatible_with(encoding),
+            (V::Long(_), encoding) => T::Long.is_compatible_with(encoding),
+            (V::LongLong(_), encoding) => T::LongLong.is_compatible_with(encoding),
+            (V::UInt(_), encoding) => T::UInt.is_compatible_with(encoding),
+            (V::ULong(_), encoding) => T::ULong.is_compatible_with(encoding),
+            (V::ULongLong(_), encoding) => T::ULongLong.is_compatible_with(encoding),
+            (V::Void, T::Void)
+            | (V::Char(_), T::Char)
+            | (V::Short(_), T::Short)
+            | (V::UChar(_), T::UChar)
+            | (V::UShort(_), T::UShort)
+            | (V::Float(_), T::Float)
+            | (V::Double(_), T::Double)
+            | (V::Bool(_), T::Bool)
+            | (V::Object(_), T::Object(_) | T::Block)
+            | (V::Class(_), T::Class)
+            | (V::Sel(_), T::Sel)
+            | (V::String(_), T::String)
+            | (V::Pointer(_), T::Pointer(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Decodes a value of type `encoding` from its bytes, falling back to
+    /// [`InvocationValue::Other`] when the size of `bytes` does not match the type.
+    pub fn from_bytes(encoding: &TypeEncoding, bytes: &[u8]) -> Self {
+        use InvocationValue as V;
+        use TypeEncoding as T;
+
+        fn read<U>(bytes: &[u8]) -> Option<U> {
+            (bytes.len() == mem::size_of::<U>())
+                .then(|| unsafe { ptr::read_unaligned(bytes.as_ptr() as *const U) })
+        }
+
+        let value = match encoding.unqualified() {
+            T::Void => bytes.is_empty().then_some(V::Void),
+            T::Char => read(bytes).map(V::Char),
+            T::Short => read(bytes).map(V::Short),
+            T::Int => read(bytes).map(V::Int),
+            T::Long => read(bytes).map(V::Long),
+            T::LongLong => read(bytes).map(V::LongLong),
+            T::UChar => read(bytes).map(V::UChar),
+            T::UShort => read(bytes).map(V::UShort),
+            T::UInt => read(bytes).map(V::UInt),
+            T::ULong => read(bytes).map(V::ULong),
+            T::ULongLong => read(bytes).map(V::ULongLong),
+            T::Float => read(bytes).map(V::Float),
+            T::Double => read(bytes).map(V::Double),
+            T::Bool => read::<u8>(bytes).map(|value| V::Bool(value != 0)),
+            T::Object(_) | T::Block => read(bytes).map(V::Object),
+            T::Class => read(bytes).map(V::Class),
+            T::Sel => read::<*const c_void>(bytes).map(|ptr| V::Sel(unsafe { Sel::from_ptr(ptr) })),
+            T::String => read(bytes).map(V::String),
+            T::Pointer(_) => read(bytes).map(V::Pointer),
+            _ => None,
+        };
+
+        value.unwrap_or_else(|| V::Other(encoding.clone(), bytes.to_vec()))
+    }
+
+    /// Returns the bytes of the value, which are empty for [`InvocationValue::Void`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        use InvocationValue as V;
+
+        fn bytes_of<U>(value: &U) -> Vec<u8> {
+            unsafe {
+                std::slice::from_raw_parts(value as *const U as *const u8, mem::size_of::<U>())
+            }
+            .to_vec()
+        }
+
+        match self {
+            V::Void => Vec::new(),
+            V::Char(value) => bytes_of(value),
+            V::Short(value) => bytes_of(value),
+            V::Int(value) => bytes_of(value),
+            V::Long(value) => bytes_of(value),
+            V::LongLong(value) => bytes_of(value),
+            V::UChar(value) => bytes_of(value),
+            V::UShort(value) => bytes_of(value),
+            V::UInt(value) => bytes_of(value),
+            V::ULong(value) => bytes_of(value),
+            V::ULongLong(value) => bytes_of(value),
+            V::Float(value) => bytes_of(value),
+            V::Double(value) => bytes_of(value),
+            V::Bool(value) => bytes_of(value),
+            V::Object(value) => bytes_of(value),
+            V::Class(value) => bytes_of(value),
+            V::Sel(value) => bytes_of(value),
+            V::String(value) => bytes_of(value),
+            V::Pointer(value) => bytes_of(value),
+            V::Other(_, bytes) => bytes.clone(),
+        }
+    }
+
+    /// Reads the argument at `index` of `invocation`, whose type is `encoding`.
+    unsafe fn read_argument(
+        invocation: &NSInvocation,
+        index: Int,
+        encoding: &TypeEncoding,
+    ) -> Self {
+        use InvocationValue as V;
+        use TypeEncoding as T;
+
+        match encoding.unqualified() {
+            T::Void => V::Void,
+            T::Char => V::Char(invocation.argument(index)),
+            T::Short => V::Short(invocation.argument(index)),
+            T::Int => V::Int(invocation.argument(index)),
+            T::Long => V::Long(invocation.argument(index)),
+            T::LongLong => V::LongLong(invocation.argument(index)),
+            T::UChar => V::UChar(invocation.argument(index)),
+            T::UShort => V::UShort(invocation.argument(index)),
+            T::UInt => V::UInt(invocation.argument(index)),
+            T::ULong => V::ULong(invocation.argument(index)),
+            T::ULongLong => V::ULongLong(invocation.argument(index)),
+            T::Float => V::Float(invocation.argument(index)),
+            T::Double => V::Double(invocation.argument(index)),
+            T::Bool => V::Bool(invocation.argument(index)),
+            T::Object(_) | T::Block => V::Object(invocation.argument(index)),
+            T::Class => V::Class(invocation.argument(index)),
+            T::Sel => V::Sel(invocation.argument(index)),
+            T::String => V::String(invocation.argument(index)),
+            T::Pointer(_) => V::Pointer(invocation.argument(index)),
+            other => {
+                let mut bytes = vec![0u8; size_of_encoding(other)];
+                let _: () = msg_send![
+                    invocation.m_self(),
+                    getArgument: bytes.as_mut_ptr() as *mut c_void
+                    atIndex: index
+                ];
+                V::Other(other.clone(), bytes)
+            }
+        }
+    }
+
+    /// Sets the value as the return value of `invocation`.
+    unsafe fn write_return_value(&self, invocation: &NSInvocation) {
+        use InvocationValue as V;
+
+        match self {
+            V::Void => {}
+            V::Char(value) => invocation.set_return_value(value),
+            V::Short(value) => invocation.set_return_value(value),
+            V::Int(value) => invocation.set_return_value(value),
+            V::Long(value) => invocation.set_return_value(value),
+            V::LongLong(value) => invocation.set_return_value(value),
+            V::UChar(value) => invocation.set_return_value(value),
+            V::UShort(value) => invocation.set_return_value(value),
+            V::UInt(value) => invocation.set_return_value(value),
+            V::ULong(value) => invocation.set_return_value(value),
+            V::ULongLong(value) => invocation.set_return_value(value),
+            V::Float(value) => invocation.set_return_value(value),
+            V::Double(value) => invocation.set_return_value(value),
+            V::Bool(value) => invocation.set_return_value(value),
+            V::Object(value) => invocation.set_return_value(value),
+            V::Class(value) => invocation.set_return_value(value),
+            V::Sel(value) => invocation.set_return_value(value),
+            V::String(value) => invocation.set_return_value(value),
+            V::Pointer(value) => invocation.set_return_value(value),
+            V::Other(_, bytes) => {
+                let _: () = msg_send![
+                    invocation.m_self(),
+                    setReturnValue: bytes.as_ptr() as *mut c_void
+                ];
+            }
+        }
+    }
+}
+
+/// Returns the size in bytes of a value of type `encoding`.
+fn size_of_encoding(encoding: &TypeEncoding) -> usize {
+    let types = CString::new(encoding.to_string()).unwrap();
+    let mut size: UInt = 0;
+
+    unsafe { NSGetSizeAndAlignment(types.as_ptr(), &mut size, std::ptr::null_mut()) };
+    size as usize
+}
+
+/// A message sent to a [`Proxy`], decoded from its `NSInvocation`.
+#[derive(Debug)]
+pub struct Invocation {
+    /// The selector of the message.
+    pub selector: Sel,
+    /// The arguments of the message, without the receiver and the selector.
+    pub arguments: Vec<InvocationValue>,
+    /// The type the method returns.
+    pub return_type: TypeEncoding,
+    /// The invocation the arguments were decoded from.
+    pub raw: NSInvocation,
+}
+
+impl Invocation {
+    /// Decodes the selector and the arguments of `invocation`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments of `invocation` must have been set.
+    pub unsafe fn from_ns_invocation(invocation: NSInvocation) -> Self {
+        let signature = invocation.method_signature();
+
+        Self {
+            selector: invocation.selector(),
+            arguments: (2..signature.number_of_arguments())
+                .map(|index| {
+                    let encoding = signature.get_argument_type_at_index(index);
+                    InvocationValue::read_argument(&invocation, index as Int, &encoding)
+                })
+                .collect(),
+            return_type: signature.method_return_type(),
+            raw: invocation,
+        }
+    }
+}
+
+/// The Rust side of a [`Proxy`], which receives every message sent to it.
+pub trait ForwardingTarget {
+    /// Returns the encoding of the method the proxy implements for `selector`, or `None`
+    /// if it does not respond to it.
+    ///
+    /// The arguments of the encoding start with the receiver (`@`) and the selector (`:`).
+    fn method_encoding(&self, selector: Sel) -> Option<MethodEncoding>;
+
+    /// Handles a message, returning the value the method returns.
+    ///
+    /// The value must match [`Invocation::return_type`], which is
+    /// [`InvocationValue::Void`] for methods that return `void`. A value that does not
+    /// match aborts the process, as the panic cannot unwind into the runtime.
+    fn forward_invocation(&self, invocation: &Invocation) -> InvocationValue;
+}
+
+extern "C" fn method_signature_for_selector(this: &Object, _: Sel, selector: Sel) -> id {
+    unsafe {
+        let target = &*(*this.get_ivar::<usize>(PROXY_TARGET_PTR) as *const Target);
+
+        target
+            .method_encoding(selector)
+            .and_then(|encoding| {
+                autoreleasepool(|pool| {
+                    NSMethodSignature::signature_with_objc_types(&encoding.to_string(), pool)
+                        .map(|signature| signature.retain())
+                })
+            })
+            .into_raw()
+    }
+}
+
+extern "C" fn forward_invocation(this: &Object, _: Sel, invocation: id) {
+    unsafe {
+        let target = &*(*this.get_ivar::<usize>(PROXY_TARGET_PTR) as *const Target);
+        let invocation = Invocation::from_ns_invocation(NSInvocation::from_id(invocation));
+
+        let value = target.forward_invocation(&invocation);
+        assert!(
+            value.matches(&invocation.return_type),
+            "the proxy returned {value:?} from a method returning {}",
+            invocation.return_type
+        );
+
+        value.write_return_value(&invocation.raw);
+    }
+}
+
+extern "C" fn responds_to_selector(this: &Object, _: Sel, selector: Sel) -> BOOL {
+    unsafe {
+        let target = &*(*this.get_ivar::<usize>(PROXY_TARGET_PTR) as *const Target);
+
+        if this.class().instance_method(selector).is_some()
+            || target.method_encoding(selector).is_some()
+        {
+            YES
+        } else {
+            NO
+        }
+    }
+}
+
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        let target_ptr: usize = *this.get_ivar(PROXY_TARGET_PTR);
+        drop(Box::from_raw(target_ptr as *mut Target));
+
+        let _: () = msg_send![super(this, class!(NSProxy)), dealloc];
+    }
+}
+
+fn proxy_class() -> &'static Class {
+    register_class_for_type::<Target, _>("RSTProxy", class!(NSProxy), |decl| unsafe {
+        decl.add_ivar::<usize>(PROXY_TARGET_PTR);
+
+        decl.add_method(
+            sel!(methodSignatureForSelector:),
+            method_signature_for_selector as extern "C" fn(&Object, Sel, Sel) -> id,
+        );
+        decl.add_method(
+            sel!(forwardInvocation:),
+            forward_invocation as extern "C" fn(&Object, Sel, id),
+        );
+        decl.add_method(
+            sel!(respondsToSelector:),
+            responds_to_selector as extern "C" fn(&Object, Sel, Sel) -> BOOL,
+        );
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(&mut Object, Sel));
+    })
+    .unwrap_or_else(|error| panic!("{error}"))
+}
+
+/// An `NSProxy` that responds to the messages its Rust [`ForwardingTarget`] handles.
+///
+/// Every message the proxy does not implement itself is decoded into an [`Invocation`]
+/// and handed to the target, which makes it useful for test doubles, remote proxies and
+/// delegates that multiplex to several objects. Messages the target has no encoding for
+/// raise `NSInvalidArgumentException`, like for any object.
+///
+/// ```no_run
+/// use rust_macios::objective_c_runtime::{
+///     encoding::MethodEncoding, msg_send, runtime::Sel, sel, sel_impl, traits::PNSObject,
+///     ForwardingTarget, Invocation, InvocationValue, Proxy,
+/// };
+///
+/// struct Doubler;
+///
+/// impl ForwardingTarget for Doubler {
+///     fn method_encoding(&self, selector: Sel) -> Option<MethodEncoding> {
+///         (selector == sel!(double:)).then(|| "q@:q".parse().unwrap())
+///     }
+///
+///     fn forward_invocation(&self, invocation: &Invocation) -> InvocationValue {
+///         match invocation.arguments[..] {
+///             [InvocationValue::LongLong(value)] => InvocationValue::LongLong(value * 2),
+///             _ => unreachable!(),
+///         }
+///     }
+/// }
+///
+/// let proxy = Proxy::new(Doubler);
+/// let doubled: i64 = unsafe { msg_send![proxy.m_self(), double: 21_i64] };
+/// ```
+pub struct Proxy<T> {
+    ptr: id,
+    target: *const T,
+    marker: PhantomData<T>,
+}
+
+impl<T> Proxy<T>
+where
+    T: ForwardingTarget + 'static,
+{
+    /// Creates a proxy that forwards messages to `target`.
+    pub fn new(target: T) -> Self {
+        let target = Box::new(target);
+        let target_ptr: *const T = &*target;
+        let target: Target = target;
+
+        unsafe {
+            let ptr: id = msg_send![proxy_class(), alloc];
+            (*ptr).set_ivar::<usize>(PROXY_TARGET_PTR, Box::into_raw(Box::new(target)) as usize);
+
+            Self {
+                ptr,
+                target: target_ptr,
+                marker: PhantomData,
+            }
+        }
+    }
+}
+
+impl<T> Proxy<T> {
+    /// Returns the target of the proxy.
+    pub fn target(&self) -> &T {
+        unsafe { &*self.target }
+    }
+}
+
+impl<T> PNSObject for Proxy<T> {
+    fn m_class<'a>() -> &'a Class {
+        proxy_class()
+    }
+
+    fn m_self(&self) -> id {
+        self.ptr
+    }
+}
+
+impl<T> ToId for Proxy<T> {
+    fn to_id(self) -> id {
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { msg_send![this.ptr, autorelease] }
+    }
+}
+
+impl<T> Clone for Proxy<T> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: unsafe { msg_send![self.ptr, retain] },
+            target: self.target,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for Proxy<T> {
+    fn drop(&mut self) {
+        unsafe { msg_send![self.ptr, release] }
+    }
+}
+
+impl<T> fmt::Debug for Proxy<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proxy")
+            .field("target", self.target())
+            .finish()
+    }
+}
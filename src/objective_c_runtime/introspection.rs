@@ -0,0 +1,339 @@
+use std::{
+    ffi::{c_char, c_uint, CStr},
+    fmt::Write,
+    slice,
+};
+
+use objc::runtime::{self, Class, Ivar, Method, Protocol, Sel, BOOL, NO, YES};
+
+use super::encoding::{MethodEncoding, PropertyAttributes, TypeEncoding};
+
+#[repr(C)]
+struct Property {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+struct MethodDescription {
+    name: Sel,
+    types: *const c_char,
+}
+
+extern "C" {
+    fn class_copyPropertyList(class: *const Class, count: *mut c_uint) -> *mut *const Property;
+    fn property_getName(property: *const Property) -> *const c_char;
+    fn property_getAttributes(property: *const Property) -> *const c_char;
+    fn method_getTypeEncoding(method: *const Method) -> *const c_char;
+    fn protocol_copyMethodDescriptionList(
+        protocol: *const Protocol,
+        is_required: BOOL,
+        is_instance: BOOL,
+        count: *mut c_uint,
+    ) -> *mut MethodDescription;
+}
+
+/// Copies a list returned by one of the runtime's `copy...List` functions and frees it.
+unsafe fn copy_list<T, U>(
+    copy: impl FnOnce(*mut c_uint) -> *mut T,
+    map: impl FnMut(&T) -> U,
+) -> Vec<U> {
+    let mut count: c_uint = 0;
+    let list = copy(&mut count);
+
+    if list.is_null() {
+        return Vec::new();
+    }
+
+    let items = slice::from_raw_parts(list, count as usize)
+        .iter()
+        .map(map)
+        .collect();
+    libc::free(list as *mut _);
+
+    items
+}
+
+unsafe fn to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+/// A method implemented by a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodInfo {
+    /// The method's selector, such as `initWithFrame:`.
+    pub name: String,
+    /// The method's type encoding as the runtime reports it, with stack offsets.
+    pub raw_encoding: String,
+    /// The decoded type encoding, or `None` if the runtime's encoding could not be parsed.
+    pub encoding: Option<MethodEncoding>,
+}
+
+impl MethodInfo {
+    pub(super) fn new(method: &Method) -> Self {
+        let raw_encoding = unsafe { to_string(method_getTypeEncoding(method)) };
+
+        Self {
+            name: method.name().name().to_string(),
+            encoding: raw_encoding.parse().ok(),
+            raw_encoding,
+        }
+    }
+}
+
+/// An instance variable declared by a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IvarInfo {
+    /// The instance variable's name.
+    pub name: String,
+    /// The offset of the instance variable from the start of the object, in bytes.
+    pub offset: isize,
+    /// The instance variable's type encoding as the runtime reports it.
+    pub raw_encoding: String,
+    /// The decoded type encoding, or `None` if the runtime's encoding could not be parsed.
+    pub encoding: Option<TypeEncoding>,
+}
+
+impl IvarInfo {
+    fn new(ivar: &Ivar) -> Self {
+        let raw_encoding = unsafe { to_string(runtime::ivar_getTypeEncoding(ivar)) };
+
+        Self {
+            name: ivar.name().to_string(),
+            offset: ivar.offset(),
+            encoding: raw_encoding.parse().ok(),
+            raw_encoding,
+        }
+    }
+}
+
+/// A property declared by a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyInfo {
+    /// The property's name.
+    pub name: String,
+    /// The property's attributes as the runtime reports them.
+    pub raw_attributes: String,
+    /// The decoded attributes, or `None` if the runtime's attributes could not be parsed.
+    pub attributes: Option<PropertyAttributes>,
+}
+
+/// A method declared by a protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolMethodInfo {
+    /// The method's selector.
+    pub name: String,
+    /// The method's type encoding as the runtime reports it.
+    pub raw_encoding: String,
+    /// The decoded type encoding, or `None` if the runtime's encoding could not be parsed.
+    pub encoding: Option<MethodEncoding>,
+    /// Whether conforming classes must implement the method, rather than it being
+    /// `@optional`.
+    pub required: bool,
+    /// Whether the method is an instance method, rather than a class method.
+    pub instance: bool,
+}
+
+/// Lists what a class implements, such as its methods and instance variables.
+///
+/// Implemented for the [`Class`] returned by
+/// [`PNSObject::m_class`](super::traits::PNSObject::m_class).
+/// Only the class's own declarations are listed, not those it inherits.
+pub trait ClassIntrospection {
+    /// The instance methods the class implements.
+    fn methods(&self) -> Vec<MethodInfo>;
+
+    /// The class methods the class implements.
+    fn class_methods(&self) -> Vec<MethodInfo>;
+
+    /// The instance variables the class declares.
+    fn ivars(&self) -> Vec<IvarInfo>;
+
+    /// The properties the class declares.
+    fn properties(&self) -> Vec<PropertyInfo>;
+
+    /// The protocols the class adopts.
+    fn protocols(&self) -> Vec<&'static Protocol>;
+
+    /// The class followed by its superclasses, ending with the root class.
+    fn class_hierarchy(&self) -> Vec<&Class>;
+}
+
+impl ClassIntrospection for Class {
+    fn methods(&self) -> Vec<MethodInfo> {
+        unsafe {
+            copy_list(
+                |count| runtime::class_copyMethodList(self, count),
+                |&method| MethodInfo::new(&*method),
+            )
+        }
+    }
+
+    fn class_methods(&self) -> Vec<MethodInfo> {
+        self.metaclass().methods()
+    }
+
+    fn ivars(&self) -> Vec<IvarInfo> {
+        unsafe {
+            copy_list(
+                |count| runtime::class_copyIvarList(self, count),
+                |&ivar| IvarInfo::new(&*ivar),
+            )
+        }
+    }
+
+    fn properties(&self) -> Vec<PropertyInfo> {
+        unsafe {
+            copy_list(
+                |count| class_copyPropertyList(self, count),
+                |&property| {
+                    let raw_attributes = to_string(property_getAttributes(property));
+
+                    PropertyInfo {
+                        name: to_string(property_getName(property)),
+                        attributes: raw_attributes.parse().ok(),
+                        raw_attributes,
+                    }
+                },
+            )
+        }
+    }
+
+    fn protocols(&self) -> Vec<&'static Protocol> {
+        unsafe {
+            copy_list(
+                |count| runtime::class_copyProtocolList(self, count),
+                |&protocol| &*protocol,
+            )
+        }
+    }
+
+    fn class_hierarchy(&self) -> Vec<&Class> {
+        let mut classes = vec![self];
+
+        while let Some(superclass) = classes[classes.len() - 1].superclass() {
+            classes.push(superclass);
+        }
+
+        classes
+    }
+}
+
+/// Lists the methods a protocol declares.
+pub trait ProtocolIntrospection {
+    /// The methods the protocol itself declares, required and optional, excluding those of
+    /// the protocols it adopts.
+    fn method_descriptions(&self) -> Vec<ProtocolMethodInfo>;
+}
+
+impl ProtocolIntrospection for Protocol {
+    fn method_descriptions(&self) -> Vec<ProtocolMethodInfo> {
+        let mut methods = Vec::new();
+
+        for (required, instance) in [(true, true), (false, true), (true, false), (false, false)] {
+            let to_bool = |value| if value { YES } else { NO };
+
+            methods.extend(unsafe {
+                copy_list(
+                    |count| {
+                        protocol_copyMethodDescriptionList(
+                            self,
+                            to_bool(required),
+                            to_bool(instance),
+                            count,
+                        )
+                    },
+                    |description| {
+                        let raw_encoding = to_string(description.types);
+
+                        ProtocolMethodInfo {
+                            name: description.name.name().to_string(),
+                            encoding: raw_encoding.parse().ok(),
+                            raw_encoding,
+                            required,
+                            instance,
+                        }
+                    },
+                )
+            });
+        }
+
+        methods
+    }
+}
+
+/// Returns every class registered with the runtime.
+pub fn class_list() -> Vec<&'static Class> {
+    unsafe { copy_list(|count| runtime::objc_copyClassList(count), |&class| &*class) }
+}
+
+/// Returns a description of everything `class` declares, laid out like an `@interface`.
+///
+/// Each declaration is followed by its type encoding, which is printed as the runtime
+/// reports it.
+///
+/// ```no_run
+/// use rust_macios::{
+///     foundation::NSString,
+///     objective_c_runtime::{dump_class, traits::PNSObject},
+/// };
+///
+/// println!("{}", dump_class(NSString::m_class()));
+/// ```
+pub fn dump_class(class: &Class) -> String {
+    let mut dump = format!("@interface {}", class.name());
+
+    if let Some(superclass) = class.superclass() {
+        let _ = write!(dump, " : {}", superclass.name());
+    }
+
+    let protocols = class.protocols();
+
+    if !protocols.is_empty() {
+        let names = protocols
+            .iter()
+            .map(|protocol| protocol.name())
+            .collect::<Vec<_>>();
+        let _ = write!(dump, " <{}>", names.join(", "));
+    }
+
+    dump.push('\n');
+
+    let ivars = class.ivars();
+
+    if !ivars.is_empty() {
+        dump.push_str("{\n");
+
+        for ivar in ivars {
+            let _ = writeln!(
+                dump,
+                "    {} ({}, offset {})",
+                ivar.name, ivar.raw_encoding, ivar.offset
+            );
+        }
+
+        dump.push_str("}\n");
+    }
+
+    for property in class.properties() {
+        let _ = writeln!(
+            dump,
+            "@property {} ({})",
+            property.name, property.raw_attributes
+        );
+    }
+
+    for method in class.class_methods() {
+        let _ = writeln!(dump, "+ {} ({})", method.name, method.raw_encoding);
+    }
+
+    for method in class.methods() {
+        let _ = writeln!(dump, "- {} ({})", method.name, method.raw_encoding);
+    }
+
+    dump.push_str("@end");
+    dump
+}
@@ -1,20 +1,74 @@
+use crate::objective_c_runtime::msg_send;
+use objc::{
+    runtime::{Class, BOOL, NO},
+    sel, sel_impl,
+};
+
+use super::id;
+
 pub use rust_macios_objective_c_runtime_proc_macros::*;
 
+/// Panics if `ptr` is not an instance of the class named `class_name` or of a class that
+/// inherits from it.
+///
+/// Used by the `from_id` of [`object!`] types in debug builds. Names that are not classes,
+/// such as protocols, are not checked.
+#[doc(hidden)]
+pub unsafe fn assert_kind_of_class(ptr: id, class_name: &str) {
+    let Some(class) = Class::get(class_name) else {
+        return;
+    };
+
+    if ptr.is_null() {
+        return;
+    }
+
+    let is_kind_of_class: BOOL = msg_send![ptr, isKindOfClass: class];
+    assert!(
+        is_kind_of_class != NO,
+        "expected an instance of {}, found an instance of {}",
+        class_name,
+        (*ptr).class().name()
+    );
+}
+
 /// The given name must be a valid Objective-C class that inherits NSObject.
+///
+/// A superclass may follow the name, as in `unsafe pub struct NSButton: NSControl;`, which
+/// implements [`Subclass`](crate::objective_c_runtime::traits::Subclass) so that the type
+/// picks up the traits of its superclasses.
+///
+/// Zero-sized `PhantomData` fields may follow in braces instead of the semicolon, as in
+/// `unsafe pub struct NSMenu { main_thread: PhantomData<MainThreadMarker> }`, which makes
+/// the type neither `Send` nor `Sync` and implements `MainThreadOnly` in place of
+/// [`AnyThread`](crate::objective_c_runtime::traits::AnyThread).
 #[macro_export]
 macro_rules! object {
     (
         $(#[$m:meta])*
-        unsafe $v:vis struct $name:ident $(;)?
+        unsafe $v:vis struct $name:ident $(: $super:ty)? $(;)?
     ) => {
         object! {
             $(#[$m])*
-            unsafe $v struct $name<> {,}
+            unsafe $v struct $name<> $(: $super)? {,}
         }
     };
     (
         $(#[$m:meta])*
-        unsafe $v:vis struct $name:ident<$($t:ident $(: $b:ident)?),*> {
+        unsafe $v:vis struct $name:ident $(: $super:ty)? {
+            $($p:ident: $pty:ty),*$(,)+
+        }
+    ) => {
+        object! {
+            $(#[$m])*
+            unsafe $v struct $name<> $(: $super)? {
+                $($p: $pty),*,
+            }
+        }
+    };
+    (
+        $(#[$m:meta])*
+        unsafe $v:vis struct $name:ident<$($t:ident $(: $b:ident)?),*> $(: $super:ty)? {
             $($p:ident: $pty:ty),*$(,)+
         }
     ) => {
@@ -61,24 +115,39 @@ macro_rules! object {
         }
 
         impl<$($t $(: $b)?),*> $crate::objective_c_runtime::traits::ToId for $name<$($t),*> {
-            fn to_id(mut self) -> $crate::objective_c_runtime::id {
-                &mut *self.ptr
+            fn to_id(self) -> $crate::objective_c_runtime::id {
+                use $crate::objective_c_runtime::{traits::PNSObject, msg_send, sel, sel_impl};
+
+                // Hand our reference to the pool instead of releasing it.
+                let this = std::mem::ManuallyDrop::new(self);
+                unsafe { msg_send![this.m_self(), autorelease] }
             }
         }
 
         impl<$($t $(: $b)?),*> $crate::objective_c_runtime::traits::FromId for $name<$($t),*> {
             unsafe fn from_id(ptr: $crate::objective_c_runtime::id) -> Self {
+                if cfg!(debug_assertions) {
+                    $crate::objective_c_runtime::macros::assert_kind_of_class(ptr, stringify!($name));
+                }
+
                 Self {
                     ptr: $crate::objective_c_runtime::Id::from_ptr(ptr),
-                     $($p: std::marker::PhantomData),*
+                    $($p: std::marker::PhantomData),*
+                }
+            }
+
+            unsafe fn from_retained_id(ptr: $crate::objective_c_runtime::id) -> Self {
+                Self {
+                    ptr: $crate::objective_c_runtime::Id::from_retained_ptr(ptr),
+                    $($p: std::marker::PhantomData),*
                 }
             }
         }
 
         impl<$($t $(: $b)?),*> Clone for $name<$($t),*> {
             fn clone(&self) -> Self {
-                use $crate::objective_c_runtime::{traits::{FromId, PNSObject}, msg_send, sel, sel_impl};
-                unsafe { Self::from_id(msg_send![self.m_self(), retain]) }
+                use $crate::objective_c_runtime::traits::{FromId, PNSObject};
+                unsafe { Self::from_id(self.m_self()) }
             }
         }
 
@@ -103,6 +172,26 @@ macro_rules! object {
                 unsafe { $crate::objective_c_runtime::Encoding::from_str("@") }
             }
         }
+
+        object! {
+            @subclass [$($t $(: $b)?),*] $name [$($t),*] $($super)?
+        }
+
+        object! {
+            @thread [$($t $(: $b)?),*] $name [$($t),*] [$($p)*]
+        }
+    };
+    (@thread [$($g:tt)*] $name:ident [$($a:tt)*] [main_thread]) => {
+        impl<$($g)*> $crate::appkit::MainThreadOnly for $name<$($a)*> {}
+    };
+    (@thread [$($g:tt)*] $name:ident [$($a:tt)*] [$($p:ident)*]) => {
+        unsafe impl<$($g)*> $crate::objective_c_runtime::traits::AnyThread for $name<$($a)*> {}
+    };
+    (@subclass [$($g:tt)*] $name:ident [$($a:tt)*]) => {};
+    (@subclass [$($g:tt)*] $name:ident [$($a:tt)*] $super:ty) => {
+        unsafe impl<$($g)*> $crate::objective_c_runtime::traits::Subclass for $name<$($a)*> {
+            type Super = $super;
+        }
     };
 }
 
@@ -159,24 +248,38 @@ macro_rules! shared_object {
 
         impl<$($t $(: $b)?),*> $crate::objective_c_runtime::traits::ToId for $name<$($t),*> {
             fn to_id(self) -> $crate::objective_c_runtime::id {
-                use $crate::objective_c_runtime::traits::PNSObject;
-                self.m_self()
+                use $crate::objective_c_runtime::{traits::PNSObject, msg_send, sel, sel_impl};
+
+                // Hand our reference to the pool instead of releasing it.
+                let this = std::mem::ManuallyDrop::new(self);
+                unsafe { msg_send![this.m_self(), autorelease] }
             }
         }
 
         impl<$($t $(: $b)?),*> $crate::objective_c_runtime::traits::FromId for $name<$($t),*> {
             unsafe fn from_id(ptr: $crate::objective_c_runtime::id) -> Self {
+                if cfg!(debug_assertions) {
+                    $crate::objective_c_runtime::macros::assert_kind_of_class(ptr, stringify!($name));
+                }
+
                 Self {
                     ptr: $crate::objective_c_runtime::Id::from_ptr(ptr),
                     $($p: std::marker::PhantomData),*
                 }
             }
+
+            unsafe fn from_retained_id(ptr: $crate::objective_c_runtime::id) -> Self {
+                Self {
+                    ptr: $crate::objective_c_runtime::Id::from_retained_ptr(ptr),
+                    $($p: std::marker::PhantomData),*
+                }
+            }
         }
 
         impl<$($t $(: $b)?),*> Clone for $name<$($t),*> {
             fn clone(&self) -> Self {
-                use $crate::objective_c_runtime::{traits::{FromId, PNSObject}, msg_send, sel, sel_impl};
-                unsafe { Self::from_id(msg_send![self.m_self(), retain]) }
+                use $crate::objective_c_runtime::traits::{FromId, PNSObject};
+                unsafe { Self::from_id(self.m_self()) }
             }
         }
 
@@ -196,5 +299,70 @@ macro_rules! shared_object {
             }
         }
 
+        unsafe impl<$($t $(: $b)?),*> $crate::objective_c_runtime::traits::AnyThread for $name<$($t),*> {}
     };
 }
+
+/// Sends a message like `msg_send!`, returning `Err` with the `NSException` if the method
+/// throws one instead of aborting the process.
+///
+/// Like `msg_send!`, it must be called in an `unsafe` block with `sel` and `sel_impl` in
+/// scope. The receiver and the arguments are evaluated before the message is sent with
+/// [`send_message_unwind`](crate::objective_c_runtime::send_message_unwind) inside
+/// [`catch_exception`](crate::objective_c_runtime::catch_exception), so the return type must
+/// be an [`UnwindReturn`](crate::objective_c_runtime::UnwindReturn) rather than a structure.
+///
+/// ```no_run
+/// use rust_macios::{
+///     foundation::{NSArray, NSString},
+///     objective_c_runtime::{id, sel, sel_impl, traits::PNSObject},
+///     try_msg_send,
+/// };
+///
+/// let array = NSArray::<NSString>::new();
+/// let result: Result<id, _> = unsafe { try_msg_send![array.m_self(), objectAtIndex: 1u64] };
+/// assert!(result.is_err());
+/// ```
+#[macro_export]
+macro_rules! try_msg_send {
+    ($obj:expr, $name:ident) => ({
+        let sel = sel!($name);
+        let receiver = ::std::ptr::addr_of!(*$obj);
+        $crate::objective_c_runtime::catch_exception(move || {
+            $crate::objective_c_runtime::send_message_unwind(receiver, sel, ())
+        })
+    });
+    ($obj:expr, $($name:ident : $arg:expr)+) => ({
+        let sel = sel!($($name:)+);
+        let receiver = ::std::ptr::addr_of!(*$obj);
+        let arguments = ($($arg,)*);
+        $crate::objective_c_runtime::catch_exception(move || {
+            $crate::objective_c_runtime::send_message_unwind(receiver, sel, arguments)
+        })
+    });
+}
+
+/// Returns whether the running OS is at least the version given for its platform, as in
+/// `available!(macos = "12.0", ios = "15.0")`.
+///
+/// The platforms are `macos`, `ios`, `tvos` and `watchos`, and the ones not listed are
+/// unavailable. Malformed requirements fail to compile. The OS version is read once, see
+/// [`check_availability`](crate::objective_c_runtime::check_availability).
+///
+/// ```no_run
+/// use rust_macios::available;
+///
+/// if available!(macos = "11.0", ios = "14.0") {
+///     // Send messages that macOS 11 and iOS 14 introduced.
+/// }
+/// ```
+#[macro_export]
+macro_rules! available {
+    ($($platform:ident = $version:literal),+ $(,)?) => {{
+        const REQUIREMENTS: &[$crate::objective_c_runtime::Requirement] = &[
+            $($crate::objective_c_runtime::Requirement::new(stringify!($platform), $version)),+
+        ];
+
+        $crate::objective_c_runtime::check_availability(REQUIREMENTS).is_ok()
+    }};
+}
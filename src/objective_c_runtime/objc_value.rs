@@ -0,0 +1,160 @@
+use crate::objective_c_runtime::msg_send;
+use block::{Block, BlockArguments, RcBlock};
+use objc::{runtime::Sel, sel, sel_impl, Encode};
+
+use super::{
+    id, nil,
+    traits::{FromId, PNSObject},
+};
+
+/// A Rust type that can be passed to or returned from an Objective-C method.
+///
+/// Plain values such as `bool`, numbers and `#[derive(Encode)]` structs cross the boundary
+/// as they are. Objects cross it as `id`: arguments are retained for as long as the Rust
+/// value lives, and returned objects are autoreleased, as the caller does not own them.
+/// `Option` of an object maps `None` to `nil`. Blocks, such as completion handlers, cross
+/// as [`RcBlock`].
+///
+/// The methods of a `#[protocol]` take these, so a delegate cannot take a `String`:
+///
+/// ```compile_fail
+/// use rust_macios::objective_c_runtime::protocol;
+///
+/// #[protocol(class = "RMSearchDelegate", register = register_search_delegate_class, ivar = "rmSearchDelegatePtr")]
+/// pub trait PSearchDelegate {
+///     #[objc_sel("searchFieldDidChange:text:")]
+///     fn did_change(&self, text: String) {
+///         let _ = text;
+///     }
+/// }
+/// ```
+///
+/// The `#[objc_sel]` methods of a `#[register_class]` type take the raw `Encode` values
+/// instead, which do not include a `String` either:
+///
+/// ```compile_fail
+/// use rust_macios::objective_c_runtime::{objc_sel, register_class, runtime::Object, ShareId};
+///
+/// struct Greeter {
+///     ptr: ShareId<Object>,
+/// }
+///
+/// #[register_class(NSObject)]
+/// impl Greeter {
+///     #[objc_sel("greet:")]
+///     fn greet(&self, name: String) {
+///         let _ = name;
+///     }
+/// }
+/// ```
+pub trait ObjcValue: Sized {
+    /// The type the Objective-C runtime passes.
+    type Raw: Encode;
+
+    /// Converts a value received from the runtime.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid value of `Self`, such as a live object of the right class.
+    unsafe fn from_raw(raw: Self::Raw) -> Self;
+
+    /// Converts the value into one to hand back to the runtime.
+    fn into_raw(self) -> Self::Raw;
+}
+
+impl<T> ObjcValue for T
+where
+    T: FromId + PNSObject,
+{
+    type Raw = id;
+
+    unsafe fn from_raw(raw: id) -> Self {
+        T::from_id(raw)
+    }
+
+    fn into_raw(self) -> id {
+        // `self` releases its reference when dropped, so the caller gets an autoreleased one.
+        unsafe {
+            let object: id = msg_send![self.m_self(), retain];
+            msg_send![object, autorelease]
+        }
+    }
+}
+
+impl<T> ObjcValue for Option<T>
+where
+    T: FromId + PNSObject,
+{
+    type Raw = id;
+
+    unsafe fn from_raw(raw: id) -> Self {
+        if raw.is_null() {
+            None
+        } else {
+            Some(T::from_id(raw))
+        }
+    }
+
+    fn into_raw(self) -> id {
+        self.map_or(nil, ObjcValue::into_raw)
+    }
+}
+
+impl<A, R> ObjcValue for RcBlock<A, R>
+where
+    A: BlockArguments,
+{
+    type Raw = id;
+
+    unsafe fn from_raw(raw: id) -> Self {
+        // Copying moves a stack block, such as a completion handler, to the heap.
+        RcBlock::copy(raw as *mut Block<A, R>)
+    }
+
+    fn into_raw(self) -> id {
+        unsafe {
+            let block: id = msg_send![&*self as *const Block<A, R> as id, retain];
+            msg_send![block, autorelease]
+        }
+    }
+}
+
+/// Implements [`ObjcValue`] for types the runtime passes as they are.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_objc_value {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl $crate::objective_c_runtime::ObjcValue for $t {
+                type Raw = Self;
+
+                unsafe fn from_raw(raw: Self) -> Self {
+                    raw
+                }
+
+                fn into_raw(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+__impl_objc_value!(
+    (),
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    id,
+    Sel,
+);
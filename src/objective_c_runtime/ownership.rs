@@ -0,0 +1,237 @@
+use std::{cell::UnsafeCell, fmt, marker::PhantomData, mem::ManuallyDrop, ops::Deref};
+
+use objc::{
+    runtime::{objc_copyWeak, objc_destroyWeak, objc_initWeak, objc_loadWeakRetained},
+    sel, sel_impl,
+};
+
+use crate::objective_c_runtime::msg_send;
+
+use super::{
+    id, nil,
+    traits::{FromId, PNSObject},
+    AutoreleasePool,
+};
+
+extern "C" {
+    fn objc_storeWeak(location: *mut id, object: id) -> id;
+}
+
+/// An object that `alloc` returned and no `init` message has initialized yet.
+///
+/// It is not a `T` until it is initialized, so it has no methods of `T`. Send it an `init`
+/// message with [`Allocated::init`], or with [`Allocated::into_raw`] for the others.
+/// Dropping it releases the memory.
+pub struct Allocated<T> {
+    ptr: id,
+    marker: PhantomData<T>,
+}
+
+impl<T> Allocated<T> {
+    /// Takes over the result of `alloc`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a non-nil, uninitialized instance of the class of `T` that the caller
+    /// owns.
+    pub unsafe fn new(ptr: id) -> Self {
+        Self {
+            ptr,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the reference for an `init` message, which consumes it and returns the
+    /// initialized object, or `nil` after releasing it.
+    pub fn into_raw(self) -> id {
+        ManuallyDrop::new(self).ptr
+    }
+
+    /// Initializes the object with `init`.
+    pub fn init(self) -> T
+    where
+        T: FromId,
+    {
+        unsafe { T::from_retained_id(msg_send![self.into_raw(), init]) }
+    }
+}
+
+impl<T> Drop for Allocated<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![self.ptr, release];
+        }
+    }
+}
+
+impl<T> fmt::Debug for Allocated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Allocated").field(&self.ptr).finish()
+    }
+}
+
+/// A reference the caller does not own, as returned by class factories such as
+/// `stringWithString:` and by methods that derive a new object, such as `uppercaseString`.
+/// It stays valid until the autorelease pool `'pool` drains.
+///
+/// The types `object!` declares own the reference they hold, like the results of methods
+/// in the `new`, `alloc`, `copy`, `mutableCopy` and `init` families. This one does not:
+/// dropping it does nothing, and [`Autoreleased::retain`] keeps the object beyond the pool.
+pub struct Autoreleased<'pool, T> {
+    // Never dropped, as the reference belongs to the pool.
+    object: ManuallyDrop<T>,
+    pool: PhantomData<&'pool ()>,
+}
+
+impl<'pool, T> Autoreleased<'pool, T>
+where
+    T: FromId,
+{
+    /// Wraps a +0 reference, such as the result of a getter, without retaining it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a non-nil object of class `T` that stays alive until `pool` drains,
+    /// which holds when it was autoreleased into `pool` or is owned by an object that is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pool` is not the innermost pool.
+    pub unsafe fn new(ptr: id, pool: &'pool AutoreleasePool) -> Self {
+        pool.assert_innermost();
+        Self::from_ptr(ptr)
+    }
+
+    /// Wraps a +0 reference like [`Autoreleased::new`], or returns `None` if it is `nil`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be `nil`, or an object of class `T` that stays alive until `pool` drains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pool` is not the innermost pool.
+    pub unsafe fn try_new(ptr: id, pool: &'pool AutoreleasePool) -> Option<Self> {
+        pool.assert_innermost();
+        (!ptr.is_null()).then(|| Self::from_ptr(ptr))
+    }
+
+    unsafe fn from_ptr(ptr: id) -> Self {
+        Self {
+            object: ManuallyDrop::new(T::from_retained_id(ptr)),
+            pool: PhantomData,
+        }
+    }
+
+    /// Retains the object, so that it outlives the pool.
+    pub fn retain(&self) -> T
+    where
+        T: PNSObject,
+    {
+        unsafe { T::from_id(self.object.m_self()) }
+    }
+}
+
+impl<T> Clone for Autoreleased<'_, T>
+where
+    T: FromId + PNSObject,
+{
+    fn clone(&self) -> Self {
+        unsafe { Self::from_ptr(self.object.m_self()) }
+    }
+}
+
+impl<T> Deref for Autoreleased<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.object
+    }
+}
+
+impl<T> fmt::Debug for Autoreleased<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.object.fmt(f)
+    }
+}
+
+/// A reference that does not keep the object alive, and becomes empty when the object
+/// deallocates.
+///
+/// Use it for back-pointers, such as from a delegate to the object it is the delegate of,
+/// which would otherwise form a retain cycle.
+pub struct Weak<T> {
+    // The runtime tracks the location, so it must not move.
+    location: Box<UnsafeCell<id>>,
+    marker: PhantomData<T>,
+}
+
+impl<T> Weak<T> {
+    /// Creates a reference to `object` that does not retain it.
+    pub fn new(object: &T) -> Self
+    where
+        T: PNSObject,
+    {
+        let weak = Self::default();
+        unsafe { objc_initWeak(weak.location.get(), object.m_self()) };
+        weak
+    }
+
+    /// Makes the reference point to `object`, or to nothing if `object` is `None`.
+    pub fn set(&self, object: Option<&T>)
+    where
+        T: PNSObject,
+    {
+        let object = object.map_or(nil, PNSObject::m_self);
+        unsafe { objc_storeWeak(self.location.get(), object) };
+    }
+
+    /// Returns a strong reference to the object, or `None` if it was deallocated.
+    pub fn upgrade(&self) -> Option<T>
+    where
+        T: FromId,
+    {
+        unsafe {
+            let ptr = objc_loadWeakRetained(self.location.get());
+
+            if ptr.is_null() {
+                None
+            } else {
+                Some(T::from_retained_id(ptr))
+            }
+        }
+    }
+}
+
+impl<T> Default for Weak<T> {
+    /// Creates a reference that points to nothing.
+    fn default() -> Self {
+        Self {
+            location: Box::new(UnsafeCell::new(nil)),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        let weak = Self::default();
+        unsafe { objc_copyWeak(weak.location.get(), self.location.get()) };
+        weak
+    }
+}
+
+impl<T> Drop for Weak<T> {
+    fn drop(&mut self) {
+        unsafe { objc_destroyWeak(self.location.get()) };
+    }
+}
+
+impl<T> fmt::Debug for Weak<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(Weak)")
+    }
+}
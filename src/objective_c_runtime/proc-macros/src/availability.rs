@@ -0,0 +1,217 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Error, ImplItemMethod, Lit, LitStr, Meta,
+    NestedMeta, ReturnType, Stmt, Type,
+};
+
+const PLATFORMS: &[(&str, &str)] = &[
+    ("macos", "macOS"),
+    ("ios", "iOS"),
+    ("tvos", "tvOS"),
+    ("watchos", "watchOS"),
+];
+
+/// The requirements of an `#[available(macos = "12.0", ios = "15.0")]` method.
+pub struct Availability {
+    requirements: Vec<(String, LitStr)>,
+}
+
+/// Returns whether the attribute is `#[available]`, which is consumed by `interface_impl`.
+pub fn is_available(attr: &Attribute) -> bool {
+    attr.path.is_ident("available")
+}
+
+impl Availability {
+    /// Parses the `#[available]` attribute among `attrs`, if there is one.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Option<Self>> {
+        let attr = match attrs.iter().find(|attr| is_available(attr)) {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        let list = match attr.parse_meta()? {
+            Meta::List(list) if !list.nested.is_empty() => list,
+            meta => {
+                return Err(Error::new(
+                    meta.span(),
+                    "expected requirements such as `#[available(macos = \"12.0\")]`",
+                ))
+            }
+        };
+
+        let mut requirements = Vec::new();
+
+        for nested in list.nested {
+            let (platform, version) = match nested {
+                NestedMeta::Meta(Meta::NameValue(value)) => match value.lit {
+                    Lit::Str(version) => (value.path, version),
+                    lit => return Err(Error::new(lit.span(), "expected a version string")),
+                },
+                nested => {
+                    return Err(Error::new(
+                        nested.span(),
+                        "expected a requirement such as `macos = \"12.0\"`",
+                    ))
+                }
+            };
+
+            let name = platform
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .filter(|name| PLATFORMS.iter().any(|(platform, _)| platform == name))
+                .ok_or_else(|| {
+                    Error::new(
+                        platform.span(),
+                        "expected one of the platforms `macos`, `ios`, `tvos` and `watchos`",
+                    )
+                })?;
+
+            if !is_version(&version.value()) {
+                return Err(Error::new(
+                    version.span(),
+                    "expected a version such as `12.0` or `10.15.4`",
+                ));
+            }
+
+            requirements.push((name, version));
+        }
+
+        Ok(Some(Self { requirements }))
+    }
+
+    /// Documents the requirements and what the method returns without them.
+    pub fn doc(&self, output: &ReturnType) -> TokenStream {
+        let versions = self
+            .requirements
+            .iter()
+            .map(|(platform, version)| {
+                let name = PLATFORMS.iter().find(|(p, _)| p == platform).unwrap().1;
+                format!("{name} {} or later", version.value())
+            })
+            .collect::<Vec<_>>();
+
+        let otherwise = match wrapper(output) {
+            Some(Wrapper::Option) => "`None`",
+            _ => "an [`Unavailable`](crate::objective_c_runtime::Unavailable) error",
+        };
+
+        let doc = format!(
+            " Requires {}, and returns {otherwise} without sending the message on older systems.",
+            versions.join(" or ")
+        );
+
+        quote! {
+            #[doc = ""]
+            #[doc = #doc]
+        }
+    }
+
+    /// Guards the body of the method with a check of the running OS version, wrapping its
+    /// value in `Some` or `Ok`.
+    pub fn guard(&self, output: &ReturnType, body: &[Stmt]) -> TokenStream {
+        let requirements = self.requirements.iter().map(|(platform, version)| {
+            quote! {
+                rust_macios::objective_c_runtime::Requirement::new(#platform, #version)
+            }
+        });
+
+        let check = quote! {
+            const REQUIREMENTS: &[rust_macios::objective_c_runtime::Requirement] = &[
+                #(#requirements),*
+            ];
+        };
+
+        match wrapper(output) {
+            Some(Wrapper::Option) => quote! {
+                #check
+
+                if rust_macios::objective_c_runtime::check_availability(REQUIREMENTS).is_err() {
+                    return None;
+                }
+
+                Some({ #(#body)* })
+            },
+            Some(Wrapper::Result) => quote! {
+                #check
+
+                if let Err(unavailable) = rust_macios::objective_c_runtime::check_availability(REQUIREMENTS) {
+                    return Err(::core::convert::From::from(unavailable));
+                }
+
+                Ok({ #(#body)* })
+            },
+            None => Error::new(
+                match output {
+                    ReturnType::Default => Span::call_site(),
+                    ReturnType::Type(_, ty) => ty.span(),
+                },
+                "methods marked `#[available]` must return an `Option`, or a `Result` whose error converts from `Unavailable`",
+            )
+            .to_compile_error(),
+        }
+    }
+}
+
+enum Wrapper {
+    Option,
+    Result,
+}
+
+fn wrapper(output: &ReturnType) -> Option<Wrapper> {
+    let path = match output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => &path.path,
+            _ => return None,
+        },
+        ReturnType::Default => return None,
+    };
+
+    match path.segments.last()?.ident.to_string().as_str() {
+        "Option" => Some(Wrapper::Option),
+        "Result" => Some(Wrapper::Result),
+        _ => None,
+    }
+}
+
+fn is_version(version: &str) -> bool {
+    let parts = version.split('.').collect::<Vec<_>>();
+
+    parts.len() <= 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+pub fn available(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attr = TokenStream::from(attr);
+    let input = parse_macro_input!(item as ImplItemMethod);
+
+    let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[available(#attr)])];
+    let availability = match Availability::from_attrs(&attrs) {
+        Ok(availability) => availability.unwrap(),
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let ImplItemMethod {
+        attrs,
+        vis,
+        defaultness,
+        sig,
+        block,
+    } = &input;
+    let doc = availability.doc(&sig.output);
+    let body = availability.guard(&sig.output, &block.stmts);
+
+    quote! {
+        #(#attrs)*
+        #doc
+        #vis #defaultness #sig {
+            #body
+        }
+    }
+    .into()
+}
@@ -1,160 +1,297 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, FnArg, ImplItemMethod, ItemImpl, Pat};
-
-#[derive(Debug)]
-struct ExportAttributes {
-    super_class_name: String,
-    protocol: Option<String>,
+use syn::{
+    braced, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Field, ImplItemMethod, ItemImpl, LitStr, Token,
+};
+
+use crate::selector::{self, Selector};
+
+/// The arguments of `#[register_class(...)]`.
+///
+/// ```text
+/// #[register_class(
+///     NSObject,
+///     protocols(NSTableViewDataSource, NSTableViewDelegate),
+///     ivars { row_count: i64 },
+///     name = "TableController",
+/// )]
+/// ```
+///
+/// Protocols may also be listed bare after the superclass, as in
+/// `#[register_class(NSObject, NSApplicationDelegate)]`.
+struct ClassAttributes {
+    super_class: Ident,
+    protocols: Vec<Ident>,
+    ivars: Vec<Field>,
+    name: Option<LitStr>,
 }
 
-impl ExportAttributes {
-    pub fn new(attributes: &str) -> Self {
-        let mut vals = attributes.split(',');
+impl Parse for ClassAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attributes = Self {
+            super_class: input.parse()?,
+            protocols: Vec::new(),
+            ivars: Vec::new(),
+            name: None,
+        };
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            if input.is_empty() {
+                break;
+            }
 
-        Self {
-            super_class_name: vals.next().unwrap().trim().to_string(),
-            protocol: vals.next().map(|val| val.trim().to_string()),
+            let key: Ident = input.parse()?;
+
+            if key == "protocols" && input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                let protocols = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                attributes.protocols.extend(protocols);
+            } else if key == "ivars" && input.peek(syn::token::Brace) {
+                let content;
+                braced!(content in input);
+                let ivars = content.parse_terminated::<_, Token![,]>(Field::parse_named)?;
+                attributes.ivars.extend(ivars);
+            } else if key == "name" && input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                attributes.name = Some(input.parse()?);
+            } else if input.is_empty() || input.peek(Token![,]) {
+                attributes.protocols.push(key);
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "expected a protocol, `protocols(...)`, `ivars { ... }` or `name = \"...\"`",
+                ));
+            }
         }
+
+        Ok(attributes)
     }
 }
 
 pub fn register_class(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let attributes = ExportAttributes::new(&attr.to_string());
+    let attributes = parse_macro_input!(attr as ClassAttributes);
 
     let input = parse_macro_input!(item as ItemImpl);
     let input_type = input.self_ty.clone();
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
 
-    let super_class_name = Ident::new(&attributes.super_class_name, Span::call_site());
-    let protocol_name = attributes
-        .protocol
-        .map(|val| Ident::new(&val, Span::call_site()));
+    let super_class_name = &attributes.super_class;
+    let protocol_names = attributes
+        .protocols
+        .iter()
+        .map(|protocol| protocol.to_string());
 
-    let protocol_block = if let Some(protocol_name) = protocol_name {
-        quote! {
-            {
-            let p = Protocol::get(stringify!(#protocol_name)).unwrap();
-            decl.add_protocol(p);
-            }
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    let mut methods = Vec::new();
+
+    for item in &input.items {
+        let method = match item {
+            syn::ImplItem::Method(method) => method,
+            _ => continue,
+        };
+
+        let result = if find_attr(method, &["class_init", "selector_init"]).is_some() {
+            check_init(method).map(|()| {
+                quote! {
+                    decl.add_method(
+                        sel!(init),
+                        <#input_type>::generated_init as extern "C" fn(&Object, Sel) -> id,
+                    );
+                }
+            })
+        } else if let Some(attr) = find_attr(method, &["objc_sel", "selector_impl"]) {
+            attr.parse_args::<Selector>().and_then(|selector| {
+                selector.check_method(method)?;
+
+                let sel = selector.to_sel();
+                let fn_name = gen_sel_fn_name(method);
+                let fn_type = gen_sel_fn_type(method, &selector);
+
+                Ok(if selector.is_class_method() {
+                    quote! {
+                        decl.add_class_method(#sel, <#input_type>::#fn_name as #fn_type);
+                    }
+                } else {
+                    quote! {
+                        decl.add_method(#sel, <#input_type>::#fn_name as #fn_type);
+                    }
+                })
+            })
+        } else {
+            continue;
+        };
+
+        match result {
+            Ok(add_method) => methods.push(add_method),
+            Err(error) => push_error(error),
         }
-    } else {
-        quote! {
-            {}
+    }
+
+    // Each instantiation of a generic type gets its own class, so a fixed name would collide.
+    if let Some(name) = attributes
+        .name
+        .as_ref()
+        .filter(|_| !input.generics.params.is_empty())
+    {
+        push_error(syn::Error::new(
+            name.span(),
+            "a generic type needs a class per instantiation and cannot use a fixed `name`",
+        ));
+    }
+
+    for ivar in &attributes.ivars {
+        if ivar
+            .ident
+            .as_ref()
+            .is_some_and(|ident| ident == "RUST_OBJ_PTR")
+        {
+            push_error(syn::Error::new(
+                ivar.span(),
+                "`RUST_OBJ_PTR` is reserved for the pointer to the Rust object",
+            ));
         }
+    }
+
+    if let Some(errors) = errors {
+        return errors.to_compile_error().into();
+    }
+
+    // A fixed name belongs to one type, so another class of that name is a collision. Other
+    // classes are named after the type by the registry, which renames them if two names clash.
+    let register = match &attributes.name {
+        Some(name) => quote! {
+            static CLASS: std::sync::OnceLock<&'static Class> =
+                std::sync::OnceLock::new();
+
+            CLASS.get_or_init(|| {
+                let mut decl = ClassDecl::new(#name, superclass).unwrap_or_else(|| {
+                    panic!(
+                        "the class `{}` of `{}` is already declared",
+                        #name,
+                        std::any::type_name::<Self>()
+                    )
+                });
+                declare(&mut decl);
+                decl.register()
+            })
+        },
+        None => quote! {
+            rust_macios::objective_c_runtime::register_class_for_type::<Self, _>(
+                "RUST",
+                superclass,
+                declare,
+            )
+            .unwrap_or_else(|error| panic!("{error}"))
+        },
     };
 
-    let methods = input
-        .items
-        .iter()
-        .filter_map(|val| match val {
-            syn::ImplItem::Method(val) => {
-                if val.attrs.is_empty() {
-                    return None;
-                }
+    let ivar_decls = attributes.ivars.iter().map(|ivar| {
+        let name = ivar.ident.as_ref().unwrap().to_string();
+        let ty = &ivar.ty;
 
-                let path = val.attrs[0].path.clone();
-                if path.is_ident("selector_init") {
-                    let add_method = quote! {
-                        decl.add_method(
-                            rust_macios::objective_c_runtime::sel!(init),
-                            #input_type::generated_init as extern "C" fn(&Object, _) -> id,
-                        );
-                    };
-                    Some(add_method)
-                } else if path.is_ident("selector_impl") {
-                    let objc_delegate = val.attrs[0].tokens.to_string();
-                    let objc_delegate = get_objc_full_sel(&objc_delegate);
-                    let sel_ts: proc_macro2::TokenStream = objc_delegate.parse().unwrap();
-                    let fn_name = gen_sel_fn_name(val);
-                    let fn_type = gen_sel_fn_type(val);
-
-                    let add_method = quote! {
-                        decl.add_method(
-                            rust_macios::objective_c_runtime::sel!(#sel_ts),
-                            #input_type::#fn_name as #fn_type,
-                        );
-                    };
-                    Some(add_method)
-                } else {
-                    None
-                }
+        quote! {
+            decl.add_ivar::<#ty>(#name);
+        }
+    });
+
+    let ivar_accessors = attributes.ivars.iter().map(|ivar| {
+        let ident = ivar.ident.as_ref().unwrap();
+        let name = ident.to_string();
+        let getter = format_ident!("ivar_{}", ident);
+        let setter = format_ident!("set_ivar_{}", ident);
+        let vis = &ivar.vis;
+        let ty = &ivar.ty;
+        let docs = ivar
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .collect::<Vec<_>>();
+
+        quote! {
+            #(#docs)*
+            #vis fn #getter(&self) -> #ty {
+                let this = <Self as rust_macios::objective_c_runtime::traits::PNSObject>::m_self(self);
+                unsafe { *(*this).get_ivar::<#ty>(#name) }
             }
-            _ => None,
-        })
-        .collect::<Vec<_>>();
 
-    let dealloc_method = quote! {
-        decl.add_method(
-            rust_macios::objective_c_runtime::sel!(dealloc),
-            #input_type::generated_dealloc as extern "C" fn(&rust_macios::objective_c_runtime::runtime::Object, _),
-        );
-    };
+            #(#docs)*
+            #vis fn #setter(&self, value: #ty) {
+                let this = <Self as rust_macios::objective_c_runtime::traits::PNSObject>::m_self(self);
+                unsafe { (*this).set_ivar::<#ty>(#name, value) }
+            }
+        }
+    });
 
-    let input_type_string = quote! {
-        #input_type
-    }
-    .to_string();
-    let objc_class_ident = format_ident!("RUST_{}", input_type_string);
     quote! {
-        impl #input_type {
+        impl #impl_generics #input_type #where_clause {
             pub fn register_class() -> &'static rust_macios::objective_c_runtime::runtime::Class {
-                use std::sync::Once;
-
                 use rust_macios::objective_c_runtime::{
                     class,
                     declare::ClassDecl,
-                    msg_send,
+                    id,
                     runtime::{Class, Object, Protocol, Sel},
                     sel, sel_impl,
                 };
 
-                static INIT: Once = Once::new();
-
-                INIT.call_once(|| unsafe {
-                    let class_name = stringify!(#objc_class_ident);
-
-                    let superclass = class!(#super_class_name);
-                    let mut decl = ClassDecl::new(class_name, superclass).unwrap();
-
+                let superclass = class!(#super_class_name);
+                let declare = |decl: &mut ClassDecl| unsafe {
                     decl.add_ivar::<usize>("RUST_OBJ_PTR");
 
-                    #protocol_block
+                    #(#ivar_decls)*
 
-                    #(#methods)*
+                    #(
+                        decl.add_protocol(Protocol::get(#protocol_names).expect(concat!(
+                            "protocol `",
+                            #protocol_names,
+                            "` is not known to the Objective-C runtime"
+                        )));
+                    )*
 
-                    #dealloc_method
+                    #(#methods)*
 
-                    decl.register();
-                });
+                    decl.add_method(
+                        sel!(dealloc),
+                        <#input_type>::generated_dealloc as extern "C" fn(&Object, Sel),
+                    );
+                };
 
-                Class::get(stringify!(#objc_class_ident)).unwrap()
+                #register
             }
 
             pub fn init_objc_proxy_obj(self: std::sync::Arc<Self>) -> *mut rust_macios::objective_c_runtime::runtime::Object {
-                use rust_macios::objective_c_runtime::{
-                    class,
-                    declare::ClassDecl,
-                    msg_send,
-                    runtime::{Class, Object, Protocol, Sel},
-                    sel, sel_impl,
-                };
+                use rust_macios::objective_c_runtime::{msg_send, runtime::Object, sel, sel_impl};
 
-                let class = #input_type::register_class();
+                let class = <#input_type>::register_class();
                 let objc_object = unsafe {
-                    let ret: *mut rust_macios::objective_c_runtime::runtime::Object = msg_send![class, new];
+                    let ret: *mut Object = msg_send![class, new];
                     ret
                 };
                 let raw_ptr = std::sync::Arc::into_raw(self);
                 let raw_ptr_value = raw_ptr as usize;
 
                 unsafe {
-                    (&mut *objc_object).set_ivar("RUST_OBJ_PTR", raw_ptr_value);
+                    (*objc_object).set_ivar("RUST_OBJ_PTR", raw_ptr_value);
                 }
 
                 objc_object
             }
 
+            #(#ivar_accessors)*
+
             extern "C" fn generated_dealloc(this: &rust_macios::objective_c_runtime::runtime::Object, _: rust_macios::objective_c_runtime::runtime::Sel) {
                 let arc = unsafe {
                     let raw_ptr_value: usize = *this.get_ivar("RUST_OBJ_PTR");
@@ -165,47 +302,80 @@ pub fn register_class(attr: TokenStream, item: TokenStream) -> TokenStream {
             }
         }
 
-        impl PNSObject for #input_type {
+        impl #impl_generics rust_macios::objective_c_runtime::traits::PNSObject for #input_type #where_clause {
             fn m_class<'a>() -> &'a rust_macios::objective_c_runtime::runtime::Class {
                 Self::register_class()
             }
 
-            fn m_self(&self) -> id {
+            fn m_self(&self) -> rust_macios::objective_c_runtime::id {
+                use rust_macios::objective_c_runtime::{msg_send, sel, sel_impl};
+
                 unsafe { msg_send![&*self.ptr, self] }
             }
         }
 
-
-        unsafe impl rust_macios::objective_c_runtime::Message for #input_type { }
+        unsafe impl #impl_generics rust_macios::objective_c_runtime::Message for #input_type #where_clause { }
 
         #input
     }
     .into()
 }
 
-pub fn sel_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn sel_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let selector = parse_macro_input!(attr as Selector);
     let input = parse_macro_input!(item as ImplItemMethod);
-    let gen_sig = gen_sel_fn(&input);
 
-    let method_name = input.sig.ident.clone();
-    let args = gen_sel_fn_args(&input);
-    let body = quote! {
-        {
-            let arc = unsafe {
-                let raw_ptr_value: usize = *this.get_ivar("RUST_OBJ_PTR");
-                let raw_ptr = raw_ptr_value as *const Self;
-                std::sync::Arc::from_raw(raw_ptr)
-            };
+    if let Err(error) = selector.check_method(&input) {
+        return error.to_compile_error().into();
+    }
+
+    let name = gen_sel_fn_name(&input);
+    let vis = &input.vis;
+    let method_name = &input.sig.ident;
+    let return_type = &input.sig.output;
+    let encode_assertions = selector::assert_encode(&input);
+
+    let arg_names = (0..selector.arity())
+        .map(|index| format_ident!("arg{}", index))
+        .collect::<Vec<_>>();
+    let arg_types = selector::argument_types(&input.sig);
+
+    let generated = if selector.is_class_method() {
+        quote! {
+            #vis extern "C" fn #name(
+                _class: &rust_macios::objective_c_runtime::runtime::Class,
+                _sel: rust_macios::objective_c_runtime::runtime::Sel,
+                #(#arg_names: #arg_types,)*
+            ) #return_type {
+                #encode_assertions
+
+                rust_macios::objective_c_runtime::exit_on_panic(|| Self::#method_name(#(#arg_names,)*))
+            }
+        }
+    } else {
+        quote! {
+            #vis extern "C" fn #name(
+                this: &rust_macios::objective_c_runtime::runtime::Object,
+                _sel: rust_macios::objective_c_runtime::runtime::Sel,
+                #(#arg_names: #arg_types,)*
+            ) #return_type {
+                #encode_assertions
+
+                // The object keeps the `Arc` alive until `dealloc`, so only borrow it here.
+                let rust_obj = unsafe {
+                    let raw_ptr_value: usize = *this.get_ivar("RUST_OBJ_PTR");
+                    &*(raw_ptr_value as *const Self)
+                };
 
-            arc.#method_name(#(#args,)*)
+                rust_macios::objective_c_runtime::exit_on_panic(|| rust_obj.#method_name(#(#arg_names,)*))
+            }
         }
     };
 
     quote! {
         #input
 
-         #gen_sig
-            #body
+        #generated
     }
     .into()
 }
@@ -213,23 +383,31 @@ pub fn sel_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn impl_init(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ImplItemMethod);
 
+    if let Err(error) = check_init(&input) {
+        return error.to_compile_error().into();
+    }
+
     let method_name = input.sig.ident.clone();
 
     quote! {
         #input
 
-        extern "C" fn generated_init(this: &Object, _: rust_macios::objective_c_runtime::runtime::Sel) -> rust_macios::objective_c_runtime::id {
+        extern "C" fn generated_init(this: &rust_macios::objective_c_runtime::runtime::Object, _: rust_macios::objective_c_runtime::runtime::Sel) -> rust_macios::objective_c_runtime::id {
             use rust_macios::objective_c_runtime::{
-                class, declare::ClassDecl,
                 msg_send,
-                runtime::{Class, Object, Protocol, Sel},
+                runtime::Object,
                 sel, sel_impl,
             };
 
             let initialized_obj = Self::#method_name();
 
+            // The superclass of the declared class, as `this` may be an instance of a subclass.
+            let superclass = Self::register_class()
+                .superclass()
+                .expect("declared classes have a superclass");
+
             let objc_object = unsafe {
-                let ret: *mut rust_macios::objective_c_runtime::runtime::Object = msg_send![super(this, class!(NSObject)), init];
+                let ret: *mut Object = msg_send![super(this, superclass), init];
                 ret
             };
             let arc = std::sync::Arc::new(initialized_obj);
@@ -237,58 +415,46 @@ pub fn impl_init(_attr: TokenStream, item: TokenStream) -> TokenStream {
             let raw_ptr_value = raw_ptr as usize;
 
             unsafe {
-                (&mut *objc_object).set_ivar("RUST_OBJ_PTR", raw_ptr_value);
+                (*objc_object).set_ivar("RUST_OBJ_PTR", raw_ptr_value);
             }
 
             objc_object
         }
-    }.into()
+    }
+    .into()
 }
 
-fn get_objc_full_sel(token: &str) -> &str {
-    token
-        .trim_start_matches('(')
-        .trim_start_matches('"')
-        .trim_end_matches(')')
-        .trim_end_matches('"')
+/// Returns the first attribute of `method` named one of `names`.
+fn find_attr<'a>(method: &'a ImplItemMethod, names: &[&str]) -> Option<&'a Attribute> {
+    method
+        .attrs
+        .iter()
+        .find(|attr| names.iter().any(|name| attr.path.is_ident(name)))
 }
 
-fn gen_sel_fn(input: &ImplItemMethod) -> proc_macro2::TokenStream {
-    let name = gen_sel_fn_name(input);
-
-    let vis = input.vis.clone();
-    let args: Vec<_> = input
-        .sig
-        .inputs
-        .clone()
-        .into_iter()
-        .skip_while(|arg| match arg {
-            FnArg::Receiver(_) => true,
-            FnArg::Typed(_) => false,
-        })
-        .collect();
-    let return_type = input.sig.output.clone();
-
-    quote! {
-        #vis extern "C" fn #name(this: &rust_macios::objective_c_runtime::runtime::Object, _sel: rust_macios::objective_c_runtime::runtime::Sel,  #(#args,)*) #return_type
+fn check_init(method: &ImplItemMethod) -> syn::Result<()> {
+    if method.sig.inputs.is_empty() {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            method.sig.inputs.span(),
+            "`#[class_init]` functions must not take any arguments",
+        ))
     }
 }
 
-fn gen_sel_fn_type(input: &ImplItemMethod) -> proc_macro2::TokenStream {
-    let args: Vec<_> = input
-        .sig
-        .inputs
-        .clone()
-        .into_iter()
-        .skip_while(|arg| match arg {
-            FnArg::Receiver(_) => true,
-            FnArg::Typed(_) => false,
-        })
-        .collect();
-    let return_type = input.sig.output.clone();
+fn gen_sel_fn_type(input: &ImplItemMethod, selector: &Selector) -> proc_macro2::TokenStream {
+    let arg_types = selector::argument_types(&input.sig);
+    let return_type = &input.sig.output;
+
+    let receiver = if selector.is_class_method() {
+        quote!(&rust_macios::objective_c_runtime::runtime::Class)
+    } else {
+        quote!(&rust_macios::objective_c_runtime::runtime::Object)
+    };
 
     quote! {
-        extern "C" fn(this: &rust_macios::objective_c_runtime::runtime::Object, _sel: rust_macios::objective_c_runtime::runtime::Sel,  #(#args,)*) #return_type
+        extern "C" fn(#receiver, rust_macios::objective_c_runtime::runtime::Sel, #(#arg_types,)*) #return_type
     }
 }
 
@@ -298,20 +464,3 @@ fn gen_sel_fn_name(input: &ImplItemMethod) -> proc_macro2::Ident {
 
     Ident::new(&new_name, Span::call_site())
 }
-
-fn gen_sel_fn_args(input: &ImplItemMethod) -> Vec<Pat> {
-    input
-        .sig
-        .inputs
-        .clone()
-        .into_iter()
-        .skip_while(|arg| match arg {
-            FnArg::Receiver(_) => true,
-            FnArg::Typed(_) => false,
-        })
-        .map(|val| match val {
-            FnArg::Receiver(_) => unreachable!(),
-            FnArg::Typed(val) => *val.pat,
-        })
-        .collect()
-}
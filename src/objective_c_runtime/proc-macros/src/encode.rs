@@ -0,0 +1,155 @@
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, Lit, Meta,
+    NestedMeta,
+};
+
+/// The integer types an enum may be represented as. The enum is encoded as that type.
+const INTEGER_REPRS: &[&str] = &[
+    "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+
+pub fn derive_encode(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "`#[derive(Encode)]` does not support generic types",
+        ));
+    }
+
+    let reprs = reprs(&input.attrs)?;
+    let name = encoding_name(&input.attrs)?.unwrap_or_else(|| ident.to_string());
+
+    let encoding = match &input.data {
+        Data::Struct(data) => {
+            require_repr_c(input, &reprs)?;
+            let fields = field_encodings(&data.fields);
+            quote! {
+                rust_macios::objective_c_runtime::encoding::TypeEncoding::structure(#name, [#(#fields),*])
+            }
+        }
+        Data::Union(data) => {
+            require_repr_c(input, &reprs)?;
+            let fields = field_encodings(&Fields::Named(data.fields.clone()));
+            quote! {
+                rust_macios::objective_c_runtime::encoding::TypeEncoding::union(#name, [#(#fields),*])
+            }
+        }
+        Data::Enum(_) => {
+            let repr = reprs
+                .iter()
+                .find(|repr| INTEGER_REPRS.contains(&repr.to_string().as_str()))
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        ident.span(),
+                        "`#[derive(Encode)]` on an enum requires an integer `#[repr]`, such as `#[repr(u64)]`",
+                    )
+                })?;
+            quote! {
+                <#repr as rust_macios::objective_c_runtime::encoding::EncodeType>::type_encoding()
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl rust_macios::objective_c_runtime::encoding::EncodeType for #ident {
+            fn type_encoding() -> rust_macios::objective_c_runtime::encoding::TypeEncoding {
+                #encoding
+            }
+        }
+
+        rust_macios::__impl_runtime_encode!(#ident);
+    })
+}
+
+fn field_encodings(fields: &Fields) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            quote_spanned! { ty.span() =>
+                <#ty as rust_macios::objective_c_runtime::encoding::EncodeType>::type_encoding()
+            }
+        })
+        .collect()
+}
+
+/// Collects the idents inside every `#[repr(...)]` attribute.
+fn reprs(attrs: &[Attribute]) -> syn::Result<Vec<syn::Ident>> {
+    let mut reprs = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested {
+                if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                    if let Some(ident) = path.get_ident() {
+                        reprs.push(ident.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(reprs)
+}
+
+fn require_repr_c(input: &DeriveInput, reprs: &[syn::Ident]) -> syn::Result<()> {
+    if reprs
+        .iter()
+        .any(|repr| repr == "C" || repr == "transparent")
+    {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            input.ident.span(),
+            "`#[derive(Encode)]` requires `#[repr(C)]` so that the layout matches the encoding",
+        ))
+    }
+}
+
+/// Reads the name override from `#[encoding(name = "...")]`.
+fn encoding_name(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let attr = match attrs.iter().find(|attr| attr.path.is_ident("encoding")) {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+
+    let list = match attr.parse_meta()? {
+        Meta::List(list) => list,
+        meta => {
+            return Err(syn::Error::new(
+                meta.span(),
+                "expected `#[encoding(name = \"...\")]`",
+            ))
+        }
+    };
+
+    let name_value = match list.nested.into_iter().next() {
+        Some(NestedMeta::Meta(Meta::NameValue(name_value))) if name_value.path.is_ident("name") => {
+            name_value
+        }
+        nested => {
+            return Err(syn::Error::new(
+                nested.span(),
+                "unknown `encoding` option, expected `name = \"...\"`",
+            ))
+        }
+    };
+
+    match name_value.lit {
+        Lit::Str(name) => Ok(Some(name.value())),
+        lit => Err(syn::Error::new(lit.span(), "expected a string literal")),
+    }
+}
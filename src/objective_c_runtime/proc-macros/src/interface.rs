@@ -3,10 +3,16 @@ use proc_macro2::{Ident, Span};
 use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, spanned::Spanned, FnArg, ImplItemMethod, ItemImpl, Signature};
 
+use crate::{
+    availability::{is_available, Availability},
+    ownership,
+};
+
 #[derive(Debug)]
 struct ExportAttributes {
     super_class_name: String,
     is_super_class_generic: bool,
+    subclasses: bool,
 }
 
 impl ExportAttributes {
@@ -40,6 +46,7 @@ impl ExportAttributes {
         Self {
             super_class_name: vals.next().unwrap().trim().to_string(),
             is_super_class_generic,
+            subclasses: vals.any(|val| val.trim() == "subclasses"),
         }
     }
 }
@@ -153,15 +160,23 @@ pub fn interface_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let trait_doc = format!("A trait containing all the methods for [`{input_type}`]");
 
     match input.generics.params.is_empty() {
-        true => quote! {
-            #type_impl
+        true => {
+            let subclasses = attributes
+                .subclasses
+                .then(|| subclass_impls(&input_type, &trait_name));
+
+            quote! {
+                #type_impl
 
-            #[doc = #trait_doc]
-            #type_trait
+                #[doc = #trait_doc]
+                #type_trait
 
-            impl #trait_name for #input_type {}
+                impl #trait_name for #input_type {}
+
+                #subclasses
+            }
+            .into()
         }
-        .into(),
         false => {
             let generic_params = input.generics.params.iter();
             let input_type_generic = generic_params.clone();
@@ -182,6 +197,76 @@ pub fn interface_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 }
 
+/// Implements the interface trait for the subclasses of the type, which also convert into
+/// it with `From` and with the `as_<name>` method of an `As<Type>` trait.
+fn subclass_impls(
+    input_type: &proc_macro2::TokenStream,
+    trait_name: &Ident,
+) -> proc_macro2::TokenStream {
+    let type_name = input_type.to_string();
+    let as_trait = Ident::new(&format!("As{type_name}"), Span::call_site());
+    let as_method = Ident::new(
+        &format!("as_{}", snake_case(unprefixed(&type_name))),
+        Span::call_site(),
+    );
+    let as_trait_doc = format!("Upcasts the implementors of [`{trait_name}`] to [`{type_name}`].");
+    let as_method_doc = format!("Returns the object as an [`{type_name}`].");
+
+    quote! {
+        impl<T> #trait_name for T
+        where
+            T: rust_macios::objective_c_runtime::traits::Subclass,
+            T::Super: #trait_name,
+        {
+        }
+
+        impl<T> From<T> for #input_type
+        where
+            T: rust_macios::objective_c_runtime::traits::Subclass,
+            T::Super: #trait_name,
+        {
+            fn from(object: T) -> Self {
+                use rust_macios::objective_c_runtime::traits::{FromId, PNSObject};
+                unsafe { Self::from_id(object.m_self()) }
+            }
+        }
+
+        #[doc = #as_trait_doc]
+        pub trait #as_trait: #trait_name {
+            #[doc = #as_method_doc]
+            fn #as_method(&self) -> #input_type {
+                use rust_macios::objective_c_runtime::traits::FromId;
+                unsafe { #input_type::from_id(self.m_self()) }
+            }
+        }
+
+        impl<T> #as_trait for T where T: #trait_name {}
+    }
+}
+
+/// Strips the framework prefix of a class name, as in `NSView` to `View`.
+fn unprefixed(name: &str) -> &str {
+    let prefix = name
+        .char_indices()
+        .take_while(|(_, c)| c.is_ascii_uppercase())
+        .last()
+        .map_or(0, |(index, _)| index);
+
+    &name[prefix..]
+}
+
+fn snake_case(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .fold(String::new(), |mut snake, (index, c)| {
+            if c.is_ascii_uppercase() && index > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+            snake
+        })
+}
+
 /// This method is for extracting the method data from the [`ImplItemMethod`] and parsing a new
 /// method replacing the body to contain a method call to the Interface's trait method which matches the name of the method
 /// excluding the prefix "m_".
@@ -228,11 +313,7 @@ fn method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
         })
         .collect::<Vec<_>>();
 
-    let attrs = input
-        .attrs
-        .iter()
-        .filter(|attr| !attr.path.is_ident("method"))
-        .collect::<Vec<_>>();
+    let attrs = copied_attrs(input, "method");
 
     match input.sig.receiver() {
         Some(receiver) => match receiver {
@@ -349,7 +430,7 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
 
     let generic_params = generics.params;
 
-    let body = input.block.stmts.clone();
+    let body = guarded_body(input);
 
     let mut fn_args: Vec<FnArg> = vec![];
 
@@ -382,11 +463,7 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
         })
         .collect::<Vec<_>>();
 
-    let attrs = input
-        .attrs
-        .iter()
-        .filter(|attr| !attr.path.is_ident("method"))
-        .collect::<Vec<_>>();
+    let attrs = copied_attrs(input, "method");
 
     match input.sig.receiver() {
         Some(receiver) => match receiver {
@@ -396,13 +473,13 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                         Some(clause) => quote! {
                             #(#attrs)*
                             fn #name(&mut self, #(#arg_name: #arg_type),* ) #return_type #clause {
-                                #(#body)*
+                                #body
                             }
                         },
                         None => quote! {
                             #(#attrs)*
                             fn #name(&mut self, #(#arg_name : #arg_type),* ) #return_type {
-                                #(#body)*
+                                #body
                             }
                         },
                     },
@@ -412,13 +489,13 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                             Some(clause) => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&mut self, #(#arg_name: #arg_type),* ) #return_type #clause {
-                                    #(#body)*
+                                    #body
                                 }
                             },
                             None => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&mut self, #(#arg_name : #arg_type),* ) #return_type {
-                                    #(#body)*
+                                    #body
                                 }
                             },
                         }
@@ -429,13 +506,13 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                         Some(clause) => quote! {
                             #(#attrs)*
                             fn #name(&self, #(#arg_name : #arg_type),*) #return_type #clause {
-                               #(#body)*
+                               #body
                             }
                         },
                         None => quote! {
                             #(#attrs)*
                             fn #name(&self, #(#arg_name : #arg_type),*) #return_type {
-                               #(#body)*
+                               #body
                             }
                         },
                     },
@@ -446,13 +523,13 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                             Some(clause) => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&self, #(#arg_name : #arg_type),*) #return_type #clause {
-                                   #(#body)*
+                                   #body
                                 }
                             },
                             None => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&self, #(#arg_name : #arg_type),*) #return_type {
-                                   #(#body)*
+                                   #body
                                 }
                             },
                         }
@@ -466,13 +543,13 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                 Some(clause) => quote! {
                     #(#attrs)*
                     fn #name(#(#arg_name: #arg_type),*) #return_type #clause {
-                        #(#body)*
+                        #body
                     }
                 },
                 None => quote! {
                     #(#attrs)*
                     fn #name(#(#arg_name: #arg_type),*) #return_type {
-                        #(#body)*
+                        #body
                     }
                 },
             },
@@ -483,13 +560,13 @@ fn trait_method_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                     Some(clause) => quote! {
                         #(#attrs)*
                         fn #name<#(#generic_params),*>(#(#arg_name: #arg_type),*) #return_type #clause {
-                            #(#body)*
+                            #body
                         }
                     },
                     None => quote! {
                         #(#attrs)*
                         fn #name<#(#generic_params),*>(#(#arg_name: #arg_type),*) #return_type {
-                            #(#body)*
+                            #body
                         }
                     },
                 }
@@ -544,11 +621,7 @@ fn property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
         })
         .collect::<Vec<_>>();
 
-    let attrs = input
-        .attrs
-        .iter()
-        .filter(|attr| !attr.path.is_ident("property"))
-        .collect::<Vec<_>>();
+    let attrs = copied_attrs(input, "property");
 
     match input.sig.receiver() {
         Some(receiver) => match receiver {
@@ -624,12 +697,16 @@ fn property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
             },
             syn::FnArg::Typed(_) => unreachable!(),
         },
-        None => quote! {
-            #(#attrs)*
-            pub fn #name(#(#arg_name : #arg_type,)*) #return_type {
-                Self::#trait_name(#(#arg_name),*)
+        None => {
+            let generic_params = generic_params.iter();
+
+            quote! {
+                #(#attrs)*
+                pub fn #name<#(#generic_params),*>(#(#arg_name : #arg_type,)*) #return_type #where_clause {
+                    Self::#trait_name(#(#arg_name),*)
+                }
             }
-        },
+        }
     }
 }
 
@@ -645,7 +722,7 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
 
     let generic_params = generics.params;
 
-    let body = input.block.stmts.clone();
+    let body = guarded_body(input);
 
     let mut fn_args: Vec<FnArg> = vec![];
 
@@ -678,11 +755,7 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
         })
         .collect::<Vec<_>>();
 
-    let attrs = input
-        .attrs
-        .iter()
-        .filter(|attr| !attr.path.is_ident("property"))
-        .collect::<Vec<_>>();
+    let attrs = copied_attrs(input, "property");
 
     match input.sig.receiver() {
         Some(receiver) => match receiver {
@@ -692,13 +765,13 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                         Some(clause) => quote! {
                             #(#attrs)*
                             fn #name(&mut self, #(#arg_name: #arg_type),* ) #return_type #clause {
-                                #(#body)*
+                                #body
                             }
                         },
                         None => quote! {
                             #(#attrs)*
                             fn #name(&mut self, #(#arg_name : #arg_type),* ) #return_type {
-                                #(#body)*
+                                #body
                             }
                         },
                     },
@@ -708,13 +781,13 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                             Some(clause) => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&mut self, #(#arg_name: #arg_type),* ) #return_type #clause {
-                                    #(#body)*
+                                    #body
                                 }
                             },
                             None => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&mut self, #(#arg_name : #arg_type),* ) #return_type {
-                                    #(#body)*
+                                    #body
                                 }
                             },
                         }
@@ -725,13 +798,13 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                         Some(clause) => quote! {
                             #(#attrs)*
                             fn #name(&self, #(#arg_name : #arg_type),*) #return_type #clause {
-                               #(#body)*
+                               #body
                             }
                         },
                         None => quote! {
                             #(#attrs)*
                             fn #name(&self, #(#arg_name : #arg_type),*) #return_type {
-                               #(#body)*
+                               #body
                             }
                         },
                     },
@@ -742,13 +815,13 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                             Some(clause) => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&self, #(#arg_name : #arg_type),*) #return_type #clause {
-                                   #(#body)*
+                                   #body
                                 }
                             },
                             None => quote! {
                                 #(#attrs)*
                                 fn #name<#(#generic_params),*>(&self, #(#arg_name : #arg_type),*) #return_type {
-                                   #(#body)*
+                                   #body
                                 }
                             },
                         }
@@ -762,13 +835,13 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                 Some(clause) => quote! {
                     #(#attrs)*
                     fn #name(#(#arg_name: #arg_type),*) #return_type #clause {
-                        #(#body)*
+                        #body
                     }
                 },
                 None => quote! {
                     #(#attrs)*
                     fn #name(#(#arg_name: #arg_type),*) #return_type {
-                        #(#body)*
+                        #body
                     }
                 },
             },
@@ -779,13 +852,13 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
                     Some(clause) => quote! {
                         #(#attrs)*
                         fn #name<#(#generic_params),*>(#(#arg_name: #arg_type),*) #return_type #clause {
-                            #(#body)*
+                            #body
                         }
                     },
                     None => quote! {
                         #(#attrs)*
                         fn #name<#(#generic_params),*>(#(#arg_name: #arg_type),*) #return_type {
-                            #(#body)*
+                            #body
                         }
                     },
                 }
@@ -794,6 +867,39 @@ fn trait_property_sig(input: &ImplItemMethod) -> proc_macro2::TokenStream {
     }
 }
 
+/// Returns the attributes to copy onto a generated function, leaving out the `marker` and
+/// `#[available]`, which is documented instead.
+fn copied_attrs(input: &ImplItemMethod, marker: &str) -> Vec<proc_macro2::TokenStream> {
+    let mut attrs = input
+        .attrs
+        .iter()
+        .filter(|attr| !attr.path.is_ident(marker) && !is_available(attr))
+        .map(|attr| quote!(#attr))
+        .collect::<Vec<_>>();
+
+    if let Ok(Some(availability)) = Availability::from_attrs(&input.attrs) {
+        attrs.push(availability.doc(&input.sig.output));
+    }
+
+    attrs
+}
+
+/// Returns the body of a trait function, guarded by an OS version check if the method is
+/// marked `#[available]`.
+fn guarded_body(input: &ImplItemMethod) -> proc_macro2::TokenStream {
+    let stmts = &input.block.stmts;
+
+    if let Err(error) = ownership::lint(input) {
+        return error.to_compile_error();
+    }
+
+    match Availability::from_attrs(&input.attrs) {
+        Ok(Some(availability)) => availability.guard(&input.sig.output, stmts),
+        Ok(None) => quote!(#(#stmts)*),
+        Err(error) => error.to_compile_error(),
+    }
+}
+
 fn has_arguments(args: Signature) -> bool {
     (args.receiver().is_some() && args.inputs.len() > 1)
         || (args.receiver().is_none() && !args.inputs.is_empty())
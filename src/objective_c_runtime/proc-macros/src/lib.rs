@@ -2,25 +2,126 @@ use proc_macro::TokenStream;
 
 extern crate proc_macro;
 
+mod availability;
 mod class;
+mod encode;
 mod interface;
+mod ownership;
+mod protocol;
+mod selector;
 
+/// Declares an Objective-C class for the type, backed by the methods marked `#[objc_sel]`.
+///
+/// The superclass comes first and may be followed by adopted protocols, typed instance
+/// variables and a class name:
+/// `#[register_class(NSObject, protocols(NSCopying), ivars { count: i64 }, name = "Counter")]`.
+/// Each ivar gets `ivar_<name>` and `set_ivar_<name>` accessors.
+///
+/// Without a `name`, the class is named after the `'static` type and numbered if another
+/// class took that name. Declaring a class under a `name` that is already taken panics.
+/// The `init` of a `#[class_init]` function calls the `init` of the superclass.
 #[proc_macro_attribute]
 pub fn register_class(attr: TokenStream, item: TokenStream) -> TokenStream {
     class::register_class(attr, item)
 }
 
+/// Exports the method under a selector, such as `#[objc_sel("tableView:numberOfRows:")]`.
+///
+/// A leading `+` exports a class method, which must not take `self`.
 #[proc_macro_attribute]
 pub fn objc_sel(attr: TokenStream, item: TokenStream) -> TokenStream {
     class::sel_impl(attr, item)
 }
 
+/// Generates the Objective-C class glue for a delegate trait.
+///
+/// Each trait method marked `#[objc_sel("...")]` gets an `extern "C"` trampoline that
+/// converts its arguments with `ObjcValue` and calls the implementation. A method may take
+/// fewer arguments than the selector, in which case the leading ones, usually the sender
+/// or the notification, are dropped. The `register` function declares a class per
+/// implementing type and stores the pointer to the Rust delegate in the `ivar`:
+///
+/// ```text
+/// #[protocol(class = "RSTNSTextFieldDelegate", register = register_text_field_delegate_class, ivar = "rstDelegatePtr")]
+/// pub trait PNSTextFieldDelegate {
+///     #[objc_sel("controlTextDidChange:")]
+///     fn did_change(&self) {}
+/// }
+/// ```
+///
+/// The methods take `&self`, as Objective-C may call the delegate again from inside one of
+/// them, so delegates keep their state in a `Cell` or `RefCell`.
+///
+/// Implementations marked `#[protocol_impl]` only add the methods with a default body that
+/// they override. Unmarked implementations add every method, as they did before the marker
+/// existed, so `respondsToSelector:` is true for all of them. The `register` function
+/// returns the `DeclareClassError` if the class cannot be declared.
+///
+/// `superclass = NSWindow` changes the superclass from `NSObject`, and `protocols(...)`
+/// adopts formal protocols.
+#[proc_macro_attribute]
+pub fn protocol(attr: TokenStream, item: TokenStream) -> TokenStream {
+    protocol::protocol(attr, item)
+}
+
+/// Marks an implementation of a `#[protocol]` trait, so that only the methods it overrides
+/// are added to the class and `respondsToSelector:` is accurate for the rest.
+///
+/// The marker is optional. Delegates written before it existed keep compiling, and migrate
+/// by adding it above their `impl`.
+#[proc_macro_attribute]
+pub fn protocol_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    protocol::protocol_impl(attr, item)
+}
+
 #[proc_macro_attribute]
 pub fn class_init(_attr: TokenStream, item: TokenStream) -> TokenStream {
     class::impl_init(_attr, item)
 }
 
+/// Generates the `I<Type>` trait of an `object!` type from the functions marked `#[method]` or
+/// `#[property]`, with inherent functions that call it. The argument is the superclass.
+///
+/// `#[interface_impl(NSControl, subclasses)]` also implements the trait for every `Subclass`
+/// whose superclass implements it, converts those subclasses into the type with `From`, and
+/// adds an `As<Type>` trait whose `as_<name>` method, such as `as_view`, does the same.
+///
+/// A function marked `#[available(macos = "12.0", ios = "15.0")]` as well checks the running
+/// OS version first, and returns `None` or `Err(Unavailable)` instead of sending its message
+/// on older systems. It must return an `Option` or a `Result` whose error converts from
+/// `Unavailable`, and its body produces the value to wrap in `Some` or `Ok`.
+///
+/// Bodies are expected to follow Cocoa's memory-management rules. The results of selectors
+/// in the `new`, `alloc`, `copy`, `mutableCopy` and `init` families are taken over with
+/// `from_retained_id`, so these functions return the owned type itself. `init` functions
+/// take no receiver and send `alloc` themselves. Class factories and methods that derive a
+/// new object, such as `stringWithString:` and `uppercaseString`, return an `Autoreleased`
+/// bound to the pool. Getters of properties and shared instances still retain their result
+/// with `from_id`.
+///
+/// A best-effort lint catches the common mistakes in conversions written directly around a
+/// message, such as `Type::from_id(msg_send![receiver, copy])`. It cannot see results bound
+/// to a variable first or sent with `try_msg_send!`, so it is no substitute for review.
 #[proc_macro_attribute]
 pub fn interface_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     interface::interface_impl(attr, item)
 }
+
+/// Guards a trait or inherent method with a check of the running OS version, as
+/// `#[available]` does inside `interface_impl`, for types whose methods are written by hand.
+///
+/// ```text
+/// #[available(macos = "11.0")]
+/// fn ip_toolbar_style(&self) -> Result<NSWindowToolbarStyle, Unavailable> {
+///     unsafe { msg_send![self.m_self(), toolbarStyle] }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn available(attr: TokenStream, item: TokenStream) -> TokenStream {
+    availability::available(attr, item)
+}
+
+#[proc_macro_derive(Encode, attributes(encoding))]
+pub fn derive_encode(item: TokenStream) -> TokenStream {
+    encode::derive_encode(item)
+}
@@ -0,0 +1,146 @@
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{spanned::Spanned, Error, ImplItemMethod};
+
+/// The method families whose results the caller owns.
+const OWNING_FAMILIES: &[&str] = &["alloc", "new", "copy", "mutableCopy", "init"];
+
+/// The conversions that retain a +0 result.
+const RETAINING: &[&str] = &["from_id", "try_from_id"];
+
+/// The conversions that take over a +1 result.
+const TAKING_OVER: &[&str] = &["from_retained_id", "try_from_retained_id"];
+
+/// The constructors of `Autoreleased`, which wrap a +0 result.
+const AUTORELEASED: &[&str] = &["new", "try_new"];
+
+/// Returns the owning family of a selector, such as `init` for `initWithFrame:`.
+fn selector_family(selector: &str) -> Option<&'static str> {
+    OWNING_FAMILIES.iter().find_map(|family| {
+        let rest = selector.strip_prefix(family)?;
+        let next = rest.chars().next();
+        (!next.is_some_and(|c| c.is_ascii_lowercase())).then_some(*family)
+    })
+}
+
+/// Returns whether a function name, such as `init_with_frame`, is in the `init` family.
+fn is_init(name: &str) -> bool {
+    name == "init" || name.starts_with("init_")
+}
+
+/// Lints a `#[method]` or `#[property]` for the most common ownership mistakes.
+///
+/// This is a best-effort scan of the body's tokens, not a proof. It only looks at a conversion
+/// applied directly to a message, as in `Type::from_id(msg_send![receiver, selector...])` or
+/// `Autoreleased::new(msg_send![...], pool)`, and flags a selector in the `new`, `alloc`,
+/// `copy`, `mutableCopy` and `init` families that is retained, or one outside them that is
+/// taken over. Results bound to a variable first, sent with `try_msg_send!` or converted any
+/// other way go unchecked. It also rejects `init` functions with a receiver, as `init`
+/// consumes its receiver and these functions allocate the object they initialize.
+pub fn lint(input: &ImplItemMethod) -> syn::Result<()> {
+    if let Some(receiver) = input.sig.receiver() {
+        if is_init(&input.sig.ident.to_string()) {
+            return Err(Error::new(
+                receiver.span(),
+                "`init` functions take no receiver, as they allocate the object they initialize",
+            ));
+        }
+    }
+
+    check_tokens(input.block.to_token_stream())
+}
+
+fn check_tokens(tokens: TokenStream) -> syn::Result<()> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Group(group) => check_tokens(group.stream())?,
+            TokenTree::Ident(ident) => {
+                let conversion = ident.to_string();
+                let retains = RETAINING.contains(&conversion.as_str())
+                    || (AUTORELEASED.contains(&conversion.as_str())
+                        && is_autoreleased(&tokens[..index]));
+
+                if !retains && !TAKING_OVER.contains(&conversion.as_str()) {
+                    continue;
+                }
+
+                let (selector, span) = match tokens.get(index + 1).and_then(sent_selector) {
+                    Some(sent) => sent,
+                    None => continue,
+                };
+
+                let prefix = if conversion.starts_with("try_") {
+                    "try_"
+                } else {
+                    ""
+                };
+
+                match (selector_family(&selector), retains) {
+                    (Some(family), true) => {
+                        return Err(Error::new(
+                            span,
+                            format!(
+                                "`{selector}` is in the `{family}` family and returns a +1 reference, so take it over with `{prefix}from_retained_id`"
+                            ),
+                        ))
+                    }
+                    (None, false) => {
+                        return Err(Error::new(
+                            span,
+                            format!(
+                                "`{selector}` returns a +0 reference, so retain it with `{prefix}from_id` or return it as an `Autoreleased`"
+                            ),
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether `tokens` end with `Autoreleased::`.
+fn is_autoreleased(tokens: &[TokenTree]) -> bool {
+    match tokens {
+        [.., TokenTree::Ident(ty), TokenTree::Punct(first), TokenTree::Punct(second)] => {
+            ty == "Autoreleased" && first.as_char() == ':' && second.as_char() == ':'
+        }
+        _ => false,
+    }
+}
+
+/// Returns the selector of a `(msg_send![receiver, selector...])` argument list, along with
+/// its span.
+fn sent_selector(arguments: &TokenTree) -> Option<(String, Span)> {
+    let arguments = match arguments {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => group.stream(),
+        _ => return None,
+    };
+
+    let mut arguments = arguments.into_iter();
+    match (arguments.next(), arguments.next()) {
+        (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(bang)))
+            if name == "msg_send" && bang.as_char() == '!' => {}
+        _ => return None,
+    }
+
+    let message = match arguments.next() {
+        Some(TokenTree::Group(group)) => group.stream(),
+        _ => return None,
+    };
+
+    let mut message = message
+        .into_iter()
+        .skip_while(|token| !matches!(token, TokenTree::Punct(comma) if comma.as_char() == ','));
+
+    message.next();
+    match message.next() {
+        Some(TokenTree::Ident(selector)) => Some((selector.to_string(), selector.span())),
+        _ => None,
+    }
+}
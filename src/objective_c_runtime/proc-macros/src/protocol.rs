@@ -0,0 +1,473 @@
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Expr, FnArg, ImplItem, ItemImpl, ItemTrait, LitStr, ReturnType, Token, TraitItem,
+    TraitItemMethod, Type,
+};
+
+use crate::selector::{self, Selector};
+
+/// The hidden trait const that `#[protocol_impl]` sets, as only then do the
+/// `__OVERRIDES_*` consts report the methods the implementation overrides.
+const PROTOCOL_IMPL: &str = "__PROTOCOL_IMPL";
+
+/// The hidden trait const that records whether an implementation overrides `method`.
+fn overrides_const(method: &Ident) -> Ident {
+    format_ident!(
+        "__OVERRIDES_{}",
+        method.to_string().trim_start_matches("r#").to_uppercase()
+    )
+}
+
+/// The arguments of `#[protocol(...)]`.
+///
+/// ```text
+/// #[protocol(
+///     class = "RSTNSTextFieldDelegate",
+///     register = register_text_field_delegate_class,
+///     ivar = "rstTextFieldDelegatePtr",
+///     superclass = NSObject,
+///     protocols(NSTextFieldDelegate),
+/// )]
+/// ```
+struct ProtocolAttributes {
+    class: LitStr,
+    register: Ident,
+    ivar: Expr,
+    superclass: Option<Ident>,
+    protocols: Vec<Ident>,
+}
+
+impl Parse for ProtocolAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        let mut class = None;
+        let mut register = None;
+        let mut ivar = None;
+        let mut superclass = None;
+        let mut protocols = Vec::new();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+
+            if key == "protocols" && input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                protocols.extend(Punctuated::<Ident, Token![,]>::parse_terminated(&content)?);
+            } else if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+
+                if key == "class" {
+                    class = Some(input.parse()?);
+                } else if key == "register" {
+                    register = Some(input.parse()?);
+                } else if key == "ivar" {
+                    ivar = Some(input.parse()?);
+                } else if key == "superclass" {
+                    superclass = Some(input.parse()?);
+                } else {
+                    return Err(unknown_option(&key));
+                }
+            } else {
+                return Err(unknown_option(&key));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let missing = |option: &str| {
+            syn::Error::new(
+                span,
+                format!("`#[protocol]` requires `{option}`, as in {EXAMPLE}"),
+            )
+        };
+
+        Ok(Self {
+            class: class.ok_or_else(|| missing("class"))?,
+            register: register.ok_or_else(|| missing("register"))?,
+            ivar: ivar.ok_or_else(|| missing("ivar"))?,
+            superclass,
+            protocols,
+        })
+    }
+}
+
+const EXAMPLE: &str = "`#[protocol(class = \"RSTDelegate\", register = register_delegate_class, ivar = \"rstDelegatePtr\")]`";
+
+fn unknown_option(key: &Ident) -> syn::Error {
+    syn::Error::new(
+        key.span(),
+        "expected `class = \"...\"`, `register = ...`, `ivar = ...`, `superclass = ...` or `protocols(...)`",
+    )
+}
+
+/// A trait method exported under a selector.
+struct ProtocolMethod<'a> {
+    method: &'a TraitItemMethod,
+    selector: Selector,
+}
+
+impl ProtocolMethod<'_> {
+    fn check(&self) -> syn::Result<()> {
+        let sig = &self.method.sig;
+
+        if self.selector.is_class_method() {
+            return Err(syn::Error::new(
+                self.selector.span(),
+                "delegate methods are instance methods and cannot be exported as class methods",
+            ));
+        }
+
+        match sig.inputs.first() {
+            Some(FnArg::Receiver(receiver)) if receiver.mutability.is_some() => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    format!(
+                        "`{}` must take `&self`, as Objective-C may call the delegate again while \
+                         it runs; keep mutable state in a `Cell` or `RefCell`",
+                        sig.ident
+                    ),
+                ));
+            }
+            Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => {}
+            receiver => {
+                let span = receiver.map_or(sig.ident.span(), |receiver| receiver.span());
+
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "`{}` must take `&self` to be exported as `{}`",
+                        sig.ident,
+                        self.selector.name()
+                    ),
+                ));
+            }
+        }
+
+        let argument_count = selector::argument_types(sig).count();
+
+        if argument_count > self.selector.arity() {
+            return Err(syn::Error::new(
+                self.selector.span(),
+                format!(
+                    "selector `{}` takes {} but `{}` takes {}",
+                    self.selector.name(),
+                    selector::arguments(self.selector.arity()),
+                    sig.ident,
+                    selector::arguments(argument_count)
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn trampoline_name(&self) -> Ident {
+        format_ident!("trampoline_{}", self.method.sig.ident)
+    }
+
+    /// The raw Objective-C types of the selector's arguments. Leading arguments the
+    /// method does not take, such as the sender or notification, are passed as `id`.
+    fn raw_argument_types(&self) -> Vec<proc_macro2::TokenStream> {
+        let argument_types = selector::argument_types(&self.method.sig).collect::<Vec<_>>();
+        let ignored = self.selector.arity() - argument_types.len();
+
+        (0..ignored)
+            .map(|_| quote!(rust_macios::objective_c_runtime::id))
+            .chain(argument_types.into_iter().map(raw_type))
+            .collect()
+    }
+
+    fn raw_return_type(&self) -> proc_macro2::TokenStream {
+        match &self.method.sig.output {
+            ReturnType::Default => quote!(),
+            ReturnType::Type(_, ty) => {
+                let ty = raw_type(ty);
+                quote!(-> #ty)
+            }
+        }
+    }
+
+    /// The `extern "C"` function the class calls, which forwards to the delegate.
+    fn trampoline(&self, trait_name: &Ident, ivar: &Expr) -> proc_macro2::TokenStream {
+        let name = self.trampoline_name();
+        let method_name = &self.method.sig.ident;
+        let raw_argument_types = self.raw_argument_types();
+        let raw_return_type = self.raw_return_type();
+
+        let ignored = raw_argument_types.len() - selector::argument_types(&self.method.sig).count();
+        let arg_names = (0..raw_argument_types.len())
+            .map(|index| {
+                if index < ignored {
+                    format_ident!("_arg{}", index)
+                } else {
+                    format_ident!("arg{}", index)
+                }
+            })
+            .collect::<Vec<_>>();
+        let arguments = selector::argument_types(&self.method.sig)
+            .zip(&arg_names[ignored..])
+            .map(|(ty, arg)| {
+                quote! {
+                    unsafe { <#ty as rust_macios::objective_c_runtime::ObjcValue>::from_raw(#arg) }
+                }
+            });
+
+        let call = quote!(delegate.#method_name(#(#arguments,)*));
+        let call = match &self.method.sig.output {
+            ReturnType::Default => quote!(#call;),
+            ReturnType::Type(..) => {
+                quote!(rust_macios::objective_c_runtime::ObjcValue::into_raw(#call))
+            }
+        };
+
+        quote! {
+            extern "C" fn #name<T: #trait_name>(
+                this: &Object,
+                _: Sel,
+                #(#arg_names: #raw_argument_types,)*
+            ) #raw_return_type {
+                rust_macios::objective_c_runtime::exit_on_panic(|| {
+                    let delegate = unsafe {
+                        let delegate_ptr: usize = *this.get_ivar(#ivar);
+                        &*(delegate_ptr as *const T)
+                    };
+
+                    #call
+                })
+            }
+        }
+    }
+
+    /// Adds the trampoline to the class, unless a `#[protocol_impl]` implementation relies on
+    /// the default.
+    fn add_method(&self, trait_name: &Ident) -> proc_macro2::TokenStream {
+        let sel = self.selector.to_sel();
+        let name = self.trampoline_name();
+        let raw_argument_types = self.raw_argument_types();
+        let raw_return_type = self.raw_return_type();
+
+        let add_method = quote! {
+            decl.add_method(
+                #sel,
+                #name::<T> as extern "C" fn(&Object, Sel, #(#raw_argument_types,)*) #raw_return_type,
+            );
+        };
+
+        // Required methods are always implemented.
+        if self.method.default.is_none() {
+            add_method
+        } else {
+            let overrides = overrides_const(&self.method.sig.ident);
+
+            let protocol_impl = format_ident!("{}", PROTOCOL_IMPL);
+
+            quote! {
+                if !<T as #trait_name>::#protocol_impl || <T as #trait_name>::#overrides {
+                    #add_method
+                }
+            }
+        }
+    }
+}
+
+/// Spans the conversion at `ty`, so a type that cannot be passed is reported there.
+fn raw_type(ty: &Type) -> proc_macro2::TokenStream {
+    quote_spanned! { ty.span() =>
+        <#ty as rust_macios::objective_c_runtime::ObjcValue>::Raw
+    }
+}
+
+pub fn protocol(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attributes = parse_macro_input!(attr as ProtocolAttributes);
+    let mut input = parse_macro_input!(item as ItemTrait);
+
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    if !input.generics.params.is_empty() {
+        push_error(syn::Error::new(
+            input.generics.span(),
+            "`#[protocol]` does not support generic traits",
+        ));
+    }
+
+    // Strip the selectors from the trait, keeping them for the generated glue.
+    let mut selectors = Vec::new();
+
+    for item in &mut input.items {
+        if let TraitItem::Method(method) = item {
+            let position = method
+                .attrs
+                .iter()
+                .position(|attr| attr.path.is_ident("objc_sel"));
+
+            if let Some(position) = position {
+                match method.attrs.remove(position).parse_args::<Selector>() {
+                    Ok(selector) => selectors.push((method.sig.ident.clone(), selector)),
+                    Err(error) => push_error(error),
+                }
+            }
+        }
+    }
+
+    let methods = selectors
+        .into_iter()
+        .filter_map(|(ident, selector)| {
+            let method = input.items.iter().find_map(|item| match item {
+                TraitItem::Method(method) if method.sig.ident == ident => Some(method),
+                _ => None,
+            })?;
+
+            Some(ProtocolMethod { method, selector })
+        })
+        .collect::<Vec<_>>();
+
+    for method in &methods {
+        if let Err(error) = method.check() {
+            push_error(error);
+        }
+    }
+
+    if let Some(errors) = errors {
+        return errors.to_compile_error().into();
+    }
+
+    let vis = &input.vis;
+    let trait_name = &input.ident;
+    let register = &attributes.register;
+    let class = &attributes.class;
+    let ivar = &attributes.ivar;
+    let superclass = attributes
+        .superclass
+        .clone()
+        .unwrap_or_else(|| format_ident!("NSObject"));
+    let protocol_names = attributes
+        .protocols
+        .iter()
+        .map(|protocol| protocol.to_string());
+
+    let trampolines = methods
+        .iter()
+        .map(|method| method.trampoline(trait_name, ivar))
+        .collect::<Vec<_>>();
+    let add_methods = methods
+        .iter()
+        .map(|method| method.add_method(trait_name))
+        .collect::<Vec<_>>();
+
+    let register_doc = format!(
+        "Registers a `{superclass}` subclass that forwards to the [`{trait_name}`] implementation `T`.\n\n\
+         If `T` is marked `#[protocol_impl]`, only the methods it overrides are added, so \
+         `respondsToSelector:` reports the rest as missing. Otherwise every method is added.\n\n\
+         Returns the error if the class cannot be declared."
+    );
+
+    let overrides = input
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(method) => Some(overrides_const(&method.sig.ident)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let protocol_impl = format_ident!("{}", PROTOCOL_IMPL);
+
+    input.items.push(syn::parse_quote! {
+        /// Whether the implementation is marked `#[protocol_impl]`.
+        #[doc(hidden)]
+        const #protocol_impl: bool = false;
+    });
+
+    for overrides in overrides {
+        input.items.push(syn::parse_quote! {
+            /// Whether the implementation overrides the method. Set by `#[protocol_impl]`.
+            #[doc(hidden)]
+            const #overrides: bool = false;
+        });
+    }
+
+    quote! {
+        #input
+
+        #[doc = #register_doc]
+        #vis fn #register<T: #trait_name + 'static>() -> ::core::result::Result<
+            &'static rust_macios::objective_c_runtime::runtime::Class,
+            rust_macios::objective_c_runtime::DeclareClassError,
+        > {
+            use rust_macios::objective_c_runtime::{
+                class,
+                runtime::{Object, Protocol, Sel},
+                sel, sel_impl,
+            };
+
+            #(#trampolines)*
+
+            rust_macios::objective_c_runtime::register_class_for_type::<T, _>(
+                #class,
+                class!(#superclass),
+                |decl| unsafe {
+                    decl.add_ivar::<usize>(#ivar);
+
+                    // Not every formal protocol is known to the runtime, and adopting it is
+                    // only informational.
+                    #(
+                        if let Some(protocol) = Protocol::get(#protocol_names) {
+                            decl.add_protocol(protocol);
+                        }
+                    )*
+
+                    #(#add_methods)*
+                },
+            )
+        }
+    }
+    .into()
+}
+
+pub fn protocol_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(item as ItemImpl);
+
+    if input.trait_.is_none() {
+        return syn::Error::new(
+            input.self_ty.span(),
+            "`#[protocol_impl]` must be placed on an implementation of a `#[protocol]` trait",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let overrides = input
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Method(method) => Some(overrides_const(&method.sig.ident)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let protocol_impl = format_ident!("{}", PROTOCOL_IMPL);
+
+    input.items.push(syn::parse_quote! {
+        const #protocol_impl: bool = true;
+    });
+
+    for overrides in overrides {
+        input.items.push(syn::parse_quote! {
+            const #overrides: bool = true;
+        });
+    }
+
+    quote!(#input).into()
+}
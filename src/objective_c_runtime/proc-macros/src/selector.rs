@@ -0,0 +1,218 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    FnArg, ImplItemMethod, LitStr, ReturnType, Signature, Type,
+};
+
+/// A selector named in `#[objc_sel(...)]`, such as `"tableView:objectValueForTableColumn:row:"`.
+///
+/// A leading `+` exports the method as a class method, as in `"+sharedController"`. A
+/// leading `-` is accepted for instance methods.
+pub struct Selector {
+    name: String,
+    is_class_method: bool,
+    span: Span,
+}
+
+impl Parse for Selector {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        // The selector is normally a string literal, but the bare `viewDidLoad` and
+        // `tableView:numberOfRows:` forms accepted by `sel!` are kept working.
+        let name: String = if input.peek(LitStr) {
+            input.parse::<LitStr>()?.value()
+        } else {
+            let tokens: TokenStream = input.parse()?;
+            tokens.to_string().split_whitespace().collect()
+        };
+
+        let selector = match name.strip_prefix('+') {
+            Some(name) => Self {
+                name: name.to_string(),
+                is_class_method: true,
+                span,
+            },
+            None => Self {
+                name: name.strip_prefix('-').unwrap_or(&name).to_string(),
+                is_class_method: false,
+                span,
+            },
+        };
+        selector.check_syntax()?;
+
+        Ok(selector)
+    }
+}
+
+impl Selector {
+    /// The number of arguments the selector takes, which is the number of colons in it.
+    pub fn arity(&self) -> usize {
+        self.name.matches(':').count()
+    }
+
+    /// The selector without a leading `+` or `-`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Where the selector was written, for error messages.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Whether the selector names a class method rather than an instance method.
+    pub fn is_class_method(&self) -> bool {
+        self.is_class_method
+    }
+
+    /// Expands to a `sel!` invocation for the selector.
+    pub fn to_sel(&self) -> TokenStream {
+        let parts = self
+            .name
+            .split_terminator(':')
+            .map(|part| format_ident!("{}", part, span = self.span));
+
+        if self.arity() == 0 {
+            quote! { rust_macios::objective_c_runtime::sel!(#(#parts)*) }
+        } else {
+            quote! { rust_macios::objective_c_runtime::sel!(#(#parts:)*) }
+        }
+    }
+
+    fn check_syntax(&self) -> syn::Result<()> {
+        if self.name.is_empty() {
+            return Err(syn::Error::new(
+                self.span,
+                "expected a selector, such as `#[objc_sel(\"viewDidLoad\")]`",
+            ));
+        }
+
+        if self.arity() > 0 && !self.name.ends_with(':') {
+            return Err(syn::Error::new(
+                self.span,
+                format!(
+                    "selector `{}` must end with `:` because it takes arguments",
+                    self.name
+                ),
+            ));
+        }
+
+        match self
+            .name
+            .split_terminator(':')
+            .find(|part| !is_identifier(part))
+        {
+            Some("") => Err(syn::Error::new(
+                self.span,
+                format!(
+                    "selector `{}` has an unnamed argument, which `#[objc_sel]` does not support",
+                    self.name
+                ),
+            )),
+            Some(part) => Err(syn::Error::new(
+                self.span,
+                format!("`{part}` is not a valid selector part in `{}`", self.name),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that `method` can be called with the arguments the selector passes.
+    pub fn check_method(&self, method: &ImplItemMethod) -> syn::Result<()> {
+        let receiver = match method.sig.inputs.first() {
+            Some(FnArg::Receiver(receiver)) => Some(receiver),
+            _ => None,
+        };
+
+        if self.is_class_method {
+            if let Some(receiver) = receiver {
+                return Err(syn::Error::new(
+                    receiver.span(),
+                    format!(
+                        "`{}` is exported as the class method `+{}` and must not take `self`",
+                        method.sig.ident, self.name
+                    ),
+                ));
+            }
+        } else {
+            match receiver {
+                Some(receiver) if receiver.reference.is_some() && receiver.mutability.is_none() => {
+                }
+                _ => {
+                    let span = receiver.map_or(method.sig.ident.span(), |receiver| receiver.span());
+
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "`{}` must take `&self` to be exported as `{}`",
+                            method.sig.ident, self.name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let argument_count = argument_types(&method.sig).count();
+
+        if argument_count != self.arity() {
+            return Err(syn::Error::new(
+                self.span,
+                format!(
+                    "selector `{}` takes {} but `{}` takes {}",
+                    self.name,
+                    arguments(self.arity()),
+                    method.sig.ident,
+                    arguments(argument_count)
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// The types of the arguments after `&self`.
+pub fn argument_types(sig: &Signature) -> impl Iterator<Item = &Type> {
+    sig.inputs.iter().filter_map(|arg| match arg {
+        FnArg::Receiver(_) => None,
+        FnArg::Typed(arg) => Some(&*arg.ty),
+    })
+}
+
+/// Asserts that every argument and the return type of `method` implement `Encode`, with
+/// the error pointing at the offending type.
+pub fn assert_encode(method: &ImplItemMethod) -> TokenStream {
+    let return_type = match &method.sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => Some(&**ty),
+    };
+
+    let assertions = argument_types(&method.sig).chain(return_type).map(|ty| {
+        quote_spanned! { ty.span() =>
+            assert_encode::<#ty>();
+        }
+    });
+
+    quote! {
+        fn assert_encode<T: rust_macios::objective_c_runtime::Encode>() {}
+        #(#assertions)*
+    }
+}
+
+fn is_identifier(part: &str) -> bool {
+    let mut chars = part.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Formats an argument count, as in `1 argument` or `2 arguments`.
+pub fn arguments(count: usize) -> String {
+    match count {
+        1 => "1 argument".to_string(),
+        count => format!("{count} arguments"),
+    }
+}
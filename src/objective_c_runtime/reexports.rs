@@ -1,2 +1,6 @@
 pub use objc::*;
 pub use objc_id::*;
+
+// Takes precedence over the glob import of `objc`'s macro.
+#[cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+pub use crate::msg_send;
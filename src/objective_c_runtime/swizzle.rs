@@ -0,0 +1,473 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    ffi::{c_void, CString},
+    fmt,
+    marker::PhantomData,
+    mem,
+    sync::{Mutex, PoisonError},
+};
+
+use block::ConcreteBlock;
+use lazy_static::lazy_static;
+use objc::{
+    runtime::{self, Class, Imp, Method, Sel, BOOL},
+    Encode,
+};
+
+use super::{
+    encoding::{MethodEncoding, TypeEncoding},
+    id,
+    introspection::MethodInfo,
+};
+
+extern "C" {
+    fn imp_implementationWithBlock(block: *mut c_void) -> Imp;
+    fn imp_removeBlock(imp: Imp) -> BOOL;
+    fn class_getMethodImplementation(class: *const Class, sel: Sel) -> Imp;
+}
+
+/// An implementation installed by a [`MethodOverride`].
+struct Installed {
+    imp: Imp,
+    previous: Imp,
+    dropped: bool,
+}
+
+lazy_static! {
+    /// The overrides of each method, by the address of the method, oldest first.
+    ///
+    /// A later override may call an earlier one, so an override is only undone once every
+    /// override above it has been dropped.
+    static ref OVERRIDES: Mutex<HashMap<usize, Vec<Installed>>> = Mutex::new(HashMap::new());
+}
+
+/// Sets the implementation of `method` to `imp`, recording it so that it can be undone.
+unsafe fn install(method: *mut Method, imp: Imp) -> Imp {
+    let mut overrides = OVERRIDES.lock().unwrap_or_else(PoisonError::into_inner);
+    let previous = runtime::method_setImplementation(method, imp);
+
+    overrides
+        .entry(method as usize)
+        .or_default()
+        .push(Installed {
+            imp,
+            previous,
+            dropped: false,
+        });
+
+    previous
+}
+
+/// An error returned when a method cannot be replaced, added or exchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwizzleError {
+    /// The class does not respond to the selector.
+    MethodNotFound(String),
+    /// [`add_method`] was called with a selector the class already responds to.
+    MethodExists(String),
+    /// An encoding, of the method or of a closure argument, could not be parsed.
+    UnknownEncoding(String),
+    /// The closure's signature does not match the method's type encoding.
+    IncompatibleSignature {
+        /// The method's selector.
+        selector: String,
+        /// The encoding of the method.
+        expected: Box<MethodEncoding>,
+        /// The encoding of the closure.
+        found: Box<MethodEncoding>,
+    },
+}
+
+impl fmt::Display for SwizzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwizzleError::MethodNotFound(selector) => {
+                write!(f, "the class does not respond to `{selector}`")
+            }
+            SwizzleError::MethodExists(selector) => {
+                write!(f, "the class already responds to `{selector}`")
+            }
+            SwizzleError::UnknownEncoding(encoding) => {
+                write!(f, "the encoding `{encoding}` could not be parsed")
+            }
+            SwizzleError::IncompatibleSignature {
+                selector,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{selector}` has the encoding `{expected}`, but the implementation is `{found}`"
+            ),
+        }
+    }
+}
+
+impl Error for SwizzleError {}
+
+fn encoding_of<T>() -> Result<TypeEncoding, SwizzleError>
+where
+    T: Encode,
+{
+    let encoding = T::encode();
+
+    TypeEncoding::try_from(&encoding)
+        .map_err(|_| SwizzleError::UnknownEncoding(encoding.as_str().to_string()))
+}
+
+/// Closures that can become the implementation of a method.
+///
+/// Implemented for `Fn(id, A, B, ...) -> R` closures of up to eleven arguments, where the
+/// first argument is the receiver and the rest are the method's arguments. The selector is
+/// not passed.
+pub trait MethodImplementation<Args>: Send + Sync + 'static {
+    /// The type the method returns.
+    type Return;
+
+    /// Returns the encoding of a method implemented by the closure.
+    fn encoding() -> Result<MethodEncoding, SwizzleError>;
+
+    /// Turns the closure into an implementation that is freed with `imp_removeBlock`.
+    #[doc(hidden)]
+    fn into_imp(self) -> Imp;
+}
+
+/// Closures that wrap an existing implementation of a method.
+///
+/// Implemented for `Fn(&Original<(A, B, ...), R>, id, A, B, ...) -> R` closures, which are
+/// given the implementation they replace.
+pub trait MethodWrapper<Args, R>: Send + Sync + 'static {
+    /// Returns the encoding of a method implemented by the closure.
+    fn encoding() -> Result<MethodEncoding, SwizzleError>;
+
+    /// Turns the closure into an implementation that is freed with `imp_removeBlock`.
+    #[doc(hidden)]
+    fn into_imp(self, original: Original<Args, R>) -> Imp;
+}
+
+/// The implementation of a method before it was wrapped with [`wrap_method`].
+pub struct Original<Args, R> {
+    sel: Sel,
+    imp: Imp,
+    marker: PhantomData<fn(Args) -> R>,
+}
+
+impl<Args, R> fmt::Debug for Original<Args, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Original")
+            .field("sel", &self.sel.name())
+            .finish_non_exhaustive()
+    }
+}
+
+macro_rules! method_implementation_impls {
+    ($($arg:ident: $t:ident),*) => {
+        impl<F, R, $($t),*> MethodImplementation<($($t,)*)> for F
+        where
+            F: Fn(id, $($t),*) -> R + Send + Sync + 'static,
+            R: Encode,
+            $($t: Encode,)*
+        {
+            type Return = R;
+
+            fn encoding() -> Result<MethodEncoding, SwizzleError> {
+                Ok(MethodEncoding::new(
+                    encoding_of::<R>()?,
+                    [TypeEncoding::Object(None), TypeEncoding::Sel, $(encoding_of::<$t>()?),*],
+                ))
+            }
+
+            fn into_imp(self) -> Imp {
+                let block = ConcreteBlock::new(self).copy();
+
+                // The runtime keeps a copy of the block until `imp_removeBlock`.
+                unsafe { imp_implementationWithBlock(&*block as *const _ as *mut c_void) }
+            }
+        }
+
+        impl<F, R, $($t),*> MethodWrapper<($($t,)*), R> for F
+        where
+            F: Fn(&Original<($($t,)*), R>, id, $($t),*) -> R + Send + Sync + 'static,
+            R: Encode + 'static,
+            $($t: Encode + 'static,)*
+        {
+            fn encoding() -> Result<MethodEncoding, SwizzleError> {
+                <fn(id, $($t),*) -> R as MethodImplementation<($($t,)*)>>::encoding()
+            }
+
+            fn into_imp(self, original: Original<($($t,)*), R>) -> Imp {
+                MethodImplementation::into_imp(move |this: id, $($arg: $t),*| {
+                    self(&original, this, $($arg),*)
+                })
+            }
+        }
+
+        impl<R, $($t),*> Original<($($t,)*), R> {
+            /// Calls the original implementation.
+            ///
+            /// # Safety
+            ///
+            /// `this` must be an instance of the class whose method was wrapped.
+            #[allow(clippy::too_many_arguments)]
+            pub unsafe fn call(&self, this: id, $($arg: $t),*) -> R {
+                let imp: unsafe extern "C" fn(id, Sel, $($t),*) -> R = mem::transmute(self.imp);
+                imp(this, self.sel, $($arg),*)
+            }
+        }
+    };
+}
+
+method_implementation_impls!();
+method_implementation_impls!(a: A);
+method_implementation_impls!(a: A, b: B);
+method_implementation_impls!(a: A, b: B, c: C);
+method_implementation_impls!(a: A, b: B, c: C, d: D);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E, f: G);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E, f: G, g: H);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E, f: G, g: H, h: I);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E, f: G, g: H, h: I, i: J);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E, f: G, g: H, h: I, i: J, j: K);
+method_implementation_impls!(a: A, b: B, c: C, d: D, e: E, f: G, g: H, h: I, i: J, j: K, k: L);
+
+/// A replaced or added method, whose previous implementation is restored when this is
+/// dropped.
+///
+/// A later override of the same method may call this one, for example as the [`Original`]
+/// of [`wrap_method`]. Dropping an override while a later one is alive therefore leaves it
+/// in place until the later one is dropped too. Pass the override to [`mem::forget`] to keep
+/// the new implementation for the rest of the process.
+#[must_use = "the previous implementation is restored when the override is dropped"]
+pub struct MethodOverride {
+    sel: Sel,
+    method: *mut Method,
+    imp: Imp,
+}
+
+impl MethodOverride {
+    /// The selector of the method.
+    pub fn sel(&self) -> Sel {
+        self.sel
+    }
+}
+
+impl Drop for MethodOverride {
+    fn drop(&mut self) {
+        let mut overrides = OVERRIDES.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(stack) = overrides.get_mut(&(self.method as usize)) else {
+            return;
+        };
+
+        if let Some(installed) = stack
+            .iter_mut()
+            .find(|installed| installed.imp as usize == self.imp as usize)
+        {
+            installed.dropped = true;
+        }
+
+        // Undo the dropped overrides that nothing installed later can call anymore.
+        while stack.last().is_some_and(|installed| installed.dropped) {
+            let installed = stack.pop().unwrap();
+
+            unsafe {
+                runtime::method_setImplementation(self.method, installed.previous);
+                imp_removeBlock(installed.imp);
+            }
+        }
+
+        if stack.is_empty() {
+            overrides.remove(&(self.method as usize));
+        }
+    }
+}
+
+impl fmt::Debug for MethodOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MethodOverride")
+            .field("sel", &self.sel.name())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Two methods whose implementations are exchanged back when this is dropped.
+#[must_use = "the implementations are exchanged back when this is dropped"]
+pub struct MethodExchange {
+    first: *mut Method,
+    second: *mut Method,
+}
+
+impl Drop for MethodExchange {
+    fn drop(&mut self) {
+        unsafe { runtime::method_exchangeImplementations(self.first, self.second) };
+    }
+}
+
+impl fmt::Debug for MethodExchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MethodExchange").finish_non_exhaustive()
+    }
+}
+
+/// Looks up the instance method `sel` of `class`, which may be inherited, and checks that its
+/// encoding matches `encoding`.
+fn checked_method(
+    class: &Class,
+    sel: Sel,
+    encoding: Option<&MethodEncoding>,
+) -> Result<(*mut Method, MethodInfo), SwizzleError> {
+    let method = class
+        .instance_method(sel)
+        .ok_or_else(|| SwizzleError::MethodNotFound(sel.name().to_string()))?;
+    let info = MethodInfo::new(method);
+
+    let expected = info
+        .encoding
+        .as_ref()
+        .ok_or_else(|| SwizzleError::UnknownEncoding(info.raw_encoding.clone()))?;
+
+    if let Some(found) = encoding {
+        if !expected.is_compatible_with(found) {
+            return Err(SwizzleError::IncompatibleSignature {
+                selector: info.name,
+                expected: Box::new(expected.clone()),
+                found: Box::new(found.clone()),
+            });
+        }
+    }
+
+    Ok((method as *const Method as *mut Method, info))
+}
+
+/// Makes `method` one that `class` implements itself, copying it from the superclass if
+/// it was inherited, so that changing it does not affect other classes.
+unsafe fn own_method(class: &Class, method: *mut Method, info: &MethodInfo) -> *mut Method {
+    let types = CString::new(info.raw_encoding.as_str()).unwrap();
+    let sel = runtime::method_getName(method);
+
+    runtime::class_addMethod(
+        class as *const Class as *mut Class,
+        sel,
+        runtime::method_getImplementation(method),
+        types.as_ptr(),
+    );
+
+    runtime::class_getInstanceMethod(class, sel) as *mut Method
+}
+
+/// Replaces the implementation of the instance method `sel` of `class` with `f`.
+///
+/// `f` is given the receiver followed by the method's arguments, and its signature is
+/// checked against the method's type encoding. If the method is inherited, it is
+/// overridden in `class` alone. Pass the metaclass to replace a class method.
+///
+/// ```no_run
+/// use rust_macios::{
+///     foundation::NSString,
+///     objective_c_runtime::{id, replace_method, sel, sel_impl, traits::PNSObject},
+/// };
+///
+/// let _override = replace_method(NSString::m_class(), sel!(length), |_this: id| 0_u64).unwrap();
+/// ```
+pub fn replace_method<F, Args>(
+    class: &Class,
+    sel: Sel,
+    f: F,
+) -> Result<MethodOverride, SwizzleError>
+where
+    F: MethodImplementation<Args>,
+{
+    let (method, info) = checked_method(class, sel, Some(&F::encoding()?))?;
+
+    unsafe {
+        let method = own_method(class, method, &info);
+        let imp = f.into_imp();
+        install(method, imp);
+
+        Ok(MethodOverride { sel, method, imp })
+    }
+}
+
+/// Wraps the implementation of the instance method `sel` of `class` with `f`.
+///
+/// Like [`replace_method`], but `f` is also given the [`Original`] implementation, which
+/// it can call before or after doing its own work.
+pub fn wrap_method<F, Args, R>(
+    class: &Class,
+    sel: Sel,
+    f: F,
+) -> Result<MethodOverride, SwizzleError>
+where
+    F: MethodWrapper<Args, R>,
+{
+    let (method, info) = checked_method(class, sel, Some(&F::encoding()?))?;
+
+    unsafe {
+        let method = own_method(class, method, &info);
+        let original = Original {
+            sel,
+            imp: runtime::method_getImplementation(method),
+            marker: PhantomData,
+        };
+        let imp = f.into_imp(original);
+        install(method, imp);
+
+        Ok(MethodOverride { sel, method, imp })
+    }
+}
+
+/// Adds an instance method `sel` implemented by `f` to `class`, which must not respond to
+/// `sel` yet.
+///
+/// The method's type encoding is derived from the signature of `f`. The runtime cannot
+/// remove methods, so dropping the override makes the method forward its messages, which
+/// raises an exception unless the class handles them.
+pub fn add_method<F, Args>(class: &Class, sel: Sel, f: F) -> Result<MethodOverride, SwizzleError>
+where
+    F: MethodImplementation<Args>,
+{
+    if class.instance_method(sel).is_some() {
+        return Err(SwizzleError::MethodExists(sel.name().to_string()));
+    }
+
+    let types = CString::new(F::encoding()?.to_string()).unwrap();
+
+    unsafe {
+        // Adding the method with the forwarding implementation lets it be undone like an
+        // override.
+        let forward = class_getMethodImplementation(class, sel);
+
+        runtime::class_addMethod(
+            class as *const Class as *mut Class,
+            sel,
+            forward,
+            types.as_ptr(),
+        );
+
+        let method = runtime::class_getInstanceMethod(class, sel) as *mut Method;
+        let imp = f.into_imp();
+        install(method, imp);
+
+        Ok(MethodOverride { sel, method, imp })
+    }
+}
+
+/// Exchanges the implementations of the instance methods `first` and `second` of `class`.
+///
+/// The two methods must have compatible type encodings. Inherited methods are overridden
+/// in `class` alone.
+pub fn exchange_methods(
+    class: &Class,
+    first: Sel,
+    second: Sel,
+) -> Result<MethodExchange, SwizzleError> {
+    let (first_method, first_info) = checked_method(class, first, None)?;
+    let (second_method, second_info) = checked_method(class, second, first_info.encoding.as_ref())?;
+
+    unsafe {
+        let first = own_method(class, first_method, &first_info);
+        let second = own_method(class, second_method, &second_info);
+        runtime::method_exchangeImplementations(first, second);
+
+        Ok(MethodExchange { first, second })
+    }
+}
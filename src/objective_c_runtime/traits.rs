@@ -1,5 +1,7 @@
+use std::mem::ManuallyDrop;
+
+use crate::objective_c_runtime::msg_send;
 use objc::{
-    msg_send,
     runtime::{Class, Protocol, Sel},
     sel, sel_impl,
 };
@@ -9,7 +11,7 @@ use crate::{
     utils::to_bool,
 };
 
-use super::id;
+use super::{id, Allocated, AutoreleasePool, Autoreleased};
 
 /// The group of methods that are fundamental to all Objective-C objects.
 pub trait INSObject {
@@ -52,18 +54,50 @@ pub trait INSObject {
 pub trait PNSObject {
     ///  Allocates a new instance of the receiving class, sends it an init message, and returns the initialized object.
     fn m_new() -> Self
+    where
+        Self: Sized + FromId + AnyThread,
+    {
+        unsafe { Self::from_retained_id(msg_send![Self::m_class(), new]) }
+    }
+
+    /// Returns a new instance of the receiving class, which an `init` message must initialize
+    /// before it is used.
+    fn m_alloc() -> Allocated<Self>
+    where
+        Self: Sized + AnyThread,
+    {
+        unsafe { Allocated::new(msg_send![Self::m_class(), alloc]) }
+    }
+
+    /// Returns the object returned by `copyWithZone:`.
+    fn m_copy(&self) -> Self
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), new]) }
+        unsafe { Self::from_retained_id(msg_send![self.m_self(), copy]) }
     }
 
-    /// Returns a new instance of the receiving class.
-    fn m_alloc() -> Self
+    /// Returns the object returned by `mutableCopyWithZone:`.
+    fn m_mutable_copy<T>(&self) -> T
+    where
+        T: FromId,
+    {
+        unsafe { T::from_retained_id(msg_send![self.m_self(), mutableCopy]) }
+    }
+
+    /// Hands the reference over to `pool`, which releases it when it drains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pool` is not the innermost pool.
+    fn m_autorelease<'pool>(self, pool: &'pool AutoreleasePool) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), alloc]) }
+        unsafe {
+            let ptr: id = msg_send![ManuallyDrop::new(self).m_self(), autorelease];
+            Autoreleased::new(ptr, pool)
+        }
     }
 
     /// Initializes the class before it receives its first message.
@@ -106,6 +140,20 @@ pub trait PNSObject {
         unsafe { to_bool(msg_send![self.m_self(), isKindOfClass: class]) }
     }
 
+    /// Returns the receiver as `U` if it is an instance of `U` or of a class that inherits from it.
+    fn downcast<U>(&self) -> Option<U>
+    where
+        U: PNSObject + FromId,
+    {
+        unsafe {
+            if to_bool(msg_send![self.m_self(), isKindOfClass: U::m_class()]) {
+                U::try_from_id(self.m_self())
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns a Boolean value that indicates whether the receiver is an instance of a given class.
     fn m_is_member_of_class(&self, class: Class) -> bool {
         unsafe { to_bool(msg_send![self.m_self(), isMemberOfClass: class]) }
@@ -156,18 +204,104 @@ pub trait PNSObject {
     }
 }
 
+/// A class whose instances may be created on any thread with [`PNSObject::m_new`] and
+/// [`PNSObject::m_alloc`].
+///
+/// [`object!`](crate::object) implements it for every type except those declared with a
+/// `main_thread: PhantomData<MainThreadMarker>` field, which AppKit creates through its
+/// `MainThreadOnly` trait instead.
+///
+/// # Safety
+///
+/// Instances of the class must be safe to create off the main thread.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can only be created on the main thread",
+    note = "create it with `MainThreadOnly::m_new_on_main` and a `MainThreadMarker`"
+)]
+pub unsafe trait AnyThread {}
+
+/// An [`object!`](crate::object) type whose class inherits from the class of
+/// [`Subclass::Super`], declared as `object! { unsafe pub struct NSButton: NSControl; }`.
+///
+/// The interface traits of AppKit's class hierarchy, such as `INSView`, are implemented for
+/// every type whose superclass implements them, and the superclass types convert from
+/// their subclasses with `From`.
+///
+/// # Safety
+///
+/// Every instance of `Self` must be an instance of the class of `Super`.
+pub unsafe trait Subclass: PNSObject + FromId {
+    /// The type of the superclass.
+    type Super: PNSObject + FromId;
+
+    /// Returns the object as an instance of its superclass.
+    fn upcast(&self) -> Self::Super {
+        unsafe { Self::Super::from_id(self.m_self()) }
+    }
+}
+
 /// Converting an Objective-C pointer to Object
 pub trait FromId: ToId {
-    /// Returns `Self` representation of the object.
+    /// Returns `Self` representation of the object, retaining it.
+    ///
+    /// Use this for +0 references, such as the results of getters.
     ///
     /// # Safety
     ///
     /// This function dereferences a raw pointer
     unsafe fn from_id(ptr: id) -> Self;
+
+    /// Returns `Self` representation of the object, taking over a +1 reference instead
+    /// of retaining it.
+    ///
+    /// Use this for the results of methods in the `new`, `alloc`, `copy`, `mutableCopy` and
+    /// `init` families.
+    ///
+    /// # Safety
+    ///
+    /// This function dereferences a raw pointer, which the caller must own.
+    unsafe fn from_retained_id(ptr: id) -> Self
+    where
+        Self: Sized,
+    {
+        let object = Self::from_id(ptr);
+        let _: () = msg_send![ptr, release];
+        object
+    }
+
+    /// Returns `Self` representation of the object, retaining it, or `None` if it is `nil`.
+    ///
+    /// Use this for +0 references that may be `nil`, such as the results of optional getters.
+    ///
+    /// # Safety
+    ///
+    /// This function dereferences a raw pointer
+    unsafe fn try_from_id(ptr: id) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        (!ptr.is_null()).then(|| Self::from_id(ptr))
+    }
+
+    /// Returns `Self` representation of the object, taking over a +1 reference, or `None` if
+    /// it is `nil`.
+    ///
+    /// # Safety
+    ///
+    /// This function dereferences a raw pointer, which the caller must own.
+    unsafe fn try_from_retained_id(ptr: id) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        (!ptr.is_null()).then(|| Self::from_retained_id(ptr))
+    }
 }
 
 /// Converting Object to an Objective-C pointer
 pub trait ToId {
     /// Returns `id` representation of the object.
+    ///
+    /// The reference held by `self` is autoreleased, so the `id` stays valid until the
+    /// innermost autorelease pool drains.
     fn to_id(self) -> id;
 }
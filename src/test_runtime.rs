@@ -0,0 +1,750 @@
+//! An in-process fake of the Objective-C runtime for unit-testing wrappers.
+//!
+//! With the `test-runtime` feature on a target other than Apple's, `msg_send!` no longer
+//! dispatches through libobjc. Every message is recorded as a [`Call`] and answered by a
+//! canned response registered with [`respond`] or [`respond_class`]. Classes looked up
+//! with `class!` are created as stubs on first use, and [`stub_class`] gives them a
+//! superclass so that responses and `isKindOfClass:` follow the hierarchy.
+//!
+//! ```
+//! use rust_macios::{
+//!     foundation::NSString,
+//!     objective_c_runtime::{traits::PNSObject, InvocationValue},
+//!     test_runtime,
+//! };
+//!
+//! test_runtime::reset();
+//! test_runtime::respond("NSString", "length", |_| InvocationValue::ULong(5));
+//!
+//! let string = NSString::from("hello");
+//! assert_eq!(string.length(), 5);
+//!
+//! let calls = test_runtime::calls();
+//! assert_eq!(calls.last().unwrap().selector.name(), "length");
+//! ```
+//!
+//! Messages without a response return zero, like messages sent to `nil`, except for the
+//! few the fake implements itself:
+//!
+//! - `alloc`, `allocWithZone:` and `new` create an object of the receiving class, and
+//!   `init` methods return the receiver.
+//! - `retain`, `release`, `autorelease`, `retainCount` and `dealloc` track a retain count
+//!   that [`retain_count`] reads. Objects are never freed.
+//! - `self`, `class`, `isKindOfClass:` and `isMemberOfClass:` describe the receiver, and
+//!   `respondsToSelector:` reports whether a response is registered.
+//! - `isMainThread` reports the thread set with [`set_main_thread`].
+//!
+//! [`set_os_version`] sets the OS version that availability checks see.
+//!
+//! The responses and the recorded calls belong to the current thread, so tests can run
+//! in parallel. The memory-management and introspection messages are not recorded. The
+//! main dispatch queue runs its work right away on the calling thread, which counts as
+//! the main thread meanwhile.
+//!
+//! The fake also provides enough of the blocks runtime to copy completion handlers, which
+//! a response can call through [`Call::argument`]. The frameworks' C functions and
+//! constants are not faked, so the tests that use them, and the documentation examples,
+//! still need the real runtime: run `cargo test --features test-runtime --lib --tests`.
+
+use std::{
+    any::{type_name, Any},
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    ffi::{c_void, CStr, CString},
+    fmt, mem,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicI32, AtomicPtr, AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+use lazy_static::lazy_static;
+use libc::{c_char, c_int, c_ulong};
+use objc::{
+    runtime::{Class, Object, Sel},
+    sel, sel_impl, Message, MessageArguments,
+};
+
+use crate::objective_c_runtime::{encoding::TypeEncoding, id, nil, InvocationValue};
+
+/// Sends a message through the fake runtime, with the syntax of `objc`'s `msg_send!`.
+///
+/// `objective_c_runtime` exports it in place of `objc`'s, so that the wrappers and the
+/// tests that import `msg_send!` from there send their messages to the fake.
+#[macro_export]
+macro_rules! msg_send {
+    (super($obj:expr, $superclass:expr), $name:ident) => ({
+        let sel = sel!($name);
+        $crate::test_runtime::send_super_message(::std::ptr::addr_of!(*$obj), $superclass, sel, ())
+    });
+    (super($obj:expr, $superclass:expr), $($name:ident : $arg:expr)+) => ({
+        let sel = sel!($($name:)+);
+        $crate::test_runtime::send_super_message(::std::ptr::addr_of!(*$obj), $superclass, sel, ($($arg,)*))
+    });
+    ($obj:expr, $name:ident) => ({
+        let sel = sel!($name);
+        $crate::test_runtime::send_message(::std::ptr::addr_of!(*$obj), sel, ())
+    });
+    ($obj:expr, $($name:ident : $arg:expr)+) => ({
+        let sel = sel!($($name:)+);
+        $crate::test_runtime::send_message(::std::ptr::addr_of!(*$obj), sel, ($($arg,)*))
+    });
+}
+
+type Response = Rc<dyn Fn(&Call) -> InvocationValue>;
+
+/// The `isa` of every stub class, which tells classes and instances apart.
+static METACLASS: u8 = 0;
+
+#[repr(C)]
+struct StubClass {
+    isa: *const u8,
+    name: CString,
+    superclass: AtomicPtr<StubClass>,
+}
+
+#[repr(C)]
+struct StubObject {
+    isa: *const StubClass,
+    retain_count: AtomicUsize,
+}
+
+lazy_static! {
+    // The stubs are leaked, and stored as addresses so that the maps are `Send`.
+    static ref CLASSES: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+    static ref SELECTORS: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+}
+
+thread_local! {
+    static RESPONSES: RefCell<HashMap<(String, bool, String), Response>> =
+        RefCell::new(HashMap::new());
+    static CALLS: RefCell<Vec<Call>> = const { RefCell::new(Vec::new()) };
+    static MAIN_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A message recorded by the fake runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    /// The object or class that received the message.
+    pub receiver: id,
+    /// The name of the class that received the message, or of the receiver's class.
+    pub class: String,
+    /// Whether the receiver is a class, and the message a class method.
+    pub class_method: bool,
+    /// The selector of the message.
+    pub selector: Sel,
+    /// The arguments of the message, without the receiver and the selector.
+    pub arguments: Vec<InvocationValue>,
+}
+
+impl Call {
+    /// Returns the argument at `index`, reinterpreted as a `T`.
+    ///
+    /// Arguments whose type the fake cannot name, such as the object wrappers that are
+    /// passed by value, are recorded as [`InvocationValue::Other`] with their bytes.
+    ///
+    /// # Safety
+    ///
+    /// `T` must have the size and layout of the argument's type.
+    pub unsafe fn argument<T>(&self, index: usize) -> T {
+        let bytes = self.arguments[index].to_bytes();
+        assert_eq!(
+            bytes.len(),
+            mem::size_of::<T>(),
+            "{self}: argument {index} is not a {}",
+            type_name::<T>()
+        );
+
+        std::ptr::read_unaligned(bytes.as_ptr() as *const T)
+    }
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.class_method { '+' } else { '-' };
+        write!(f, "{kind}[{} {}]", self.class, self.selector.name())
+    }
+}
+
+/// The arguments of a message, which the fake runtime records.
+pub trait RecordArguments {
+    /// Returns the arguments, decoded according to their Rust type.
+    fn record(&self) -> Vec<InvocationValue>;
+}
+
+/// Returns the encoding of the Rust type named `name`.
+///
+/// The arguments of `msg_send!` need not implement `Encode`, and may borrow, so their
+/// name is the only description of their type that is always available.
+fn encoding_of(name: &str) -> TypeEncoding {
+    match name.trim_start_matches("&mut ").trim_start_matches('&') {
+        "i8" => TypeEncoding::Char,
+        "i16" => TypeEncoding::Short,
+        "i32" => TypeEncoding::Int,
+        "i64" => TypeEncoding::LongLong,
+        "isize" => TypeEncoding::of::<isize>(),
+        "u8" => TypeEncoding::UChar,
+        "u16" => TypeEncoding::UShort,
+        "u32" => TypeEncoding::UInt,
+        "u64" => TypeEncoding::ULongLong,
+        "usize" => TypeEncoding::of::<usize>(),
+        "f32" => TypeEncoding::Float,
+        "f64" => TypeEncoding::Double,
+        "bool" => TypeEncoding::Bool,
+        "objc::runtime::Sel" => TypeEncoding::Sel,
+        "objc::runtime::Object" | "*mut objc::runtime::Object" | "*const objc::runtime::Object" => {
+            TypeEncoding::Object(None)
+        }
+        "objc::runtime::Class" | "*const objc::runtime::Class" => TypeEncoding::Class,
+        "*const i8" | "*mut i8" | "*const u8" => TypeEncoding::String,
+        name if name.starts_with('*') => TypeEncoding::pointer(TypeEncoding::Unknown),
+        _ => TypeEncoding::Unknown,
+    }
+}
+
+fn value_of<T>(value: &T) -> InvocationValue {
+    let bytes =
+        unsafe { std::slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) };
+
+    InvocationValue::from_bytes(&encoding_of(type_name::<T>()), bytes)
+}
+
+macro_rules! record_arguments {
+    ($($t:ident),*) => {
+        impl<$($t),*> RecordArguments for ($($t,)*) {
+            #[allow(non_snake_case)]
+            fn record(&self) -> Vec<InvocationValue> {
+                let ($($t,)*) = self;
+                vec![$(value_of($t)),*]
+            }
+        }
+    };
+}
+
+record_arguments!();
+record_arguments!(A);
+record_arguments!(A, B);
+record_arguments!(A, B, C);
+record_arguments!(A, B, C, D);
+record_arguments!(A, B, C, D, E);
+record_arguments!(A, B, C, D, E, F);
+record_arguments!(A, B, C, D, E, F, G);
+record_arguments!(A, B, C, D, E, F, G, H);
+record_arguments!(A, B, C, D, E, F, G, H, I);
+record_arguments!(A, B, C, D, E, F, G, H, I, J);
+record_arguments!(A, B, C, D, E, F, G, H, I, J, K);
+record_arguments!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+fn intern(
+    map: &Mutex<HashMap<String, usize>>,
+    name: &str,
+    create: impl FnOnce() -> usize,
+) -> usize {
+    *map.lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(name.to_owned())
+        .or_insert_with(create)
+}
+
+fn stub(name: &str) -> &'static StubClass {
+    let address = intern(&CLASSES, name, || {
+        Box::into_raw(Box::new(StubClass {
+            isa: &METACLASS,
+            name: CString::new(name).unwrap(),
+            superclass: AtomicPtr::default(),
+        })) as usize
+    });
+
+    unsafe { &*(address as *const StubClass) }
+}
+
+impl StubClass {
+    fn name(&self) -> &str {
+        self.name.to_str().unwrap()
+    }
+
+    fn superclass(&self) -> Option<&'static StubClass> {
+        unsafe { self.superclass.load(Ordering::Relaxed).as_ref() }
+    }
+
+    fn ancestors(&'static self) -> impl Iterator<Item = &'static StubClass> {
+        std::iter::successors(Some(self), |class| class.superclass())
+    }
+
+    fn as_class(&self) -> &'static Class {
+        unsafe { &*(self as *const StubClass as *const Class) }
+    }
+}
+
+/// Returns whether `receiver` is a class, and the class it is or is an instance of.
+unsafe fn class_of(receiver: id) -> (bool, &'static StubClass) {
+    if std::ptr::eq(*(receiver as *const *const u8), &METACLASS) {
+        (true, &*(receiver as *const StubClass))
+    } else {
+        (false, &*(*(receiver as *const *const StubClass)))
+    }
+}
+
+/// Returns the stub class named `name`, creating it if needed, and sets its superclass.
+///
+/// Classes that are only looked up with `class!` are created without a superclass.
+pub fn stub_class(name: &str, superclass: Option<&str>) -> &'static Class {
+    let class = stub(name);
+    let superclass = superclass.map_or(std::ptr::null_mut(), |superclass| {
+        stub(superclass) as *const StubClass as *mut StubClass
+    });
+
+    class.superclass.store(superclass, Ordering::Relaxed);
+    class.as_class()
+}
+
+/// Creates an instance of the stub class named `class`, with a retain count of 1.
+pub fn new_object(class: &str) -> id {
+    Box::into_raw(Box::new(StubObject {
+        isa: stub(class),
+        retain_count: AtomicUsize::new(1),
+    })) as id
+}
+
+/// Returns the retain count of an object created by the fake runtime.
+///
+/// # Safety
+///
+/// `object` must have been created by the fake runtime.
+pub unsafe fn retain_count(object: id) -> usize {
+    (*(object as *const StubObject))
+        .retain_count
+        .load(Ordering::Relaxed)
+}
+
+fn add_response<F>(class: &str, class_method: bool, selector: &str, response: F)
+where
+    F: Fn(&Call) -> InvocationValue + 'static,
+{
+    RESPONSES.with(|responses| {
+        responses.borrow_mut().insert(
+            (class.to_owned(), class_method, selector.to_owned()),
+            Rc::new(response),
+        )
+    });
+}
+
+/// Answers the instance method `selector` of `class` and its subclasses with the value
+/// `response` returns.
+///
+/// The value must have the size of the type the caller expects, as it is reinterpreted
+/// as that type.
+pub fn respond<F>(class: &str, selector: &str, response: F)
+where
+    F: Fn(&Call) -> InvocationValue + 'static,
+{
+    add_response(class, false, selector, response)
+}
+
+/// Answers the class method `selector` of `class` and its subclasses with the value
+/// `response` returns.
+pub fn respond_class<F>(class: &str, selector: &str, response: F)
+where
+    F: Fn(&Call) -> InvocationValue + 'static,
+{
+    add_response(class, true, selector, response)
+}
+
+/// Returns the messages sent on the current thread since the last [`reset`].
+pub fn calls() -> Vec<Call> {
+    CALLS.with(|calls| calls.borrow().clone())
+}
+
+/// Returns the messages with the selector `selector` sent on the current thread.
+pub fn calls_to(selector: &str) -> Vec<Call> {
+    CALLS.with(|calls| {
+        calls
+            .borrow()
+            .iter()
+            .filter(|call| call.selector.name() == selector)
+            .cloned()
+            .collect()
+    })
+}
+
+/// Makes `NSProcessInfo` report the OS version `major.minor.patch` on the current thread,
+/// which the availability checks of `#[available]` methods and `available!` compare with.
+///
+/// Without it the version is 0.0.0, so those checks fail.
+pub fn set_os_version(major: isize, minor: isize, patch: isize) {
+    let bytes = [major, minor, patch]
+        .iter()
+        .flat_map(|part| part.to_ne_bytes())
+        .collect::<Vec<_>>();
+    let encoding = TypeEncoding::Struct(
+        "NSOperatingSystemVersion".into(),
+        Some(vec![TypeEncoding::LongLong; 3]),
+    );
+
+    respond_class("NSProcessInfo", "processInfo", |_| {
+        InvocationValue::Object(new_object("NSProcessInfo"))
+    });
+    respond("NSProcessInfo", "operatingSystemVersion", move |_| {
+        InvocationValue::Other(encoding.clone(), bytes.clone())
+    });
+}
+
+/// Makes `+[NSThread isMainThread]` report whether the current thread is the main thread.
+///
+/// Without it no thread is, except while the fake main queue runs a closure for
+/// `run_on_main`.
+pub fn set_main_thread(is_main_thread: bool) {
+    MAIN_THREAD.with(|main_thread| main_thread.set(is_main_thread));
+}
+
+/// Forgets the responses, the calls and the main thread of the current thread.
+pub fn reset() {
+    RESPONSES.with(|responses| responses.borrow_mut().clear());
+    CALLS.with(|calls| calls.borrow_mut().clear());
+    set_main_thread(false);
+}
+
+fn find_response(
+    class: &'static StubClass,
+    class_method: bool,
+    selector: &str,
+) -> Option<Response> {
+    RESPONSES.with(|responses| {
+        let responses = responses.borrow();
+
+        class.ancestors().find_map(|class| {
+            responses
+                .get(&(class.name().to_owned(), class_method, selector.to_owned()))
+                .cloned()
+        })
+    })
+}
+
+/// Reinterprets `value` as the return type `R` of the message `call`.
+unsafe fn convert<R>(call: &Call, value: InvocationValue) -> R {
+    let bytes = value.to_bytes();
+    assert_eq!(
+        bytes.len(),
+        mem::size_of::<R>(),
+        "{call} answered {value:?}, which does not have the size of {}",
+        type_name::<R>()
+    );
+
+    std::ptr::read_unaligned(bytes.as_ptr() as *const R)
+}
+
+fn bool_value(condition: bool) -> InvocationValue {
+    InvocationValue::Char(condition as i8)
+}
+
+/// Answers the messages the fake runtime implements itself.
+unsafe fn builtin(receiver: id, class: &'static StubClass, call: &Call) -> Option<InvocationValue> {
+    let object = || &*(receiver as *const StubObject);
+    let is_kind_of = || match call.arguments[..] {
+        [InvocationValue::Class(other)] => class
+            .ancestors()
+            .any(|class| std::ptr::eq(class.as_class(), other)),
+        _ => false,
+    };
+
+    let value = match (call.class_method, call.selector.name()) {
+        (true, "alloc" | "allocWithZone:" | "new") => {
+            InvocationValue::Object(new_object(class.name()))
+        }
+        (false, name) if name.starts_with("init") => InvocationValue::Object(receiver),
+        (false, "retain") => {
+            object().retain_count.fetch_add(1, Ordering::Relaxed);
+            InvocationValue::Object(receiver)
+        }
+        (false, "release") => {
+            object().retain_count.fetch_sub(1, Ordering::Relaxed);
+            InvocationValue::Void
+        }
+        (false, "retainCount") => InvocationValue::ULong(retain_count(receiver) as _),
+        // Classes are never freed, so they ignore memory management.
+        (true, "retain") | (_, "autorelease" | "self") => InvocationValue::Object(receiver),
+        (true, "release") | (_, "dealloc") => InvocationValue::Void,
+        (_, "class") => InvocationValue::Class(class.as_class()),
+        (true, "isMainThread") => bool_value(MAIN_THREAD.with(Cell::get)),
+        (false, "isKindOfClass:") => bool_value(is_kind_of()),
+        (false, "isMemberOfClass:") => bool_value(matches!(
+            call.arguments[..],
+            [InvocationValue::Class(other)] if std::ptr::eq(class.as_class(), other)
+        )),
+        (class_method, "respondsToSelector:") => bool_value(match call.arguments[..] {
+            [InvocationValue::Sel(selector)] => {
+                find_response(class, class_method, selector.name()).is_some()
+            }
+            _ => false,
+        }),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Returns whether the message is a memory-management or introspection message, which
+/// is not recorded.
+fn is_silent(selector: &str) -> bool {
+    matches!(
+        selector,
+        "retain"
+            | "release"
+            | "autorelease"
+            | "retainCount"
+            | "dealloc"
+            | "self"
+            | "class"
+            | "isKindOfClass:"
+            | "isMemberOfClass:"
+            | "isMainThread"
+    )
+}
+
+unsafe fn dispatch<A, R>(
+    receiver: id,
+    class: Option<&'static StubClass>,
+    selector: Sel,
+    arguments: A,
+) -> R
+where
+    A: RecordArguments,
+{
+    if receiver.is_null() {
+        return mem::zeroed();
+    }
+
+    let (class_method, receiver_class) = class_of(receiver);
+    let class = class.unwrap_or(receiver_class);
+    let call = Call {
+        receiver,
+        class: class.name().to_owned(),
+        class_method,
+        selector,
+        arguments: arguments.record(),
+    };
+
+    let value = if is_silent(selector.name()) {
+        builtin(receiver, class, &call)
+    } else {
+        CALLS.with(|calls| calls.borrow_mut().push(call.clone()));
+
+        // The response may send messages itself, so it is called outside of the borrow.
+        match find_response(class, class_method, selector.name()) {
+            Some(response) => Some(response(&call)),
+            None => builtin(receiver, class, &call),
+        }
+    };
+
+    match value {
+        Some(value) => convert(&call, value),
+        None => mem::zeroed(),
+    }
+}
+
+/// Sends a message through the fake runtime. Used by [`msg_send!`](crate::msg_send).
+///
+/// # Safety
+///
+/// `receiver` must be `nil` or a class or an object created by the fake runtime.
+pub unsafe fn send_message<T, A, R>(receiver: *const T, selector: Sel, arguments: A) -> R
+where
+    T: Message,
+    A: MessageArguments + RecordArguments,
+    R: Any,
+{
+    dispatch(receiver as *const c_void as id, None, selector, arguments)
+}
+
+/// Sends a message to the superclass `superclass` through the fake runtime.
+///
+/// # Safety
+///
+/// The same as [`send_message`], and `superclass` must be a stub class.
+pub unsafe fn send_super_message<T, A, R>(
+    receiver: *const T,
+    superclass: &Class,
+    selector: Sel,
+    arguments: A,
+) -> R
+where
+    T: Message,
+    A: MessageArguments + RecordArguments,
+    R: Any,
+{
+    let superclass = &*(superclass as *const Class as *const StubClass);
+
+    dispatch(
+        receiver as *const c_void as id,
+        Some(superclass),
+        selector,
+        arguments,
+    )
+}
+
+/* The parts of libobjc that `objc`, `objc_id` and the crate call directly.
+ */
+
+#[no_mangle]
+extern "C" fn sel_registerName(name: *const c_char) -> Sel {
+    let name = unsafe { CStr::from_ptr(name) };
+    let address = intern(&SELECTORS, &name.to_string_lossy(), || {
+        CString::from(name).into_raw() as usize
+    });
+
+    unsafe { Sel::from_ptr(address as *const c_void) }
+}
+
+#[no_mangle]
+extern "C" fn sel_getName(selector: Sel) -> *const c_char {
+    selector.as_ptr() as *const c_char
+}
+
+#[no_mangle]
+extern "C" fn objc_getClass(name: *const c_char) -> *const Class {
+    stub(&unsafe { CStr::from_ptr(name) }.to_string_lossy()).as_class()
+}
+
+#[no_mangle]
+extern "C" fn class_getName(class: *const Class) -> *const c_char {
+    unsafe { &*(class as *const StubClass) }.name.as_ptr()
+}
+
+#[no_mangle]
+extern "C" fn class_getSuperclass(class: *const Class) -> *const Class {
+    unsafe { &*(class as *const StubClass) }
+        .superclass()
+        .map_or(std::ptr::null(), |superclass| superclass.as_class())
+}
+
+#[no_mangle]
+extern "C" fn object_getClass(object: *const Object) -> *const Class {
+    if object.is_null() {
+        return std::ptr::null();
+    }
+
+    unsafe { class_of(object as id) }.1.as_class()
+}
+
+#[no_mangle]
+extern "C" fn objc_retain(object: id) -> id {
+    unsafe { send_message(object, sel!(retain), ()) }
+}
+
+#[no_mangle]
+extern "C" fn objc_release(object: id) {
+    unsafe { send_message(object, sel!(release), ()) }
+}
+
+#[no_mangle]
+extern "C" fn objc_autorelease(object: id) -> id {
+    object
+}
+
+#[no_mangle]
+extern "C" fn objc_autoreleasePoolPush() -> *mut c_void {
+    std::ptr::null_mut()
+}
+
+#[no_mangle]
+extern "C" fn objc_autoreleasePoolPop(_context: *mut c_void) {}
+
+/// Stands in for the `@try`/`@catch` shim, as nothing raises in the fake runtime.
+#[no_mangle]
+extern "C-unwind" fn rust_macios_try_catch(
+    f: extern "C-unwind" fn(*mut c_void),
+    context: *mut c_void,
+    exception: *mut id,
+) -> c_int {
+    f(context);
+    unsafe { *exception = nil };
+    0
+}
+
+/* The main dispatch queue, for `run_on_main`.
+ */
+
+#[no_mangle]
+#[allow(non_upper_case_globals)]
+static _dispatch_main_q: [usize; 4] = [0; 4];
+
+#[no_mangle]
+extern "C" fn dispatch_sync_f(
+    _queue: *const c_void,
+    context: *mut c_void,
+    work: extern "C" fn(*mut c_void),
+) {
+    let was_main_thread = MAIN_THREAD.with(|main_thread| main_thread.replace(true));
+    work(context);
+    set_main_thread(was_main_thread);
+}
+
+/* A minimal blocks runtime, for the wrappers that copy their completion handlers.
+ */
+
+const BLOCK_NEEDS_FREE: c_int = 1 << 24;
+const BLOCK_HAS_COPY_DISPOSE: c_int = 1 << 25;
+
+#[repr(C)]
+struct BlockLayout {
+    isa: *const c_void,
+    flags: c_int,
+    // Counts the references to a copied block, which the runtime is free to store here.
+    reference_count: AtomicI32,
+    invoke: *const c_void,
+    descriptor: *const BlockDescriptor,
+}
+
+#[repr(C)]
+struct BlockDescriptor {
+    reserved: c_ulong,
+    size: c_ulong,
+    copy_helper: unsafe extern "C" fn(*mut c_void, *const c_void),
+    dispose_helper: unsafe extern "C" fn(*mut c_void),
+}
+
+#[no_mangle]
+#[allow(non_upper_case_globals)]
+static _NSConcreteStackBlock: [usize; 4] = [0; 4];
+
+#[no_mangle]
+unsafe extern "C" fn _Block_copy(block: *const c_void) -> *mut c_void {
+    let layout = &*(block as *const BlockLayout);
+
+    if layout.flags & BLOCK_NEEDS_FREE != 0 {
+        layout.reference_count.fetch_add(1, Ordering::Relaxed);
+        return block as *mut c_void;
+    }
+
+    let size = (*layout.descriptor).size as usize;
+    let copy = libc::malloc(size);
+    std::ptr::copy_nonoverlapping(block as *const u8, copy as *mut u8, size);
+
+    let copied = &mut *(copy as *mut BlockLayout);
+    copied.flags |= BLOCK_NEEDS_FREE;
+    copied.reference_count = AtomicI32::new(1);
+
+    if layout.flags & BLOCK_HAS_COPY_DISPOSE != 0 {
+        ((*layout.descriptor).copy_helper)(copy, block);
+    }
+
+    copy
+}
+
+#[no_mangle]
+unsafe extern "C" fn _Block_release(block: *const c_void) {
+    let layout = &*(block as *const BlockLayout);
+
+    if layout.flags & BLOCK_NEEDS_FREE == 0
+        || layout.reference_count.fetch_sub(1, Ordering::Relaxed) != 1
+    {
+        return;
+    }
+
+    if layout.flags & BLOCK_HAS_COPY_DISPOSE != 0 {
+        ((*layout.descriptor).dispose_helper)(block as *mut c_void);
+    }
+
+    libc::free(block as *mut c_void);
+}
@@ -1,10 +1,11 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     foundation::{NSDate, NSDateComponents},
     object,
-    objective_c_runtime::traits::FromId,
+    objective_c_runtime::{traits::FromId, AutoreleasePool, Autoreleased},
     utils::to_optional,
 };
 
@@ -23,16 +24,18 @@ impl UNCalendarNotificationTrigger {
 
     /// Creates a calendar trigger using the date components parameter.
     #[method]
-    pub fn trigger_with_date_matching_components_repeats(
+    pub fn trigger_with_date_matching_components_repeats<'pool>(
         date_components: NSDateComponents,
         repeats: bool,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), triggerWithDateMatchingComponents: date_components repeats: repeats],
+                pool,
             )
         }
     }
@@ -1,6 +1,12 @@
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
-use crate::object;
+use crate::{
+    core_location::CLRegion,
+    object,
+    objective_c_runtime::{traits::FromId, AutoreleasePool, Autoreleased},
+};
 
 use super::IUNNotificationTrigger;
 
@@ -20,14 +26,19 @@ impl UNLocationNotificationTrigger {
     ///
     /// Required features: `"core_location"`
     #[method]
-    fn trigger_with_region_repeats(region: crate::core_location::CLRegion, repeats: bool) -> Self
+    fn trigger_with_region_repeats<'pool>(
+        region: CLRegion,
+        repeats: bool,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
-        Self: Sized + crate::objective_c_runtime::traits::FromId,
+        Self: Sized + FromId,
     {
-        use objc::{msg_send, sel, sel_impl};
-
         unsafe {
-            Self::from_id(msg_send![Self::m_class(), triggerWithRegion: region repeats: repeats])
+            Autoreleased::new(
+                msg_send![Self::m_class(), triggerWithRegion: region repeats: repeats],
+                pool,
+            )
         }
     }
 
@@ -35,10 +46,7 @@ impl UNLocationNotificationTrigger {
     ///
     /// Required features: `"core_location"`
     #[property]
-    fn region(&self) -> crate::core_location::CLRegion {
-        use crate::objective_c_runtime::traits::FromId;
-        use objc::{msg_send, sel, sel_impl};
-
-        unsafe { crate::core_location::CLRegion::from_id(msg_send![self.m_self(), region]) }
+    fn region(&self) -> CLRegion {
+        unsafe { CLRegion::from_id(msg_send![self.m_self(), region]) }
     }
 }
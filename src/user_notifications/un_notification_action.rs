@@ -1,10 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     foundation::NSString,
     object,
-    objective_c_runtime::traits::{FromId, PNSObject},
+    objective_c_runtime::{
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
     utils::to_optional,
 };
 
@@ -30,38 +34,42 @@ impl UNNotificationAction {
 
     /// Creates an action object by using the specified title and options.
     #[method]
-    pub fn action_with_identifier_title_options(
+    pub fn action_with_identifier_title_options<'pool>(
         identifier: NSString,
         title: NSString,
         options: &[UNNotificationActionOptions],
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         let options = options.iter().fold(0, |init, option| init | *option as u64);
 
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), actionWithIdentifier: identifier title: title options: options],
+                pool,
             )
         }
     }
 
     #[method]
-    pub fn action_with_identifier_title_options_icon(
+    pub fn action_with_identifier_title_options_icon<'pool>(
         identifier: NSString,
         title: NSString,
         options: &[UNNotificationActionOptions],
         icon: UNNotificationActionIcon,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         let options = options.iter().fold(0, |init, option| init | *option as u64);
 
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), actionWithIdentifier: identifier title: title options: options icon: icon],
+                pool,
             )
         }
     }
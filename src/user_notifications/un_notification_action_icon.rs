@@ -1,10 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     foundation::NSString,
     object,
-    objective_c_runtime::traits::{FromId, PNSObject},
+    objective_c_runtime::{
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
 };
 
 object! {
@@ -18,29 +22,41 @@ impl UNNotificationActionIcon {
 
     /// Creates an action icon by using a system symbol image.
     #[method]
-    pub fn icon_with_system_image_name(system_image_name: NSString) -> Self
+    pub fn icon_with_system_image_name<'pool>(
+        system_image_name: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                iconWithSystemImageName: system_image_name
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    iconWithSystemImageName: system_image_name
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates an action icon based on an image in your app’s bundle, preferably in an asset catalog.
     #[method]
-    pub fn icon_with_template_image_name(template_image_name: NSString) -> Self
+    pub fn icon_with_template_image_name<'pool>(
+        template_image_name: NSString,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                iconWithTemplateImageName: template_image_name
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    iconWithTemplateImageName: template_image_name
+                ],
+                pool,
+            )
         }
     }
 }
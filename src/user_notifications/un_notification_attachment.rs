@@ -1,12 +1,14 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     foundation::{NSDictionary, NSError, NSString, NSURL},
     object,
     objective_c_runtime::{
-        id,
+        id, nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -36,29 +38,29 @@ impl UNNotificationAttachment {
 
     /// Creates an attachment object from the specified file and options.
     #[method]
-    pub fn attachment_with_identifier_url_options(
+    pub fn attachment_with_identifier_url_options<'pool>(
         identifier: NSString,
         url: NSURL,
         options: NSDictionary<id, id>,
-    ) -> Result<Self, NSError>
+        pool: &'pool AutoreleasePool,
+    ) -> Result<Autoreleased<'pool, Self>, NSError>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            let mut error = NSError::m_alloc();
-            let ptr = Self::from_id(msg_send![
-                Self::m_class(),
-                attachmentWithIdentifier: identifier
-                URL: url
-                options: options
-                error: &mut error
-            ]);
+            let mut error: id = nil;
+            let ptr = Autoreleased::try_new(
+                msg_send![
+                    Self::m_class(),
+                    attachmentWithIdentifier: identifier
+                    URL: url
+                    options: options
+                    error: &mut error
+                ],
+                pool,
+            );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
@@ -7,6 +8,7 @@ use crate::{
     objective_c_runtime::{
         nil,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
 };
 
@@ -36,12 +38,13 @@ impl UNNotificationCategory {
 
     /// Creates a category object containing the specified actions and options.
     #[method]
-    pub fn category_with_identifier_actions_intent_identifiers_options(
+    pub fn category_with_identifier_actions_intent_identifiers_options<'pool>(
         identifier: NSString,
         actions: NSArray<UNNotificationAction>,
         intent_identifiers: NSArray<NSString>,
         options: &[UNNotificationCategoryOptions],
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
@@ -50,21 +53,25 @@ impl UNNotificationCategory {
             .fold(0u64, |init, option| init | *option as u64);
 
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), categoryWithIdentifier: identifier actions: actions intentIdentifiers: intent_identifiers options: options],
+                pool,
             )
         }
     }
 
     /// Creates a category object containing the specified actions, options, and placeholder text used when previews aren’t shown.
     #[method]
-    pub fn category_with_identifier_actions_intent_identifiers_hidden_previews_body_placeholder_options(
+    pub fn category_with_identifier_actions_intent_identifiers_hidden_previews_body_placeholder_options<
+        'pool,
+    >(
         identifier: NSString,
         actions: NSArray<UNNotificationAction>,
         intent_identifiers: NSArray<NSString>,
         hidden_previews_body_placeholder: Option<NSString>,
         options: &[UNNotificationCategoryOptions],
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
@@ -78,20 +85,24 @@ impl UNNotificationCategory {
             .fold(0u64, |init, option| init | *option as u64);
 
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), categoryWithIdentifier: identifier actions: actions intentIdentifiers: intent_identifiers hiddenPreviewsBodyPlaceholder: hidden_previews_body_placeholder options: options],
+                pool,
             )
         }
     }
 
-    pub fn categoryWithIdentifier_actions_intentIdentifiers_hiddenPreviewsBodyPlaceholder_categorySummaryFormat_options(
+    pub fn categoryWithIdentifier_actions_intentIdentifiers_hiddenPreviewsBodyPlaceholder_categorySummaryFormat_options<
+        'pool,
+    >(
         identifier: NSString,
         actions: NSArray<UNNotificationAction>,
         intent_identifiers: NSArray<NSString>,
         hidden_previews_body_placeholder: NSString,
         category_summary_format: NSString,
         options: &[UNNotificationCategoryOptions],
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
@@ -100,8 +111,9 @@ impl UNNotificationCategory {
             .fold(0u64, |init, option| init | *option as u64);
 
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), categoryWithIdentifier: identifier actions: actions intentIdentifiers: intent_identifiers hiddenPreviewsBodyPlaceholder: hidden_previews_body_placeholder categorySummaryFormat: categorySummaryFormat options: options],
+                pool,
             )
         }
     }
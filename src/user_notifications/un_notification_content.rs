@@ -1,12 +1,13 @@
+use crate::objective_c_runtime::msg_send;
 use libc::c_double;
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     foundation::{NSArray, NSDictionary, NSError, NSNumber, NSString, UInt},
     object,
     objective_c_runtime::{
-        id,
+        id, nil,
         traits::{FromId, PNSObject},
     },
     utils::to_optional,
@@ -148,17 +149,13 @@ impl UNNotificationContent {
         provider: id,
     ) -> Result<UNNotificationContent, NSError> {
         unsafe {
-            let mut error = NSError::m_alloc();
+            let mut error: id = nil;
 
-            let ptr = UNNotificationContent::from_id(
+            let ptr = UNNotificationContent::try_from_id(
                 msg_send![self.m_self(), contentByUpdatingWithProvider: provider error: &mut error],
             );
 
-            if error.m_self().is_null() {
-                Ok(ptr)
-            } else {
-                Err(error)
-            }
+            ptr.ok_or_else(|| NSError::from_out_param(error))
         }
     }
 }
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     foundation::NSString,
@@ -6,6 +7,7 @@ use crate::{
     objective_c_runtime::{
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
     },
     utils::to_optional,
 };
@@ -24,17 +26,19 @@ impl UNNotificationRequest {
 
     /// Creates a notification request object that you use to schedule a notification.
     #[method]
-    pub fn request_with_identifier_content_trigger(
+    pub fn request_with_identifier_content_trigger<'pool>(
         identifier: &NSString,
         content: &UNNotificationContent,
         trigger: &UNNotificationTrigger,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(),  requestWithIdentifier: identifier.m_self() content: content.m_self() trigger: trigger.m_self()],
+                pool,
             )
         }
     }
@@ -1,5 +1,6 @@
+use crate::objective_c_runtime::msg_send;
 use block::IntoConcreteBlock;
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{object, objective_c_runtime::traits::PNSObject};
@@ -1,4 +1,5 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 
 use crate::{
     object,
@@ -154,7 +155,7 @@ impl UNNotificationSettings {
         unsafe { to_bool(msg_send![self.m_self(), providesAppNotificationSettings]) }
     }
 
-    ///
+    /// The setting that indicates whether the app’s direct messages can break through Focus.
     #[property]
     pub fn direct_messages_setting(&self) -> UNNotificationSetting {
         unsafe { msg_send![self.m_self(), directMessagesSetting] }
@@ -1,10 +1,14 @@
+use crate::objective_c_runtime::msg_send;
 use libc::c_float;
-use objc::{msg_send, sel, sel_impl};
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     object,
-    objective_c_runtime::traits::{FromId, PNSObject},
+    objective_c_runtime::{
+        traits::{FromId, PNSObject},
+        AutoreleasePool, Autoreleased,
+    },
 };
 
 use super::UNNotificationSoundName;
@@ -27,11 +31,14 @@ impl UNNotificationSound {
 
     /// Creates a sound object that represents a custom sound file.
     #[method]
-    pub fn sound_named(name: UNNotificationSoundName) -> Self
+    pub fn sound_named<'pool>(
+        name: UNNotificationSoundName,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), soundNamed: name]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), soundNamed: name], pool) }
     }
 
     /* Getting Critical Sounds
@@ -45,39 +52,50 @@ impl UNNotificationSound {
 
     /// Creates a sound object that plays the default critical alert sound at the volume you specify.
     #[method]
-    pub fn default_critical_sound_with_audio_volume(volume: c_float) -> Self
+    pub fn default_critical_sound_with_audio_volume<'pool>(
+        volume: c_float,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                defaultCriticalSoundWithAudioVolume: volume
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    defaultCriticalSoundWithAudioVolume: volume
+                ],
+                pool,
+            )
         }
     }
 
     /// Creates a custom sound object for critical alerts.
     #[method]
-    pub fn critical_sound_named(name: UNNotificationSoundName) -> Self
+    pub fn critical_sound_named<'pool>(
+        name: UNNotificationSoundName,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), criticalSoundNamed: name]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), criticalSoundNamed: name], pool) }
     }
 
     /// Creates a custom sound object for critical alerts with the volume you specify.
     #[method]
-    pub fn critical_sound_named_with_audio_volume(
+    pub fn critical_sound_named_with_audio_volume<'pool>(
         name: UNNotificationSoundName,
         volume: c_float,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), criticalSoundNamed: name withAudioVolume: volume],
+                pool,
             )
         }
     }
@@ -94,10 +112,13 @@ impl UNNotificationSound {
      */
 
     #[method]
-    pub fn ringtone_sound_named(name: UNNotificationSoundName) -> Self
+    pub fn ringtone_sound_named<'pool>(
+        name: UNNotificationSoundName,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
-        unsafe { Self::from_id(msg_send![Self::m_class(), ringtoneSoundNamed: name]) }
+        unsafe { Autoreleased::new(msg_send![Self::m_class(), ringtoneSoundNamed: name], pool) }
     }
 }
@@ -1,7 +1,12 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
-use crate::{foundation::NSString, object, objective_c_runtime::traits::FromId};
+use crate::{
+    foundation::NSString,
+    object,
+    objective_c_runtime::{traits::FromId, AutoreleasePool, Autoreleased},
+};
 
 use super::{IUNNotificationAction, UNNotificationActionIcon, UNNotificationActionOptions};
 
@@ -18,39 +23,47 @@ impl UNTextInputNotificationAction {
 
     /// Creates an action object that accepts text input from the user.
     #[method]
-    pub fn action_with_identifier_title_options_text_input_button_title_text_input_placeholder(
+    pub fn action_with_identifier_title_options_text_input_button_title_text_input_placeholder<
+        'pool,
+    >(
         identifier: NSString,
         title: NSString,
         options: &[UNNotificationActionOptions],
         text_input_button_title: NSString,
         text_input_placeholder: NSString,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), actionWithIdentifier: identifier title: title options: options textInputButtonTitle: text_input_button_title textInputPlaceholder: text_input_placeholder],
+                pool,
             )
         }
     }
 
     /// Creates an action object that accepts text input from the user.
     #[method]
-    pub fn action_with_identifier_title_options_icon_text_input_button_title_text_input_placeholder(
+    pub fn action_with_identifier_title_options_icon_text_input_button_title_text_input_placeholder<
+        'pool,
+    >(
         identifier: NSString,
         title: NSString,
         options: &[UNNotificationActionOptions],
         icon: UNNotificationActionIcon,
         text_input_button_title: NSString,
         text_input_placeholder: NSString,
-    ) -> Self
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(
+            Autoreleased::new(
                 msg_send![Self::m_class(), actionWithIdentifier: identifier title: title options: options icon: icon textInputButtonTitle: text_input_button_title textInputPlaceholder: text_input_placeholder],
+                pool,
             )
         }
     }
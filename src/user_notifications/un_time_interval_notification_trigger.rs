@@ -1,10 +1,11 @@
-use objc::{msg_send, sel, sel_impl};
+use crate::objective_c_runtime::msg_send;
+use objc::{sel, sel_impl};
 use rust_macios_objective_c_runtime_proc_macros::interface_impl;
 
 use crate::{
     foundation::{NSDate, NSTimeInterval},
     object,
-    objective_c_runtime::traits::FromId,
+    objective_c_runtime::{traits::FromId, AutoreleasePool, Autoreleased},
     utils::to_optional,
 };
 
@@ -24,16 +25,23 @@ impl UNTimeIntervalNotificationTrigger {
 
     /// Creates a time interval trigger using the time value parameter.
     #[method]
-    pub fn trigger_with_time_interval_repeats(time_interval: NSTimeInterval, repeats: bool) -> Self
+    pub fn trigger_with_time_interval_repeats<'pool>(
+        time_interval: NSTimeInterval,
+        repeats: bool,
+        pool: &'pool AutoreleasePool,
+    ) -> Autoreleased<'pool, Self>
     where
         Self: Sized + FromId,
     {
         unsafe {
-            Self::from_id(msg_send![
-                Self::m_class(),
-                triggerWithTimeInterval: time_interval
-                repeats: repeats
-            ])
+            Autoreleased::new(
+                msg_send![
+                    Self::m_class(),
+                    triggerWithTimeInterval: time_interval
+                    repeats: repeats
+                ],
+                pool,
+            )
         }
     }
 
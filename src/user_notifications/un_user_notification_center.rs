@@ -1,5 +1,6 @@
+use crate::objective_c_runtime::msg_send;
 use block::{ConcreteBlock, IntoConcreteBlock};
-use objc::{msg_send, sel, sel_impl};
+use objc::{runtime::BOOL, sel, sel_impl};
 
 use crate::{
     foundation::{Int, NSArray, NSError, NSSet, NSString},
@@ -8,8 +9,9 @@ use crate::{
         id,
         macros::interface_impl,
         traits::{FromId, PNSObject},
+        BlockDropped, BlockFuture, CompletionError,
     },
-    utils::to_bool,
+    utils::{to_bool, to_result},
 };
 
 use super::{
@@ -79,9 +81,9 @@ pub enum UNErrorCode {
     NotificationInvalidNoDate = 1400,
     /// The notification has no user-facing content, but should.
     NotificationInvalidNoContent,
-    ///
+    /// The notification’s content-providing object is not allowed.
     ContentProvidingObjectNotAllowed = 1500,
-    ///
+    /// The notification’s content-providing object is invalid.
     ContentProvidingInvalid = 1501,
 }
 
@@ -174,6 +176,10 @@ impl UNUserNotificationCenter {
 
     /// Sets the delegate of the notification center.
     ///
+    /// The shared center outlives every delegate but holds it weakly, so keep the delegate
+    /// for the life of the app. Set it before the app finishes launching, or the response
+    /// to the notification that launched the app is never delivered.
+    ///
     /// # Arguments
     ///
     /// * `delegate` - The delegate to use.
@@ -302,3 +308,130 @@ impl UNUserNotificationCenter {
         }
     }
 }
+
+impl UNUserNotificationCenter {
+    /// Retrieves the authorization and feature-related settings for your app.
+    pub async fn get_notification_settings(&self) -> Result<UNNotificationSettings, BlockDropped> {
+        BlockFuture::send(
+            |completion| {
+                move |settings: id| {
+                    completion.complete(unsafe { UNNotificationSettings::from_id(settings) })
+                }
+            },
+            |block| unsafe {
+                msg_send![self.m_self(), getNotificationSettingsWithCompletionHandler: block]
+            },
+        )
+        .await
+    }
+
+    /// Updates the badge count for your app’s icon.
+    pub async fn set_badge_count(&mut self, new_badge_count: Int) -> Result<(), CompletionError> {
+        BlockFuture::send(
+            |completion| move |error: id| completion.complete(to_result((), error)),
+            |block| unsafe {
+                msg_send![
+                    self.m_self(),
+                    setBadgeCount: new_badge_count
+                    withCompletionHandler: block
+                ]
+            },
+        )
+        .await?
+        .map_err(CompletionError::Failed)
+    }
+
+    /// Requests the user’s authorization to allow local and remote notifications for your
+    /// app, resolving to whether the user granted it.
+    pub async fn request_authorization_with_options(
+        &mut self,
+        options: &[UNAuthorizationOptions],
+    ) -> Result<bool, CompletionError> {
+        let options = options
+            .iter()
+            .fold(0u64, |init, option| init | *option as u64);
+        BlockFuture::send(
+            |completion| {
+                move |granted: BOOL, error: id| {
+                    completion.complete(to_result(to_bool(granted), error))
+                }
+            },
+            |block| unsafe {
+                msg_send![
+                    self.m_self(),
+                    requestAuthorizationWithOptions: options
+                    completionHandler: block
+                ]
+            },
+        )
+        .await?
+        .map_err(CompletionError::Failed)
+    }
+
+    /// Schedules the delivery of a local notification.
+    pub async fn add_notification_request(
+        &mut self,
+        request: &UNNotificationRequest,
+    ) -> Result<(), CompletionError> {
+        BlockFuture::send(
+            |completion| move |error: id| completion.complete(to_result((), error)),
+            |block| unsafe {
+                msg_send![
+                    self.m_self(),
+                    addNotificationRequest: request.m_self()
+                    withCompletionHandler: block
+                ]
+            },
+        )
+        .await?
+        .map_err(CompletionError::Failed)
+    }
+
+    /// Fetches all of your app’s local notifications that are pending delivery.
+    pub async fn get_pending_notification_requests(
+        &self,
+    ) -> Result<NSArray<UNNotificationRequest>, BlockDropped> {
+        BlockFuture::send(
+            |completion| {
+                move |requests: id| completion.complete(unsafe { NSArray::from_id(requests) })
+            },
+            |block| unsafe {
+                msg_send![self.m_self(), getPendingNotificationRequestsWithCompletionHandler: block]
+            },
+        )
+        .await
+    }
+
+    /// Fetches all of your app’s delivered notifications that are still present in
+    /// Notification Center.
+    pub async fn get_delivered_notifications(
+        &self,
+    ) -> Result<NSArray<UNNotification>, BlockDropped> {
+        BlockFuture::send(
+            |completion| {
+                move |notifications: id| {
+                    completion.complete(unsafe { NSArray::from_id(notifications) })
+                }
+            },
+            |block| unsafe {
+                msg_send![self.m_self(), getDeliveredNotificationsWithCompletionHandler: block]
+            },
+        )
+        .await
+    }
+
+    /// Fetches your app’s registered notification categories.
+    pub async fn get_notification_categories(
+        &self,
+    ) -> Result<NSSet<UNNotificationCategory>, BlockDropped> {
+        BlockFuture::send(
+            |completion| {
+                move |categories: id| completion.complete(unsafe { NSSet::from_id(categories) })
+            },
+            |block| unsafe {
+                msg_send![self.m_self(), getNotificationCategoriesWithCompletionHandler: block]
+            },
+        )
+        .await
+    }
+}
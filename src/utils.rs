@@ -1,6 +1,9 @@
 use objc::runtime::{BOOL, NO, YES};
 
-use crate::objective_c_runtime::{id, nil, traits::FromId};
+use crate::{
+    foundation::NSError,
+    objective_c_runtime::{id, nil, traits::FromId},
+};
 use objc::runtime::Object;
 
 /// A helper function to convert an Objective-C bool to a Rust bool.
@@ -31,7 +34,17 @@ where
     }
 }
 
+/// Pairs the value a completion handler receives with its error, which is nil on success.
+#[allow(dead_code)]
+pub fn to_result<T>(value: T, error: id) -> Result<T, NSError> {
+    match to_optional(error) {
+        Some(error) => Err(error),
+        None => Ok(value),
+    }
+}
+
 /// Getting the instance variable of an object.
+#[allow(dead_code)]
 pub fn get_variable<'a, T>(this: &'a Object, ptr_name: &str) -> &'a T {
     unsafe {
         let ptr: usize = *this.get_ivar(ptr_name);
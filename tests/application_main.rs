@@ -0,0 +1,43 @@
+#![cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    appkit::{MainThreadMarker, NSMenu},
+    objective_c_runtime::{runtime::Sel, sel, sel_impl, InvocationValue},
+    test_runtime,
+};
+
+#[test]
+fn test_default_main_menu() {
+    test_runtime::reset();
+    test_runtime::set_main_thread(true);
+    test_runtime::respond_class("NSProcessInfo", "processInfo", |_| {
+        InvocationValue::Object(test_runtime::new_object("NSProcessInfo"))
+    });
+    test_runtime::respond("NSProcessInfo", "processName", |_| {
+        InvocationValue::Object(test_runtime::new_object("NSString"))
+    });
+    test_runtime::respond("NSString", "description", |call| {
+        InvocationValue::Object(call.receiver)
+    });
+    test_runtime::respond("NSString", "UTF8String", |_| {
+        InvocationValue::Pointer(c"Hello".as_ptr() as *mut _)
+    });
+    test_runtime::respond("NSMenu", "addItemWithTitle:action:keyEquivalent:", |_| {
+        InvocationValue::Object(test_runtime::new_object("NSMenuItem"))
+    });
+    let mtm = MainThreadMarker::new().unwrap();
+
+    NSMenu::default_main_menu(mtm);
+
+    let items = test_runtime::calls_to("addItemWithTitle:action:keyEquivalent:");
+    let actions: Vec<Sel> = items
+        .iter()
+        .map(|call| unsafe { call.argument(1) })
+        .collect();
+    assert_eq!(actions, [sel!(hide:), sel!(terminate:)]);
+
+    assert_eq!(test_runtime::calls_to("setSubmenu:").len(), 1);
+    assert_eq!(test_runtime::calls_to("addItem:").len(), 1);
+}
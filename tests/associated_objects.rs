@@ -0,0 +1,66 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use rust_macios::{
+    foundation::{NSMutableArray, NSString},
+    objective_c_runtime::{traits::PNSObject, AssociatedObjects, AssociationPolicy},
+};
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_values_are_stored_per_type() {
+    let array = NSMutableArray::<NSString>::m_new();
+
+    assert!(array.associated::<u32>().is_none());
+
+    array.set_associated(7_u32);
+    array.set_associated(String::from("state"));
+    assert_eq!(*array.associated::<u32>().unwrap(), 7);
+    assert_eq!(*array.associated::<String>().unwrap(), "state");
+
+    array.set_associated_with_policy(8_u32, AssociationPolicy::Copy);
+    assert_eq!(*array.associated::<u32>().unwrap(), 8);
+
+    array.remove_associated::<u32>();
+    assert!(array.associated::<u32>().is_none());
+    assert!(array.associated::<String>().is_some());
+}
+
+#[test]
+fn test_values_are_freed_with_the_object() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let array = NSMutableArray::<NSString>::m_new();
+
+    array.set_associated(DropFlag(dropped.clone()));
+    let value = array.associated::<DropFlag>().unwrap();
+
+    drop(array);
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    drop(value);
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_replacing_a_value_frees_the_old_one() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let array = NSMutableArray::<NSString>::m_new();
+
+    array.set_associated(DropFlag(dropped.clone()));
+    array.set_associated(DropFlag(Arc::new(AtomicBool::new(false))));
+
+    assert!(dropped.load(Ordering::SeqCst));
+}
@@ -0,0 +1,82 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    foundation::{NSMutableArray, NSString, UInt},
+    objective_c_runtime::{
+        autoreleasepool, msg_send, sel, sel_impl,
+        traits::{PNSObject, ToId},
+    },
+};
+
+fn retain_count<T: PNSObject>(object: &T) -> UInt {
+    unsafe { msg_send![object.m_self(), retainCount] }
+}
+
+#[test]
+fn test_nested_pools_drain_innermost_first() {
+    let array = NSMutableArray::<NSString>::m_new();
+
+    autoreleasepool(|outer| {
+        array.clone().to_id();
+        assert!(outer.is_innermost());
+
+        autoreleasepool(|inner| {
+            array.clone().to_id();
+            assert!(inner.is_innermost());
+            assert!(!outer.is_innermost());
+            assert_eq!(retain_count(&array), 3);
+        });
+
+        assert!(outer.is_innermost());
+        assert_eq!(retain_count(&array), 2);
+    });
+
+    assert_eq!(retain_count(&array), 1);
+}
+
+#[test]
+fn test_autoreleasepool_returns_the_result() {
+    let length = autoreleasepool(|pool| {
+        let string = NSString::from("Hello, World!");
+        string.as_str(pool).unwrap().len()
+    });
+
+    assert_eq!(length, 13);
+}
+
+#[test]
+fn test_pool_drains_on_panic() {
+    let array = NSMutableArray::<NSString>::m_new();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        autoreleasepool(|_| {
+            array.clone().to_id();
+            panic!("unwinding through the pool");
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(retain_count(&array), 1);
+}
+
+#[test]
+#[should_panic(expected = "innermost pool")]
+fn test_autoreleasing_into_an_outer_pool_panics() {
+    autoreleasepool(|outer| {
+        autoreleasepool(|_| {
+            NSString::m_new().m_autorelease(outer);
+        });
+    });
+}
+
+#[test]
+#[should_panic(expected = "innermost pool")]
+fn test_factories_reject_an_outer_pool() {
+    autoreleasepool(|outer| {
+        autoreleasepool(|_| {
+            NSString::string(outer);
+        });
+    });
+}
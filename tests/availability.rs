@@ -0,0 +1,101 @@
+#![cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    appkit::{NSScreen, NSTitlebarSeparatorStyle, NSWindow},
+    available,
+    foundation::{NSOperatingSystemVersion, NSRect},
+    objective_c_runtime::{
+        encoding::TypeEncoding, traits::FromId, InvocationValue, Platform, Requirement, Unavailable,
+    },
+    test_runtime,
+};
+
+fn version(major: isize, minor: isize, patch_version: isize) -> NSOperatingSystemVersion {
+    NSOperatingSystemVersion {
+        major,
+        minor,
+        patch_version,
+    }
+}
+
+fn screen() -> NSScreen {
+    unsafe { NSScreen::from_id(test_runtime::new_object("NSScreen")) }
+}
+
+#[test]
+fn test_requirements() {
+    const REQUIREMENT: Requirement = Requirement::new("macos", "10.15.4");
+
+    assert_eq!(REQUIREMENT.platform, Platform::MacOS);
+    assert_eq!(REQUIREMENT.version, version(10, 15, 4));
+    assert_eq!(Requirement::new("ios", "15").version, version(15, 0, 0));
+}
+
+#[test]
+fn test_available_methods_send_on_newer_systems() {
+    test_runtime::reset();
+    test_runtime::set_os_version(12, 3, 0);
+    test_runtime::respond("NSScreen", "auxiliaryTopLeftArea", |_| {
+        let bytes = [0.0f64, 0.0, 100.0, 32.0]
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+
+        InvocationValue::Other(TypeEncoding::Unknown, bytes)
+    });
+
+    let area = screen().auxiliary_top_left_area().unwrap();
+
+    assert_eq!(area.size.width, 100.0);
+    assert_eq!(area.size.height, 32.0);
+    assert_eq!(test_runtime::calls_to("auxiliaryTopLeftArea").len(), 1);
+}
+
+#[test]
+fn test_unavailable_methods_do_not_send() {
+    test_runtime::reset();
+    test_runtime::set_os_version(11, 6, 0);
+
+    let area: Option<NSRect> = screen().auxiliary_top_left_area();
+
+    assert!(area.is_none());
+    assert!(test_runtime::calls_to("auxiliaryTopLeftArea").is_empty());
+}
+
+#[test]
+fn test_unavailable_errors() {
+    test_runtime::reset();
+    test_runtime::set_os_version(10, 15, 7);
+    let window = unsafe { NSWindow::<()>::from_id(test_runtime::new_object("NSWindow")) };
+
+    let error = window
+        .set_titlebar_separator_style(NSTitlebarSeparatorStyle::Line)
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        Unavailable {
+            platform: Platform::MacOS,
+            required: Some(version(11, 0, 0)),
+            running: version(10, 15, 7),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "requires macOS 11.0.0, but the process is running on 10.15.7"
+    );
+    assert!(test_runtime::calls_to("setTitlebarSeparatorStyle:").is_empty());
+}
+
+#[test]
+fn test_available_macro() {
+    test_runtime::reset();
+    test_runtime::set_os_version(12, 0, 0);
+
+    assert!(available!(macos = "12.0"));
+    assert!(available!(macos = "11.5", ios = "15.0"));
+    assert!(!available!(macos = "12.0.1"));
+    assert!(!available!(ios = "1.0"));
+}
@@ -0,0 +1,151 @@
+#![cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::ffi::CStr;
+
+use rust_macios::{
+    foundation::{FromNS, FromNSError, IntoNS, NSArray, NSNumber, NSObject, NSString},
+    objective_c_runtime::{
+        traits::{FromId, PNSObject},
+        InvocationValue,
+    },
+    test_runtime,
+};
+
+fn setup() {
+    test_runtime::reset();
+
+    for class in ["NSArray", "NSData", "NSNull", "NSNumber", "NSString"] {
+        test_runtime::stub_class(class, Some("NSObject"));
+    }
+}
+
+/// Creates a fake number that answers its value messages with `value`, and describes
+/// itself as `description`.
+fn number(value: f64, description: &'static CStr) -> NSObject {
+    let number = test_runtime::new_object("NSNumber");
+
+    test_runtime::respond("NSNumber", "doubleValue", move |_| {
+        InvocationValue::Double(value)
+    });
+    test_runtime::respond("NSNumber", "longLongValue", move |_| {
+        InvocationValue::LongLong(value as i64)
+    });
+    test_runtime::respond("NSNumber", "unsignedLongLongValue", move |_| {
+        InvocationValue::ULongLong(value as u64)
+    });
+    test_runtime::respond("NSNumber", "stringValue", |_| {
+        InvocationValue::Object(test_runtime::new_object("NSString"))
+    });
+    test_runtime::respond("NSString", "description", |call| {
+        InvocationValue::Object(call.receiver)
+    });
+    test_runtime::respond("NSString", "UTF8String", move |_| {
+        InvocationValue::Pointer(description.as_ptr() as *mut _)
+    });
+
+    unsafe { NSObject::from_retained_id(number) }
+}
+
+#[test]
+fn test_checks_classes() {
+    setup();
+    let number = unsafe { NSNumber::from_retained_id(test_runtime::new_object("NSNumber")) };
+
+    let error = String::from_ns(&number).unwrap_err();
+
+    assert_eq!(
+        error,
+        FromNSError::UnexpectedClass {
+            expected: "NSString",
+            found: "NSNumber".into(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "expected an instance of NSString, found an instance of NSNumber"
+    );
+    assert!(Vec::<i32>::from_ns(&number).is_err());
+}
+
+#[test]
+fn test_integers_must_fit() {
+    setup();
+    let number = number(300.0, c"300");
+
+    assert_eq!(u16::from_ns(&number), Ok(300));
+    assert_eq!(
+        u8::from_ns(&number).unwrap_err().to_string(),
+        "300 does not fit in u8"
+    );
+}
+
+#[test]
+fn test_integers_must_be_whole() {
+    setup();
+    let number = number(1.5, c"1.5");
+
+    assert_eq!(f64::from_ns(&number), Ok(1.5));
+    assert!(matches!(
+        i64::from_ns(&number),
+        Err(FromNSError::OutOfRange { target: "i64", .. })
+    ));
+}
+
+#[test]
+fn test_null_is_none() {
+    setup();
+    let null = unsafe { NSObject::from_retained_id(test_runtime::new_object("NSNull")) };
+
+    assert_eq!(Option::<String>::from_ns(&null), Ok(None));
+    assert!(String::from_ns(&null).is_err());
+}
+
+#[test]
+fn test_none_is_null() {
+    setup();
+    test_runtime::respond_class("NSNull", "null", |_| {
+        InvocationValue::Object(test_runtime::new_object("NSNull"))
+    });
+
+    let null = None::<NSString>.into_ns();
+
+    assert_eq!(null.class().name(), "NSNull");
+}
+
+#[test]
+fn test_arrays_convert_their_elements() {
+    setup();
+    let elements = [number(7.0, c"7"), unsafe {
+        NSObject::from_retained_id(test_runtime::new_object("NSNull"))
+    }];
+    let array =
+        unsafe { NSArray::<NSObject>::from_retained_id(test_runtime::new_object("NSArray")) };
+
+    let objects = elements
+        .iter()
+        .map(|element| element.m_self())
+        .collect::<Vec<_>>();
+    test_runtime::respond("NSArray", "count", |_| InvocationValue::ULong(2));
+    test_runtime::respond("NSArray", "objectAtIndex:", move |call| {
+        InvocationValue::Object(objects[unsafe { call.argument::<usize>(0) }])
+    });
+
+    assert_eq!(Vec::<Option<i64>>::from_ns(&array), Ok(vec![Some(7), None]));
+    assert!(Vec::<i64>::from_ns(&array).is_err());
+}
+
+#[test]
+fn test_bytes_convert_to_data() {
+    setup();
+
+    let data = vec![1u8, 2, 3].into_ns();
+
+    let calls = test_runtime::calls_to("initWithBytes:length:");
+    assert_eq!(calls.len(), 1);
+    assert_eq!(unsafe { calls[0].argument::<u64>(1) }, 3);
+    assert_eq!(data.class().name(), "NSData");
+    // The data object takes over the reference that `alloc` returned.
+    assert_eq!(unsafe { test_runtime::retain_count(data.m_self()) }, 1);
+}
@@ -0,0 +1,63 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    foundation::{NSArray, NSMutableArray, NSString},
+    object,
+    objective_c_runtime::{
+        class, msg_send, nil, sel, sel_impl,
+        traits::{FromId, PNSObject, Subclass},
+    },
+};
+
+object! {
+    unsafe struct NSMutableString: NSString;
+}
+
+#[test]
+fn test_try_from_id_nil() {
+    assert!(unsafe { NSString::try_from_id(nil) }.is_none());
+    assert!(unsafe { NSString::try_from_retained_id(nil) }.is_none());
+
+    let string = NSString::from("value");
+    let copy = unsafe { NSString::try_from_id(string.m_self()) }.unwrap();
+    assert_eq!(copy, "value");
+}
+
+#[test]
+fn test_downcast_to_superclass_and_subclass() {
+    let mutable = NSMutableArray::<NSString>::m_new();
+    let array: NSArray<NSString> = mutable.downcast().unwrap();
+
+    assert!(array.downcast::<NSMutableArray<NSString>>().is_some());
+    assert!(array.downcast::<NSString>().is_none());
+}
+
+#[test]
+fn test_downcast_unrelated_class() {
+    let string = NSString::from("value");
+
+    assert!(string.downcast::<NSArray<NSString>>().is_none());
+    assert!(string.downcast::<NSString>().is_some());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "expected an instance of NSArray")]
+fn test_from_id_checks_the_class() {
+    let string = NSString::from("value");
+
+    let _ = unsafe { NSArray::<NSString>::from_id(string.m_self()) };
+}
+
+#[test]
+fn test_upcast_to_superclass() {
+    let mutable =
+        unsafe { NSMutableString::from_retained_id(msg_send![class!(NSMutableString), new]) };
+    let _: () = unsafe { msg_send![mutable.m_self(), appendString: NSString::from("value")] };
+
+    let string: NSString = mutable.upcast();
+    assert_eq!(string, "value");
+    assert_eq!(string.m_self(), mutable.m_self());
+}
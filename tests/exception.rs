@@ -0,0 +1,69 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    foundation::{
+        ns_array::INSArray, ns_exception_name, INSMutableArray, NSArray, NSException,
+        NSMutableArray, NSString, UInt,
+    },
+    objective_c_runtime::{autoreleasepool, catch_exception, id, sel, sel_impl, traits::PNSObject},
+    try_msg_send,
+};
+
+#[test]
+fn test_out_of_range_access_returns_the_exception() {
+    let array = NSArray::from(vec!["a", "b"]);
+
+    let exception = array.m_try_object_at_index(2).unwrap_err();
+
+    assert_eq!(exception.name(), unsafe {
+        ns_exception_name::RangeException.clone()
+    });
+    assert!(exception.reason().is_some());
+    assert_eq!(array.m_try_object_at_index(1).unwrap(), "b");
+}
+
+#[test]
+fn test_mutable_array_accessors_return_the_exception() {
+    let mut array = NSMutableArray::<NSString>::m_new();
+    let string = NSString::from("a");
+
+    assert!(array.im_try_remove_object_at_index(0).is_err());
+    assert!(array
+        .im_try_replace_object_at_index_with_object(0, &string)
+        .is_err());
+    assert!(array.im_try_insert_object_at_index(1, &string).is_err());
+
+    array.im_try_insert_object_at_index(0, &string).unwrap();
+    assert_eq!(array.p_count(), 1);
+}
+
+#[test]
+fn test_try_msg_send_returns_the_result() {
+    let array = NSArray::from(vec!["a", "b"]);
+
+    let count: Result<UInt, _> = unsafe { try_msg_send![array.m_self(), count] };
+    assert_eq!(count.unwrap(), 2);
+
+    let object: Result<id, _> = unsafe { try_msg_send![array.m_self(), objectAtIndex: 5u64] };
+    assert!(object.is_err());
+}
+
+#[test]
+fn test_raised_exception_keeps_its_name_and_reason() {
+    autoreleasepool(|pool| {
+        let exception = NSException::exception_with_name_reason_user_info(
+            NSString::from("RSTTestException"),
+            Some(NSString::from("Something went wrong")),
+            None,
+            pool,
+        );
+
+        let caught = unsafe { catch_exception(|| exception.raise()) }.unwrap_err();
+
+        assert_eq!(caught.name(), "RSTTestException");
+        assert_eq!(caught.reason().unwrap(), "Something went wrong");
+        assert!(caught.user_info().is_none());
+    });
+}
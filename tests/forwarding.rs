@@ -0,0 +1,134 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::cell::RefCell;
+
+use rust_macios::{
+    foundation::{NSInvocation, NSMethodSignature, NSString, UInt},
+    objective_c_runtime::{
+        autoreleasepool, catch_exception,
+        encoding::{MethodEncoding, TypeEncoding},
+        id, msg_send,
+        runtime::{Sel, BOOL, NO, YES},
+        sel, sel_impl,
+        traits::PNSObject,
+        ForwardingTarget, Invocation, InvocationValue, Proxy,
+    },
+};
+
+/// Adds numbers, names itself and records the selectors it receives.
+#[derive(Default)]
+struct Recorder {
+    selectors: RefCell<Vec<Sel>>,
+}
+
+impl ForwardingTarget for Recorder {
+    fn method_encoding(&self, selector: Sel) -> Option<MethodEncoding> {
+        if selector == sel!(add:to:) {
+            Some("q@:qq".parse().unwrap())
+        } else if selector == sel!(name) {
+            Some("@@:".parse().unwrap())
+        } else if selector == sel!(reset) {
+            Some("v@:".parse().unwrap())
+        } else {
+            None
+        }
+    }
+
+    fn forward_invocation(&self, invocation: &Invocation) -> InvocationValue {
+        self.selectors.borrow_mut().push(invocation.selector);
+
+        match invocation.arguments[..] {
+            [InvocationValue::LongLong(a), InvocationValue::LongLong(b)] => {
+                InvocationValue::LongLong(a + b)
+            }
+            _ if invocation.selector == sel!(name) => {
+                InvocationValue::from_object(NSString::from("recorder"))
+            }
+            _ => InvocationValue::Void,
+        }
+    }
+}
+
+#[test]
+fn test_proxy_forwards_messages() {
+    let proxy = Proxy::new(Recorder::default());
+
+    let sum: i64 = unsafe { msg_send![proxy.m_self(), add: 2_i64 to: 3_i64] };
+    assert_eq!(sum, 5);
+
+    let name: id = unsafe { msg_send![proxy.m_self(), name] };
+    assert_eq!(
+        unsafe { InvocationValue::Object(name).to_object::<NSString>() }.unwrap(),
+        "recorder"
+    );
+
+    let _: () = unsafe { msg_send![proxy.m_self(), reset] };
+
+    assert_eq!(
+        *proxy.target().selectors.borrow(),
+        [sel!(add:to:), sel!(name), sel!(reset)]
+    );
+}
+
+#[test]
+fn test_proxy_responds_to_target_selectors() {
+    let proxy = Proxy::new(Recorder::default());
+    let responds = |selector: Sel| -> BOOL {
+        unsafe { msg_send![proxy.m_self(), respondsToSelector: selector] }
+    };
+
+    assert_eq!(responds(sel!(add:to:)), YES);
+    assert_eq!(responds(sel!(missing)), NO);
+}
+
+#[test]
+fn test_proxy_raises_for_unknown_selectors() {
+    let proxy = Proxy::new(Recorder::default());
+
+    let result = unsafe {
+        catch_exception(|| {
+            let _: () = msg_send![proxy.m_self(), missing];
+        })
+    };
+    assert!(result.is_err());
+    assert!(proxy.target().selectors.borrow().is_empty());
+}
+
+#[test]
+fn test_method_signature() {
+    autoreleasepool(|pool| {
+        let signature = NSMethodSignature::signature_with_objc_types("q@:qd", pool).unwrap();
+
+        assert_eq!(signature.number_of_arguments(), 4);
+        assert_eq!(
+            signature.encoding(),
+            MethodEncoding::new(
+                TypeEncoding::LongLong,
+                [
+                    TypeEncoding::Object(None),
+                    TypeEncoding::Sel,
+                    TypeEncoding::LongLong,
+                    TypeEncoding::Double,
+                ]
+            )
+        );
+    });
+}
+
+#[test]
+fn test_invocation_invokes_a_method() {
+    let string = NSString::from("value");
+
+    autoreleasepool(|pool| {
+        let encoding = format!("{}@:", TypeEncoding::of::<UInt>());
+        let signature = NSMethodSignature::signature_with_objc_types(&encoding, pool).unwrap();
+
+        let invocation = NSInvocation::invocation_with_method_signature(&signature, pool);
+        invocation.set_selector(sel!(length));
+        invocation.invoke_with_target(string.m_self());
+
+        assert_eq!(unsafe { invocation.return_value::<UInt>() }, 5);
+    });
+}
@@ -0,0 +1,96 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::objective_c_runtime::{
+    class_list, dump_class,
+    encoding::TypeEncoding,
+    objc_sel, register_class,
+    runtime::{Object, Protocol},
+    ClassIntrospection, ProtocolIntrospection, ShareId,
+};
+
+struct Inspected {
+    #[allow(dead_code)]
+    ptr: ShareId<Object>,
+}
+
+#[register_class(NSObject, ivars { value: f64 }, name = "RMInspected")]
+impl Inspected {
+    #[objc_sel("scaleBy:")]
+    fn scale_by(&self, factor: f64) -> f64 {
+        factor
+    }
+
+    #[objc_sel("+defaultValue")]
+    fn default_value() -> f64 {
+        1.0
+    }
+}
+
+#[test]
+fn test_methods_and_ivars_of_a_registered_class() {
+    let class = Inspected::register_class();
+
+    let method = class
+        .methods()
+        .into_iter()
+        .find(|method| method.name == "scaleBy:")
+        .unwrap();
+    let encoding = method.encoding.unwrap();
+    assert_eq!(encoding.return_type, TypeEncoding::Double);
+    assert_eq!(encoding.arguments.last(), Some(&TypeEncoding::Double));
+
+    assert!(class
+        .class_methods()
+        .iter()
+        .any(|method| method.name == "defaultValue"));
+
+    let ivar = class
+        .ivars()
+        .into_iter()
+        .find(|ivar| ivar.name == "value")
+        .unwrap();
+    assert_eq!(ivar.encoding, Some(TypeEncoding::Double));
+    assert!(ivar.offset > 0);
+}
+
+#[test]
+fn test_class_hierarchy_and_class_list() {
+    let class = Inspected::register_class();
+
+    let names = class
+        .class_hierarchy()
+        .iter()
+        .map(|class| class.name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["RMInspected", "NSObject"]);
+
+    assert!(class_list()
+        .iter()
+        .any(|listed| listed.name() == "RMInspected"));
+}
+
+#[test]
+fn test_protocol_method_descriptions() {
+    let protocol = Protocol::get("NSObject").unwrap();
+
+    let method = protocol
+        .method_descriptions()
+        .into_iter()
+        .find(|method| method.name == "isEqual:")
+        .unwrap();
+    assert!(method.required);
+    assert!(method.instance);
+}
+
+#[test]
+fn test_dump_class() {
+    let dump = dump_class(Inspected::register_class());
+
+    assert!(dump.starts_with("@interface RMInspected : NSObject\n"));
+    assert!(dump.contains("    value (d, offset "));
+    assert!(dump.contains("+ defaultValue ("));
+    assert!(dump.contains("- scaleBy: ("));
+    assert!(dump.ends_with("@end"));
+}
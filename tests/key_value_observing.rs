@@ -0,0 +1,138 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::sync::{Arc, Mutex, Once};
+
+use rust_macios::{
+    foundation::{
+        observe, KVOChange, NSKeyValueChange, NSKeyValueObservingOptions, NSNumber, NSString,
+    },
+    object,
+    objective_c_runtime::{
+        class,
+        declare::ClassDecl,
+        id, msg_send,
+        runtime::{Class, Object, Sel},
+        sel, sel_impl,
+        traits::{FromId, PNSObject},
+    },
+};
+
+extern "C" fn title(this: &Object, _: Sel) -> id {
+    unsafe { *this.get_ivar("title") }
+}
+
+extern "C" fn set_title(this: &mut Object, _: Sel, title: id) {
+    unsafe {
+        let old: id = *this.get_ivar("title");
+        let _: id = msg_send![title, retain];
+        this.set_ivar("title", title);
+        let _: () = msg_send![old, release];
+    }
+}
+
+/// A class with a key-value compliant `title` property.
+fn observable_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("RMObservable", class!(NSObject)).unwrap();
+
+        unsafe {
+            decl.add_ivar::<id>("title");
+            decl.add_method(sel!(title), title as extern "C" fn(&Object, Sel) -> id);
+            decl.add_method(
+                sel!(setTitle:),
+                set_title as extern "C" fn(&mut Object, Sel, id),
+            );
+        }
+
+        decl.register();
+    });
+
+    class!(RMObservable)
+}
+
+object! {
+    unsafe struct RMObservable;
+}
+
+fn new_observable() -> RMObservable {
+    unsafe { RMObservable::from_retained_id(msg_send![observable_class(), new]) }
+}
+
+fn set(observable: &RMObservable, value: &str) {
+    unsafe { msg_send![observable.m_self(), setTitle: NSString::from(value).m_self()] }
+}
+
+#[test]
+fn test_observe_old_and_new_values() {
+    let observable = new_observable();
+    set(&observable, "first");
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let observation = observe(
+        &observable,
+        "title",
+        &[NSKeyValueObservingOptions::OldNew],
+        {
+            let changes = changes.clone();
+            move |change: KVOChange<NSString>| changes.lock().unwrap().push(change)
+        },
+    );
+
+    set(&observable, "second");
+
+    {
+        let changes = changes.lock().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, NSKeyValueChange::Setting);
+        assert_eq!(changes[0].old.clone().unwrap(), "first");
+        assert_eq!(changes[0].new.clone().unwrap(), "second");
+        assert!(changes[0].indexes.is_none());
+        assert!(!changes[0].is_prior);
+    }
+
+    drop(observation);
+    set(&observable, "third");
+    assert_eq!(changes.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_initial_and_prior_notifications() {
+    let observable = new_observable();
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let _observation = observe(
+        &observable,
+        "title",
+        &[
+            NSKeyValueObservingOptions::Initial,
+            NSKeyValueObservingOptions::Prior,
+        ],
+        {
+            let changes = changes.clone();
+            move |change: KVOChange<NSString>| changes.lock().unwrap().push(change.is_prior)
+        },
+    );
+
+    set(&observable, "value");
+
+    assert_eq!(*changes.lock().unwrap(), [false, true, false]);
+}
+
+#[test]
+fn test_values_of_other_classes_are_none() {
+    let observable = new_observable();
+
+    let values = Arc::new(Mutex::new(Vec::new()));
+    let _observation = observe(&observable, "title", &[NSKeyValueObservingOptions::New], {
+        let values = values.clone();
+        move |change: KVOChange<NSNumber>| values.lock().unwrap().push(change.new.is_none())
+    });
+
+    set(&observable, "value");
+
+    assert_eq!(*values.lock().unwrap(), [true]);
+}
@@ -0,0 +1,76 @@
+#![cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+
+use std::{panic, thread};
+
+use rust_macios::{
+    appkit::{run_on_main, MainThreadMarker, NSMenu, NSStatusBar},
+    objective_c_runtime::InvocationValue,
+    test_runtime,
+};
+
+#[test]
+fn test_markers_need_the_main_thread() {
+    test_runtime::reset();
+    assert!(MainThreadMarker::new().is_none());
+
+    test_runtime::set_main_thread(true);
+    assert!(MainThreadMarker::new().is_some());
+}
+
+#[test]
+fn test_constructors_take_markers() {
+    test_runtime::reset();
+    test_runtime::set_main_thread(true);
+    test_runtime::respond_class("NSStatusBar", "systemStatusBar", |_| {
+        InvocationValue::Object(test_runtime::new_object("NSStatusBar"))
+    });
+    let mtm = MainThreadMarker::new().unwrap();
+
+    let bar = NSStatusBar::system_status_bar(mtm);
+    let calls = test_runtime::calls_to("systemStatusBar");
+
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].to_string(), "+[NSStatusBar systemStatusBar]");
+    assert_eq!(bar.class().name(), "NSStatusBar");
+}
+
+#[test]
+fn test_run_on_main_from_other_threads() {
+    let call = thread::spawn(|| {
+        test_runtime::reset();
+
+        run_on_main(|mtm| {
+            assert!(MainThreadMarker::new().is_some());
+            NSMenu::new(mtm);
+        });
+
+        assert!(MainThreadMarker::new().is_none());
+        test_runtime::calls_to("new")[0].to_string()
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(call, "+[NSMenu new]");
+}
+
+#[test]
+fn test_run_on_main_on_the_main_thread() {
+    test_runtime::reset();
+    test_runtime::set_main_thread(true);
+
+    assert_eq!(run_on_main(|_| 42), 42);
+    assert!(MainThreadMarker::new().is_some());
+}
+
+#[test]
+fn test_run_on_main_resumes_panics() {
+    test_runtime::reset();
+
+    let result = panic::catch_unwind(|| run_on_main(|_| panic!("on the main thread")));
+
+    assert_eq!(
+        *result.unwrap_err().downcast::<&str>().unwrap(),
+        "on the main thread"
+    );
+    assert!(MainThreadMarker::new().is_none());
+}
@@ -0,0 +1,115 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    foundation::{NSMutableArray, NSString, UInt},
+    objective_c_runtime::{
+        autoreleasepool, msg_send, sel, sel_impl,
+        traits::{PNSObject, ToId},
+        Autoreleased, Weak,
+    },
+};
+
+fn retain_count<T: PNSObject>(object: &T) -> UInt {
+    unsafe { msg_send![object.m_self(), retainCount] }
+}
+
+#[test]
+fn test_new_is_taken_over() {
+    let array = NSMutableArray::<NSString>::m_new();
+
+    assert_eq!(retain_count(&array), 1);
+}
+
+#[test]
+fn test_alloc_is_taken_over_by_init() {
+    let array = NSMutableArray::<NSString>::m_alloc().init();
+
+    assert_eq!(retain_count(&array), 1);
+}
+
+#[test]
+fn test_clone_retains_and_drop_releases() {
+    let array = NSMutableArray::<NSString>::m_new();
+    let clone = array.clone();
+
+    assert_eq!(retain_count(&array), 2);
+    drop(clone);
+    assert_eq!(retain_count(&array), 1);
+}
+
+#[test]
+fn test_autoreleased_does_not_release() {
+    let array = NSMutableArray::<NSString>::m_new();
+
+    autoreleasepool(|pool| {
+        let borrowed: Autoreleased<'_, NSMutableArray<NSString>> =
+            unsafe { Autoreleased::new(array.m_self(), pool) };
+        let retained = borrowed.retain();
+        assert_eq!(retain_count(&*borrowed.clone()), 2);
+
+        drop(retained);
+        assert_eq!(retain_count(&array), 1);
+    });
+}
+
+#[test]
+fn test_autorelease_releases_when_the_pool_drains() {
+    let array = NSMutableArray::<NSString>::m_new();
+    let other = array.clone();
+
+    autoreleasepool(|pool| {
+        let borrowed = other.clone().m_autorelease(pool);
+        assert_eq!(retain_count(&*borrowed), 3);
+    });
+
+    assert_eq!(retain_count(&array), 2);
+}
+
+#[test]
+fn test_to_id_hands_the_reference_to_the_pool() {
+    let array = NSMutableArray::<NSString>::m_new();
+    let other = array.clone();
+
+    autoreleasepool(|_| {
+        let ptr = array.to_id();
+        assert_eq!(ptr, other.m_self());
+        assert_eq!(retain_count(&other), 2);
+    });
+
+    assert_eq!(retain_count(&other), 1);
+}
+
+#[test]
+fn test_weak_upgrades_while_the_object_is_alive() {
+    let array = NSMutableArray::<NSString>::m_new();
+    let weak = Weak::new(&array);
+
+    let upgraded = weak.upgrade().unwrap();
+    assert_eq!(upgraded.m_self(), array.m_self());
+    assert_eq!(retain_count(&array), 2);
+    drop(upgraded);
+
+    let clone = weak.clone();
+    drop(array);
+    assert!(weak.upgrade().is_none());
+    assert!(clone.upgrade().is_none());
+}
+
+#[test]
+fn test_weak_set() {
+    let first = NSMutableArray::<NSString>::m_new();
+    let second = NSMutableArray::<NSString>::m_new();
+    let weak = Weak::<NSMutableArray<NSString>>::default();
+    assert!(weak.upgrade().is_none());
+
+    weak.set(Some(&first));
+    assert_eq!(weak.upgrade().unwrap().m_self(), first.m_self());
+
+    weak.set(Some(&second));
+    assert_eq!(weak.upgrade().unwrap().m_self(), second.m_self());
+
+    weak.set(None);
+    assert!(weak.upgrade().is_none());
+}
@@ -0,0 +1,172 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::{cell::Cell, env, process::Command};
+
+use rust_macios::objective_c_runtime::{
+    id, msg_send, nil, protocol, protocol_impl,
+    runtime::{Sel, BOOL, NO},
+    sel, sel_impl,
+};
+
+static DELEGATE_PTR: &str = "rmCounterDelegatePtr";
+
+#[protocol(class = "RMCounterDelegate", register = register_counter_delegate_class, ivar = DELEGATE_PTR)]
+trait PCounterDelegate {
+    #[objc_sel("counter:didIncrementBy:")]
+    fn did_increment_by(&self, step: i64);
+
+    #[objc_sel("counterShouldReset:")]
+    fn should_reset(&self) -> bool {
+        false
+    }
+
+    #[objc_sel("counterWillReset:")]
+    fn will_reset(&self) {}
+}
+
+#[derive(Default)]
+struct Recorder {
+    total: Cell<i64>,
+    resets: Cell<i64>,
+}
+
+#[protocol_impl]
+impl PCounterDelegate for Recorder {
+    fn did_increment_by(&self, step: i64) {
+        self.total.set(self.total.get() + step);
+    }
+
+    fn should_reset(&self) -> bool {
+        self.resets.set(self.resets.get() + 1);
+        true
+    }
+}
+
+#[derive(Default)]
+struct Defaults;
+
+#[protocol_impl]
+impl PCounterDelegate for Defaults {
+    fn did_increment_by(&self, _step: i64) {}
+}
+
+/// Written before `#[protocol_impl]` existed.
+struct Unmarked;
+
+impl PCounterDelegate for Unmarked {
+    fn did_increment_by(&self, _step: i64) {}
+}
+
+struct Panicking;
+
+#[protocol_impl]
+impl PCounterDelegate for Panicking {
+    fn did_increment_by(&self, _step: i64) {
+        panic!("the delegate panicked");
+    }
+}
+
+/// Set in the process that `test_panics_in_the_delegate_exit_the_process` spawns.
+const PANIC_IN_DELEGATE: &str = "RUST_MACIOS_PANIC_IN_DELEGATE";
+
+fn new_delegate<T: PCounterDelegate + 'static>(delegate: &T) -> id {
+    unsafe {
+        let object: id = msg_send![register_counter_delegate_class::<T>().unwrap(), new];
+        (*object).set_ivar(DELEGATE_PTR, delegate as *const T as usize);
+        object
+    }
+}
+
+fn responds_to(object: id, selector: Sel) -> bool {
+    let responds: BOOL = unsafe { msg_send![object, respondsToSelector: selector] };
+    responds != NO
+}
+
+#[test]
+fn test_trampolines_forward_to_the_delegate() {
+    let recorder = Recorder::default();
+    let object = new_delegate(&recorder);
+
+    unsafe {
+        let _: () = msg_send![object, counter: nil didIncrementBy: 2i64];
+        let _: () = msg_send![object, counter: nil didIncrementBy: 3i64];
+
+        let should_reset: bool = msg_send![object, counterShouldReset: nil];
+        assert!(should_reset);
+
+        let _: () = msg_send![object, release];
+    }
+
+    assert_eq!(recorder.total.get(), 5);
+    assert_eq!(recorder.resets.get(), 1);
+}
+
+#[test]
+fn test_only_overridden_methods_are_added() {
+    let recorder = Recorder::default();
+    let object = new_delegate(&recorder);
+
+    assert!(responds_to(object, sel!(counter:didIncrementBy:)));
+    assert!(responds_to(object, sel!(counterShouldReset:)));
+    assert!(!responds_to(object, sel!(counterWillReset:)));
+
+    unsafe {
+        let _: () = msg_send![object, release];
+    }
+}
+
+#[test]
+fn test_defaults_are_not_added() {
+    let object = new_delegate(&Defaults);
+
+    assert!(responds_to(object, sel!(counter:didIncrementBy:)));
+    assert!(!responds_to(object, sel!(counterShouldReset:)));
+    assert!(!responds_to(object, sel!(counterWillReset:)));
+
+    unsafe {
+        let _: () = msg_send![object, release];
+    }
+}
+
+#[test]
+fn test_unmarked_implementations_add_every_method() {
+    let object = new_delegate(&Unmarked);
+
+    assert!(responds_to(object, sel!(counter:didIncrementBy:)));
+    assert!(responds_to(object, sel!(counterShouldReset:)));
+    assert!(responds_to(object, sel!(counterWillReset:)));
+
+    unsafe {
+        let should_reset: bool = msg_send![object, counterShouldReset: nil];
+        assert!(!should_reset);
+
+        let _: () = msg_send![object, release];
+    }
+}
+
+#[test]
+fn test_panics_in_the_delegate_exit_the_process() {
+    if env::var_os(PANIC_IN_DELEGATE).is_some() {
+        let object = new_delegate(&Panicking);
+
+        unsafe {
+            let _: () = msg_send![object, counter: nil didIncrementBy: 1i64];
+        }
+        unreachable!("the process exits in the delegate");
+    }
+
+    let output = Command::new(env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "test_panics_in_the_delegate_exit_the_process",
+            "--nocapture",
+        ])
+        .env(PANIC_IN_DELEGATE, "1")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(101));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("the delegate panicked"));
+}
@@ -0,0 +1,192 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    },
+};
+
+use rust_macios::objective_c_runtime::{
+    class, class_init,
+    declare::ClassDecl,
+    id, msg_send, objc_sel, register_class, register_class_for_type,
+    runtime::{Class, Object, Sel},
+    sel, sel_impl, Id, ShareId,
+};
+
+struct Counter {
+    ptr: ShareId<Object>,
+}
+
+#[register_class(NSObject, protocols(NSCopying), ivars { count: i64 }, name = "RMCounter")]
+impl Counter {
+    fn new() -> Self {
+        Self {
+            ptr: unsafe { Id::from_retained_ptr(msg_send![Self::register_class(), new]) },
+        }
+    }
+
+    #[objc_sel("+defaultStep")]
+    fn default_step() -> i64 {
+        2
+    }
+}
+
+struct Wrapper<T> {
+    ptr: ShareId<Object>,
+    marker: PhantomData<T>,
+}
+
+#[register_class(NSObject)]
+impl<T: 'static> Wrapper<T> {}
+
+#[test]
+fn test_custom_name_and_protocols() {
+    let class = Counter::register_class();
+
+    assert_eq!(class.name(), "RMCounter");
+    assert!(class.conforms_to(
+        rust_macios::objective_c_runtime::runtime::Protocol::get("NSCopying").unwrap()
+    ));
+}
+
+#[test]
+fn test_class_method() {
+    let step: i64 = unsafe { msg_send![Counter::register_class(), defaultStep] };
+
+    assert_eq!(step, Counter::default_step());
+}
+
+#[test]
+fn test_ivars() {
+    let counter = Counter::new();
+
+    assert_eq!(counter.ivar_count(), 0);
+    counter.set_ivar_count(5);
+    assert_eq!(counter.ivar_count(), 5);
+}
+
+#[test]
+fn test_generic_types_get_distinct_classes() {
+    let first: &Class = Wrapper::<u8>::register_class();
+    let second: &Class = Wrapper::<u16>::register_class();
+
+    assert_ne!(first.name(), second.name());
+    assert_eq!(first.name(), Wrapper::<u8>::register_class().name());
+
+    let _ = Wrapper::<u8> {
+        ptr: unsafe { Id::from_retained_ptr(msg_send![first, new]) },
+        marker: PhantomData,
+    };
+}
+
+struct First {
+    ptr: ShareId<Object>,
+}
+
+#[register_class(NSObject, name = "RMDuplicate")]
+impl First {}
+
+struct Second {
+    ptr: ShareId<Object>,
+}
+
+#[register_class(NSObject, name = "RMDuplicate")]
+impl Second {}
+
+#[test]
+#[should_panic(expected = "is already declared")]
+fn test_fixed_names_do_not_collide() {
+    let first = First::register_class();
+    assert_eq!(first.name(), "RMDuplicate");
+
+    Second::register_class();
+}
+
+static BASE_INITS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn base_init(this: &Object, _: Sel) -> id {
+    BASE_INITS.fetch_add(1, Ordering::SeqCst);
+    unsafe { msg_send![super(this, class!(NSObject)), init] }
+}
+
+/// Declares `RMBase`, whose `init` counts its calls.
+fn declare_base() {
+    static DECLARE: Once = Once::new();
+
+    DECLARE.call_once(|| {
+        let mut decl = ClassDecl::new("RMBase", class!(NSObject)).unwrap();
+        unsafe {
+            decl.add_method(sel!(init), base_init as extern "C" fn(&Object, Sel) -> id);
+        }
+        decl.register();
+    });
+}
+
+struct Derived {
+    ptr: ShareId<Object>,
+}
+
+#[register_class(RMBase)]
+impl Derived {
+    #[class_init]
+    fn init() -> Self {
+        Self {
+            ptr: unsafe { Id::from_retained_ptr(msg_send![class!(NSObject), new]) },
+        }
+    }
+}
+
+#[test]
+fn test_init_calls_the_declared_superclass() {
+    declare_base();
+
+    unsafe {
+        let object: id = msg_send![Derived::register_class(), alloc];
+        let object: id = msg_send![object, init];
+
+        assert_eq!(BASE_INITS.load(Ordering::SeqCst), 1);
+        let _: () = msg_send![object, release];
+    }
+}
+
+struct Outer;
+struct Inner;
+struct Taken;
+
+#[test]
+fn test_declare_may_register_other_classes() {
+    let mut inner = None;
+
+    let outer = register_class_for_type::<Outer, _>("RMNested", class!(NSObject), |_| {
+        inner = Some(register_class_for_type::<Inner, _>(
+            "RMNested",
+            class!(NSObject),
+            |_| {},
+        ));
+    })
+    .unwrap();
+
+    let inner = inner.unwrap().unwrap();
+
+    assert!(outer.name().starts_with("RMNested_") && outer.name().ends_with("Outer"));
+    assert!(inner.name().starts_with("RMNested_") && inner.name().ends_with("Inner"));
+}
+
+#[test]
+fn test_taken_names_are_numbered() {
+    // Take the name the type would get, whatever `type_name` spells it as.
+    let name = register_class_for_type::<Taken, _>("RMTakenProbe", class!(NSObject), |_| {})
+        .unwrap()
+        .name()
+        .replacen("RMTakenProbe_", "RMTaken_", 1);
+    ClassDecl::new(&name, class!(NSObject)).unwrap().register();
+
+    let class = register_class_for_type::<Taken, _>("RMTaken", class!(NSObject), |_| {}).unwrap();
+
+    assert_eq!(class.name(), format!("{name}_1"));
+}
@@ -0,0 +1,159 @@
+#![cfg(any(target_vendor = "apple", feature = "gnustep"))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::sync::Once;
+
+use rust_macios::objective_c_runtime::{
+    add_method, class,
+    declare::ClassDecl,
+    exchange_methods, id, msg_send, replace_method,
+    runtime::{Class, Object, Sel},
+    sel, sel_impl, wrap_method, Original, SwizzleError,
+};
+
+type Method = extern "C" fn(&Object, Sel, i64) -> i64;
+
+extern "C" fn doubled(_: &Object, _: Sel, value: i64) -> i64 {
+    value * 2
+}
+
+extern "C" fn tripled(_: &Object, _: Sel, value: i64) -> i64 {
+    value * 3
+}
+
+extern "C" fn halved(_: &Object, _: Sel, value: i64) -> i64 {
+    value / 2
+}
+
+extern "C" fn negated(_: &Object, _: Sel, value: i64) -> i64 {
+    -value
+}
+
+extern "C" fn squared(_: &Object, _: Sel, value: i64) -> i64 {
+    value * value
+}
+
+extern "C" fn incremented(_: &Object, _: Sel, value: i64) -> i64 {
+    value + 1
+}
+
+/// A class with a method per test, so that the tests can run in parallel.
+fn swizzled_class() -> &'static Class {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut decl = ClassDecl::new("RMSwizzled", class!(NSObject)).unwrap();
+
+        unsafe {
+            decl.add_method(sel!(doubled:), doubled as Method);
+            decl.add_method(sel!(tripled:), tripled as Method);
+            decl.add_method(sel!(halved:), halved as Method);
+            decl.add_method(sel!(negated:), negated as Method);
+            decl.add_method(sel!(squared:), squared as Method);
+            decl.add_method(sel!(incremented:), incremented as Method);
+        }
+
+        decl.register();
+    });
+
+    class!(RMSwizzled)
+}
+
+fn new_object() -> id {
+    unsafe { msg_send![swizzled_class(), new] }
+}
+
+#[test]
+fn test_replace_method_is_restored_on_drop() {
+    let object = new_object();
+    let call = || -> i64 { unsafe { msg_send![object, doubled: 4_i64] } };
+
+    let replaced = replace_method(swizzled_class(), sel!(doubled:), |_: id, value: i64| {
+        value + 1
+    })
+    .unwrap();
+    assert_eq!(call(), 5);
+
+    drop(replaced);
+    assert_eq!(call(), 8);
+}
+
+#[test]
+fn test_wrap_method_calls_the_original() {
+    let object = new_object();
+
+    let _wrapped = wrap_method(
+        swizzled_class(),
+        sel!(tripled:),
+        |original: &Original<(i64,), i64>, this: id, value: i64| unsafe {
+            original.call(this, value) + 1
+        },
+    )
+    .unwrap();
+
+    let result: i64 = unsafe { msg_send![object, tripled: 2_i64] };
+    assert_eq!(result, 7);
+}
+
+#[test]
+fn test_overrides_dropped_out_of_order_stay_until_the_later_ones_go() {
+    let object = new_object();
+    let call = || -> i64 { unsafe { msg_send![object, incremented: 1_i64] } };
+
+    let replaced = replace_method(swizzled_class(), sel!(incremented:), |_: id, value: i64| {
+        value + 10
+    })
+    .unwrap();
+    let wrapped = wrap_method(
+        swizzled_class(),
+        sel!(incremented:),
+        |original: &Original<(i64,), i64>, this: id, value: i64| unsafe {
+            original.call(this, value) * 2
+        },
+    )
+    .unwrap();
+    assert_eq!(call(), 22);
+
+    // The wrapper still calls the replacement.
+    drop(replaced);
+    assert_eq!(call(), 22);
+
+    drop(wrapped);
+    assert_eq!(call(), 2);
+}
+
+#[test]
+fn test_exchange_methods() {
+    let object = new_object();
+
+    let exchange = exchange_methods(swizzled_class(), sel!(halved:), sel!(negated:)).unwrap();
+    let result: i64 = unsafe { msg_send![object, halved: 8_i64] };
+    assert_eq!(result, -8);
+
+    drop(exchange);
+    let result: i64 = unsafe { msg_send![object, halved: 8_i64] };
+    assert_eq!(result, 4);
+}
+
+#[test]
+fn test_add_method() {
+    let object = new_object();
+
+    let _added = add_method(swizzled_class(), sel!(answer), |_: id| 42_i64).unwrap();
+    let result: i64 = unsafe { msg_send![object, answer] };
+    assert_eq!(result, 42);
+
+    let error = add_method(swizzled_class(), sel!(squared:), |_: id, _: i64| 0_i64).unwrap_err();
+    assert_eq!(error, SwizzleError::MethodExists("squared:".to_string()));
+}
+
+#[test]
+fn test_signatures_are_checked() {
+    let error =
+        replace_method(swizzled_class(), sel!(squared:), |_: id, _: f64| 0_i64).unwrap_err();
+    assert!(matches!(error, SwizzleError::IncompatibleSignature { .. }));
+
+    let error = replace_method(swizzled_class(), sel!(missing), |_: id| {}).unwrap_err();
+    assert_eq!(error, SwizzleError::MethodNotFound("missing".to_string()));
+}
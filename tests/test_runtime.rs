@@ -0,0 +1,249 @@
+#![cfg(all(feature = "test-runtime", not(target_vendor = "apple")))]
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use std::{
+    cell::Cell,
+    ffi::CStr,
+    future::Future,
+    os::raw::c_char,
+    pin::pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+use block::Block;
+use rust_macios::{
+    appkit::NSWindow,
+    contacts::{CNAuthorizationStatus, CNContact, CNContactStore, CNEntityType},
+    foundation::{NSArray, NSError, NSString, UInt},
+    objective_c_runtime::{
+        class, id, msg_send, nil, sel, sel_impl, send_message_unwind,
+        traits::{FromId, PNSObject},
+        Allocated, BlockDropped, CompletionError, InvocationValue,
+    },
+    test_runtime,
+    user_notifications::UNUserNotificationCenter,
+};
+
+#[test]
+fn test_records_messages() {
+    test_runtime::reset();
+    let window = unsafe { NSWindow::<()>::from_id(test_runtime::new_object("NSWindow")) };
+    let title = NSString::from("Title");
+
+    window.set_title(title.clone());
+
+    let calls = test_runtime::calls_to("setTitle:");
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].class, "NSWindow");
+    assert!(!calls[0].class_method);
+    assert_eq!(calls[0].receiver, window.m_self());
+    assert_eq!(unsafe { calls[0].argument::<id>(0) }, title.m_self());
+    assert_eq!(calls[0].to_string(), "-[NSWindow setTitle:]");
+}
+
+#[test]
+fn test_canned_responses() {
+    test_runtime::reset();
+    let window = unsafe { NSWindow::<()>::from_id(test_runtime::new_object("NSWindow")) };
+    assert!(!window.is_visible());
+
+    test_runtime::respond("NSWindow", "isVisible", |_| InvocationValue::Char(1));
+    assert!(window.is_visible());
+}
+
+#[test]
+fn test_class_method_responses() {
+    test_runtime::reset();
+    let center = test_runtime::new_object("UNUserNotificationCenter");
+    test_runtime::respond_class(
+        "UNUserNotificationCenter",
+        "currentNotificationCenter",
+        move |_| InvocationValue::Object(center),
+    );
+
+    let mut current = UNUserNotificationCenter::current_notification_center();
+    current.remove_all_delivered_notifications();
+
+    let calls = test_runtime::calls();
+    assert_eq!(
+        calls.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "+[UNUserNotificationCenter currentNotificationCenter]",
+            "-[UNUserNotificationCenter removeAllDeliveredNotifications]",
+        ]
+    );
+    assert_eq!(calls[1].receiver, center);
+}
+
+#[test]
+fn test_responses_see_the_arguments() {
+    test_runtime::reset();
+    test_runtime::respond_class(
+        "CNContactStore",
+        "authorizationStatusForEntityType:",
+        |call| match unsafe { call.argument::<CNEntityType>(0) } {
+            CNEntityType::Contacts => InvocationValue::LongLong(3),
+        },
+    );
+
+    assert_eq!(
+        CNContactStore::authorization_status_for_entity_type(CNEntityType::Contacts),
+        CNAuthorizationStatus::Authorized
+    );
+}
+
+#[test]
+fn test_responses_can_call_completion_handlers() {
+    test_runtime::reset();
+    test_runtime::respond(
+        "CNContactStore",
+        "requestAccessForEntityType:completionHandler:",
+        |call| {
+            let handler = unsafe { &*call.argument::<*mut Block<(bool, *mut NSError), ()>>(1) };
+            unsafe { handler.call((true, std::ptr::null_mut())) };
+            InvocationValue::Void
+        },
+    );
+
+    let granted = Rc::new(Cell::new(false));
+    let store = unsafe { CNContactStore::from_id(test_runtime::new_object("CNContactStore")) };
+    store.request_access_for_entity_type_completion_handler(CNEntityType::Contacts, {
+        let granted = granted.clone();
+        move |value, _| granted.set(value)
+    });
+
+    assert!(granted.get());
+}
+
+/// Polls `future` once, for futures that the fake runtime resolves while sending.
+fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+    pin!(future).poll(&mut Context::from_waker(Waker::noop()))
+}
+
+#[test]
+fn test_async_twins_fold_their_errors() {
+    test_runtime::reset();
+    test_runtime::respond(
+        "CNContactStore",
+        "requestAccessForEntityType:completionHandler:",
+        |call| {
+            let handler = unsafe { &*call.argument::<*mut Block<(bool, *mut NSError), ()>>(1) };
+            unsafe { handler.call((true, std::ptr::null_mut())) };
+            InvocationValue::Void
+        },
+    );
+
+    let store = unsafe { CNContactStore::from_id(test_runtime::new_object("CNContactStore")) };
+    let granted = poll_once(store.request_access_for_entity_type(CNEntityType::Contacts));
+    assert!(matches!(granted, Poll::Ready(Ok(true))));
+
+    // Without a response the block is released without being called.
+    test_runtime::reset();
+    let dropped = poll_once(store.request_access_for_entity_type(CNEntityType::Contacts));
+    assert!(matches!(
+        dropped,
+        Poll::Ready(Err(CompletionError::Dropped(BlockDropped)))
+    ));
+}
+
+#[test]
+fn test_fallible_getters_send_through_the_fake() {
+    test_runtime::reset();
+    let name = NSString::from("Ann");
+    let name_ptr = name.m_self();
+    test_runtime::respond("CNContact", "givenName", move |_| {
+        InvocationValue::Object(name_ptr)
+    });
+
+    let contact = unsafe { CNContact::from_id(test_runtime::new_object("CNContact")) };
+
+    assert_eq!(contact.try_given_name().unwrap().m_self(), name_ptr);
+    assert_eq!(test_runtime::calls_to("givenName").len(), 1);
+}
+
+#[test]
+fn test_failures_without_an_error_are_unreported() {
+    test_runtime::reset();
+    let store = unsafe { CNContactStore::from_id(test_runtime::new_object("CNContactStore")) };
+
+    // Unanswered messages return nil and leave the error out-parameter alone.
+    let result = store.unified_me_contact_with_keys_to_fetch(NSArray::new());
+
+    assert!(result.is_err());
+    let error = test_runtime::calls_to("initWithDomain:code:userInfo:");
+    assert_eq!(error.len(), 1);
+
+    let domain = test_runtime::calls_to("initWithBytes:length:encoding:")
+        .into_iter()
+        .find(|call| call.receiver == unsafe { error[0].argument::<id>(0) })
+        .unwrap();
+    let bytes = unsafe { CStr::from_ptr(domain.argument::<*const c_char>(0)) };
+    assert_eq!(bytes.to_str(), Ok(NSError::UNREPORTED_DOMAIN));
+}
+
+#[test]
+fn test_responses_are_inherited() {
+    test_runtime::reset();
+    test_runtime::stub_class("RSTWindow", Some("NSWindow"));
+    test_runtime::respond("NSWindow", "isVisible", |_| InvocationValue::Char(1));
+
+    let window = unsafe { NSWindow::<()>::from_id(test_runtime::new_object("RSTWindow")) };
+    assert!(window.is_visible());
+
+    let is_kind: bool = unsafe { msg_send![window.m_self(), isKindOfClass: class!(NSWindow)] };
+    assert!(is_kind);
+    let is_member: bool = unsafe { msg_send![window.m_self(), isMemberOfClass: class!(NSWindow)] };
+    assert!(!is_member);
+}
+
+#[test]
+fn test_tracks_retain_counts() {
+    test_runtime::reset();
+    let string = unsafe { NSString::from_retained_id(test_runtime::new_object("NSString")) };
+    let ptr = string.m_self();
+
+    let copy = string.clone();
+    assert_eq!(unsafe { test_runtime::retain_count(ptr) }, 2);
+
+    drop(copy);
+    drop(string);
+    assert_eq!(unsafe { test_runtime::retain_count(ptr) }, 0);
+    assert!(test_runtime::calls().is_empty());
+}
+
+#[test]
+fn test_allocated_objects_are_initialized_or_released() {
+    test_runtime::reset();
+    let string = NSString::m_alloc().init();
+
+    assert_eq!(test_runtime::calls_to("init").len(), 1);
+    assert_eq!(unsafe { test_runtime::retain_count(string.m_self()) }, 1);
+
+    let allocated = NSString::m_alloc();
+    let ptr = allocated.into_raw();
+    let allocated = unsafe { Allocated::<NSString>::new(ptr) };
+    drop(allocated);
+    assert_eq!(unsafe { test_runtime::retain_count(ptr) }, 0);
+}
+
+#[test]
+fn test_messages_to_nil() {
+    test_runtime::reset();
+    let value: id = unsafe { msg_send![nil, description] };
+
+    assert!(value.is_null());
+    assert!(test_runtime::calls().is_empty());
+}
+
+#[test]
+fn test_unwinding_messages_to_nil_return_zero() {
+    test_runtime::reset();
+    let count: UInt = unsafe { send_message_unwind(nil, sel!(count), ()) };
+    let value: id = unsafe { send_message_unwind(nil, sel!(description), ()) };
+
+    assert_eq!(count, 0);
+    assert!(value.is_null());
+    assert!(test_runtime::calls().is_empty());
+}
@@ -0,0 +1,13 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+
+    // These cases expand far enough to type-check against the runtime, which the fake one
+    // stands in for on Linux.
+    #[cfg(any(target_vendor = "apple", feature = "gnustep", feature = "test-runtime"))]
+    t.compile_fail("tests/ui/runtime/*.rs");
+
+    #[cfg(any(target_vendor = "apple", feature = "test-runtime"))]
+    t.compile_fail("tests/ui/appkit/*.rs");
+}
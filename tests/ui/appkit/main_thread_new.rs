@@ -0,0 +1,10 @@
+use rust_macios::{
+    appkit::{NSScreen, NSView},
+    objective_c_runtime::traits::PNSObject,
+};
+
+fn main() {
+    let _view = NSView::m_new();
+    let _view = NSView::m_alloc();
+    let _screen = NSScreen::main_screen();
+}
@@ -0,0 +1,13 @@
+use rust_macios::appkit::{MainThreadMarker, NSApplication, NSMenu, NSView, NSWindow};
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+fn main() {
+    assert_send::<MainThreadMarker>();
+    assert_send::<NSApplication>();
+    assert_send::<NSMenu>();
+    assert_send::<NSView>();
+    assert_sync::<NSWindow>();
+}
@@ -0,0 +1,21 @@
+// The items the macro rejects are dropped, leaving the imports unused.
+#![allow(unused_imports)]
+
+use rust_macios::objective_c_runtime::{objc_sel, register_class};
+
+struct DataSource;
+
+#[register_class(NSObject)]
+impl DataSource {
+    #[objc_sel("tableView:numberOfRowsInSection:")]
+    fn number_of_rows(&self, section: i64) -> i64 {
+        section
+    }
+
+    #[objc_sel("reloadData")]
+    fn reload_data(&self, animated: bool) {
+        let _ = animated;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// The items the macro rejects are dropped, leaving the imports unused.
+#![allow(unused_imports)]
+
+use rust_macios::objective_c_runtime::{class_init, objc_sel, register_class};
+
+struct Counter;
+
+#[register_class(NSObject)]
+impl Counter {
+    #[class_init]
+    fn init(start: i32) -> Self {
+        let _ = start;
+        Self
+    }
+
+    #[objc_sel("increment")]
+    fn increment(&mut self) {}
+
+    #[objc_sel("reset")]
+    fn reset() {}
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// The items the macro rejects are dropped, leaving the imports unused.
+#![allow(unused_imports)]
+
+use rust_macios::objective_c_runtime::{objc_sel, register_class};
+
+struct Controller;
+
+#[register_class(NSObject)]
+impl Controller {
+    #[objc_sel("setTitle:animated")]
+    fn set_title(&self, title: i32, animated: bool) {
+        let _ = (title, animated);
+    }
+
+    #[objc_sel("performAction::")]
+    fn perform_action(&self, first: i32, second: i32) {
+        let _ = (first, second);
+    }
+
+    #[objc_sel("2fast")]
+    fn too_fast(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use rust_macios::objective_c_runtime::protocol;
+
+#[protocol(class = "RMTableViewDelegate", register = register_table_view_delegate_class)]
+pub trait PTableViewDelegate {}
+
+#[protocol(
+    class = "RMTextFieldDelegate",
+    register = register_text_field_delegate_class,
+    ivar = "rmTextFieldDelegatePtr"
+)]
+pub trait PTextFieldDelegate {
+    #[objc_sel("controlTextDidChange:")]
+    fn did_change(&self, control: i64, notification: i64) {
+        let _ = (control, notification);
+    }
+
+    #[objc_sel("+controlClass")]
+    fn control_class(&self) {}
+
+    #[objc_sel("controlTextDidBeginEditing:")]
+    fn did_begin_editing(&mut self) {}
+
+    #[objc_sel("controlTextDidEndEditing:")]
+    fn did_end_editing(notification: i64) {
+        let _ = notification;
+    }
+}
+
+fn main() {}
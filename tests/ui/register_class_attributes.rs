@@ -0,0 +1,26 @@
+// The items the macro rejects are dropped, leaving the imports unused.
+#![allow(unused_imports)]
+
+use std::marker::PhantomData;
+
+use rust_macios::objective_c_runtime::{objc_sel, register_class};
+
+struct Cache<T>(PhantomData<T>);
+
+#[register_class(NSObject, name = "Cache")]
+impl<T> Cache<T> {}
+
+struct Store;
+
+#[register_class(NSObject, ivars { RUST_OBJ_PTR: usize })]
+impl Store {
+    #[objc_sel("+sharedStore")]
+    fn shared_store(&self) {}
+}
+
+struct Loader;
+
+#[register_class(NSObject, protocols = NSCopying)]
+impl Loader {}
+
+fn main() {}
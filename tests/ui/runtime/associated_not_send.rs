@@ -0,0 +1,11 @@
+use std::rc::Rc;
+
+use rust_macios::{
+    foundation::{NSMutableArray, NSString},
+    objective_c_runtime::{traits::PNSObject, AssociatedObjects},
+};
+
+fn main() {
+    let array = NSMutableArray::<NSString>::m_new();
+    array.set_associated(Rc::new(0_u32));
+}
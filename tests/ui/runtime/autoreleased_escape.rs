@@ -0,0 +1,34 @@
+use rust_macios::{
+    foundation::{NSData, NSRange, NSString},
+    objective_c_runtime::autoreleasepool,
+};
+
+fn main() {
+    let string = NSString::from("Hello, World!");
+    let bytes = [1u8, 2, 3];
+    let data = NSData::init_with_bytes_length(bytes.as_ptr().cast(), 3);
+
+    let escaped = autoreleasepool(|pool| NSString::string_with_string(string.clone(), pool));
+    println!("{}", escaped.length());
+
+    let escaped = autoreleasepool(|pool| string.uppercase_string(pool));
+    println!("{}", escaped.length());
+
+    let escaped = autoreleasepool(|pool| NSData::data(pool));
+    println!("{}", escaped.length());
+
+    let escaped = autoreleasepool(|pool| {
+        data.subdata_with_range(
+            NSRange {
+                location: 0,
+                length: 1,
+            },
+            pool,
+        )
+    });
+    println!("{}", escaped.length());
+
+    // Retaining the result gives an owned object, which may outlive the pool.
+    let retained = autoreleasepool(|pool| string.uppercase_string(pool).retain());
+    println!("{retained}");
+}
@@ -0,0 +1,14 @@
+use rust_macios::{
+    foundation::NSString,
+    objective_c_runtime::{autoreleasepool, traits::PNSObject},
+};
+
+fn main() {
+    let string = NSString::from("Hello, World!");
+
+    let escaped = autoreleasepool(|pool| string.as_str(pool).unwrap());
+    println!("{escaped}");
+
+    let escaped = autoreleasepool(|pool| NSString::m_new().m_autorelease(pool));
+    println!("{}", escaped.length());
+}
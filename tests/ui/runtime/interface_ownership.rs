@@ -0,0 +1,70 @@
+// `objc`'s macros expand to `cfg(feature = "cargo-clippy")`.
+#![allow(unexpected_cfgs)]
+
+use rust_macios::{
+    foundation::NSString,
+    object,
+    objective_c_runtime::{
+        id,
+        macros::interface_impl,
+        msg_send, sel, sel_impl,
+        traits::{FromId, PNSObject},
+    },
+};
+
+object! {
+    unsafe pub struct RMWidget;
+}
+
+#[interface_impl(NSObject)]
+impl RMWidget {
+    #[method]
+    pub fn copy_name(&self) -> NSString {
+        unsafe { NSString::from_id(msg_send![self.m_self(), copyName]) }
+    }
+
+    #[method]
+    pub fn widget_with_name(name: &NSString) -> Option<Self>
+    where
+        Self: Sized + FromId,
+    {
+        unsafe { Self::try_from_retained_id(msg_send![Self::m_class(), widgetWithName: name]) }
+    }
+
+    #[method]
+    pub fn init_with_name(&self, name: &NSString) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe { Self::from_retained_id(msg_send![self.m_self(), initWithName: name]) }
+    }
+
+    #[method]
+    pub fn init() -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_id(msg_send![obj, init])
+        }
+    }
+
+    #[method]
+    pub fn init_with_title(title: &NSString) -> Self
+    where
+        Self: Sized + FromId,
+    {
+        unsafe {
+            let obj: id = msg_send![Self::m_class(), alloc];
+            Self::from_retained_id(msg_send![obj, initWithTitle: title])
+        }
+    }
+
+    #[property]
+    pub fn name(&self) -> NSString {
+        unsafe { NSString::from_id(msg_send![self.m_self(), name]) }
+    }
+}
+
+fn main() {}